@@ -36,6 +36,8 @@ Commands:
 Options:
     -h, --help               print this help text
     --double                 run check with double-precision (implies 'api-custom' feature)
+    --third-party            generate bindings for classes of other GDExtensions, from
+                             itest/rust/third-party-api.json (also runs their itests).
     -f, --filter <arg>       only run integration tests which contain any of the
                              args (comma-separated). requires itest.
     -a, --api-version <ver>  specify the Godot API version to use (e.g. 4.3, 4.3.1).
@@ -176,12 +178,13 @@ function cmd_klippy() {
 }
 
 function cmd_test() {
-    run cargo test "${extraCargoArgs[@]}"
+    run cargo test "${extraCargoArgs[@]}" && \
+        run cargo test -p godot-core --features stub-interface "${extraCargoArgs[@]}"
 }
 
 function cmd_itest() {
     findGodot && \
-        run cargo build -p itest "${extraCargoArgs[@]}" || return 1
+        run cargo build -p itest -p shared-provider "${extraCargoArgs[@]}" || return 1
 
     # Logic to abort immediately if Godot outputs certain keywords (would otherwise fail only in CI).
     # Keep in sync with: .github/composite/godot-itest/action.yml (steps "Run Godot integration tests" and "Check for memory leaks").
@@ -257,6 +260,10 @@ while [[ $# -gt 0 ]]; do
         --double)
             extraCargoArgs+=("--features" "godot/double-precision,godot/api-custom")
             ;;
+        --third-party)
+            extraCargoArgs+=("--features" "itest/codegen-third-party")
+            export GODOT4_THIRD_PARTY_JSON="$PWD/itest/rust/third-party-api.json"
+            ;;
         fmt | test | itest | clippy | klippy | doc | dok)
             cmds+=("$arg")
             ;;
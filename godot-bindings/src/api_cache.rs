@@ -0,0 +1,483 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Cache of files dumped by the Godot binary, so that `api-custom` builds only launch Godot when the binary changes.
+//!
+//! Each Godot binary has a key file, named after its canonical path. The key stores the binary's modification time, size and
+//! `--version` output at the time of dumping:
+//! - If modification time and size are unchanged, the dumped files are reused without launching Godot.
+//! - Otherwise, `--version` is queried again. If the version is unchanged (e.g. binary was copied or touched), the files are still reused.
+//! - If the version changed, the files are dumped anew.
+//!
+//! Dumped files live in an entry directory named after both path and version. A version change thus never modifies or deletes an existing
+//! entry, which build scripts running concurrently may still read; entries of old versions are left in place. New entries are
+//! prepared in a temporary directory and renamed into place.
+//!
+//! The cache lives in `OUT_DIR` by default. Setting `GODOT4_API_CACHE_DIR` opts into a shared directory, which survives `cargo clean`.
+//!
+//! Alternatively, `GODOT4_DUMP_DIR` points to a directory with files dumped in advance; no binary is needed then.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use regex::Regex;
+
+use crate::godot_exe;
+use crate::godot_version::{parse_godot_version, validate_godot_version};
+use crate::{GodotVersion, StopWatch};
+
+/// Files that can be dumped by Godot.
+#[derive(Copy, Clone)]
+pub(crate) enum DumpFile {
+    ExtensionApi,
+    Header,
+}
+
+impl DumpFile {
+    /// Name of the file as written by Godot, and as expected in `GODOT4_DUMP_DIR`.
+    pub fn dumped_name(self) -> &'static str {
+        match self {
+            Self::ExtensionApi => "extension_api.json",
+            Self::Header => "gdextension_interface.h",
+        }
+    }
+
+    /// Name of the file in a cache entry.
+    fn cached_name(self) -> &'static str {
+        match self {
+            // With docs, the JSON is considerably larger and dumped with a different flag, so it's cached separately.
+            Self::ExtensionApi if cfg!(feature = "api-docs") => "extension_api_with_docs.json",
+            other => other.dumped_name(),
+        }
+    }
+}
+
+/// Returns the path to an up-to-date `file`, dumping it first if necessary.
+pub(crate) fn locate_dump(file: DumpFile, watch: &mut StopWatch) -> PathBuf {
+    match resolve_source(watch) {
+        ApiSource::PreDumped { dir } => {
+            let path = dir.join(file.dumped_name());
+            assert!(
+                path.is_file(),
+                "GODOT4_DUMP_DIR is set, but '{}' does not exist.\n\
+                 Create it with: godot --headless --dump-extension-api --dump-gdextension-interface",
+                path.display()
+            );
+
+            godot_exe::rerun_on_changed(&path);
+            path
+        }
+        ApiSource::Binary { godot_bin, entry } => {
+            let path = entry.dir.join(file.cached_name());
+            if !path.is_file() {
+                godot_exe::dump_file(&godot_bin, file, &path);
+                watch.record("dump_to_cache");
+            }
+
+            path
+        }
+    }
+}
+
+/// Returns the version of the Godot binary, or of the pre-dumped API.
+pub(crate) fn godot_version(watch: &mut StopWatch) -> GodotVersion {
+    match resolve_source(watch) {
+        ApiSource::PreDumped { dir } => {
+            version_from_json(&dir.join(DumpFile::ExtensionApi.dumped_name()))
+        }
+        ApiSource::Binary { entry, .. } => parse_stored_version(&entry.key.version),
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+enum ApiSource {
+    /// Files dumped in advance, from `GODOT4_DUMP_DIR`.
+    PreDumped { dir: PathBuf },
+
+    /// Cache entry for a Godot binary; missing files are dumped on demand.
+    Binary {
+        godot_bin: PathBuf,
+        entry: CacheEntry,
+    },
+}
+
+struct CacheEntry {
+    dir: PathBuf,
+    key: CacheKey,
+}
+
+/// Identifies the binary that an entry's files were dumped from.
+#[derive(Clone, Eq, PartialEq, Debug)]
+struct CacheKey {
+    path: String,
+    modified: String,
+    size: u64,
+
+    /// Trimmed output of `--version`.
+    version: String,
+}
+
+fn resolve_source(watch: &mut StopWatch) -> ApiSource {
+    println!("cargo:rerun-if-env-changed=GODOT4_DUMP_DIR");
+    if let Some(dir) = std::env::var_os("GODOT4_DUMP_DIR") {
+        let dir = PathBuf::from(dir);
+        println!(
+            "Using pre-dumped Godot API from GODOT4_DUMP_DIR: '{}'",
+            dir.display()
+        );
+        return ApiSource::PreDumped { dir };
+    }
+
+    let godot_bin = godot_exe::locate_godot_binary();
+    godot_exe::rerun_on_changed(&godot_bin);
+    watch.record("locate_godot");
+
+    let entry = validate_entry(&godot_bin);
+    watch.record("validate_api_cache");
+
+    ApiSource::Binary { godot_bin, entry }
+}
+
+/// Returns the cache entry for `godot_bin`, creating a new one if the binary's version changed.
+fn validate_entry(godot_bin: &Path) -> CacheEntry {
+    let canonical = fs::canonicalize(godot_bin).unwrap_or_else(|e| {
+        panic!(
+            "Godot binary '{}' is not accessible: {e}",
+            godot_bin.display()
+        )
+    });
+    let metadata = fs::metadata(&canonical)
+        .unwrap_or_else(|e| panic!("failed to read metadata of '{}': {e}", canonical.display()));
+
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map(|duration| format!("{}.{:09}", duration.as_secs(), duration.subsec_nanos()))
+        .unwrap_or_default();
+
+    let stamp = FileStamp {
+        path: canonical.display().to_string(),
+        modified,
+        size: metadata.len(),
+    };
+
+    // Note: read_godot_version() already panics if 4.0 is still in use; no need to check again.
+    // This also validates whether we run a Debug build.
+    validate_entry_in(&cache_root(), stamp, || {
+        godot_exe::read_godot_version(godot_bin).full_string
+    })
+}
+
+/// Path, modification time and size of a Godot binary; the part of [`CacheKey`] that is known without launching Godot.
+struct FileStamp {
+    path: String,
+    modified: String,
+    size: u64,
+}
+
+/// Looks up the entry for the binary described by `stamp` in `root`. `read_version` is only invoked if the binary may have changed.
+fn validate_entry_in(
+    root: &Path,
+    stamp: FileStamp,
+    read_version: impl FnOnce() -> String,
+) -> CacheEntry {
+    let path_hash = fnv1a(stamp.path.as_bytes());
+    let key_path = root.join(format!("{path_hash:016x}.key"));
+    let stored = fs::read_to_string(&key_path).ok();
+    let stored_key = stored.as_deref().and_then(CacheKey::parse);
+
+    // Fast path: same file as last time, no need to launch Godot.
+    if let Some(stored) = &stored_key {
+        if stored.path == stamp.path
+            && stored.modified == stamp.modified
+            && stored.size == stamp.size
+        {
+            let dir = entry_dir(root, path_hash, &stored.version);
+            if dir.is_dir() {
+                println!(
+                    "Reusing Godot API cache '{}' (version {}).",
+                    dir.display(),
+                    stored.version
+                );
+                let key = stored.clone();
+                return CacheEntry { dir, key };
+            }
+        }
+    }
+
+    let key = CacheKey {
+        path: stamp.path,
+        modified: stamp.modified,
+        size: stamp.size,
+        version: read_version(),
+    };
+
+    match (&stored, &stored_key) {
+        (_, Some(stored)) if stored.version == key.version => {
+            println!(
+                "Godot binary '{}' was modified, but its version {} is unchanged; reusing API cache.",
+                key.path, key.version
+            );
+        }
+        (_, Some(stored)) => {
+            println!(
+                "cargo:warning=Godot API cache is stale: '{}' changed from version {} to {}; dumping API again.",
+                key.path, stored.version, key.version
+            );
+        }
+        (Some(_), None) => {
+            println!(
+                "cargo:warning=Godot API cache key '{}' is invalid; dumping API again.",
+                key_path.display()
+            );
+        }
+        (None, None) => {}
+    }
+
+    let dir = entry_dir(root, path_hash, &key.version);
+    create_entry_dir(&dir);
+    write_atomically(&key_path, &key.to_text());
+
+    CacheEntry { dir, key }
+}
+
+/// Directory holding the files dumped by one version of one binary.
+fn entry_dir(root: &Path, path_hash: u64, version: &str) -> PathBuf {
+    root.join(format!(
+        "{path_hash:016x}-{:016x}",
+        fnv1a(version.as_bytes())
+    ))
+}
+
+/// Creates `dir` unless it exists, via a temporary directory, so other processes never see a partially created entry.
+fn create_entry_dir(dir: &Path) {
+    if dir.is_dir() {
+        return;
+    }
+
+    println!("Creating Godot API cache '{}'...", dir.display());
+    let tmp_dir = dir.with_extension(format!("tmp{}", std::process::id()));
+    fs::create_dir_all(&tmp_dir).unwrap_or_else(|e| {
+        panic!(
+            "failed to create API cache dir '{}': {e}",
+            tmp_dir.display()
+        )
+    });
+
+    if let Err(e) = fs::rename(&tmp_dir, dir) {
+        // Another build script may have created the entry in the meantime. The temporary directory is only known to this process.
+        let _ = fs::remove_dir_all(&tmp_dir);
+        assert!(
+            dir.is_dir(),
+            "failed to move API cache dir to '{}': {e}",
+            dir.display()
+        );
+    }
+}
+
+/// Directory that holds all cache entries.
+///
+/// Defaults to a directory in `OUT_DIR`, so that builds don't write outside the target directory. Setting `GODOT4_API_CACHE_DIR` shares
+/// the cache between crates and projects, and keeps it across `cargo clean`.
+fn cache_root() -> PathBuf {
+    println!("cargo:rerun-if-env-changed=GODOT4_API_CACHE_DIR");
+    match std::env::var_os("GODOT4_API_CACHE_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(std::env::var("OUT_DIR").unwrap()).join("godot-api-cache"),
+    }
+}
+
+impl CacheKey {
+    fn to_text(&self) -> String {
+        format!(
+            "path={}\nmodified={}\nsize={}\nversion={}\n",
+            self.path, self.modified, self.size, self.version
+        )
+    }
+
+    fn parse(text: &str) -> Option<Self> {
+        let field = |name: &str| {
+            text.lines()
+                .find_map(|line| line.strip_prefix(name)?.strip_prefix('='))
+                .map(str::to_string)
+        };
+
+        Some(Self {
+            path: field("path")?,
+            modified: field("modified")?,
+            size: field("size")?.parse().ok()?,
+            version: field("version")?,
+        })
+    }
+}
+
+fn parse_stored_version(version: &str) -> GodotVersion {
+    let version = parse_godot_version(version)
+        .unwrap_or_else(|err| panic!("failed to parse cached Godot version '{version}': {err}"));
+    validate_godot_version(&version);
+    version
+}
+
+/// Reads the version from the `header` of a pre-dumped `extension_api.json`.
+fn version_from_json(json_path: &Path) -> GodotVersion {
+    let json = fs::read_to_string(json_path)
+        .unwrap_or_else(|e| panic!("failed to read '{}': {e}", json_path.display()));
+
+    let version = extract_full_version(&json).unwrap_or_else(|| {
+        panic!(
+            "'{}' has no `header.version_full_name` of the form \"Godot Engine v4.x...\"",
+            json_path.display()
+        )
+    });
+
+    parse_stored_version(version)
+}
+
+fn extract_full_version(json: &str) -> Option<&str> {
+    let regex = Regex::new(r#""version_full_name"\s*:\s*"Godot Engine v([^"]+)""#).unwrap();
+    let caps = regex.captures(json)?;
+
+    Some(caps.get(1)?.as_str())
+}
+
+/// Writes via a temporary file, so that concurrently running build scripts never see partial contents.
+pub(crate) fn write_atomically(path: &Path, contents: &str) {
+    let tmp_path = path.with_extension(format!("tmp{}", std::process::id()));
+
+    fs::write(&tmp_path, contents)
+        .unwrap_or_else(|e| panic!("failed to write '{}': {e}", tmp_path.display()));
+    fs::rename(&tmp_path, path)
+        .unwrap_or_else(|e| panic!("failed to move file to '{}': {e}", path.display()));
+}
+
+/// Stable hash for directory names; `std`'s hashers may change between Rust versions.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_key_roundtrip() {
+        let key = CacheKey {
+            path: "/opt/godot/godot.linuxbsd.editor.x86_64".to_string(),
+            modified: "1712345678.000000042".to_string(),
+            size: 123_456_789,
+            version: "4.4.1.stable.official.49a5bc7b6".to_string(),
+        };
+
+        assert_eq!(CacheKey::parse(&key.to_text()), Some(key));
+        assert_eq!(CacheKey::parse("path=/godot\nsize=12\n"), None);
+    }
+
+    #[test]
+    fn full_version_from_json() {
+        let json = r#"{
+            "header": {
+                "version_major": 4,
+                "version_full_name": "Godot Engine v4.3.stable.official",
+                "precision": "single"
+            }
+        }"#;
+
+        assert_eq!(extract_full_version(json), Some("4.3.stable.official"));
+        assert_eq!(extract_full_version("{}"), None);
+    }
+
+    #[test]
+    fn dump_dir_uses_godot_file_names() {
+        assert_eq!(DumpFile::ExtensionApi.dumped_name(), "extension_api.json");
+        assert_eq!(DumpFile::Header.dumped_name(), "gdextension_interface.h");
+        assert_eq!(DumpFile::Header.cached_name(), "gdextension_interface.h");
+    }
+
+    #[test]
+    fn entry_reused_if_binary_unchanged() {
+        let root = test_root("unchanged");
+
+        let first = validate_entry_in(&root, stamp("1.0", 100), || "4.3.stable".to_string());
+        fs::write(first.dir.join("extension_api.json"), "{}").unwrap();
+
+        // Same stamp: version must not be queried again.
+        let second = validate_entry_in(&root, stamp("1.0", 100), || unreachable!());
+        assert_eq!(second.dir, first.dir);
+        assert_eq!(second.key, first.key);
+
+        // Touched binary with same version: entry and its files are kept.
+        let third = validate_entry_in(&root, stamp("2.0", 100), || "4.3.stable".to_string());
+        assert_eq!(third.dir, first.dir);
+        assert_eq!(third.key.modified, "2.0");
+        assert!(third.dir.join("extension_api.json").is_file());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn entry_replaced_on_version_mismatch() {
+        let root = test_root("mismatch");
+
+        let old = validate_entry_in(&root, stamp("1.0", 100), || "4.3.stable".to_string());
+        fs::write(old.dir.join("extension_api.json"), "{}").unwrap();
+
+        let new = validate_entry_in(&root, stamp("2.0", 200), || "4.4.stable".to_string());
+        assert_ne!(new.dir, old.dir);
+        assert_eq!(new.key.version, "4.4.stable");
+        assert!(!new.dir.join("extension_api.json").exists());
+
+        // The stale entry is not deleted, as other build scripts may still read it.
+        assert!(old.dir.join("extension_api.json").is_file());
+
+        // Key now refers to the new version.
+        let again = validate_entry_in(&root, stamp("2.0", 200), || unreachable!());
+        assert_eq!(again.dir, new.dir);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn entry_with_invalid_key_is_recreated() {
+        let root = test_root("invalid-key");
+
+        let first = validate_entry_in(&root, stamp("1.0", 100), || "4.3.stable".to_string());
+        let key_path = root.join(format!("{:016x}.key", fnv1a(first.key.path.as_bytes())));
+        fs::write(&key_path, "garbage").unwrap();
+
+        let second = validate_entry_in(&root, stamp("1.0", 100), || "4.3.stable".to_string());
+        assert_eq!(second.dir, first.dir);
+        assert_eq!(
+            CacheKey::parse(&fs::read_to_string(&key_path).unwrap()),
+            Some(second.key)
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    fn stamp(modified: &str, size: u64) -> FileStamp {
+        FileStamp {
+            path: "/opt/godot/godot4".to_string(),
+            modified: modified.to_string(),
+            size,
+        }
+    }
+
+    fn test_root(name: &str) -> PathBuf {
+        let root =
+            std::env::temp_dir().join(format!("gdext-api-cache-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        root
+    }
+}
@@ -13,37 +13,19 @@ use std::process::{Command, Output};
 
 use regex::Regex;
 
+use crate::api_cache::{locate_dump, DumpFile};
 use crate::godot_version::{parse_godot_version, validate_godot_version};
 use crate::header_gen::generate_rust_binding;
 use crate::watch::StopWatch;
 use crate::GodotVersion;
 
-// Note: CARGO_BUILD_TARGET_DIR and CARGO_TARGET_DIR are not set.
-// OUT_DIR would be standing to reason, but it's an unspecified path that cannot be referenced by CI.
-// const GODOT_VERSION_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/gen/godot_version.txt");
-
 pub fn load_gdextension_json(watch: &mut StopWatch) -> String {
-    let path = format!("{}/extension_api.json", std::env::var("OUT_DIR").unwrap());
-    let json_path = Path::new(&path);
-
     // Listening to changes on files that are generated by this build step cause an infinite loop with cargo watch of
     // build -> detect change -> rebuild -> detect change -> ...
-    // rerun_on_changed(json_path);
-
-    let godot_bin = locate_godot_binary();
-    rerun_on_changed(&godot_bin);
-    watch.record("locate_godot");
+    // Cached files live outside the crate directory, so this is not an issue; they are only rewritten when the Godot binary changes.
+    let json_path = locate_dump(DumpFile::ExtensionApi, watch);
 
-    // Regenerate API JSON if first time or Godot version is different
-    let _version = read_godot_version(&godot_bin);
-    // if !json_path.exists() || has_version_changed(&version) {
-    dump_extension_api(&godot_bin, json_path);
-    // update_version_file(&version);
-
-    watch.record("dump_api_json");
-    // }
-
-    let result = fs::read_to_string(json_path)
+    let result = fs::read_to_string(&json_path)
         .unwrap_or_else(|_| panic!("failed to open file {}", json_path.display()));
 
     watch.record("read_api_json");
@@ -56,23 +38,22 @@ pub fn write_gdextension_headers(
     is_h_provided: bool,
     watch: &mut StopWatch,
 ) {
-    // Use Godot binary to dump GDExtension headers if they weren't provided by the user.
+    // Use Godot binary (or its cache) to dump GDExtension headers if they weren't provided by the user.
     if !is_h_provided {
-        // No external C header file: Godot binary is present, we use it to dump C header
-        let godot_bin = locate_godot_binary();
-        rerun_on_changed(&godot_bin);
-        watch.record("locate_godot");
-
-        // Regenerate API JSON if first time or Godot version is different.
-        // Note: read_godot_version() already panics if 4.0 is still in use; no need to check again.
-        // This also validates whether we run a Debug build.
-        let _version = read_godot_version(&godot_bin);
-
-        // if !c_header_path.exists() || has_version_changed(&version) {
-        dump_header_file(&godot_bin, inout_h_path);
-        // update_version_file(&version);
-        watch.record("dump_header_h");
-        // }
+        let cached_h_path = locate_dump(DumpFile::Header, watch);
+
+        if let Some(dir) = inout_h_path.parent() {
+            fs::create_dir_all(dir)
+                .unwrap_or_else(|_| panic!("create directory '{}'", dir.display()));
+        }
+        fs::copy(&cached_h_path, inout_h_path).unwrap_or_else(|e| {
+            panic!(
+                "failed to copy C header from '{}' to '{}': {e}",
+                cached_h_path.display(),
+                inout_h_path.display()
+            )
+        });
+        watch.record("copy_header_h");
     };
 
     // Listening to changes on files that are generated by this build step cause an infinite loop with cargo watch of
@@ -85,25 +66,6 @@ pub fn write_gdextension_headers(
     watch.record("generate_header_rs");
 }
 
-/*
-fn has_version_changed(current_version: &str) -> bool {
-    let version_path = Path::new(GODOT_VERSION_PATH);
-
-    match fs::read_to_string(version_path) {
-        Ok(last_version) => current_version != last_version,
-        Err(_) => true,
-    }
-}
-
-fn update_version_file(version: &str) {
-    let version_path = Path::new(GODOT_VERSION_PATH);
-    rerun_on_changed(version_path);
-
-    fs::write(version_path, version)
-        .unwrap_or_else(|_| panic!("write Godot version to file {}", version_path.display()));
-}
-*/
-
 pub(crate) fn read_godot_version(godot_bin: &Path) -> GodotVersion {
     let mut cmd = Command::new(godot_bin);
     cmd.arg("--version");
@@ -144,32 +106,42 @@ fn is_godot_debug_build(godot_bin: &Path) -> bool {
         .any(|window| window == needle)
 }
 
-fn dump_extension_api(godot_bin: &Path, out_file: &Path) {
-    let cwd = out_file.parent().unwrap();
-    fs::create_dir_all(cwd).unwrap_or_else(|_| panic!("create directory '{}'", cwd.display()));
-    println!("Dump GDExtension API JSON to dir '{}'...", cwd.display());
-
-    let mut cmd = Command::new(godot_bin);
-    cmd.current_dir(cwd)
-        .arg("--headless")
-        .arg("--dump-extension-api");
-
-    execute(cmd, "dump Godot JSON file");
-    println!("Generated {}/extension_api.json.", cwd.display());
-}
+/// Lets Godot dump `file` and moves it to `out_file`.
+///
+/// Godot writes to a fixed file name in its working directory. A per-process directory is used, so that build scripts running
+/// concurrently (e.g. of `godot-ffi` and `godot-core`) do not observe each other's partially written files.
+pub(crate) fn dump_file(godot_bin: &Path, file: DumpFile, out_file: &Path) {
+    let dir = out_file.parent().unwrap();
+    let cwd = dir.join(format!("dump-{}", std::process::id()));
+    fs::create_dir_all(&cwd).unwrap_or_else(|_| panic!("create directory '{}'", cwd.display()));
+
+    let (dump_arg, description) = match file {
+        // With docs, the JSON is considerably larger, so only include them when requested.
+        DumpFile::ExtensionApi if cfg!(feature = "api-docs") => {
+            ("--dump-extension-api-with-docs", "dump Godot JSON file")
+        }
+        DumpFile::ExtensionApi => ("--dump-extension-api", "dump Godot JSON file"),
+        DumpFile::Header => ("--dump-gdextension-interface", "dump Godot header file"),
+    };
+    let dumped_name = file.dumped_name();
 
-fn dump_header_file(godot_bin: &Path, out_file: &Path) {
-    let cwd = out_file.parent().unwrap();
-    fs::create_dir_all(cwd).unwrap_or_else(|_| panic!("create directory '{}'", cwd.display()));
-    println!("Dump GDExtension header file to dir '{}'...", cwd.display());
+    println!("Dump {dumped_name} to dir '{}'...", dir.display());
 
     let mut cmd = Command::new(godot_bin);
-    cmd.current_dir(cwd)
-        .arg("--headless")
-        .arg("--dump-gdextension-interface");
+    cmd.current_dir(&cwd).arg("--headless").arg(dump_arg);
+    execute(cmd, description);
+
+    let dumped_file = cwd.join(dumped_name);
+    fs::rename(&dumped_file, out_file).unwrap_or_else(|e| {
+        panic!(
+            "failed to move '{}' to '{}': {e}",
+            dumped_file.display(),
+            out_file.display()
+        )
+    });
+    let _ = fs::remove_dir_all(&cwd);
 
-    execute(cmd, "dump Godot header file");
-    println!("Generated {}/gdextension_interface.h.", cwd.display());
+    println!("Generated {}.", out_file.display());
 }
 
 pub(crate) fn patch_c_header(in_h_path: &Path, out_h_path: &Path) {
@@ -257,6 +229,6 @@ fn try_execute(mut cmd: Command, error_message: &str) -> Result<Output, String>
     }
 }
 
-fn rerun_on_changed(path: &Path) {
+pub(crate) fn rerun_on_changed(path: &Path) {
     println!("cargo:rerun-if-changed={}", path.display());
 }
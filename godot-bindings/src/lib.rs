@@ -63,6 +63,7 @@ mod depend_on_custom {
 
     use super::*;
 
+    pub(crate) mod api_cache;
     pub(crate) mod godot_exe;
     pub(crate) mod godot_version;
     pub(crate) mod header_gen;
@@ -81,7 +82,8 @@ mod depend_on_custom {
     }
 
     pub(crate) fn get_godot_version() -> GodotVersion {
-        godot_exe::read_godot_version(&godot_exe::locate_godot_binary())
+        // Only the cache validation is timed here; build scripts record their own steps.
+        api_cache::godot_version(&mut StopWatch::start())
     }
 }
 
@@ -200,6 +202,80 @@ pub use depend_on_prebuilt::*;
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Common
 
+/// Loads a separate API JSON that contains the Godot class reference, if provided via `GODOT4_DOCS_JSON`.
+///
+/// The file is the output of `godot --dump-extension-api-with-docs`. It is needed for prebuilt and `api-custom-json` APIs, which come
+/// without documentation. With `api-custom`, the dumped JSON already includes docs.
+#[cfg(feature = "api-docs")]
+pub fn load_gdextension_docs_json(watch: &mut StopWatch) -> Option<String> {
+    println!("cargo:rerun-if-env-changed=GODOT4_DOCS_JSON");
+    let path = std::env::var("GODOT4_DOCS_JSON").ok()?;
+    println!("cargo:rerun-if-changed={path}");
+
+    let json = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("failed to read GODOT4_DOCS_JSON file '{path}': {e}"));
+
+    watch.record("read_docs_json");
+    Some(json)
+}
+
+/// Loads API JSONs describing classes registered by other GDExtensions, from the paths listed in `GODOT4_THIRD_PARTY_JSON`.
+///
+/// Multiple paths are separated like in `PATH` (`:` on Unix, `;` on Windows). Each file is either dumped by an editor with the
+/// extension loaded (`godot --headless --path <project> --dump-extension-api`), or a hand-written subset with a `classes` array.
+#[cfg(feature = "api-third-party")]
+pub fn load_third_party_api_jsons(watch: &mut StopWatch) -> Vec<(std::path::PathBuf, String)> {
+    println!("cargo:rerun-if-env-changed=GODOT4_THIRD_PARTY_JSON");
+    let Some(paths) = std::env::var_os("GODOT4_THIRD_PARTY_JSON") else {
+        return Vec::new();
+    };
+
+    let jsons = std::env::split_paths(&paths)
+        .filter(|path| !path.as_os_str().is_empty())
+        .map(|path| {
+            println!("cargo:rerun-if-changed={}", path.display());
+
+            let json = std::fs::read_to_string(&path).unwrap_or_else(|e| {
+                panic!(
+                    "failed to read GODOT4_THIRD_PARTY_JSON file '{}': {e}",
+                    path.display()
+                )
+            });
+
+            (path, json)
+        })
+        .collect();
+
+    watch.record("read_third_party_jsons");
+    jsons
+}
+
+/// Reads the file listing the engine classes to generate, from the path in `GODOT4_CODEGEN_CLASSES`.
+///
+/// Returns the path and the file's contents. The format (a class list or a TOML manifest) is interpreted by the caller.
+#[cfg(feature = "class-allowlist")]
+pub fn load_class_allowlist(watch: &mut StopWatch) -> (std::path::PathBuf, String) {
+    println!("cargo:rerun-if-env-changed=GODOT4_CODEGEN_CLASSES");
+    let Some(path) = std::env::var_os("GODOT4_CODEGEN_CLASSES").map(std::path::PathBuf::from)
+    else {
+        panic!(
+            "feature `codegen-allowlist` requires GODOT4_CODEGEN_CLASSES, pointing to a class list or a Cargo.toml.\n\
+             Tip: set it in `.cargo/config.toml` under `[env]`, with `relative = true`."
+        );
+    };
+    println!("cargo:rerun-if-changed={}", path.display());
+
+    let contents = std::fs::read_to_string(&path).unwrap_or_else(|e| {
+        panic!(
+            "failed to read GODOT4_CODEGEN_CLASSES file '{}': {e}",
+            path.display()
+        )
+    });
+
+    watch.record("read_class_allowlist");
+    (path, contents)
+}
+
 pub fn clear_dir(dir: &Path, watch: &mut StopWatch) {
     if dir.exists() {
         remove_dir_all_reliable(dir);
@@ -189,6 +189,11 @@ impl<'a> Context<'a> {
     ) {
         // Note: already checked for class excluded/deleted.
 
+        // Method binds of third-party classes are not stored in tables, but looked up on first call.
+        if util::is_third_party_class(class) {
+            return;
+        }
+
         for method in methods.iter() {
             if special_cases::is_class_method_deleted(class_name, method, ctx) || method.is_virtual
             {
@@ -306,6 +311,11 @@ impl<'a> Context<'a> {
         self.singletons.contains(class_name.godot_ty.as_str())
     }
 
+    /// Whether `class_name` is an engine class for which code is generated (i.e. not deleted).
+    pub fn is_engine_class(&self, class_name: &TyName) -> bool {
+        self.classes_final.contains_key(class_name)
+    }
+
     pub fn is_final(&self, class_name: &TyName) -> bool {
         *self.classes_final.get(class_name).unwrap_or_else(|| {
             panic!(
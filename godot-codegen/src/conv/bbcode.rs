@@ -0,0 +1,232 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Conversion of Godot's class reference (BBCode dialect) to rustdoc Markdown.
+//!
+//! See <https://docs.godotengine.org/en/stable/contributing/documentation/class_reference_primer.html> for the tags.
+
+/// Converts a BBCode description from the Godot class reference to Markdown.
+///
+/// `class_link` maps a Godot class name (as in `[Node]`) to a Rust path for an intra-doc link. If it returns `None`, the class is
+/// rendered as a code span.
+pub fn bbcode_to_markdown(bbcode: &str, class_link: impl Fn(&str) -> Option<String>) -> String {
+    let mut out = String::with_capacity(bbcode.len());
+    let mut rest = bbcode;
+
+    while let Some(start) = rest.find('[') {
+        push_text(&mut out, &rest[..start]);
+        rest = &rest[start..];
+
+        let Some(end) = rest.find(']') else {
+            break;
+        };
+
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        let (name, arg) = match tag.split_once([' ', '=']) {
+            Some((name, arg)) => (name, Some(arg)),
+            None => (tag, None),
+        };
+
+        match (name, arg) {
+            ("b" | "/b", None) => out.push_str("**"),
+            ("i" | "/i", None) => out.push('*'),
+            ("s" | "/s", None) => out.push_str("~~"),
+            ("u" | "/u" | "center" | "/center" | "/color" | "/font", None) => {}
+            ("color" | "font", Some(_)) => {}
+            ("br", None) => out.push_str("<br>"),
+            ("lb", None) => out.push_str("\\["),
+            ("rb", None) => out.push_str("\\]"),
+
+            ("code", None) => push_code_span(&mut out, take_until(&mut rest, "[/code]")),
+            ("kbd", None) => push_code_span(&mut out, take_until(&mut rest, "[/kbd]")),
+            ("codeblock", _) => {
+                let lang = arg
+                    .and_then(|arg| arg.strip_prefix("lang="))
+                    .unwrap_or("gdscript");
+
+                push_code_block(&mut out, take_until(&mut rest, "[/codeblock]"), lang);
+            }
+            ("codeblocks", None) => {
+                let blocks = take_until(&mut rest, "[/codeblocks]");
+                for lang in ["gdscript", "csharp"] {
+                    if let Some(code) = find_block(blocks, lang) {
+                        push_code_block(&mut out, code, lang);
+                        break;
+                    }
+                }
+            }
+            ("img", _) => {
+                take_until(&mut rest, "[/img]");
+            }
+            ("url", Some(link)) => {
+                let link = resolve_url(link);
+                let text = take_until(&mut rest, "[/url]");
+                out.push('[');
+                push_text(&mut out, text);
+                out.push_str(&format!("]({link})"));
+            }
+            ("url", None) => {
+                let link = resolve_url(take_until(&mut rest, "[/url]"));
+                out.push_str(&format!("<{link}>"));
+            }
+
+            ("method" | "constructor", Some(symbol)) => {
+                push_code_span(&mut out, &format!("{symbol}()"))
+            }
+            (
+                "member" | "signal" | "constant" | "enum" | "param" | "annotation" | "theme_item"
+                | "operator",
+                Some(symbol),
+            ) => push_code_span(&mut out, symbol),
+
+            (class_name, None) if is_class_name(class_name) => {
+                // Pseudo-classes like `@GlobalScope` have no Rust counterpart.
+                let path = if class_name.starts_with('@') {
+                    None
+                } else {
+                    class_link(class_name)
+                };
+
+                match path {
+                    Some(path) => out.push_str(&format!("[`{class_name}`]({path})")),
+                    None => push_code_span(&mut out, class_name),
+                }
+            }
+
+            // Unknown tag: keep it as literal text.
+            _ => push_text(&mut out, &format!("[{tag}]")),
+        }
+    }
+
+    push_text(&mut out, rest);
+    out.trim().to_string()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+/// Returns the text up to `closing` and advances `rest` past it. If the closing tag is missing, takes the remaining text.
+fn take_until<'a>(rest: &mut &'a str, closing: &str) -> &'a str {
+    let (content, after) = match rest.find(closing) {
+        Some(pos) => (&rest[..pos], &rest[pos + closing.len()..]),
+        None => (*rest, ""),
+    };
+
+    *rest = after;
+    content
+}
+
+/// Finds the content of `[lang]...[/lang]` (opening tag possibly with arguments) inside a `[codeblocks]` section.
+fn find_block<'a>(blocks: &'a str, lang: &str) -> Option<&'a str> {
+    let start = blocks.find(&format!("[{lang}"))?;
+    let content_start = start + blocks[start..].find(']')? + 1;
+    let content_len = blocks[content_start..].find(&format!("[/{lang}]"))?;
+
+    Some(&blocks[content_start..content_start + content_len])
+}
+
+/// Class reference links to the manual are relative to a placeholder.
+fn resolve_url(link: &str) -> String {
+    link.replace("$DOCS_URL", "https://docs.godotengine.org/en/stable")
+}
+
+fn is_class_name(name: &str) -> bool {
+    // Lowercase builtins are referenced like classes.
+    if matches!(name, "bool" | "int" | "float") {
+        return true;
+    }
+
+    let mut chars = name.chars();
+    let first_ok = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_uppercase() || c == '@');
+
+    first_ok && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Pushes regular text, escaping Markdown syntax. Each line break starts a new paragraph, as in Godot's editor help.
+fn push_text(out: &mut String, text: &str) {
+    for (i, line) in text.split('\n').enumerate() {
+        if i > 0 {
+            push_paragraph_break(out);
+        }
+
+        // Leading whitespace would turn into indented code blocks.
+        let line = if out.is_empty() || out.ends_with('\n') {
+            line.trim_start()
+        } else {
+            line
+        };
+
+        for c in line.chars() {
+            if matches!(c, '\\' | '*' | '`' | '<' | '>' | '[' | ']') {
+                out.push('\\');
+            }
+            out.push(c);
+        }
+    }
+}
+
+fn push_paragraph_break(out: &mut String) {
+    let trimmed_len = out.trim_end_matches([' ', '\t']).len();
+    out.truncate(trimmed_len);
+
+    if out.is_empty() || out.ends_with("\n\n") {
+        return;
+    }
+
+    if out.ends_with('\n') {
+        out.push('\n');
+    } else {
+        out.push_str("\n\n");
+    }
+}
+
+fn push_code_span(out: &mut String, code: &str) {
+    let code = code.replace("[lb]", "[").replace("[rb]", "]");
+    if code.is_empty() {
+        return;
+    }
+
+    // Backticks inside need a longer delimiter; spaces avoid merging with a leading/trailing backtick.
+    if code.contains('`') {
+        out.push_str(&format!("`` {code} ``"));
+    } else {
+        out.push_str(&format!("`{code}`"));
+    }
+}
+
+fn push_code_block(out: &mut String, code: &str, lang: &str) {
+    let code = code.replace('\t', "    ");
+    let lines: Vec<&str> = code
+        .lines()
+        .skip_while(|line| line.trim().is_empty())
+        .collect();
+
+    let end = lines
+        .iter()
+        .rposition(|line| !line.trim().is_empty())
+        .map_or(0, |pos| pos + 1);
+    let lines = &lines[..end];
+
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    push_paragraph_break(out);
+    out.push_str(&format!("```{lang}\n"));
+    for line in lines {
+        out.push_str(line.get(indent..).unwrap_or("").trim_end());
+        out.push('\n');
+    }
+    out.push_str("```\n");
+}
@@ -5,8 +5,10 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+mod bbcode;
 mod name_conversions;
 mod type_conversions;
 
+pub(crate) use bbcode::*;
 pub(crate) use name_conversions::*;
 pub(crate) use type_conversions::*;
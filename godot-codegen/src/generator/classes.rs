@@ -49,6 +49,7 @@ pub fn generate_class_files(
             inherits_macro_ident: generated_class.inherits_macro_ident,
             is_pub_sidecar: generated_class.has_sidecar_module,
             has_interface_trait: generated_class.has_interface_trait,
+            is_third_party: class.is_third_party,
         });
     }
 
@@ -77,6 +78,7 @@ struct GeneratedClassModule {
     inherits_macro_ident: Option<Ident>,
     is_pub_sidecar: bool,
     has_interface_trait: bool,
+    is_third_party: bool,
 }
 
 struct Construction {
@@ -113,6 +115,17 @@ fn make_class(class: &Class, ctx: &mut Context, view: &ApiView) -> GeneratedClas
     let api_level = class.api_level;
     let init_level = api_level.to_init_level();
 
+    // Third-party classes are only available if their GDExtension is loaded; fail early with a clear message otherwise.
+    // Their name may already be cached from a dynamic lookup (e.g. an object's runtime class), so it's not allocated as a fresh entry.
+    let alloc_class_id = if class.is_third_party {
+        quote! {
+            crate::classes::ensure_third_party_class(#godot_class_str);
+            ClassId::new_dynamic(#godot_class_str)
+        }
+    } else {
+        quote! { ClassId::__alloc_next_unicode(#godot_class_str) }
+    };
+
     // These attributes are for our nightly docs pipeline, which enables "only available in ..." labels in the HTML output. The website CI sets
     // RUSTFLAGS="--cfg published_docs" during the `cargo +nightly doc` invocation. They are applied to classes, interface traits, sidecar modules,
     // the notification enum, other enums and default-parameter extender structs.
@@ -184,6 +197,8 @@ fn make_class(class: &Class, ctx: &mut Context, view: &ApiView) -> GeneratedClas
         has_sidecar_module,
         has_interface_trait,
         has_own_signals,
+        class.is_third_party,
+        class.doc.as_deref(),
     );
 
     // notify() and notify_reversed() are added after other methods, to list others first in docs.
@@ -250,7 +265,9 @@ fn make_class(class: &Class, ctx: &mut Context, view: &ApiView) -> GeneratedClas
                     // Optimization note: instead of lazy init, could use separate static which is manually initialized during registration.
                     static CLASS_ID: std::sync::OnceLock<ClassId> = std::sync::OnceLock::new();
 
-                    let name: &'static ClassId = CLASS_ID.get_or_init(|| ClassId::__alloc_next_unicode(#godot_class_str));
+                    let name: &'static ClassId = CLASS_ID.get_or_init(|| {
+                        #alloc_class_id
+                    });
                     *name
                 }
 
@@ -354,6 +371,7 @@ fn make_inherits_macro(class: &Class, all_bases: &[TyName]) -> (Option<Ident>, T
 fn make_class_module_file(classes_and_modules: Vec<GeneratedClassModule>) -> TokenStream {
     let mut class_decls = Vec::new();
     let mut notify_decls = Vec::new();
+    let mut third_party_decls = Vec::new();
 
     for m in classes_and_modules.iter() {
         let GeneratedClassModule {
@@ -367,14 +385,33 @@ fn make_class_module_file(classes_and_modules: Vec<GeneratedClassModule>) -> Tok
 
         let vis = is_pub.then_some(quote! { pub });
 
-        let interface_reexport = m.has_interface_trait.then(|| {
-            quote! { pub use #module_name::re_export::#virtual_trait_name; }
-        });
+        let class_decl = if m.is_third_party {
+            let exports = if m.has_interface_trait {
+                quote! { #class_name, #virtual_trait_name }
+            } else {
+                quote! { #class_name }
+            };
+
+            // Documented in `third_party`. Still re-exported here, because generated code and macros refer to `classes::Class`.
+            third_party_decls.push(quote! {
+                pub use super::#module_name::re_export::{#exports};
+            });
 
-        let class_decl = quote! {
-            #vis mod #module_name;
-            pub use #module_name::re_export::#class_name;
-            #interface_reexport
+            quote! {
+                #vis mod #module_name;
+                #[doc(hidden)]
+                pub use #module_name::re_export::{#exports};
+            }
+        } else {
+            let interface_reexport = m.has_interface_trait.then(|| {
+                quote! { pub use #module_name::re_export::#virtual_trait_name; }
+            });
+
+            quote! {
+                #vis mod #module_name;
+                pub use #module_name::re_export::#class_name;
+                #interface_reexport
+            }
         };
         class_decls.push(class_decl);
 
@@ -405,8 +442,22 @@ fn make_class_module_file(classes_and_modules: Vec<GeneratedClassModule>) -> Tok
         }
     });
 
+    let third_party_mod = if third_party_decls.is_empty() {
+        TokenStream::new()
+    } else {
+        quote! {
+            /// Classes registered by other GDExtensions, generated from the JSON files in `GODOT4_THIRD_PARTY_JSON`.
+            ///
+            /// The GDExtension providing a class must be loaded; otherwise, using the class panics.
+            pub mod third_party {
+                #( #third_party_decls )*
+            }
+        }
+    };
+
     quote! {
         #( #class_decls )*
+        #third_party_mod
 
         /// Notification enums for all classes.
         pub mod notify {
@@ -587,29 +638,38 @@ fn make_class_method_definition(
 
     let receiver = functions_common::make_receiver(method.qualifier(), quote! { self.object_ptr });
 
-    let table_index = ctx.get_table_index(&MethodTableKey::from_class(class, method));
-
     let validated_obj = if method.qualifier() == FnQualifier::Static {
         quote! { None }
     } else {
         quote! { Some(self.__validated_obj()) }
     };
 
-    let fptr_access = if cfg!(feature = "codegen-lazy-fptrs") {
-        let godot_class_name = &class.name().godot_ty;
+    let godot_class_name = &class.name().godot_ty;
+    let method_bind = if class.is_third_party {
+        // Not part of the method tables, which are loaded at startup for engine classes only.
+        quote! {
+            static METHOD_BIND: std::sync::OnceLock<sys::ClassMethodBind> = std::sync::OnceLock::new();
+            let method_bind = *METHOD_BIND.get_or_init(|| {
+                crate::classes::load_third_party_method(#godot_class_name, #godot_method_name, #hash)
+            });
+        }
+    } else if cfg!(feature = "codegen-lazy-fptrs") {
         quote! {
-            fptr_by_key(sys::lazy_keys::ClassMethodKey {
+            let method_bind = sys::#get_method_table().fptr_by_key(sys::lazy_keys::ClassMethodKey {
                 class_name: #godot_class_name,
                 method_name: #godot_method_name,
                 hash: #hash,
-            })
+            });
         }
     } else {
-        quote! { fptr_by_index(#table_index) }
+        let table_index = ctx.get_table_index(&MethodTableKey::from_class(class, method));
+        quote! {
+            let method_bind = sys::#get_method_table().fptr_by_index(#table_index);
+        }
     };
 
     let ptrcall_invocation = quote! {
-        let method_bind = sys::#get_method_table().#fptr_access;
+        #method_bind
 
         Signature::<CallParams, CallRet>::out_class_ptrcall(
             method_bind,
@@ -621,7 +681,7 @@ fn make_class_method_definition(
     };
 
     let varcall_invocation = quote! {
-        let method_bind = sys::#get_method_table().#fptr_access;
+        #method_bind
 
         Signature::<CallParams, CallRet>::out_class_varcall(
             method_bind,
@@ -28,8 +28,13 @@ fn make_constant_definition(constant: &ClassConstant) -> TokenStream {
         quote! { pub }
     };
 
+    let maybe_doc = match &constant.doc {
+        Some(doc) => quote! { #[doc = #doc] },
+        None => TokenStream::new(),
+    };
+
     match constant.value {
-        ClassConstantValue::I32(value) => quote! { #vis const #ident: i32 = #value; },
-        ClassConstantValue::I64(value) => quote! { #vis const #ident: i64 = #value; },
+        ClassConstantValue::I32(value) => quote! { #maybe_doc #vis const #ident: i32 = #value; },
+        ClassConstantValue::I64(value) => quote! { #maybe_doc #vis const #ident: i64 = #value; },
     }
 }
@@ -105,9 +105,12 @@ pub fn make_function_definition_with_defaults(
         }
     };
 
+    let maybe_doc = fns::make_fn_doc(sig);
+
     let functions = quote! {
         // Simple function:
         // Lifetime is set if any parameter is a reference.
+        #maybe_doc
         #[doc = #default_parameter_usage]
         #[inline]
         #vis fn #simple_fn_name (
@@ -121,6 +124,7 @@ pub fn make_function_definition_with_defaults(
 
         // _ex() function:
         // Lifetime is set if any parameter is a reference OR if the method is not static/global (and thus can refer to self).
+        #maybe_doc
         #[inline]
         #vis fn #extended_fn_name<'ex> (
             #extended_receiver_param
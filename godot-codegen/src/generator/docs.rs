@@ -22,9 +22,15 @@ pub fn make_class_doc(
     has_sidecar_module: bool,
     has_interface_trait: bool,
     has_signal_collection: bool,
+    is_third_party: bool,
+    description: Option<&str>,
 ) -> String {
     let TyName { rust_ty, godot_ty } = class_name;
 
+    let description = description
+        .map(|description| format!("{description}\n\n"))
+        .unwrap_or_default();
+
     let inherits_line = if let Some(base) = base_ident_opt {
         format!("Inherits [`{base}`][crate::classes::{base}].")
     } else {
@@ -59,10 +65,16 @@ pub fn make_class_doc(
         String::new()
     };
 
-    let online_link = format!(
-        "https://docs.godotengine.org/en/stable/classes/class_{}.html",
-        godot_ty.to_ascii_lowercase()
-    );
+    let online_line = if is_third_party {
+        "Registered by another GDExtension, not by Godot itself. \
+        Using this class panics if that GDExtension is not loaded."
+            .to_string()
+    } else {
+        format!(
+            "See also [Godot docs for `{godot_ty}`](https://docs.godotengine.org/en/stable/classes/class_{}.html).",
+            godot_ty.to_ascii_lowercase()
+        )
+    };
 
     let interface_trait_line = if has_interface_trait {
         let trait_name = class_name.virtual_trait_name();
@@ -78,6 +90,7 @@ pub fn make_class_doc(
     format!(
         "Godot class `{godot_ty}.`\n\n\
         \
+        {description}\
         {inherits_line}\n\n\
         \
         Related symbols:\n\n\
@@ -86,7 +99,7 @@ pub fn make_class_doc(
         {signal_line}\
         {notify_line}\
         \n\n\
-        See also [Godot docs for `{godot_ty}`]({online_link}).\n\n{notes}",
+        {online_line}\n\n{notes}",
     )
 }
 
@@ -562,10 +562,17 @@ fn make_enumerator_definition(
         name,
         godot_name,
         value,
+        doc,
     } = enumerator;
 
-    let docs = if &name.to_string() != godot_name {
-        let doc = format!("Godot enumerator name: `{godot_name}`");
+    let description_doc = match doc {
+        Some(doc) => quote! { #[doc = #doc] },
+        None => TokenStream::new(),
+    };
+
+    let name_doc = if &name.to_string() != godot_name {
+        let separator = if doc.is_some() { "\n\n" } else { "" };
+        let doc = format!("{separator}Godot enumerator name: `{godot_name}`");
 
         quote! {
             #[doc(alias = #godot_name)]
@@ -575,6 +582,11 @@ fn make_enumerator_definition(
         TokenStream::new()
     };
 
+    let docs = quote! {
+        #description_doc
+        #name_doc
+    };
+
     if as_constant {
         quote! {
             #docs
@@ -192,11 +192,19 @@ pub fn make_function_definition(
         quote! { { unimplemented!() } }
     };
 
+    // With default parameters, the docs go to the public functions instead of `*_full`.
+    let maybe_doc = if has_default_params {
+        TokenStream::new()
+    } else {
+        make_fn_doc(sig)
+    };
+
     let receiver_param = &code.receiver.param;
     let primary_function = if sig.is_virtual() {
         // Virtual functions
 
         quote! {
+            #maybe_doc
             #maybe_safety_doc
             #maybe_unsafe fn #primary_fn_name (
                 #receiver_param
@@ -212,6 +220,7 @@ pub fn make_function_definition(
         // TODO Utility functions: update as well.
         if !code.is_varcall_fallible {
             quote! {
+                #maybe_doc
                 #maybe_safety_doc
                 #vis #maybe_unsafe fn #primary_fn_name (
                     #receiver_param
@@ -241,6 +250,7 @@ pub fn make_function_definition(
             } = make_params_exprs(sig.params().iter(), FnKind::DelegateTry);
 
             quote! {
+                #maybe_doc
                 /// # Panics
                 /// This is a _varcall_ method, meaning parameters and return values are passed as `Variant`.
                 /// It can detect call failures and will panic in such a case.
@@ -254,6 +264,7 @@ pub fn make_function_definition(
                         .unwrap_or_else(|e| panic!("{e}"))
                 }
 
+                #maybe_doc
                 /// # Return type
                 /// This is a _varcall_ method, meaning parameters and return values are passed as `Variant`.
                 /// It can detect call failures and will return `Err` in such a case.
@@ -279,6 +290,7 @@ pub fn make_function_definition(
         let ptrcall_invocation = &code.ptrcall_invocation;
 
         quote! {
+            #maybe_doc
             #maybe_safety_doc
             #vis #maybe_unsafe fn #primary_fn_name #maybe_func_generic_params (
                 #receiver_param
@@ -341,6 +353,14 @@ pub fn make_vis(is_private: bool) -> TokenStream {
     }
 }
 
+/// Doc attribute with the description from Godot's class reference, if available.
+pub fn make_fn_doc(sig: &dyn Function) -> TokenStream {
+    match &sig.common().doc {
+        Some(doc) => quote! { #[doc = #doc] },
+        None => TokenStream::new(),
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Implementation
 
@@ -154,7 +154,11 @@ fn make_signal_collection(
         let signal_name_str = &sig.name;
         let signal_name = ident(&sig.name);
         let individual_struct_name = make_individual_struct_name(&sig.name);
-        let provider_docs = format!("Signature: `({})`", params.formatted_types);
+        let signature = format!("Signature: `({})`", params.formatted_types);
+        let provider_docs = match &sig.doc {
+            Some(doc) => format!("{doc}\n\n{signature}"),
+            None => signature,
+        };
 
         quote! {
             // Important to return lifetime 'c here, not '_.
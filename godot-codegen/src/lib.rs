@@ -44,6 +44,12 @@ use crate::models::json::{load_extension_api, JsonExtensionApi};
 
 pub type SubmitFn = dyn FnMut(PathBuf, TokenStream);
 
+#[cfg(all(feature = "codegen-full", feature = "codegen-allowlist"))]
+compile_error!(
+    "Feature `codegen-allowlist` generates only selected classes and cannot be combined with full codegen. \
+    Disable the default features of the `godot` crate."
+);
+
 #[cfg(not(feature = "codegen-full"))]
 pub const IS_CODEGEN_FULL: bool = false;
 
@@ -111,6 +117,9 @@ pub fn generate_sys_files(
 ) {
     let json_api = load_extension_api(watch);
 
+    #[cfg(feature = "codegen-allowlist")]
+    special_cases::init_class_allowlist(&json_api, watch);
+
     let mut ctx = Context::build_from_api(&json_api);
     watch.record("build_context");
 
@@ -155,7 +164,17 @@ pub fn generate_core_files(core_gen_path: &Path) {
 
     generate_core_mod_file(core_gen_path, &mut submit_fn);
 
-    let json_api = load_extension_api(&mut watch);
+    #[allow(unused_mut)]
+    let mut json_api = load_extension_api(&mut watch);
+
+    // Before adding third-party classes, so the selection is the same as in godot-ffi.
+    #[cfg(feature = "codegen-allowlist")]
+    special_cases::init_class_allowlist(&json_api, &mut watch);
+
+    // Third-party classes are only generated in godot-core; godot-ffi method tables cover engine classes only.
+    #[cfg(feature = "codegen-third-party")]
+    models::json::load_third_party_api(&mut json_api, &mut watch);
+
     let mut ctx = Context::build_from_api(&json_api);
     watch.record("build_context");
 
@@ -163,12 +163,16 @@ pub struct Class {
     pub is_instantiable: bool,
     pub is_experimental: bool,
     pub is_final: bool,
+    /// Registered by another GDExtension; method binds are looked up lazily and the class is checked at runtime.
+    pub is_third_party: bool,
     pub base_class: Option<TyName>,
     pub api_level: ClassCodegenLevel,
     pub constants: Vec<ClassConstant>,
     pub enums: Vec<Enum>,
     pub methods: Vec<ClassMethod>,
     pub signals: Vec<ClassSignal>,
+    /// Description from Godot's class reference, converted to Markdown. Same for other `doc` fields.
+    pub doc: Option<String>,
 }
 
 impl ClassLike for Class {
@@ -198,6 +202,7 @@ trait Constant {
 pub struct ClassConstant {
     pub name: String,
     pub value: ClassConstantValue,
+    pub doc: Option<String>,
 }
 
 pub enum ClassConstantValue {
@@ -287,6 +292,7 @@ pub struct FunctionCommon {
     /// Whether raw pointers appear in signature. Affects safety, and in case of virtual methods, the name.
     pub is_unsafe: bool,
     pub direction: FnDirection,
+    pub doc: Option<String>,
 }
 
 pub trait Function: fmt::Display {
@@ -479,6 +485,7 @@ pub struct ClassSignal {
     pub name: String,
     pub parameters: Vec<FnParam>,
     pub surrounding_class: TyName,
+    pub doc: Option<String>,
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
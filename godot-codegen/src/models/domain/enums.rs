@@ -128,6 +128,8 @@ pub struct Enumerator {
     // i64 is common denominator for enum, bitfield and constant values.
     // Note that values > i64::MAX will be implicitly wrapped, see https://github.com/not-fl3/nanoserde/issues/89.
     pub value: EnumeratorValue,
+
+    pub doc: Option<String>,
 }
 
 #[derive(Clone, Eq, PartialEq, Hash)]
@@ -23,7 +23,7 @@ use crate::models::json::{
     JsonMethodArg, JsonMethodReturn, JsonNativeStructure, JsonOperator, JsonSignal, JsonSingleton,
     JsonUtilityFunction,
 };
-use crate::util::{get_api_level, ident, option_as_slice};
+use crate::util::{get_api_level, ident, is_third_party_class, option_as_slice};
 use crate::{conv, special_cases};
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -52,7 +52,7 @@ impl ExtensionApi {
             global_enums: json
                 .global_enums
                 .iter()
-                .map(|json| Enum::from_json(json, None))
+                .map(|json| Enum::from_json(json, None, ctx))
                 .collect(),
             godot_version: GodotApiVersion::from_json(&json.header),
             builtin_sizes: Self::builtin_size_from_json(&json.builtin_class_sizes),
@@ -103,14 +103,14 @@ impl Class {
 
         let constants = option_as_slice(&json.constants)
             .iter()
-            .map(ClassConstant::from_json)
+            .map(|c| ClassConstant::from_json(c, ctx))
             .collect();
 
         let enums = option_as_slice(&json.enums)
             .iter()
             .map(|e| {
                 let surrounding_class = Some(&ty_name);
-                Enum::from_json(e, surrounding_class)
+                Enum::from_json(e, surrounding_class, ctx)
             })
             .collect();
 
@@ -135,6 +135,12 @@ impl Class {
             .as_ref()
             .map(|godot_name| TyName::from_godot(godot_name));
 
+        let description = match (&json.brief_description, &json.description) {
+            (Some(brief), Some(description)) => Some(format!("{brief}\n\n{description}")),
+            (brief, description) => brief.clone().or_else(|| description.clone()),
+        };
+        let doc = make_doc(description.as_deref(), ctx);
+
         Some(Self {
             common: ClassCommons {
                 name: ty_name,
@@ -144,12 +150,14 @@ impl Class {
             is_instantiable,
             is_experimental,
             is_final,
+            is_third_party: is_third_party_class(json),
             base_class,
             api_level: get_api_level(json),
             constants,
             enums,
             methods,
             signals,
+            doc,
         })
     }
 }
@@ -188,7 +196,7 @@ impl BuiltinClass {
             .iter()
             .map(|e| {
                 let surrounding_class = Some(&ty_name);
-                Enum::from_json(&e.to_enum(), surrounding_class)
+                Enum::from_json(&e.to_enum(), surrounding_class, ctx)
             })
             .collect();
 
@@ -422,6 +430,7 @@ impl BuiltinMethod {
                 direction: FnDirection::Outbound {
                     hash: method.hash.expect("hash absent for builtin method"),
                 },
+                doc: None, // Builtin descriptions are not part of the API JSON.
             },
             qualifier: FnQualifier::from_const_static(method.is_const, method.is_static),
             surrounding_class,
@@ -599,6 +608,7 @@ impl ClassMethod {
                 is_virtual_required,
                 is_unsafe,
                 direction,
+                doc: make_doc(method.description.as_deref(), ctx),
             },
             qualifier,
             surrounding_class: class_name.clone(),
@@ -648,6 +658,7 @@ impl ClassSignal {
             name: json_signal.name.clone(),
             parameters: FnParam::builder().build_many(&json_signal.arguments, flow, ctx),
             surrounding_class: surrounding_class.clone(),
+            doc: make_doc(json_signal.description.as_deref(), ctx),
         })
     }
 }
@@ -691,6 +702,7 @@ impl UtilityFunction {
                 direction: FnDirection::Outbound {
                     hash: function.hash,
                 },
+                doc: make_doc(function.description.as_deref(), ctx),
             },
         })
     }
@@ -700,7 +712,11 @@ impl UtilityFunction {
 // Enums + enumerator constants
 
 impl Enum {
-    pub fn from_json(json_enum: &JsonEnum, surrounding_class: Option<&TyName>) -> Self {
+    pub fn from_json(
+        json_enum: &JsonEnum,
+        surrounding_class: Option<&TyName>,
+        ctx: &Context,
+    ) -> Self {
         let godot_name = &json_enum.name;
         let is_bitfield = special_cases::is_enum_bitfield(surrounding_class, godot_name)
             .unwrap_or(json_enum.is_bitfield);
@@ -731,7 +747,7 @@ impl Enum {
             .iter()
             .zip(rust_enumerator_names)
             .map(|(json_constant, rust_name)| {
-                Enumerator::from_json(json_constant, rust_name, is_bitfield)
+                Enumerator::from_json(json_constant, rust_name, is_bitfield, ctx)
             })
             .collect();
 
@@ -751,7 +767,12 @@ impl Enum {
 }
 
 impl Enumerator {
-    pub fn from_json(json: &JsonEnumConstant, rust_name: Ident, is_bitfield: bool) -> Self {
+    pub fn from_json(
+        json: &JsonEnumConstant,
+        rust_name: Ident,
+        is_bitfield: bool,
+        ctx: &Context,
+    ) -> Self {
         let value = if is_bitfield {
             let ord = json.value.try_into().unwrap_or_else(|_| {
                 panic!(
@@ -776,6 +797,7 @@ impl Enumerator {
             name: rust_name,
             godot_name: json.name.clone(),
             value,
+            doc: make_doc(json.description.as_deref(), ctx),
         }
     }
 }
@@ -784,7 +806,7 @@ impl Enumerator {
 // Constants
 
 impl ClassConstant {
-    pub fn from_json(json: &JsonClassConstant) -> Self {
+    pub fn from_json(json: &JsonClassConstant, ctx: &Context) -> Self {
         // Godot types only use i32, but other extensions may have i64. Use smallest possible type.
         let value = if let Ok(i32_value) = i32::try_from(json.value) {
             ClassConstantValue::I32(i32_value)
@@ -795,12 +817,30 @@ impl ClassConstant {
         Self {
             name: json.name.clone(),
             value,
+            doc: make_doc(json.description.as_deref(), ctx),
         }
     }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+/// Converts a BBCode description from Godot's class reference to rustdoc Markdown, with intra-doc links to generated engine classes.
+fn make_doc(description: Option<&str>, ctx: &Context) -> Option<String> {
+    let description = description?.trim();
+    if description.is_empty() {
+        return None;
+    }
+
+    let doc = conv::bbcode_to_markdown(description, |godot_class_name| {
+        let class_name = TyName::from_godot(godot_class_name);
+
+        ctx.is_engine_class(&class_name)
+            .then(|| format!("crate::classes::{}", class_name.rust_ty))
+    });
+
+    Some(doc)
+}
+
 /// Validates that all parameters and non-unit return types declared in an enum replacement slices actually exist in the method.
 ///
 /// This is a measure to prevent accidental typos or listing inexistent parameters, which would have no effect.
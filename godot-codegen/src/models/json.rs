@@ -29,6 +29,14 @@ pub struct JsonExtensionApi {
     pub singletons: Vec<JsonSingleton>,
 }
 
+/// Classes registered by other GDExtensions. Either a full API JSON dumped with these extensions loaded, or a hand-written subset.
+#[cfg(feature = "codegen-third-party")]
+#[derive(DeJson)]
+pub struct JsonThirdPartyApi {
+    pub classes: Vec<JsonClass>,
+    pub singletons: Option<Vec<JsonSingleton>>,
+}
+
 #[derive(DeJson, Clone, Debug)]
 pub struct JsonHeader {
     pub version_major: u8,
@@ -81,6 +89,9 @@ pub struct JsonClass {
     pub methods: Option<Vec<JsonClassMethod>>,
     // pub properties: Option<Vec<Property>>,
     pub signals: Option<Vec<JsonSignal>>,
+    /// Class reference (BBCode), only with `--dump-extension-api-with-docs`. Same for other `description` fields.
+    pub brief_description: Option<String>,
+    pub description: Option<String>,
 }
 
 #[derive(DeJson)]
@@ -127,6 +138,7 @@ pub struct JsonEnumConstant {
     // i64 is common denominator for enum, bitfield and constant values.
     // Note that values > i64::MAX will be implicitly wrapped, see https://github.com/not-fl3/nanoserde/issues/89.
     pub value: i64,
+    pub description: Option<String>,
 }
 
 impl JsonEnumConstant {
@@ -185,6 +197,7 @@ pub struct JsonProperty {
 pub struct JsonSignal {
     pub name: String,
     pub arguments: Option<Vec<JsonMethodArg>>,
+    pub description: Option<String>,
 }
 
 #[derive(DeJson)]
@@ -203,6 +216,7 @@ pub struct JsonUtilityFunction {
     pub is_vararg: bool,
     pub hash: i64,
     pub arguments: Option<Vec<JsonMethodArg>>,
+    pub description: Option<String>,
 }
 
 #[derive(DeJson)]
@@ -228,6 +242,7 @@ pub struct JsonClassMethod {
     pub hash: Option<i64>,
     pub return_value: Option<JsonMethodReturn>,
     pub arguments: Option<Vec<JsonMethodArg>>,
+    pub description: Option<String>,
 }
 
 // Example: set_point_weight_scale ->
@@ -276,5 +291,164 @@ pub fn load_extension_api(watch: &mut godot_bindings::StopWatch) -> JsonExtensio
     watch.record("deserialize_json");
 
     println!("Parsed extension_api.json for version {:?}", model.header);
+
+    #[cfg(feature = "codegen-docs")]
+    let model = load_docs(model, watch);
+
     model
 }
+
+/// Adds classes of other GDExtensions to the engine API, so they are generated alongside engine classes.
+///
+/// Classes already known to the engine API are skipped, so a JSON dumped from an editor only contributes the extension classes.
+#[cfg(feature = "codegen-third-party")]
+pub fn load_third_party_api(api: &mut JsonExtensionApi, watch: &mut godot_bindings::StopWatch) {
+    use std::collections::HashSet;
+
+    let mut known_classes: HashSet<String> = api.classes.iter().map(|c| c.name.clone()).collect();
+    let mut added_classes = HashSet::new();
+    let mut known_singletons: HashSet<String> =
+        api.singletons.iter().map(|s| s.name.clone()).collect();
+
+    for (path, json) in godot_bindings::load_third_party_api_jsons(watch) {
+        let third_party: JsonThirdPartyApi = DeJson::deserialize_json(&json).unwrap_or_else(|e| {
+            panic!(
+                "failed to deserialize third-party API JSON '{}': {e}",
+                path.display()
+            )
+        });
+
+        let mut class_count = 0;
+        for mut class in third_party.classes {
+            if !known_classes.insert(class.name.clone()) {
+                continue;
+            }
+
+            // Marks the class as third-party; hand-written JSONs may use the engine's "core"/"editor" instead.
+            class.api_type = match class.api_type.as_str() {
+                "editor" | "editor_extension" => "editor_extension",
+                _ => "extension",
+            }
+            .to_string();
+
+            added_classes.insert(class.name.clone());
+            api.classes.push(class);
+            class_count += 1;
+        }
+
+        for singleton in third_party.singletons.into_iter().flatten() {
+            if known_singletons.insert(singleton.name.clone()) {
+                api.singletons.push(singleton);
+            }
+        }
+
+        if class_count > 0 {
+            println!(
+                "cargo:warning=Added {class_count} third-party classes from {}",
+                path.display()
+            );
+        }
+    }
+
+    crate::special_cases::init_third_party_classes(added_classes);
+    watch.record("merge_third_party_json");
+}
+
+/// Adds the class reference from a separately provided JSON, unless the API JSON already contains it.
+#[cfg(feature = "codegen-docs")]
+fn load_docs(
+    mut model: JsonExtensionApi,
+    watch: &mut godot_bindings::StopWatch,
+) -> JsonExtensionApi {
+    let has_docs = model.classes.iter().any(|c| c.description.is_some());
+    if has_docs {
+        return model;
+    }
+
+    let Some(json) = godot_bindings::load_gdextension_docs_json(watch) else {
+        println!(
+            "cargo:warning=Feature `codegen-docs` needs an API JSON with docs; set GODOT4_DOCS_JSON \
+            to the output of `godot --dump-extension-api-with-docs`. Continuing without Godot docs."
+        );
+        return model;
+    };
+
+    let docs: JsonExtensionApi =
+        DeJson::deserialize_json(&json).expect("failed to deserialize docs JSON");
+
+    merge_docs(&mut model, docs);
+    watch.record("merge_docs_json");
+    model
+}
+
+/// Copies descriptions from `docs` into `model`, matching symbols by name. Symbols missing in `docs` stay undocumented.
+#[cfg(feature = "codegen-docs")]
+fn merge_docs(model: &mut JsonExtensionApi, docs: JsonExtensionApi) {
+    use std::collections::HashMap;
+
+    fn by_name<T>(items: Option<Vec<T>>, name: impl Fn(&T) -> &str) -> HashMap<String, T> {
+        items
+            .into_iter()
+            .flatten()
+            .map(|item| (name(&item).to_string(), item))
+            .collect()
+    }
+
+    fn merge_constants(constants: &mut [JsonEnumConstant], doc_constants: Vec<JsonEnumConstant>) {
+        let mut doc_constants = by_name(Some(doc_constants), |c| c.name.as_str());
+        for constant in constants {
+            if let Some(doc) = doc_constants.remove(&constant.name) {
+                constant.description = doc.description;
+            }
+        }
+    }
+
+    fn merge_enums(enums: &mut [JsonEnum], doc_enums: Option<Vec<JsonEnum>>) {
+        let mut doc_enums = by_name(doc_enums, |e| e.name.as_str());
+        for enum_ in enums {
+            if let Some(doc) = doc_enums.remove(&enum_.name) {
+                merge_constants(&mut enum_.values, doc.values);
+            }
+        }
+    }
+
+    merge_enums(&mut model.global_enums, Some(docs.global_enums));
+
+    let mut doc_functions = by_name(Some(docs.utility_functions), |f| f.name.as_str());
+    for function in &mut model.utility_functions {
+        if let Some(doc) = doc_functions.remove(&function.name) {
+            function.description = doc.description;
+        }
+    }
+
+    let mut doc_classes = by_name(Some(docs.classes), |c| c.name.as_str());
+    for class in &mut model.classes {
+        let Some(doc) = doc_classes.remove(&class.name) else {
+            continue;
+        };
+
+        class.brief_description = doc.brief_description;
+        class.description = doc.description;
+
+        if let (Some(constants), Some(doc_constants)) = (&mut class.constants, doc.constants) {
+            merge_constants(constants, doc_constants);
+        }
+        if let Some(enums) = &mut class.enums {
+            merge_enums(enums, doc.enums);
+        }
+
+        let mut doc_methods = by_name(doc.methods, |m| m.name.as_str());
+        for method in class.methods.iter_mut().flatten() {
+            if let Some(doc) = doc_methods.remove(&method.name) {
+                method.description = doc.description;
+            }
+        }
+
+        let mut doc_signals = by_name(doc.signals, |s| s.name.as_str());
+        for signal in class.signals.iter_mut().flatten() {
+            if let Some(doc) = doc_signals.remove(&signal.name) {
+                signal.description = doc.description;
+            }
+        }
+    }
+}
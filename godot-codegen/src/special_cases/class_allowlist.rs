@@ -0,0 +1,440 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Engine classes selected by the user (feature `codegen-allowlist`), as a faster-compiling alternative to `codegen-full`.
+//!
+//! The selection is the union of:
+//! - classes that godot-rust itself needs (the minimal codegen set),
+//! - classes listed by the user,
+//! - direct dependencies of listed classes: types of method arguments, return values and signal parameters, and classes declaring
+//!   enums used in them,
+//! - base classes of all the above.
+//!
+//! Dependencies are not followed further, so that a single class doesn't pull in most of the engine. Methods of dependencies that
+//! refer to non-selected classes are skipped, like in minimal codegen. These are listed in the build output.
+//!
+//! Only engine classes are selected; classes of third-party GDExtensions are unaffected.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::Path;
+use std::sync::OnceLock;
+
+use crate::models::json::{JsonClass, JsonClassMethod, JsonExtensionApi};
+
+/// Engine classes not to generate. Set once per build script, before any class is checked.
+static EXCLUDED: OnceLock<HashSet<String>> = OnceLock::new();
+
+/// Reads the user's class list and resolves it against the engine API.
+pub(crate) fn init(
+    api: &JsonExtensionApi,
+    required_classes: &[&str],
+    watch: &mut godot_bindings::StopWatch,
+) {
+    EXCLUDED.get_or_init(|| {
+        let (path, contents) = godot_bindings::load_class_allowlist(watch);
+        let listed = parse_class_list(&path, &contents);
+
+        let engine_classes = EngineClasses::new(&api.classes);
+        let selected = resolve(&engine_classes, required_classes, &listed);
+        report(&path, &engine_classes, &listed, &selected);
+
+        watch.record("resolve_class_allowlist");
+        api.classes
+            .iter()
+            .filter(|class| !selected.contains(&class.name))
+            .map(|class| class.name.clone())
+            .collect()
+    });
+}
+
+/// Whether the type is, or refers to, an engine class outside the allowlist.
+///
+/// Also accepts qualified types such as `enum::Node.ProcessMode`. Non-class types are never excluded.
+pub(crate) fn is_excluded(godot_type: &str) -> bool {
+    let excluded = EXCLUDED
+        .get()
+        .expect("class allowlist must be initialized before codegen");
+
+    type_tokens(godot_type).any(|token| excluded.contains(token))
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Parsing
+
+/// Parses either a `.toml` manifest with a `classes` array, or a plain list of class names.
+pub(crate) fn parse_class_list(path: &Path, contents: &str) -> Vec<String> {
+    if path.extension().is_some_and(|ext| ext == "toml") {
+        return parse_manifest_classes(contents).unwrap_or_else(|| {
+            panic!(
+                "'{}' has no `classes` array in [package.metadata.godot] or [workspace.metadata.godot]",
+                path.display()
+            )
+        });
+    }
+
+    // One or more names per line, separated by whitespace or commas; `#` starts a comment.
+    contents
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default())
+        .flat_map(|line| line.split(|c: char| c.is_whitespace() || c == ','))
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Finds `classes = ["...", ...]` in the `[package.metadata.godot]` or `[workspace.metadata.godot]` table.
+///
+/// Only supports the subset of TOML needed for a string array (possibly spanning multiple lines), so no TOML parser is needed.
+fn parse_manifest_classes(toml: &str) -> Option<Vec<String>> {
+    let mut in_godot_table = false;
+    let mut array: Option<String> = None;
+
+    for line in toml.lines() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+
+        if let Some(array) = array.as_mut() {
+            array.push_str(line);
+        } else if line.starts_with('[') {
+            let table = line.replace(' ', "");
+            in_godot_table =
+                table == "[package.metadata.godot]" || table == "[workspace.metadata.godot]";
+            continue;
+        } else if in_godot_table {
+            let value = line
+                .strip_prefix("classes")
+                .and_then(|rest| rest.trim_start().strip_prefix('='));
+
+            match value {
+                Some(value) => array = Some(value.to_string()),
+                None => continue,
+            }
+        } else {
+            continue;
+        }
+
+        if array.as_ref().is_some_and(|array| array.contains(']')) {
+            break;
+        }
+    }
+
+    // Names are every second piece between quotes: `["Node3D", "Camera3D"]`.
+    let names = array?
+        .split('"')
+        .skip(1)
+        .step_by(2)
+        .map(str::to_string)
+        .collect();
+
+    Some(names)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Resolution
+
+/// Splits a JSON type into identifiers: `typedarray::24/17:Texture2D` -> `typedarray`, `24`, `17`, `Texture2D`.
+fn type_tokens(ty: &str) -> impl Iterator<Item = &str> {
+    ty.split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        .filter(|token| !token.is_empty())
+}
+
+struct EngineClasses<'a> {
+    by_name: HashMap<&'a str, &'a JsonClass>,
+}
+
+impl<'a> EngineClasses<'a> {
+    fn new(classes: &'a [JsonClass]) -> Self {
+        let by_name = classes
+            .iter()
+            .map(|class| (class.name.as_str(), class))
+            .collect();
+
+        Self { by_name }
+    }
+
+    fn get(&self, name: &str) -> Option<&'a JsonClass> {
+        self.by_name.get(name).copied()
+    }
+
+    /// Engine classes mentioned in a JSON type, e.g. `Node` in `enum::Node.ProcessMode` or `Texture2D` in `typedarray::Texture2D`.
+    fn classes_in_type<'t>(&'t self, ty: &'t str) -> impl Iterator<Item = &'t str> {
+        type_tokens(ty).filter(|token| self.by_name.contains_key(token))
+    }
+
+    /// Engine classes mentioned in the signature of a method.
+    fn classes_in_method<'t>(&'t self, method: &'t JsonClassMethod) -> Vec<&'t str> {
+        let return_type = method.return_value.iter().map(|ret| ret.type_.as_str());
+        let arg_types = method
+            .arguments
+            .iter()
+            .flatten()
+            .map(|arg| arg.type_.as_str());
+
+        return_type
+            .chain(arg_types)
+            .flat_map(|ty| self.classes_in_type(ty))
+            .collect()
+    }
+}
+
+fn resolve(
+    engine_classes: &EngineClasses,
+    required_classes: &[&str],
+    listed: &[String],
+) -> HashSet<String> {
+    let unknown: Vec<String> = listed
+        .iter()
+        .filter(|name| engine_classes.get(name).is_none())
+        .map(|name| match suggest(engine_classes, name) {
+            Some(suggestion) => format!("{name} (did you mean {suggestion}?)"),
+            None => name.clone(),
+        })
+        .collect();
+
+    assert!(
+        unknown.is_empty(),
+        "codegen allowlist contains classes that are not in the engine API: {}",
+        unknown.join(", ")
+    );
+
+    let mut selected = HashSet::new();
+    let mut add_with_bases = |name: &str| {
+        let mut current = engine_classes.get(name);
+        while let Some(class) = current {
+            if !selected.insert(class.name.clone()) {
+                break;
+            }
+            current = class
+                .inherits
+                .as_deref()
+                .and_then(|base| engine_classes.get(base));
+        }
+    };
+
+    for name in required_classes {
+        add_with_bases(name);
+    }
+
+    for name in listed {
+        add_with_bases(name);
+
+        let class = engine_classes.get(name).expect("checked above");
+        for method in class.methods.iter().flatten() {
+            for dependency in engine_classes.classes_in_method(method) {
+                add_with_bases(dependency);
+            }
+        }
+
+        let signal_args = class
+            .signals
+            .iter()
+            .flatten()
+            .flat_map(|signal| signal.arguments.iter().flatten());
+
+        for arg in signal_args {
+            for dependency in engine_classes.classes_in_type(&arg.type_) {
+                add_with_bases(dependency);
+            }
+        }
+    }
+
+    selected
+}
+
+/// Proposes the class with the same name, ignoring case (e.g. `HttpRequest` -> `HTTPRequest`).
+fn suggest<'a>(engine_classes: &EngineClasses<'a>, name: &str) -> Option<&'a str> {
+    engine_classes
+        .by_name
+        .keys()
+        .find(|class_name| class_name.eq_ignore_ascii_case(name))
+        .copied()
+}
+
+/// Prints which classes are generated, and which excluded classes cause methods of selected classes to be skipped.
+fn report(
+    path: &Path,
+    engine_classes: &EngineClasses,
+    listed: &[String],
+    selected: &HashSet<String>,
+) {
+    println!(
+        "Codegen allowlist '{}': generating {} of {} engine classes ({} listed, others required or dependencies).",
+        path.display(),
+        selected.len(),
+        engine_classes.by_name.len(),
+        listed.len(),
+    );
+
+    // Excluded class -> methods skipped because of it.
+    let mut skipped_methods: BTreeMap<&str, BTreeSet<String>> = BTreeMap::new();
+    for class_name in selected {
+        let class = engine_classes
+            .get(class_name)
+            .expect("selected from engine API");
+
+        for method in class.methods.iter().flatten() {
+            for dependency in engine_classes.classes_in_method(method) {
+                if !selected.contains(dependency) {
+                    skipped_methods
+                        .entry(dependency)
+                        .or_default()
+                        .insert(format!("{class_name}::{}", method.name));
+                }
+            }
+        }
+    }
+
+    if skipped_methods.is_empty() {
+        return;
+    }
+
+    // Warnings, since Cargo hides regular build script output; skipped methods otherwise surface only as confusing compile errors.
+    println!(
+        "cargo:warning=Codegen allowlist: excluded classes and the methods skipped because of them \
+        (add the classes to the allowlist to keep them):"
+    );
+    for (excluded, methods) in skipped_methods {
+        const MAX_SHOWN: usize = 5;
+
+        let mut shown = methods
+            .iter()
+            .take(MAX_SHOWN)
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(", ");
+        if methods.len() > MAX_SHOWN {
+            shown.push_str(&format!(" and {} more", methods.len() - MAX_SHOWN));
+        }
+
+        println!("cargo:warning=  {excluded}: {shown}");
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use nanoserde::DeJson;
+
+    use super::*;
+
+    #[test]
+    fn parse_plain_class_list() {
+        let list = "# Gameplay\nNode3D, Camera3D\n\n  HTTPRequest # networking\n";
+        let classes = parse_class_list(Path::new("godot-classes.txt"), list);
+
+        assert_eq!(classes, ["Node3D", "Camera3D", "HTTPRequest"]);
+    }
+
+    #[test]
+    fn parse_manifest_class_list() {
+        let manifest = r#"
+            [package]
+            name = "game"
+
+            [package.metadata.godot]
+            edition = "2024"
+            classes = [
+                "Node3D", # player
+                "Camera3D",
+            ]
+
+            [dependencies]
+            godot = "0.4"
+        "#;
+
+        let classes = parse_class_list(Path::new("Cargo.toml"), manifest);
+        assert_eq!(classes, ["Node3D", "Camera3D"]);
+
+        let workspace = "[workspace.metadata.godot]\nclasses = [\"Timer\"]";
+        assert_eq!(parse_manifest_classes(workspace).unwrap(), ["Timer"]);
+        assert_eq!(
+            parse_manifest_classes("[package]\nclasses = [\"Timer\"]"),
+            None
+        );
+    }
+
+    #[test]
+    fn resolve_pulls_direct_dependencies() {
+        let classes = test_classes();
+        let engine_classes = EngineClasses::new(&classes);
+        let selected = resolve(&engine_classes, &["Object"], &["Camera3D".to_string()]);
+
+        let expected = [
+            "Object",
+            // Listed class and its bases.
+            "Camera3D",
+            "Node3D",
+            "Node",
+            // Method argument, with bases.
+            "Environment",
+            "Resource",
+            "RefCounted",
+            // Class declaring an enum in a return type.
+            "Viewport",
+            // Signal parameter.
+            "World3D",
+        ];
+        assert_eq!(selected, expected.into_iter().map(String::from).collect());
+
+        // Dependencies of dependencies are not followed; unrelated classes are not selected.
+        assert!(!selected.contains("Sky"));
+        assert!(!selected.contains("HTTPRequest"));
+    }
+
+    #[test]
+    #[should_panic(expected = "HttpRequest (did you mean HTTPRequest?), NoSuchClass")]
+    fn resolve_rejects_unknown_classes() {
+        let classes = test_classes();
+        let engine_classes = EngineClasses::new(&classes);
+        let listed = ["HttpRequest".to_string(), "NoSuchClass".to_string()];
+
+        resolve(&engine_classes, &[], &listed);
+    }
+
+    fn test_classes() -> Vec<JsonClass> {
+        let class = |name: &str, inherits: Option<&str>, methods: &str, signals: &str| {
+            let inherits = inherits.map_or("null".to_string(), |base| format!("\"{base}\""));
+            format!(
+                r#"{{ "name": "{name}", "is_refcounted": false, "is_instantiable": true, "inherits": {inherits},
+                    "api_type": "core", "methods": [{methods}], "signals": [{signals}] }}"#
+            )
+        };
+        let method = |name: &str, arg_type: &str, return_type: &str| {
+            format!(
+                r#"{{ "name": "{name}", "is_const": false, "is_vararg": false, "is_static": false, "is_virtual": false,
+                    "return_value": {{ "type": "{return_type}" }}, "arguments": [{{ "name": "arg", "type": "{arg_type}" }}] }}"#
+            )
+        };
+
+        let classes = [
+            class("Object", None, "", ""),
+            class("RefCounted", Some("Object"), "", ""),
+            class("Resource", Some("RefCounted"), "", ""),
+            class("Node", Some("Object"), "", ""),
+            class("Node3D", Some("Node"), "", ""),
+            class("Viewport", Some("Node"), "", ""),
+            class("Sky", Some("Resource"), "", ""),
+            class("World3D", Some("Resource"), "", ""),
+            class("HTTPRequest", Some("Node"), "", ""),
+            class(
+                "Environment",
+                Some("Resource"),
+                &method("set_sky", "Sky", "void"),
+                "",
+            ),
+            class(
+                "Camera3D",
+                Some("Node3D"),
+                &method("set_environment", "Environment", "enum::Viewport.Msaa"),
+                r#"{ "name": "world_changed", "arguments": [{ "name": "world", "type": "World3D" }] }"#,
+            ),
+        ];
+
+        let json = format!("[{}]", classes.join(","));
+        DeJson::deserialize_json(&json).expect("valid test JSON")
+    }
+}
@@ -9,7 +9,14 @@
 
 // TODO make this file private and only accessed by special_cases.rs.
 
+#[cfg(feature = "codegen-third-party")]
+use std::collections::HashSet;
+#[cfg(feature = "codegen-third-party")]
+use std::sync::OnceLock;
+
 use crate::context::Context;
+#[cfg(feature = "codegen-allowlist")]
+use crate::models::json::JsonExtensionApi;
 use crate::models::json::{JsonBuiltinMethod, JsonClassMethod, JsonUtilityFunction};
 use crate::special_cases;
 
@@ -19,9 +26,46 @@ pub(crate) fn is_builtin_method_excluded(_method: &JsonBuiltinMethod) -> bool {
     false
 }
 
-#[cfg(not(feature = "codegen-full"))]
+#[cfg(all(not(feature = "codegen-full"), not(feature = "codegen-allowlist")))]
 pub(crate) fn is_class_excluded(godot_class_name: &str) -> bool {
-    !SELECTED_CLASSES.contains(&godot_class_name)
+    // Minimal codegen only selects engine classes; third-party classes are explicitly requested by the user.
+    !SELECTED_CLASSES.contains(&godot_class_name) && !is_third_party_class(godot_class_name)
+}
+
+/// Classes added from third-party API JSONs. Set once per build script, before any class is checked.
+#[cfg(feature = "codegen-third-party")]
+static THIRD_PARTY_CLASSES: OnceLock<HashSet<String>> = OnceLock::new();
+
+#[cfg(feature = "codegen-third-party")]
+pub(crate) fn init_third_party_classes(class_names: HashSet<String>) {
+    let _ = THIRD_PARTY_CLASSES.set(class_names);
+}
+
+#[cfg(all(not(feature = "codegen-full"), not(feature = "codegen-allowlist")))]
+fn is_third_party_class(godot_class_name: &str) -> bool {
+    #[cfg(feature = "codegen-third-party")]
+    {
+        THIRD_PARTY_CLASSES
+            .get()
+            .is_some_and(|classes| classes.contains(godot_class_name))
+    }
+
+    #[cfg(not(feature = "codegen-third-party"))]
+    {
+        let _ = godot_class_name;
+        false
+    }
+}
+
+#[cfg(feature = "codegen-allowlist")]
+pub(crate) fn is_class_excluded(godot_class_name: &str) -> bool {
+    super::class_allowlist::is_excluded(godot_class_name)
+}
+
+/// Resolves the user's class allowlist; the minimal codegen classes are always included, since godot-rust itself needs them.
+#[cfg(feature = "codegen-allowlist")]
+pub(crate) fn init_class_allowlist(api: &JsonExtensionApi, watch: &mut godot_bindings::StopWatch) {
+    super::class_allowlist::init(api, SELECTED_CLASSES, watch);
 }
 
 #[cfg(feature = "codegen-full")]
@@ -128,7 +172,7 @@ pub(crate) fn is_utility_function_excluded(
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Allowed-classes
 
-// Classes for minimal config
+// Classes for minimal config; also the base of the user's allowlist (`codegen-allowlist`).
 #[cfg(not(feature = "codegen-full"))]
 const SELECTED_CLASSES: &[&str] = &[
     // Core class hierarchy
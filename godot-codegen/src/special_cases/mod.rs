@@ -6,6 +6,8 @@
  */
 
 // Deliberately private -- all checks must go through `special_cases`.
+#[cfg(feature = "codegen-allowlist")]
+mod class_allowlist;
 mod codegen_special_cases;
 #[allow(clippy::module_inception)]
 mod special_cases;
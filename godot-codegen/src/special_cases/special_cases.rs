@@ -35,6 +35,8 @@ use crate::conv::to_enum_type_uncached;
 use crate::models::domain::{
     ClassCodegenLevel, Enum, EnumReplacements, FnReturn, RustTy, TyName, VirtualMethodPresence,
 };
+#[cfg(feature = "codegen-allowlist")]
+use crate::models::json::JsonExtensionApi;
 use crate::models::json::{JsonBuiltinMethod, JsonClassMethod, JsonSignal, JsonUtilityFunction};
 use crate::special_cases::codegen_special_cases;
 use crate::util::option_as_slice;
@@ -92,6 +94,18 @@ pub fn is_class_deleted(class_name: &TyName) -> bool {
         || is_godot_type_deleted(&class_name.godot_ty)
 }
 
+/// Records the classes added from third-party API JSONs, which are generated regardless of minimal codegen.
+#[cfg(feature = "codegen-third-party")]
+pub fn init_third_party_classes(class_names: std::collections::HashSet<String>) {
+    codegen_special_cases::init_third_party_classes(class_names)
+}
+
+/// Selects the classes to generate from the user's allowlist. Must be called before any class is checked for exclusion.
+#[cfg(feature = "codegen-allowlist")]
+pub fn init_class_allowlist(api: &JsonExtensionApi, watch: &mut godot_bindings::StopWatch) {
+    codegen_special_cases::init_class_allowlist(api, watch)
+}
+
 /// Native-struct types excluded in minimal codegen, because they hold codegen-excluded classes as fields.
 pub fn is_native_struct_excluded(ty: &str) -> bool {
     codegen_special_cases::is_native_struct_excluded(ty)
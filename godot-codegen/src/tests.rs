@@ -170,3 +170,179 @@ fn test_parse_native_structures_format() {
     ];
     assert_eq!(actual.unwrap(), expected);
 }
+
+#[test]
+fn test_bbcode_to_markdown() {
+    fn convert(bbcode: &str) -> String {
+        conv::bbcode_to_markdown(bbcode, |class_name| {
+            (class_name == "Node").then(|| format!("crate::classes::{class_name}"))
+        })
+    }
+
+    // Formatting, escaping and paragraphs.
+    assert_eq!(
+        convert("[b]Note:[/b] [i]very[/i] [u]important[/u]."),
+        "**Note:** *very* important."
+    );
+    assert_eq!(
+        convert("Use [lb]x[rb], not <x> or *x*."),
+        r"Use \[x\], not \<x\> or \*x\*."
+    );
+    assert_eq!(
+        convert("First line.\n\tSecond line.\n"),
+        "First line.\n\nSecond line."
+    );
+    assert_eq!(convert("[unknown]tag"), r"\[unknown\]tag");
+
+    // Symbols and classes.
+    assert_eq!(
+        convert("See [method Node.add_child]."),
+        "See `Node.add_child()`."
+    );
+    assert_eq!(
+        convert("Emits [signal ready] for [param node]."),
+        "Emits `ready` for `node`."
+    );
+    assert_eq!(
+        convert("A [Node] in [Object]."),
+        "A [`Node`](crate::classes::Node) in `Object`."
+    );
+    assert_eq!(
+        convert("[@GlobalScope] and [int]"),
+        "`@GlobalScope` and `int`"
+    );
+
+    // Code and links.
+    assert_eq!(
+        convert("Call [code]get_node(\"A\")[/code]."),
+        "Call `get_node(\"A\")`."
+    );
+    assert_eq!(convert("[code]`[/code]"), "`` ` ``");
+    assert_eq!(
+        convert("[url=https://godotengine.org]Godot[/url]"),
+        "[Godot](https://godotengine.org)"
+    );
+    assert_eq!(
+        convert("[url=$DOCS_URL/tutorials/io/index.html]Files[/url]"),
+        "[Files](https://docs.godotengine.org/en/stable/tutorials/io/index.html)"
+    );
+    assert_eq!(
+        convert("[url]https://godotengine.org[/url]"),
+        "<https://godotengine.org>"
+    );
+    assert_eq!(
+        convert("Icon: [img width=16]res://icon.png[/img]."),
+        "Icon: ."
+    );
+
+    assert_eq!(
+        convert("Example:\n[codeblock]\n\tfunc _ready():\n\t\tpass\n[/codeblock]\nDone."),
+        "Example:\n\n```gdscript\nfunc _ready():\n    pass\n```\n\nDone."
+    );
+    assert_eq!(
+        convert("[codeblocks]\n[gdscript]\nvar x = 1\n[/gdscript]\n[csharp]\nint x = 1;\n[/csharp]\n[/codeblocks]"),
+        "```gdscript\nvar x = 1\n```"
+    );
+    assert_eq!(
+        convert("[codeblock lang=text]\nplain\n[/codeblock]"),
+        "```text\nplain\n```"
+    );
+}
+
+#[cfg(feature = "codegen-third-party")]
+#[test]
+fn test_third_party_class_generation() {
+    use std::collections::HashMap;
+
+    use crate::context::Context;
+    use crate::generator::classes::generate_class_files;
+    use crate::models::domain::{ApiView, ExtensionApi};
+    use crate::models::json::{load_extension_api, load_third_party_api};
+
+    // Hand-written subset; `Node` is already part of the engine API and must not be added twice.
+    const JSON: &str = r#"{
+        "classes": [
+            {
+                "name": "Node",
+                "is_refcounted": false,
+                "is_instantiable": true,
+                "inherits": "Object",
+                "api_type": "core"
+            },
+            {
+                "name": "TerrainGenerator",
+                "is_refcounted": false,
+                "is_instantiable": true,
+                "inherits": "Node",
+                "api_type": "core",
+                "methods": [
+                    {
+                        "name": "get_height",
+                        "is_const": true,
+                        "is_vararg": false,
+                        "is_static": false,
+                        "is_virtual": false,
+                        "hash": 1234567,
+                        "return_value": { "type": "float", "meta": "double" },
+                        "arguments": [ { "name": "x", "type": "float", "meta": "double" } ]
+                    }
+                ]
+            }
+        ]
+    }"#;
+
+    let tmp_dir = std::env::temp_dir().join(format!("gdext-third-party-{}", std::process::id()));
+    std::fs::create_dir_all(&tmp_dir).unwrap();
+    let json_path = tmp_dir.join("terrain.json");
+    std::fs::write(&json_path, JSON).unwrap();
+
+    // Only read by load_third_party_api(); no other test depends on it.
+    std::env::set_var("GODOT4_THIRD_PARTY_JSON", &json_path);
+
+    let mut watch = godot_bindings::StopWatch::start();
+    let mut json_api = load_extension_api(&mut watch);
+    let engine_class_count = json_api.classes.len();
+    load_third_party_api(&mut json_api, &mut watch);
+
+    assert_eq!(json_api.classes.len(), engine_class_count + 1);
+    let json_class = json_api.classes.last().unwrap();
+    assert_eq!(json_class.name, "TerrainGenerator");
+    assert_eq!(json_class.api_type, "extension");
+
+    let mut ctx = Context::build_from_api(&json_api);
+    let api = ExtensionApi::from_json(&json_api, &mut ctx);
+    let view = ApiView::new(&api);
+
+    let class = api
+        .classes
+        .iter()
+        .find(|class| class.name().godot_ty == "TerrainGenerator")
+        .expect("third-party class is not excluded by minimal codegen");
+    assert!(class.is_third_party);
+
+    // Compare without whitespace, which depends on token formatting.
+    let gen_path = tmp_dir.join("classes");
+    let mut files = HashMap::new();
+    generate_class_files(&api, &mut ctx, &view, &gen_path, &mut |path, code| {
+        let code: String = code.to_string().split_whitespace().collect();
+        files.insert(path, code);
+    });
+    std::fs::remove_dir_all(&tmp_dir).unwrap();
+
+    let class_code = &files[&gen_path.join("terrain_generator.rs")];
+    assert!(class_code.contains(r#"ensure_third_party_class("TerrainGenerator")"#));
+    assert!(class_code.contains(r#"ClassId::new_dynamic("TerrainGenerator")"#));
+    assert!(
+        class_code.contains(r#"load_third_party_method("TerrainGenerator","get_height",1234567"#)
+    );
+    assert!(class_code.contains("pubfnget_height(&self,x:f64,)"));
+
+    // Engine classes are looked up through the method tables instead.
+    let node_code = &files[&gen_path.join("node.rs")];
+    assert!(!node_code.contains("load_third_party_method"));
+
+    let mod_code = &files[&gen_path.join("mod.rs")];
+    assert!(mod_code.contains(
+        "pubmodthird_party{pubusesuper::terrain_generator::re_export::{TerrainGenerator"
+    ));
+}
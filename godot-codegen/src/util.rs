@@ -74,6 +74,11 @@ pub fn get_api_level(class: &JsonClass) -> ClassCodegenLevel {
     }
 }
 
+/// Whether the class is registered by another GDExtension rather than the engine.
+pub fn is_third_party_class(class: &JsonClass) -> bool {
+    matches!(class.api_type.as_str(), "extension" | "editor_extension")
+}
+
 pub fn ident(s: &str) -> Ident {
     format_ident!("{}", s)
 }
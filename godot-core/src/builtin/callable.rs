@@ -203,6 +203,30 @@ impl Callable {
         )
     }
 
+    /// Like [`from_once_fn()`][Self::from_once_fn], but the callable may be created on another thread than the one invoking it.
+    ///
+    /// Used to hand work to the main thread via `call_deferred()`, which Godot allows from any thread.
+    pub(crate) fn from_once_sync_fn<R, F, S>(name: S, rust_function: F) -> Self
+    where
+        R: ToGodot,
+        F: 'static + Send + FnOnce(&[&Variant]) -> R,
+        S: Into<CowStr>,
+    {
+        let mut rust_fn_once = Some(rust_function);
+        Self::from_fn_wrapper_with_thread(
+            name,
+            move |args| {
+                let rust_fn_once = rust_fn_once
+                    .take()
+                    .expect("callable created with from_once_sync_fn() has already been consumed");
+
+                rust_fn_once(args)
+            },
+            None,
+            None, // Not bound to the creating thread.
+        )
+    }
+
     #[cfg(feature = "trace")] // Test only.
     #[doc(hidden)]
     pub fn __once_fn<F, S>(name: S, rust_function: F) -> Self
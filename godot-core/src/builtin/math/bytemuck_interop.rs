@@ -0,0 +1,71 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! [`bytemuck`] `Pod` and `Zeroable` impls for builtin math types, behind the `interop-bytemuck` feature.
+//!
+//! All listed types are `#[repr(C)]` and consist only of [`real`], `f32` or `i32` components, so they have no padding with either
+//! precision. The size assertions below verify that, since `double-precision` changes the component size of `real`-based types.
+
+use crate::builtin::{
+    real, Aabb, Basis, Color, Plane, Projection, Quaternion, Rect2, Rect2i, Transform2D,
+    Transform3D, Vector2, Vector2i, Vector3, Vector3i, Vector4, Vector4i,
+};
+
+macro_rules! impl_bytemuck {
+    ($( $Type:ty: $count:literal x $Component:ty ),* $(,)?) => {
+        $(
+            const _: () = assert!(
+                size_of::<$Type>() == $count * size_of::<$Component>(),
+                concat!(stringify!($Type), " must consist of ", stringify!($count), " packed ", stringify!($Component), " components")
+            );
+
+            // SAFETY: `#[repr(C)]`, only components of a single primitive type without padding (see assertion above); all bit patterns
+            // are valid, and all-zero is a valid value.
+            unsafe impl bytemuck::Zeroable for $Type {}
+            unsafe impl bytemuck::Pod for $Type {}
+        )*
+    };
+}
+
+impl_bytemuck!(
+    Vector2: 2 x real,
+    Vector3: 3 x real,
+    Vector4: 4 x real,
+    Vector2i: 2 x i32,
+    Vector3i: 3 x i32,
+    Vector4i: 4 x i32,
+    Quaternion: 4 x real,
+    Plane: 4 x real,
+    Rect2: 4 x real,
+    Rect2i: 4 x i32,
+    Aabb: 6 x real,
+    Basis: 9 x real,
+    Transform2D: 6 x real,
+    Transform3D: 12 x real,
+    Projection: 16 x real,
+    Color: 4 x f32,
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn cast_slices() {
+        let points = [Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0)];
+        let components: &[real] = bytemuck::cast_slice(&points);
+        assert_eq!(components, [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let transform: Transform3D = bytemuck::Zeroable::zeroed();
+        assert_eq!(transform.origin, Vector3::ZERO);
+
+        let color = Color::from_rgba(0.5, 0.25, 1.0, 1.0);
+        let bytes = bytemuck::bytes_of(&color);
+        assert_eq!(bytes.len(), 16);
+        assert_eq!(bytemuck::pod_read_unaligned::<Color>(bytes), color);
+    }
+}
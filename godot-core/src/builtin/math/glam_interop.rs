@@ -0,0 +1,103 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Public `From` conversions between builtin math types and [`glam`], behind the `interop-glam` feature.
+//!
+//! Float types map to the glam types matching [`real`](crate::builtin::real), i.e. `Vec3` by default and `DVec3` with
+//! `double-precision`. The conversions reuse the internal mapping that builtin methods are implemented with.
+
+use crate::builtin::math::GlamType;
+use crate::builtin::{
+    Basis, Color, Projection, Quaternion, RAffine2, RAffine3, RMat3, RMat4, RQuat, RVec2, RVec3,
+    RVec4, Transform2D, Transform3D, Vector2, Vector2i, Vector3, Vector3i, Vector4, Vector4i,
+};
+
+macro_rules! impl_glam_from {
+    ($( $Godot:ty => $Glam:ty ),* $(,)?) => {
+        $(
+            impl From<$Godot> for $Glam {
+                #[inline]
+                fn from(value: $Godot) -> Self {
+                    <$Glam as GlamType>::from_front(&value)
+                }
+            }
+
+            impl From<$Glam> for $Godot {
+                #[inline]
+                fn from(value: $Glam) -> Self {
+                    value.to_front()
+                }
+            }
+        )*
+    };
+}
+
+impl_glam_from!(
+    Vector2 => RVec2,
+    Vector3 => RVec3,
+    Vector4 => RVec4,
+    Vector2i => glam::IVec2,
+    Vector3i => glam::IVec3,
+    Vector4i => glam::IVec4,
+    Quaternion => RQuat,
+    Basis => RMat3,
+    Transform2D => RAffine2,
+    Transform3D => RAffine3,
+    Projection => RMat4,
+);
+
+// Color is always single-precision and has no dedicated glam type; map it component-wise to (r, g, b, a).
+impl From<Color> for glam::Vec4 {
+    #[inline]
+    fn from(value: Color) -> Self {
+        glam::Vec4::new(value.r, value.g, value.b, value.a)
+    }
+}
+
+impl From<glam::Vec4> for Color {
+    #[inline]
+    fn from(value: glam::Vec4) -> Self {
+        Color::from_rgba(value.x, value.y, value.z, value.w)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::assert_eq_approx;
+    use crate::builtin::EulerOrder;
+
+    #[test]
+    fn vector_roundtrip() {
+        let vector = Vector3::new(1.0, -2.5, 3.0);
+        let glam_vector = RVec3::from(vector);
+        assert_eq!(glam_vector.to_array(), [1.0, -2.5, 3.0]);
+        assert_eq!(Vector3::from(glam_vector), vector);
+
+        let vector = Vector2i::new(7, -8);
+        assert_eq!(Vector2i::from(glam::IVec2::from(vector)), vector);
+    }
+
+    #[test]
+    fn transform_matches_godot_math() {
+        let basis = Basis::from_euler(EulerOrder::XYZ, Vector3::new(0.3, -0.7, 1.1));
+        let transform = Transform3D::new(basis, Vector3::new(1.0, 2.0, 3.0));
+        let point = Vector3::new(-4.0, 5.0, 0.5);
+
+        let glam_transform = RAffine3::from(transform);
+        let transformed = Vector3::from(glam_transform.transform_point3(point.into()));
+        assert_eq_approx!(transformed, transform * point);
+        assert_eq_approx!(Transform3D::from(glam_transform), transform);
+    }
+
+    #[test]
+    fn color_components() {
+        let color = Color::from_rgba(0.1, 0.2, 0.3, 0.4);
+        assert_eq!(glam::Vec4::from(color), glam::Vec4::new(0.1, 0.2, 0.3, 0.4));
+        assert_eq!(Color::from(glam::Vec4::from(color)), color);
+    }
+}
@@ -0,0 +1,257 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Conversions between builtin math types and [`mint`], behind the `interop-mint` feature.
+//!
+//! Matrices convert to column-major mint types, matching the column vectors that Godot exposes (`Basis::col_a()`,
+//! `Transform2D::a`, `Projection::cols`, ...). `Basis` additionally converts to `RowMatrix3`, which is its storage layout.
+
+use crate::builtin::{
+    real, Basis, Color, Projection, Quaternion, Transform2D, Transform3D, Vector2, Vector2i,
+    Vector3, Vector3i, Vector4, Vector4i,
+};
+
+macro_rules! impl_mint_vector {
+    ($( $Godot:ty => $Mint:ident<$T:ty> { $($field:ident),+ } ),* $(,)?) => {
+        $(
+            impl From<$Godot> for mint::$Mint<$T> {
+                #[inline]
+                fn from(value: $Godot) -> Self {
+                    Self { $( $field: value.$field ),+ }
+                }
+            }
+
+            impl From<mint::$Mint<$T>> for $Godot {
+                #[inline]
+                fn from(value: mint::$Mint<$T>) -> Self {
+                    Self { $( $field: value.$field ),+ }
+                }
+            }
+
+            impl mint::IntoMint for $Godot {
+                type MintType = mint::$Mint<$T>;
+            }
+        )*
+    };
+}
+
+impl_mint_vector!(
+    Vector2 => Vector2<real> { x, y },
+    Vector3 => Vector3<real> { x, y, z },
+    Vector4 => Vector4<real> { x, y, z, w },
+    Vector2i => Vector2<i32> { x, y },
+    Vector3i => Vector3<i32> { x, y, z },
+    Vector4i => Vector4<i32> { x, y, z, w },
+);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Quaternion and color
+
+impl From<Quaternion> for mint::Quaternion<real> {
+    #[inline]
+    fn from(value: Quaternion) -> Self {
+        Self {
+            v: mint::Vector3 {
+                x: value.x,
+                y: value.y,
+                z: value.z,
+            },
+            s: value.w,
+        }
+    }
+}
+
+impl From<mint::Quaternion<real>> for Quaternion {
+    #[inline]
+    fn from(value: mint::Quaternion<real>) -> Self {
+        Quaternion::new(value.v.x, value.v.y, value.v.z, value.s)
+    }
+}
+
+impl mint::IntoMint for Quaternion {
+    type MintType = mint::Quaternion<real>;
+}
+
+/// Component-wise as (r, g, b, a).
+impl From<Color> for mint::Vector4<f32> {
+    #[inline]
+    fn from(value: Color) -> Self {
+        Self {
+            x: value.r,
+            y: value.g,
+            z: value.b,
+            w: value.a,
+        }
+    }
+}
+
+impl From<mint::Vector4<f32>> for Color {
+    #[inline]
+    fn from(value: mint::Vector4<f32>) -> Self {
+        Color::from_rgba(value.x, value.y, value.z, value.w)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Matrices
+
+impl From<Basis> for mint::ColumnMatrix3<real> {
+    #[inline]
+    fn from(value: Basis) -> Self {
+        Self {
+            x: value.col_a().into(),
+            y: value.col_b().into(),
+            z: value.col_c().into(),
+        }
+    }
+}
+
+impl From<mint::ColumnMatrix3<real>> for Basis {
+    #[inline]
+    fn from(value: mint::ColumnMatrix3<real>) -> Self {
+        Basis::from_cols(value.x.into(), value.y.into(), value.z.into())
+    }
+}
+
+impl From<Basis> for mint::RowMatrix3<real> {
+    #[inline]
+    fn from(value: Basis) -> Self {
+        let [x, y, z] = value.rows;
+        Self {
+            x: x.into(),
+            y: y.into(),
+            z: z.into(),
+        }
+    }
+}
+
+impl From<mint::RowMatrix3<real>> for Basis {
+    #[inline]
+    fn from(value: mint::RowMatrix3<real>) -> Self {
+        Basis::from_rows(value.x.into(), value.y.into(), value.z.into())
+    }
+}
+
+impl mint::IntoMint for Basis {
+    type MintType = mint::ColumnMatrix3<real>;
+}
+
+/// Columns `a`, `b` and `origin`.
+impl From<Transform2D> for mint::ColumnMatrix2x3<real> {
+    #[inline]
+    fn from(value: Transform2D) -> Self {
+        Self {
+            x: value.a.into(),
+            y: value.b.into(),
+            z: value.origin.into(),
+        }
+    }
+}
+
+impl From<mint::ColumnMatrix2x3<real>> for Transform2D {
+    #[inline]
+    fn from(value: mint::ColumnMatrix2x3<real>) -> Self {
+        Transform2D::from_cols(value.x.into(), value.y.into(), value.z.into())
+    }
+}
+
+impl mint::IntoMint for Transform2D {
+    type MintType = mint::ColumnMatrix2x3<real>;
+}
+
+/// Three basis columns, followed by `origin`.
+impl From<Transform3D> for mint::ColumnMatrix3x4<real> {
+    #[inline]
+    fn from(value: Transform3D) -> Self {
+        Self {
+            x: value.basis.col_a().into(),
+            y: value.basis.col_b().into(),
+            z: value.basis.col_c().into(),
+            w: value.origin.into(),
+        }
+    }
+}
+
+impl From<mint::ColumnMatrix3x4<real>> for Transform3D {
+    #[inline]
+    fn from(value: mint::ColumnMatrix3x4<real>) -> Self {
+        Transform3D::from_cols(
+            value.x.into(),
+            value.y.into(),
+            value.z.into(),
+            value.w.into(),
+        )
+    }
+}
+
+impl mint::IntoMint for Transform3D {
+    type MintType = mint::ColumnMatrix3x4<real>;
+}
+
+impl From<Projection> for mint::ColumnMatrix4<real> {
+    #[inline]
+    fn from(value: Projection) -> Self {
+        let [x, y, z, w] = value.cols;
+        Self {
+            x: x.into(),
+            y: y.into(),
+            z: z.into(),
+            w: w.into(),
+        }
+    }
+}
+
+impl From<mint::ColumnMatrix4<real>> for Projection {
+    #[inline]
+    fn from(value: mint::ColumnMatrix4<real>) -> Self {
+        Projection::from_cols(
+            value.x.into(),
+            value.y.into(),
+            value.z.into(),
+            value.w.into(),
+        )
+    }
+}
+
+impl mint::IntoMint for Projection {
+    type MintType = mint::ColumnMatrix4<real>;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn vector_roundtrip() {
+        let vector = Vector4::new(1.0, 2.0, 3.0, 4.0);
+        let mint_vector = mint::Vector4::from(vector);
+        assert_eq!((mint_vector.x, mint_vector.w), (1.0, 4.0));
+        assert_eq!(Vector4::from(mint_vector), vector);
+    }
+
+    #[test]
+    fn matrix_layout() {
+        let basis = Basis::from_cols(
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(4.0, 5.0, 6.0),
+            Vector3::new(7.0, 8.0, 9.0),
+        );
+
+        let columns = mint::ColumnMatrix3::from(basis);
+        assert_eq!(Vector3::from(columns.y), Vector3::new(4.0, 5.0, 6.0));
+        assert_eq!(Basis::from(columns), basis);
+
+        let rows = mint::RowMatrix3::from(basis);
+        assert_eq!(Vector3::from(rows.x), Vector3::new(1.0, 4.0, 7.0));
+        assert_eq!(Basis::from(rows), basis);
+
+        let transform = Transform3D::new(basis, Vector3::new(-1.0, -2.0, -3.0));
+        let mint_transform = mint::ColumnMatrix3x4::from(transform);
+        assert_eq!(Vector3::from(mint_transform.w), transform.origin);
+        assert_eq!(Transform3D::from(mint_transform), transform);
+    }
+}
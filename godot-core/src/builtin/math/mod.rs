@@ -10,6 +10,13 @@ mod float;
 mod glam_helpers;
 mod xform;
 
+#[cfg(feature = "interop-bytemuck")]
+mod bytemuck_interop;
+#[cfg(feature = "interop-glam")]
+mod glam_interop;
+#[cfg(feature = "interop-mint")]
+mod mint_interop;
+
 pub use approx_eq::ApproxEq;
 pub use float::FloatExt;
 
@@ -30,6 +37,10 @@ pub fn wrapi(value: i64, min: i64, max: i64) -> i64 {
 
 // Internal glam re-exports
 pub(crate) use glam_helpers::*;
+
+/// Re-export of the [`glam`] version that builtin types convert to, to avoid version mismatches.
+#[cfg(feature = "interop-glam")]
+pub use glam;
 pub use xform::XformInv;
 
 pub use crate::{assert_eq_approx, assert_ne_approx};
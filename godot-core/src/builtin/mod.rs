@@ -58,6 +58,7 @@ pub mod __prelude_reexport {
 
     pub use crate::static_node_path;
     pub use crate::static_sname;
+    pub use crate::{match_sname, sname};
 }
 
 pub use crate::gen::builtin_classes::*;
@@ -187,6 +188,13 @@ pub(crate) fn to_i32(i: i64) -> i32 {
     })
 }
 
+#[track_caller]
+pub(crate) fn to_i32_from_usize(i: usize) -> i32 {
+    i.try_into().unwrap_or_else(|_| {
+        panic!("godot-rust: value {i} exceeds i32::MAX, which is not supported by Godot")
+    })
+}
+
 #[track_caller]
 pub(crate) fn to_u64(i: i64) -> u64 {
     i.try_into().unwrap_or_else(|_| {
@@ -201,6 +209,12 @@ pub(crate) fn to_i64_from_u64(i: u64) -> i64 {
     })
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// #[test]s without Godot, see godot_ffi::stub
+
+#[cfg(all(test, feature = "stub-interface"))]
+mod stub_tests;
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // #[test] utils for serde
 
@@ -110,6 +110,13 @@ impl Rid {
     }
 }
 
+// Lets APIs accept both plain RIDs and owning wrappers such as `OwnedRdBuffer` via `impl AsRef<Rid>`.
+impl AsRef<Rid> for Rid {
+    fn as_ref(&self) -> &Rid {
+        self
+    }
+}
+
 impl std::fmt::Display for Rid {
     /// Formats `Rid` to match Godot's string representation.
     ///
@@ -158,6 +158,15 @@ impl StringName {
         /// ```
     }
 
+    /// O(1) equality check, comparing the interned pointers without calling into Godot.
+    ///
+    /// Returns the same result as `==`: Godot interns all `StringName`s, so equal names always share the same data. This is what
+    /// [`match_sname!`][crate::builtin::match_sname] uses for dispatching, typically against [`sname!`][crate::builtin::sname] literals.
+    #[inline]
+    pub fn ptr_eq(&self, other: &StringName) -> bool {
+        self.opaque.bitwise_eq(&other.opaque)
+    }
+
     /// O(1), non-lexicographic, non-stable ordering relation.
     ///
     /// The result of the comparison is **not** lexicographic and **not** stable across multiple runs of your application.
@@ -325,6 +334,23 @@ impl StringName {
             })
         }
     }
+
+    /// Creates a `StringName` from a NUL-terminated string literal, see [`sname!`][crate::builtin::sname].
+    ///
+    /// ASCII literals are passed to Godot as static Latin-1 buffers without copying. The result must be stored in a `static`.
+    #[doc(hidden)]
+    pub fn __static_str(nul_terminated: &'static str) -> Self {
+        let Ok(c_str) = std::ffi::CStr::from_bytes_with_nul(nul_terminated.as_bytes()) else {
+            panic!("sname!() literal must not contain NUL bytes: {nul_terminated:?}");
+        };
+
+        if nul_terminated.is_ascii() {
+            // ASCII is a subset of Latin-1.
+            Self::__cstr_with_static(c_str, true)
+        } else {
+            Self::from(c_str.to_str().expect("literal is UTF-8"))
+        }
+    }
 }
 
 // SAFETY:
@@ -682,3 +708,101 @@ macro_rules! static_sname {
         SNAME.get_or_init(|| StringName::__cstr_with_static(c_str, true))
     }};
 }
+
+/// Returns a `&'static StringName` for a string literal.
+///
+/// The `StringName` is created on first use and cached in a `static`, so subsequent evaluations cost a single atomic load. Unlike
+/// [`static_sname!`], this accepts regular (UTF-8) string literals; ASCII ones are handed to Godot without copying.
+///
+/// A `StringName` cannot be created at compile time, as it must be interned by the running engine. So `sname!` is not usable in `const`
+/// items or as a `match` pattern. To dispatch on names, use [`match_sname!`] instead, which compares interned pointers.
+///
+/// # Example
+/// ```no_run
+/// use godot::builtin::{sname, StringName};
+///
+/// fn is_health(property: &StringName) -> bool {
+///     // No allocation or conversion to String.
+///     property.ptr_eq(sname!("health"))
+/// }
+/// ```
+#[macro_export]
+macro_rules! sname {
+    ($str:literal) => {{
+        static SNAME: ::std::sync::OnceLock<$crate::builtin::StringName> =
+            ::std::sync::OnceLock::new();
+
+        SNAME.get_or_init(|| $crate::builtin::StringName::__static_str(concat!($str, "\0")))
+    }};
+}
+
+/// Dispatches a `StringName` to different string literals.
+///
+/// Similar to a `match` statement on strings, but each literal is interned once via [`sname!`], and comparisons are pointer checks (see
+/// [`StringName::ptr_eq()`][crate::builtin::StringName::ptr_eq]). Apart from interning on first use, there is no conversion to `String`
+/// and no call into Godot. Branches are checked linearly, in order.
+///
+/// The subject can be a `StringName` or `&StringName`. Like in [`match_class!`][crate::classes::match_class], a _fallback branch_ is
+/// required unless the expression has type `()`. It is either `_`, or a `variable` that binds the original subject.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// # struct Player { health: i32 }
+/// # impl Player {
+/// fn get_property(&self, name: StringName) -> Option<Variant> {
+///     match_sname! { name,
+///         "health" => Some(self.health.to_variant()),
+///         "hp" | "hit_points" => Some(self.health.to_variant()),
+///         // Fallback with variable -- binds the original StringName.
+///         other => {
+///             godot_print!("unknown property {other}");
+///             None
+///         }
+///     }
+/// }
+/// # }
+/// ```
+///
+/// # Expression and control flow
+/// The `match_sname!` macro is an expression, as such it has a type. Control-flow statements like `?`, `return`, `continue`, `break` can
+/// be used within the match arms.
+#[macro_export]
+macro_rules! match_sname {
+    ($subject:expr, $($tt:tt)*) => {{
+        let subject = $subject;
+        let name: &$crate::builtin::StringName = ::std::borrow::Borrow::borrow(&subject);
+        $crate::match_sname_muncher!(subject, name, $($tt)*)
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! match_sname_muncher {
+    // "literal" | "other" => { ... }.
+    ($subject:ident, $name:ident, $($str:literal)|+ => $block:expr, $($rest:tt)*) => {{
+        if $( $name.ptr_eq($crate::sname!($str)) )||+ {
+            $block
+        } else {
+            $crate::match_sname_muncher!($subject, $name, $($rest)*)
+        }
+    }};
+
+    // mut variable => { ... }.
+    ($subject:ident, $name:ident, mut $var:ident => $block:expr $(,)?) => {{
+        let mut $var = $subject;
+        $block
+    }};
+
+    // variable => { ... }.
+    ($subject:ident, $name:ident, $var:ident => $block:expr $(,)?) => {{
+        let $var = $subject;
+        $block
+    }};
+
+    // _ => { ... }
+    // or nothing, if fallback is absent and overall expression being ().
+    ($subject:ident, $name:ident, $(_ => $block:expr $(,)?)?) => {{
+        $($block)?
+    }};
+}
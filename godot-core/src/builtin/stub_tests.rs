@@ -0,0 +1,148 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Unit tests for builtin types that need the GDExtension interface, run against `godot_ffi::stub`.
+//!
+//! Behavior is covered in depth by the integration tests; these tests check that the stub is wired up correctly.
+
+use godot_ffi as sys;
+
+use crate::builtin::*;
+use crate::meta::ToGodot;
+
+#[test]
+fn stub_gstring() {
+    sys::stub::run(|| {
+        let s = GString::from("Hello World");
+        assert_eq!(s.len(), 11);
+        assert_eq!(s.to_string(), "Hello World");
+        assert_eq!(s.substr(6..), "World");
+        assert_eq!(s.find("o"), Some(4));
+        assert_eq!(s.count("l", ..), 3);
+
+        let parts = s.split(" ");
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts.get(1), Some(GString::from("World")));
+
+        assert!(GString::from("Alpha") < GString::from("Beta"));
+        assert_eq!(s.clone(), s);
+    });
+}
+
+#[test]
+fn stub_string_name() {
+    sys::stub::run(|| {
+        let name = StringName::from("some_name");
+        assert_eq!(name.len(), 9);
+        assert_eq!(name.to_gstring(), GString::from("some_name"));
+        assert_eq!(name, StringName::from(&GString::from("some_name")));
+        assert_eq!(name.hash_u32(), StringName::from("some_name").hash_u32());
+    });
+}
+
+#[test]
+fn stub_array() {
+    sys::stub::run(|| {
+        let mut array: Array<i64> = array![3, 1, 2];
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.at(0), 3);
+
+        array.push(4);
+        array.insert(0, 0);
+        assert_eq!(array.pop(), Some(4));
+        assert_eq!(array.front(), Some(0));
+        assert_eq!(array.find(2, None), Some(3));
+        assert!(array.contains(1));
+
+        array.sort_unstable();
+        assert_eq!(array, array![0, 1, 2, 3]);
+        assert_eq!(array.subarray_shallow(1..3, None), array![1, 2]);
+        assert_eq!(array.iter_shared().sum::<i64>(), 6);
+        assert_eq!(array.max(), Some(3));
+
+        let copy = array.duplicate_shallow();
+        array.clear();
+        assert!(array.is_empty());
+        assert_eq!(copy.len(), 4);
+    });
+}
+
+#[test]
+fn stub_dictionary() {
+    sys::stub::run(|| {
+        let mut dict = vdict! {
+            "apple": 1,
+            "banana": 2,
+        };
+        assert_eq!(dict.len(), 2);
+        assert_eq!(dict.get("banana"), Some(2.to_variant()));
+        assert_eq!(dict.get("cherry"), None);
+
+        dict.set("cherry", 3);
+        assert_eq!(dict.remove("apple"), Some(1.to_variant()));
+        assert_eq!(dict.keys_array(), varray!["banana", "cherry"]);
+
+        let sum: i64 = dict
+            .iter_shared()
+            .typed::<GString, i64>()
+            .map(|(_, v)| v)
+            .sum();
+        assert_eq!(sum, 5);
+    });
+}
+
+#[test]
+fn stub_packed_array() {
+    sys::stub::run(|| {
+        let mut packed = PackedInt32Array::from(&[5, 1, 4]);
+        packed.push(2);
+        assert_eq!(packed.as_slice(), &[5, 1, 4, 2]);
+
+        packed.sort();
+        assert_eq!(packed.to_vec(), vec![1, 2, 4, 5]);
+        assert_eq!(packed.find(4, None), Some(2));
+        assert_eq!(packed.subarray(1..3).as_slice(), &[2, 4]);
+
+        let mut strings = PackedStringArray::new();
+        strings.push("a");
+        strings.push("b");
+        assert_eq!(strings.get(1), Some(GString::from("b")));
+    });
+}
+
+#[test]
+fn stub_variant() {
+    sys::stub::run(|| {
+        let int = 7.to_variant();
+        assert_eq!(int.get_type(), VariantType::INT);
+        assert_eq!(int.to::<i64>(), 7);
+        assert_eq!(int.stringify(), GString::from("7"));
+        assert!(int.booleanize());
+
+        let sum = int.evaluate(&5.to_variant(), VariantOperator::ADD);
+        assert_eq!(sum, Some(12.to_variant()));
+
+        let text = "text".to_variant();
+        assert_eq!(text.to::<GString>(), GString::from("text"));
+        assert_eq!(text.try_to::<i64>().ok(), None);
+
+        let array = varray![1, "two"].to_variant();
+        assert_eq!(array.get_type(), VariantType::ARRAY);
+        assert_eq!(array.to::<VarArray>().len(), 2);
+    });
+}
+
+#[test]
+fn stub_unsupported_interface_fns_unavailable() {
+    sys::stub::run(|| {
+        // SAFETY: called on the thread that initialized the binding.
+        let interface = unsafe { sys::get_interface() };
+        assert!(interface.variant_new_copy.is_some());
+        assert!(interface.classdb_get_method_bind.is_none());
+        assert!(interface.object_method_bind_ptrcall.is_none());
+    });
+}
@@ -205,6 +205,50 @@ where
     Gd::<T>::from_obj_sys(object_ptr)
 }
 
+/// Panics if the class, registered by another GDExtension, is not available in Godot.
+#[cfg(feature = "codegen-third-party")]
+pub(crate) fn ensure_third_party_class(class_name: &str) {
+    let class_sname = StringName::from(class_name);
+
+    // SAFETY: Godot returns null if the class is not registered.
+    let class_tag = unsafe { sys::interface_fn!(classdb_get_class_tag)(class_sname.string_sys()) };
+
+    assert!(
+        !class_tag.is_null(),
+        "class `{class_name}` is not registered in Godot; make sure the GDExtension providing it is loaded"
+    );
+}
+
+/// Looks up a method of a class registered by another GDExtension. Called once per method, on first use.
+#[cfg(feature = "codegen-third-party")]
+pub(crate) fn load_third_party_method(
+    class_name: &'static str,
+    method_name: &'static str,
+    hash: i64,
+) -> sys::ClassMethodBind {
+    ensure_third_party_class(class_name);
+
+    let class_sname = StringName::from(class_name);
+    let method_sname = StringName::from(method_name);
+
+    // SAFETY: Godot returns null if the method does not exist or the hash does not match.
+    let method_bind = unsafe {
+        sys::interface_fn!(classdb_get_method_bind)(
+            class_sname.string_sys(),
+            method_sname.string_sys(),
+            hash,
+        )
+    };
+
+    assert!(
+        !method_bind.is_null(),
+        "method `{class_name}::{method_name}` (hash {hash}) is not available; \
+        the loaded GDExtension may differ from the API JSON used to generate bindings"
+    );
+
+    sys::ClassMethodBind(method_bind)
+}
+
 /// Checks that the object with the given instance ID is still alive and that the pointer is valid.
 ///
 /// This does **not** perform type checking — use `ensure_object_type()` for that.
@@ -15,6 +15,7 @@
 //! Noteworthy sub-modules of `godot::classes` are:
 //! * [`native`]: definition of _native structure_ types.
 //! * [`notify`]: all notification enums, used when working with the virtual callback to handle lifecycle notifications.
+//! * `third_party`: classes registered by other GDExtensions; only available with the `codegen-third-party` feature.
 
 mod class_runtime;
 pub mod ext;
@@ -241,6 +241,11 @@ unsafe fn gdext_on_level_init(level: InitLevel, _userdata: &InitUserData) {
 fn gdext_on_level_deinit(level: InitLevel) {
     crate::registry::class::unregister_classes(level);
 
+    if level == InitLevel::Servers {
+        // Owned RIDs still alive at this point will be destroyed along with the servers, without their wrappers noticing.
+        crate::servers::rid_tracker::report_leaks();
+    }
+
     if level == InitLevel::Core {
         // If lowest level is unloaded, call global deinitialization.
         // No business logic by itself, but ensures consistency if re-initialization (hot-reload on Linux) occurs.
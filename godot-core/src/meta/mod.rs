@@ -60,6 +60,8 @@ pub(crate) mod sealed;
 
 pub mod error;
 pub mod inspect;
+#[cfg(feature = "profiling")]
+pub mod profiling;
 pub(crate) mod signed_range;
 
 // Public re-exports
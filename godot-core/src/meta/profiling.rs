@@ -0,0 +1,332 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Measures calls across the Rust <-> Godot boundary (feature `profiling`).
+//!
+//! Every call from Rust into the engine (class methods, builtin methods, utility functions, script-virtual methods, shared classes) and
+//! every call from Godot into a `#[func]` is reported to a [`CallProfiler`], once one is installed with [`set_call_profiler()`]. While no
+//! profiler is installed, the overhead is a single atomic load per call; without the `profiling` feature, there is none at all.
+//!
+//! [`CallStats`] is a ready-made profiler that aggregates count and duration per function. To integrate with other tools (`tracing`,
+//! Tracy, custom overlays...), implement [`CallProfiler`] yourself.
+//!
+//! # Example
+//! ```no_run
+//! use std::sync::Arc;
+//! use godot::meta::profiling::{set_call_profiler, CallStats};
+//!
+//! let stats = Arc::new(CallStats::new());
+//! set_call_profiler(Some(stats.clone()));
+//!
+//! // ... run the game for a while ...
+//!
+//! for entry in stats.snapshot().iter().take(10) {
+//!     godot::global::godot_print!("{entry}");
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, RwLock};
+use std::time::{Duration, Instant};
+
+use crate::meta::CallContext;
+
+/// Which boundary a call crosses, and how its arguments are passed.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum CallKind {
+    /// Rust calls an engine class method via ptrcall.
+    ClassPtrcall,
+
+    /// Rust calls an engine class method via varcall (methods with varargs).
+    ClassVarcall,
+
+    /// Rust calls a method of a builtin type, such as `GString` or `Array`.
+    BuiltinPtrcall,
+
+    /// Rust calls a global utility function, such as `print()`.
+    UtilityPtrcall,
+
+    /// Rust calls a virtual method implemented in a script (`#[func(virtual)]`).
+    ScriptVirtual,
+
+    /// Rust calls a `#[func]` of a class shared by another extension (see [`crate::registry::shared`]).
+    SharedPtrcall,
+
+    /// Godot calls a `#[func]` via ptrcall (e.g. from typed GDScript).
+    FuncPtrcall,
+
+    /// Godot calls a `#[func]` via varcall (e.g. `Object.call()`, untyped GDScript).
+    FuncVarcall,
+}
+
+impl CallKind {
+    /// Whether the call goes from Godot into Rust (a `#[func]`), as opposed to from Rust into the engine.
+    pub fn is_inbound(self) -> bool {
+        matches!(self, Self::FuncPtrcall | Self::FuncVarcall)
+    }
+}
+
+/// Describes a call that is being measured.
+#[derive(Copy, Clone, Debug)]
+pub struct CallInfo<'a> {
+    class_name: &'a str,
+    function_name: &'a str,
+    kind: CallKind,
+}
+
+impl<'a> CallInfo<'a> {
+    /// Name of the class or builtin type; empty for utility functions.
+    pub fn class_name(&self) -> &'a str {
+        self.class_name
+    }
+
+    /// Name of the method or function, as known to Godot.
+    pub fn function_name(&self) -> &'a str {
+        self.function_name
+    }
+
+    pub fn kind(&self) -> CallKind {
+        self.kind
+    }
+}
+
+impl fmt::Display for CallInfo<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.class_name.is_empty() {
+            write!(f, "{}", self.function_name)
+        } else {
+            write!(f, "{}::{}", self.class_name, self.function_name)
+        }
+    }
+}
+
+/// Receives calls across the Rust <-> Godot boundary.
+///
+/// Calls can be nested (an engine method may call a `#[func]`, which calls the engine again), and can happen on any thread that interacts
+/// with Godot. `begin` and `end` of a call happen on the same thread, so per-thread stacks can pair them up, e.g. to open and close
+/// `tracing` spans.
+///
+/// Implementations are invoked for every call, so they should be cheap. They must not panic.
+pub trait CallProfiler: Send + Sync + 'static {
+    /// Called right before the call. Does nothing by default.
+    fn begin(&self, call: &CallInfo) {
+        let _ = call;
+    }
+
+    /// Called after the call finished, with the time it took, including argument and return value conversions.
+    ///
+    /// Also called if the call panics, in which case `panicked` is true and `duration` covers the time until the panic.
+    fn end(&self, call: &CallInfo, duration: Duration, panicked: bool);
+}
+
+/// Installs a profiler for all subsequent calls, replacing the previous one. `None` disables profiling.
+pub fn set_call_profiler(profiler: Option<Arc<dyn CallProfiler>>) {
+    let mut current = PROFILER
+        .write()
+        .unwrap_or_else(|poison| poison.into_inner());
+
+    IS_ENABLED.store(profiler.is_some(), Ordering::Release);
+    *current = profiler;
+}
+
+/// Returns the currently installed profiler, if any.
+pub fn call_profiler() -> Option<Arc<dyn CallProfiler>> {
+    PROFILER
+        .read()
+        .unwrap_or_else(|poison| poison.into_inner())
+        .clone()
+}
+
+static IS_ENABLED: AtomicBool = AtomicBool::new(false);
+static PROFILER: RwLock<Option<Arc<dyn CallProfiler>>> = RwLock::new(None);
+
+/// Runs `call`, reporting it to the installed profiler (if any).
+#[inline]
+pub(crate) fn profile<R>(kind: CallKind, call_ctx: &CallContext, call: impl FnOnce() -> R) -> R {
+    if !IS_ENABLED.load(Ordering::Acquire) {
+        return call();
+    }
+
+    let Some(profiler) = call_profiler() else {
+        return call();
+    };
+
+    let info = CallInfo {
+        class_name: &call_ctx.class_name,
+        function_name: call_ctx.function_name,
+        kind,
+    };
+
+    profiler.begin(&info);
+    let mut guard = ProfileGuard {
+        profiler,
+        info,
+        start: Instant::now(),
+        panicked: true,
+    };
+
+    let result = call();
+    guard.panicked = false;
+
+    result
+}
+
+/// Reports the end of a call on drop, so that `begin` and `end` stay paired even if the call unwinds.
+struct ProfileGuard<'a> {
+    profiler: Arc<dyn CallProfiler>,
+    info: CallInfo<'a>,
+    start: Instant,
+    panicked: bool,
+}
+
+impl Drop for ProfileGuard<'_> {
+    fn drop(&mut self) {
+        self.profiler
+            .end(&self.info, self.start.elapsed(), self.panicked);
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// CallStats
+
+/// Profiler that aggregates call count and duration per function and [`CallKind`].
+///
+/// Durations of nested calls are included in their callers, e.g. a `#[func]` that calls engine methods also accounts for their time.
+#[derive(Default)]
+pub struct CallStats {
+    by_kind: Mutex<TotalsByKind>,
+}
+
+// Nested maps (kind -> class -> function), so that lookups can use the borrowed names without allocating.
+type TotalsByKind = HashMap<CallKind, HashMap<String, HashMap<String, Totals>>>;
+
+#[derive(Copy, Clone, Default)]
+struct Totals {
+    count: u64,
+    panicked: u64,
+    total: Duration,
+    max: Duration,
+}
+
+impl CallStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns one entry per function called so far, sorted by total duration (descending).
+    pub fn snapshot(&self) -> Vec<CallStatsEntry> {
+        let by_kind = self.lock();
+
+        let mut entries = Vec::new();
+        for (&kind, classes) in by_kind.iter() {
+            for (class_name, functions) in classes {
+                for (function_name, totals) in functions {
+                    entries.push(CallStatsEntry {
+                        class_name: class_name.clone(),
+                        function_name: function_name.clone(),
+                        kind,
+                        count: totals.count,
+                        panicked: totals.panicked,
+                        total: totals.total,
+                        max: totals.max,
+                    });
+                }
+            }
+        }
+
+        entries.sort_by(|a, b| b.total.cmp(&a.total).then_with(|| b.count.cmp(&a.count)));
+        entries
+    }
+
+    /// Discards all statistics collected so far.
+    pub fn reset(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, TotalsByKind> {
+        self.by_kind
+            .lock()
+            .unwrap_or_else(|poison| poison.into_inner())
+    }
+}
+
+impl CallProfiler for CallStats {
+    fn end(&self, call: &CallInfo, duration: Duration, panicked: bool) {
+        let mut by_kind = self.lock();
+
+        let classes = by_kind.entry(call.kind).or_default();
+        let functions = entry_by_str(classes, call.class_name);
+        let totals = entry_by_str(functions, call.function_name);
+
+        totals.count += 1;
+        totals.panicked += u64::from(panicked);
+        totals.total += duration;
+        totals.max = totals.max.max(duration);
+    }
+}
+
+/// Like `map.entry(key.to_string()).or_default()`, but only allocates the key on insertion.
+fn entry_by_str<'m, V: Default>(map: &'m mut HashMap<String, V>, key: &str) -> &'m mut V {
+    if !map.contains_key(key) {
+        map.insert(key.to_string(), V::default());
+    }
+
+    map.get_mut(key).expect("inserted above")
+}
+
+/// Aggregated statistics of one function, see [`CallStats::snapshot()`].
+#[derive(Clone, Debug)]
+pub struct CallStatsEntry {
+    pub class_name: String,
+    pub function_name: String,
+    pub kind: CallKind,
+    pub count: u64,
+    /// How many of the `count` calls panicked.
+    pub panicked: u64,
+    pub total: Duration,
+    pub max: Duration,
+}
+
+impl CallStatsEntry {
+    /// Average duration of a single call.
+    pub fn mean(&self) -> Duration {
+        match u32::try_from(self.count) {
+            Ok(0) => Duration::ZERO,
+            Ok(count) => self.total / count,
+            Err(_) => Duration::from_secs_f64(self.total.as_secs_f64() / self.count as f64),
+        }
+    }
+}
+
+impl fmt::Display for CallStatsEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let info = CallInfo {
+            class_name: &self.class_name,
+            function_name: &self.function_name,
+            kind: self.kind,
+        };
+
+        write!(
+            f,
+            "{info} ({:?}): {} calls, total {:?}, mean {:?}, max {:?}",
+            self.kind,
+            self.count,
+            self.total,
+            self.mean(),
+            self.max
+        )?;
+
+        if self.panicked > 0 {
+            write!(f, ", {} panicked", self.panicked)?;
+        }
+
+        Ok(())
+    }
+}
@@ -20,6 +20,21 @@ use crate::meta::{
 };
 use crate::obj::{GodotClass, ValidatedObject};
 
+/// Evaluates `$call`, reporting it to the call profiler if feature `profiling` is enabled.
+macro_rules! profiled {
+    ($kind:ident, $call_ctx:expr, $call:expr) => {{
+        #[cfg(feature = "profiling")]
+        let result = crate::meta::profiling::profile(
+            crate::meta::profiling::CallKind::$kind,
+            $call_ctx,
+            || $call,
+        );
+        #[cfg(not(feature = "profiling"))]
+        let result = $call;
+        result
+    }};
+}
+
 /// Checks for `#[func]` expansions that all parameters implement `FromGodot` and the return type implements `ToGodot`.
 ///
 /// [`Signature`] itself only requires `EngineFromGodot` and `EngineToGodot`.
@@ -88,17 +103,20 @@ where
         #[cfg(feature = "trace")]
         trace::push(true, false, call_ctx);
 
-        // SAFETY: `args_ptr` must point to `arg_count` valid `GDExtensionConstVariantPtr`s, which is ensured by Godot
-        // when making a varcall.
-        let args =
-            unsafe { Params::from_varcall_args(args_ptr, arg_count, default_values, call_ctx)? };
+        profiled!(FuncVarcall, call_ctx, {
+            // SAFETY: `args_ptr` must point to `arg_count` valid `GDExtensionConstVariantPtr`s, which is ensured by Godot
+            // when making a varcall.
+            let args = unsafe {
+                Params::from_varcall_args(args_ptr, arg_count, default_values, call_ctx)?
+            };
 
-        let rust_result = unsafe { func(instance_ptr, args) };
+            let rust_result = unsafe { func(instance_ptr, args) };
 
-        // SAFETY: `ret` must be a valid `GDExtensionVariantPtr` and `err` must be a valid `GDExtensionCallError` pointer,
-        // both are provided by Godot for the duration of the call.
-        unsafe { varcall_return::<Ret>(rust_result, ret, err) };
-        Ok(())
+            // SAFETY: `ret` must be a valid `GDExtensionVariantPtr` and `err` must be a valid `GDExtensionCallError` pointer,
+            // both are provided by Godot for the duration of the call.
+            unsafe { varcall_return::<Ret>(rust_result, ret, err) };
+            Ok(())
+        })
     }
 
     /// Receive a ptrcall from Godot, and return the value in `ret` as a type pointer.
@@ -120,14 +138,16 @@ where
         #[cfg(feature = "trace")]
         trace::push(true, true, call_ctx);
 
-        // SAFETY: `args_ptr` must point to valid `GDExtensionConstTypePtr`s for each parameter in `Params`, which is
-        // ensured by Godot when making a ptrcall.
-        let args = unsafe { Params::from_ptrcall_args(args_ptr, call_type, call_ctx)? };
+        profiled!(FuncPtrcall, call_ctx, {
+            // SAFETY: `args_ptr` must point to valid `GDExtensionConstTypePtr`s for each parameter in `Params`, which is
+            // ensured by Godot when making a ptrcall.
+            let args = unsafe { Params::from_ptrcall_args(args_ptr, call_type, call_ctx)? };
 
-        // SAFETY: `ret` is a pointer to an initialized value of the return type's FFI representation, provided by Godot.
-        unsafe { ptrcall_return::<Ret>(func(instance_ptr, args), ret, call_ctx, call_type) };
+            // SAFETY: `ret` is a pointer to an initialized value of the return type's FFI representation, provided by Godot.
+            unsafe { ptrcall_return::<Ret>(func(instance_ptr, args), ret, call_ctx, call_type) };
 
-        Ok(())
+            Ok(())
+        })
     }
 }
 
@@ -155,52 +175,54 @@ impl<Params: OutParamTuple, Ret: EngineFromGodot> Signature<Params, Ret> {
 
         let class_fn = sys::interface_fn!(object_method_bind_call);
 
-        let variant = args.with_variants(|explicit_args| {
-            use crate::builtin::to_i64;
-
-            let total_count = explicit_args.len() + varargs.len();
-
-            let call_with_ptrs = |ptrs: *const sys::GDExtensionConstVariantPtr| unsafe {
-                Variant::new_with_var_uninit_result(|return_ptr| {
-                    let mut err = sys::default_call_error();
-                    class_fn(
-                        method_bind.0,
-                        ValidatedObject::object_ptr(validated_obj.as_ref()),
-                        ptrs,
-                        to_i64(total_count),
-                        return_ptr,
-                        &raw mut err,
-                    );
-
-                    CallError::check_out_varcall(&call_ctx, err, explicit_args, varargs)
-                })
-            };
+        profiled!(ClassVarcall, &call_ctx, {
+            let variant = args.with_variants(|explicit_args| {
+                use crate::builtin::to_i64;
 
-            let ptrs_vec;
-            let mut ptrs_arr = [std::ptr::null(); sys::MAX_STACK_ARGS];
-            let ptrs: *const sys::GDExtensionConstVariantPtr = if total_count <= sys::MAX_STACK_ARGS
-            {
-                for (i, arg) in explicit_args.iter().enumerate() {
-                    ptrs_arr[i] = arg.var_sys();
-                }
-                for (i, arg) in varargs.iter().enumerate() {
-                    ptrs_arr[explicit_args.len() + i] = arg.var_sys();
-                }
-                ptrs_arr.as_ptr()
-            } else {
-                ptrs_vec = explicit_args
-                    .iter()
-                    .map(Variant::var_sys)
-                    .chain(varargs.iter().map(Variant::var_sys))
-                    .collect::<Vec<_>>();
-                ptrs_vec.as_ptr()
-            };
-            call_with_ptrs(ptrs)
-        });
+                let total_count = explicit_args.len() + varargs.len();
+
+                let call_with_ptrs = |ptrs: *const sys::GDExtensionConstVariantPtr| unsafe {
+                    Variant::new_with_var_uninit_result(|return_ptr| {
+                        let mut err = sys::default_call_error();
+                        class_fn(
+                            method_bind.0,
+                            ValidatedObject::object_ptr(validated_obj.as_ref()),
+                            ptrs,
+                            to_i64(total_count),
+                            return_ptr,
+                            &raw mut err,
+                        );
+
+                        CallError::check_out_varcall(&call_ctx, err, explicit_args, varargs)
+                    })
+                };
 
-        variant.and_then(|v| {
-            Ret::engine_try_from_variant(&v)
-                .map_err(|e| CallError::failed_return_conversion::<Ret>(&call_ctx, e))
+                let ptrs_vec;
+                let mut ptrs_arr = [std::ptr::null(); sys::MAX_STACK_ARGS];
+                let ptrs: *const sys::GDExtensionConstVariantPtr =
+                    if total_count <= sys::MAX_STACK_ARGS {
+                        for (i, arg) in explicit_args.iter().enumerate() {
+                            ptrs_arr[i] = arg.var_sys();
+                        }
+                        for (i, arg) in varargs.iter().enumerate() {
+                            ptrs_arr[explicit_args.len() + i] = arg.var_sys();
+                        }
+                        ptrs_arr.as_ptr()
+                    } else {
+                        ptrs_vec = explicit_args
+                            .iter()
+                            .map(Variant::var_sys)
+                            .chain(varargs.iter().map(Variant::var_sys))
+                            .collect::<Vec<_>>();
+                        ptrs_vec.as_ptr()
+                    };
+                call_with_ptrs(ptrs)
+            });
+
+            variant.and_then(|v| {
+                Ret::engine_try_from_variant(&v)
+                    .map_err(|e| CallError::failed_return_conversion::<Ret>(&call_ctx, e))
+            })
         })
     }
 
@@ -229,27 +251,29 @@ impl<Params: OutParamTuple, Ret: EngineFromGodot> Signature<Params, Ret> {
 
         let object_call_script_method = sys::interface_fn!(object_call_script_method);
 
-        let variant = args.with_variant_pointers(|sys_args| {
-            use crate::builtin::to_i64;
-
-            // SAFETY: `return_ptr` is a pointer to an uninitialized `Variant`, which is safe to initialize.
-            unsafe {
-                Variant::new_with_var_uninit(|return_ptr| {
-                    let mut err = sys::default_call_error();
-                    object_call_script_method(
-                        object_ptr,
-                        method_sname_ptr,
-                        sys_args.as_ptr(),
-                        to_i64(sys_args.len()),
-                        return_ptr,
-                        &raw mut err,
-                    );
-                })
-            }
-        });
+        profiled!(ScriptVirtual, &call_ctx, {
+            let variant = args.with_variant_pointers(|sys_args| {
+                use crate::builtin::to_i64;
+
+                // SAFETY: `return_ptr` is a pointer to an uninitialized `Variant`, which is safe to initialize.
+                unsafe {
+                    Variant::new_with_var_uninit(|return_ptr| {
+                        let mut err = sys::default_call_error();
+                        object_call_script_method(
+                            object_ptr,
+                            method_sname_ptr,
+                            sys_args.as_ptr(),
+                            to_i64(sys_args.len()),
+                            return_ptr,
+                            &raw mut err,
+                        );
+                    })
+                }
+            });
 
-        let result = <Ret as FromGodot>::try_from_variant(&variant);
-        result.unwrap_or_else(|err| return_error::<Ret>(&call_ctx, err))
+            let result = <Ret as FromGodot>::try_from_variant(&variant);
+            result.unwrap_or_else(|err| return_error::<Ret>(&call_ctx, err))
+        })
     }
 
     /// Make a ptrcall to the Godot engine for a utility function that has varargs.
@@ -267,7 +291,7 @@ impl<Params: OutParamTuple, Ret: EngineFromGodot> Signature<Params, Ret> {
         let call_ctx = CallContext::outbound("", function_name);
         //$crate::out!("out_utility_ptrcall_varargs: {call_ctx}");
 
-        unsafe {
+        profiled!(UtilityPtrcall, &call_ctx, unsafe {
             Self::raw_ptrcall(args, &call_ctx, |explicit_args, return_ptr| {
                 let total_count = explicit_args.len() + varargs.len();
                 let type_ptrs_vec;
@@ -297,7 +321,7 @@ impl<Params: OutParamTuple, Ret: EngineFromGodot> Signature<Params, Ret> {
                 // `type_ptrs` contains valid pointers to arguments.
                 utility_fn(return_ptr, type_ptrs, to_i32(total_count as i64));
             })
-        }
+        })
     }
 
     /// Make a ptrcall to the Godot engine for a builtin method that has varargs.
@@ -316,7 +340,7 @@ impl<Params: OutParamTuple, Ret: EngineFromGodot> Signature<Params, Ret> {
         let call_ctx = CallContext::outbound(class_name, method_name);
         //$crate::out!("out_builtin_ptrcall_varargs: {call_ctx}");
 
-        unsafe {
+        profiled!(BuiltinPtrcall, &call_ctx, unsafe {
             Self::raw_ptrcall(args, &call_ctx, |explicit_args, return_ptr| {
                 let total_count = explicit_args.len() + varargs.len();
                 let type_ptrs_vec;
@@ -344,7 +368,7 @@ impl<Params: OutParamTuple, Ret: EngineFromGodot> Signature<Params, Ret> {
                 // Important: this calls from_sys_init_default().
                 builtin_fn(type_ptr, type_ptrs, return_ptr, to_i32(total_count as i64));
             })
-        }
+        })
     }
 
     /// Make a ptrcall to the Godot engine for a class method.
@@ -365,7 +389,7 @@ impl<Params: OutParamTuple, Ret: EngineFromGodot> Signature<Params, Ret> {
 
         let class_fn = sys::interface_fn!(object_method_bind_ptrcall);
 
-        unsafe {
+        profiled!(ClassPtrcall, &call_ctx, unsafe {
             Self::raw_ptrcall(args, &call_ctx, |explicit_args, return_ptr| {
                 class_fn(
                     method_bind.0,
@@ -374,7 +398,7 @@ impl<Params: OutParamTuple, Ret: EngineFromGodot> Signature<Params, Ret> {
                     return_ptr,
                 );
             })
-        }
+        })
     }
 
     /// Make a ptrcall to the Godot engine for a builtin method.
@@ -393,7 +417,7 @@ impl<Params: OutParamTuple, Ret: EngineFromGodot> Signature<Params, Ret> {
         let call_ctx = CallContext::outbound(class_name, method_name);
         // $crate::out!("out_builtin_ptrcall: {call_ctx}");
 
-        unsafe {
+        profiled!(BuiltinPtrcall, &call_ctx, unsafe {
             Self::raw_ptrcall(args, &call_ctx, |explicit_args, return_ptr| {
                 use crate::builtin::to_i32;
 
@@ -404,7 +428,7 @@ impl<Params: OutParamTuple, Ret: EngineFromGodot> Signature<Params, Ret> {
                     to_i32(explicit_args.len() as i64),
                 );
             })
-        }
+        })
     }
 
     /// Make a ptrcall to the Godot engine for a utility function.
@@ -420,7 +444,7 @@ impl<Params: OutParamTuple, Ret: EngineFromGodot> Signature<Params, Ret> {
         let call_ctx = CallContext::outbound("", function_name);
         // $crate::out!("out_utility_ptrcall: {call_ctx}");
 
-        unsafe {
+        profiled!(UtilityPtrcall, &call_ctx, unsafe {
             Self::raw_ptrcall(args, &call_ctx, |explicit_args, return_ptr| {
                 use crate::builtin::to_i32;
 
@@ -430,7 +454,25 @@ impl<Params: OutParamTuple, Ret: EngineFromGodot> Signature<Params, Ret> {
                     to_i32(explicit_args.len() as i64),
                 );
             })
-        }
+        })
+    }
+
+    /// Make a ptrcall to a `#[func]` of a class shared by another extension, see [`crate::registry::shared`].
+    ///
+    /// # Safety
+    /// - `shared_fn` must call a method that expects explicit args `args`, and returns a value of type `Ret`.
+    #[inline]
+    pub(crate) unsafe fn out_shared_ptrcall(
+        class_name: &str,
+        method_name: &str,
+        args: Params,
+        shared_fn: impl FnOnce(&[sys::GDExtensionConstTypePtr], sys::GDExtensionTypePtr),
+    ) -> Ret {
+        let call_ctx = CallContext::outbound(class_name, method_name);
+
+        profiled!(SharedPtrcall, &call_ctx, unsafe {
+            Self::raw_ptrcall(args, &call_ctx, shared_fn)
+        })
     }
 
     /// Performs a ptrcall and processes the return value to give nice error output.
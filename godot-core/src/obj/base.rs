@@ -322,6 +322,13 @@ impl<T: GodotClass> Base<T> {
         self.obj.obj_sys()
     }
 
+    /// Returns the last known instance ID of the base object, without checking whether it is still alive.
+    ///
+    /// Used for bookkeeping during construction and destruction, where the object is not fully usable.
+    pub(crate) fn instance_id_unchecked(&self) -> crate::obj::InstanceId {
+        self.obj.instance_id_unchecked()
+    }
+
     // Internal use only, do not make public.
     #[cfg(feature = "debug-log")]
     pub(crate) fn debug_instance_id(&self) -> crate::obj::InstanceId {
@@ -10,7 +10,7 @@ use std::mem::ManuallyDrop;
 
 use godot_ffi::GodotNullableFfi;
 
-use crate::obj::{GodotClass, RawGd};
+use crate::obj::{Gd, GodotClass, PassiveGd, RawGd};
 use crate::sys;
 
 /// Represents a successful low-level cast from `T` to `U`.
@@ -62,6 +62,22 @@ impl<T: GodotClass, U: GodotClass> CastSuccess<T, U> {
         &mut self.dest
     }
 
+    /// Extracts destination object as a passive `Gd`, keeping the source as-is.
+    ///
+    /// # Safety
+    /// The source object must stay alive while the result is used.
+    pub unsafe fn into_passive_dest(self) -> PassiveGd<U> {
+        #[cfg(safeguards_strict)]
+        self.check_validity();
+
+        let weak_gd = Gd {
+            raw: ManuallyDrop::into_inner(self.dest),
+        };
+
+        // SAFETY: `dest` is a weak pointer; caller ensures that the object stays alive.
+        unsafe { PassiveGd::new(weak_gd) }
+    }
+
     /// Extracts destination object, sacrificing the source in exchange.
     ///
     /// This trade is needed because the result is a weak pointer (no ref-count increment). By submitting a strong pointer in its place,
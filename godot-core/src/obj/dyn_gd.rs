@@ -14,7 +14,9 @@ use crate::meta::error::ConvertError;
 use crate::meta::{ClassId, FromGodot, GodotConvert, PropertyHintInfo, ToGodot};
 use crate::obj::guards::DynGdRef;
 use crate::obj::{bounds, AsDyn, Bounds, DynGdMut, Gd, GodotClass, Inherits, OnEditor};
-use crate::registry::class::{get_dyn_property_hint_string, try_dynify_object};
+use crate::registry::class::{
+    describe_dyn_assignment_failure, get_dyn_property_hint_string, try_dynify_object,
+};
 use crate::registry::property::{object_export_element_type_string, Export, Var};
 use crate::{meta, sys};
 
@@ -183,10 +185,11 @@ use crate::{meta, sys};
 ///
 /// ## Node-based classes
 ///
-/// If `T` inherits `Node`, exporting `DynGd<T, D>` works identically to `Gd<T>`.
+/// If `T` inherits `Node`, exporting `DynGd<T, D>` will limit the node picker to known implementors of the trait `D` that inherit `T`.
+/// If no such implementor is registered, the picker falls back to `T` itself, like `Gd<T>`.
 ///
-/// If you try to assign a class from the editor that does not implement trait `D`, Godot will report a conversion-failed error,
-/// but it will only do so when accessing the given value.
+/// If an object of a class that does not implement `D` is still assigned (e.g. from GDScript or via a node path), the property setter
+/// reports an error listing the valid classes, and the previous value is kept.
 ///
 /// ## Resource-based classes
 ///
@@ -649,6 +652,21 @@ where
             Err((from_godot_err, obj)) => Err(from_godot_err.into_error(obj)),
         }
     }
+
+    // Overridden for a more descriptive panic message. This is the path taken when the editor or GDScript assigns an object to an
+    // exported `DynGd` property, so the error should point out which classes would have been valid.
+    fn from_godot(via: Self::Via) -> Self {
+        match try_dynify_object(via) {
+            Ok(dyn_gd) => dyn_gd,
+            Err((_, obj)) => {
+                let dynamic_class = obj.dynamic_class_string();
+                panic!(
+                    "{}",
+                    describe_dyn_assignment_failure::<T, D>(&dynamic_class.to_string())
+                )
+            }
+        }
+    }
 }
 
 /*
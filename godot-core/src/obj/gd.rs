@@ -20,7 +20,7 @@ use crate::meta::{
 };
 use crate::obj::{
     bounds, cap, Bounds, DynGd, GdDerefTarget, GdMut, GdRef, GodotClass, Inherits, InstanceId,
-    OnEditor, RawGd, Singleton, WithBaseField, WithSignals,
+    OnEditor, RawGd, Singleton, TypedFunc, WithBaseField, WithSignals,
 };
 use crate::private::{callbacks, PanicPayload};
 use crate::registry::class::try_dynify_object;
@@ -1002,6 +1002,34 @@ where
     }
 }
 
+impl<T: GodotClass> Gd<T> {
+    /// Calls a `#[func]` of a user-defined class on this object, without `Variant` conversions.
+    ///
+    /// `func` is obtained via [`WithFuncs::funcs()`], e.g. `Player::funcs().take_damage()`. This object's dynamic class must be that
+    /// class or derived from it, while `T` can be any base such as `Object` -- useful when the receiver is only known as `Gd<Object>`.
+    ///
+    /// The Rust method is invoked directly, unless a script attached to the object overrides it (Godot 4.3+); then the call is dispatched
+    /// to the script, like [`Object::call()`][crate::classes::Object::call] would. See [`TypedFunc`] for details.
+    ///
+    /// # Panics
+    /// - If the object's dynamic class does not inherit the class of `func`.
+    /// - If the instance is already bound in an incompatible way (e.g. `&mut self` method while another `bind()` guard is alive).
+    ///
+    /// [`WithFuncs::funcs()`]: crate::obj::WithFuncs::funcs
+    pub fn call_func<C, Params, Ret>(
+        &mut self,
+        func: TypedFunc<C, Params, Ret>,
+        args: Params,
+    ) -> Ret
+    where
+        C: GodotClass,
+        Params: meta::OutParamTuple,
+        Ret: FromGodot,
+    {
+        func.call_on(self, args)
+    }
+}
+
 impl<T> Gd<T>
 where
     T: WithSignals,
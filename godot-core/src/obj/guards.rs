@@ -8,14 +8,11 @@
 use std::fmt::Debug;
 use std::ops::{Deref, DerefMut};
 
-#[cfg(feature = "experimental-threads")]
-use godot_cell::blocking::{InaccessibleGuard, MutGuard, RefGuard};
-#[cfg(not(feature = "experimental-threads"))]
-use godot_cell::panicking::{InaccessibleGuard, MutGuard, RefGuard};
 use godot_ffi::out;
 
 use crate::obj::script::ScriptInstance;
 use crate::obj::{AsDyn, Gd, GodotClass, PassiveGd};
+use crate::storage::{InaccessibleGuard, MutGuard, RefGuard};
 
 /// Immutably/shared bound reference guard for a [`Gd`][crate::obj::Gd] smart pointer.
 ///
@@ -21,7 +21,9 @@ mod on_editor;
 mod on_ready;
 mod passive_gd;
 mod raw_gd;
+pub(crate) mod thread_safe_gd;
 mod traits;
+mod typed_func;
 
 pub(crate) mod raii;
 pub(crate) mod rtti;
@@ -35,7 +37,9 @@ pub use on_editor::*;
 pub use on_ready::*;
 pub(crate) use passive_gd::PassiveGd;
 pub use raw_gd::*;
+pub use thread_safe_gd::ThreadSafeGd;
 pub use traits::*;
+pub use typed_func::TypedFunc;
 
 pub(crate) use raii::impl_owned_rid;
 
@@ -69,7 +69,7 @@ impl<T: GodotClass> PassiveGd<T> {
     /// # Safety
     /// - `weak_gd` must be a weakly created `Gd`, e.g. from [`Gd::clone_weak()`] or [`Gd::from_obj_sys_weak()`].
     /// - The caller must ensure that the underlying object remains valid for the entire lifetime of this `PassiveGd`.
-    unsafe fn new(weak_gd: Gd<T>) -> Self {
+    pub(crate) unsafe fn new(weak_gd: Gd<T>) -> Self {
         Self {
             weak_gd: ManuallyDrop::new(weak_gd),
         }
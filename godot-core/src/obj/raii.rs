@@ -31,6 +31,7 @@ macro_rules! impl_owned_rid {
                 rid: crate::builtin::Rid,
                 server: crate::obj::Gd<crate::classes::$server>,
             ) -> Self {
+                crate::servers::rid_tracker::on_create(stringify!($server), stringify!($name), rid);
                 Self { rid, server }
             }
         }
@@ -53,6 +54,7 @@ macro_rules! impl_owned_rid {
             fn drop(&mut self) {
                 if self.rid.is_valid() {
                     self.server.clone().free_rid(self.rid);
+                    crate::servers::rid_tracker::on_free(self.rid);
                 }
             }
         }
@@ -61,10 +63,31 @@ macro_rules! impl_owned_rid {
         crate::obj::impl_owned_rid!($name, $server, $doc, free_rid);
     };
     ($name:ident, $server:ident, $doc:literal, $free_method:ident) => {
+        crate::obj::impl_owned_rid!($name, $server, $doc, $free_method, deps {});
+    };
+    // `deps` are extra fields holding resources that the RID depends on (e.g. shapes attached to a body). They are dropped after the
+    // RID itself has been freed, so dependencies always outlive their users on the server side.
+    ($name:ident, $server:ident, $doc:literal, $free_method:ident, deps { $( $dep:ident: $dep_ty:ty ),* $(,)? }) => {
         #[doc = $doc]
-        #[derive(Debug, Eq, PartialEq, Hash)]
+        #[derive(Debug)]
         pub struct $name {
             rid: crate::builtin::Rid,
+            $( $dep: $dep_ty, )*
+        }
+
+        // Identity is the RID alone; dependencies are bookkeeping and don't distinguish resources.
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.rid == other.rid
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl std::hash::Hash for $name {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                std::hash::Hash::hash(&self.rid, state);
+            }
         }
 
         impl $name {
@@ -76,7 +99,11 @@ macro_rules! impl_owned_rid {
             /// # Safety
             /// The RID must have been created by the server and must not be freed elsewhere.
             pub unsafe fn from_rid(rid: crate::builtin::Rid) -> Self {
-                Self { rid }
+                crate::servers::rid_tracker::on_create(stringify!($server), stringify!($name), rid);
+                Self {
+                    rid,
+                    $( $dep: Default::default(), )*
+                }
             }
         }
 
@@ -99,6 +126,7 @@ macro_rules! impl_owned_rid {
                 if self.rid.is_valid() {
                     use crate::obj::Singleton as _;
                     crate::classes::$server::singleton().$free_method(self.rid);
+                    crate::servers::rid_tracker::on_free(self.rid);
                 }
             }
         }
@@ -109,8 +137,8 @@ macro_rules! impl_owned_rid {
     ($name:ident, $doc:literal, @default) => {
         crate::obj::impl_owned_rid!($name, RenderingServer, $doc, @default);
     };
-    ($name:ident, $server:ident, $doc:literal, @default) => {
-        crate::obj::impl_owned_rid!($name, $server, $doc);
+    ($name:ident, $server:ident, $doc:literal, @default $(, deps $deps:tt)?) => {
+        crate::obj::impl_owned_rid!($name, $server, $doc, free_rid $(, deps $deps)?);
 
         impl Default for $name {
             fn default() -> Self {
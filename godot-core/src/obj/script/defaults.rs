@@ -0,0 +1,783 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::ffi::c_void;
+
+use crate::builtin::{Array, GString, PackedStringArray, StringName, VarDictionary, Variant};
+use crate::classes::native::ScriptLanguageExtensionProfilingInfo;
+use crate::classes::{Object, Script, ScriptLanguage};
+use crate::global::Error;
+use crate::meta::RawPtr;
+use crate::obj::Gd;
+
+/// Virtual methods of [`IScriptLanguageExtension`](crate::classes::IScriptLanguageExtension), with defaults for all but the essentials.
+///
+/// Godot requires script languages to override every virtual method of `ScriptLanguageExtension`, although most of them only matter for
+/// editor integration, debugging or profiling. Implement this trait instead, overriding the methods your language supports, and let
+/// [`impl_script_language_extension!`](crate::obj::script::impl_script_language_extension) generate the `IScriptLanguageExtension` impl
+/// which forwards to it.
+///
+/// Methods have the same names and signatures as in `IScriptLanguageExtension`. Only [`get_name()`](Self::get_name),
+/// [`get_type()`](Self::get_type) and [`get_extension()`](Self::get_extension) must be implemented. The defaults describe a language
+/// without editor, debugger or profiler support.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::classes::ScriptLanguageExtension;
+/// use godot::obj::script::{impl_script_language_extension, ScriptLanguageDefaults};
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=ScriptLanguageExtension, tool)]
+/// struct MyLanguage {
+///     base: Base<ScriptLanguageExtension>,
+/// }
+///
+/// impl ScriptLanguageDefaults for MyLanguage {
+///     fn get_name(&self) -> GString {
+///         "MyLanguage".into()
+///     }
+///
+///     fn get_type(&self) -> GString {
+///         "MyScript".into()
+///     }
+///
+///     fn get_extension(&self) -> GString {
+///         "mylang".into()
+///     }
+/// }
+///
+/// impl_script_language_extension!(MyLanguage);
+/// ```
+#[allow(unused_variables)]
+pub trait ScriptLanguageDefaults {
+    fn get_name(&self) -> GString;
+    fn get_type(&self) -> GString;
+    fn get_extension(&self) -> GString;
+
+    fn init_ext(&mut self) {}
+    fn finish(&mut self) {}
+    fn get_reserved_words(&self) -> PackedStringArray {
+        PackedStringArray::new()
+    }
+    fn is_control_flow_keyword(&self, keyword: GString) -> bool {
+        false
+    }
+    fn get_comment_delimiters(&self) -> PackedStringArray {
+        PackedStringArray::new()
+    }
+    fn get_string_delimiters(&self) -> PackedStringArray {
+        PackedStringArray::new()
+    }
+    fn make_template(
+        &self,
+        template: GString,
+        class_name: GString,
+        base_class_name: GString,
+    ) -> Option<Gd<Script>> {
+        None
+    }
+    fn get_built_in_templates(&self, object: StringName) -> Array<VarDictionary> {
+        Array::new()
+    }
+    fn is_using_templates(&mut self) -> bool {
+        false
+    }
+    /// Default accepts every script; Godot requires the `"valid"` key.
+    fn validate(
+        &self,
+        script: GString,
+        path: GString,
+        validate_functions: bool,
+        validate_errors: bool,
+        validate_warnings: bool,
+        validate_safe_lines: bool,
+    ) -> VarDictionary {
+        crate::vdict! { "valid": true }
+    }
+    fn validate_path(&self, path: GString) -> GString {
+        GString::new()
+    }
+    fn create_script(&self) -> Option<Gd<Object>> {
+        None
+    }
+    fn has_named_classes(&self) -> bool {
+        false
+    }
+    fn supports_builtin_mode(&self) -> bool {
+        false
+    }
+    fn supports_documentation(&self) -> bool {
+        false
+    }
+    fn can_inherit_from_file(&self) -> bool {
+        false
+    }
+    fn find_function(&self, class_name: GString, function_name: GString) -> i32 {
+        -1
+    }
+    fn make_function(
+        &self,
+        class_name: GString,
+        function_name: GString,
+        function_args: PackedStringArray,
+    ) -> GString {
+        GString::new()
+    }
+    fn open_in_external_editor(
+        &mut self,
+        script: Option<Gd<Script>>,
+        line: i32,
+        column: i32,
+    ) -> Error {
+        Error::ERR_UNAVAILABLE
+    }
+    fn overrides_external_editor(&mut self) -> bool {
+        false
+    }
+    /// Default reports that completion is unavailable; Godot requires the `"result"` key.
+    fn complete_code(
+        &self,
+        code: GString,
+        path: GString,
+        owner: Option<Gd<Object>>,
+    ) -> VarDictionary {
+        crate::vdict! { "result": Error::ERR_UNAVAILABLE }
+    }
+    /// Default reports that lookup is unavailable; Godot requires the `"result"` key.
+    fn lookup_code(
+        &self,
+        code: GString,
+        symbol: GString,
+        path: GString,
+        owner: Option<Gd<Object>>,
+    ) -> VarDictionary {
+        crate::vdict! { "result": Error::ERR_UNAVAILABLE }
+    }
+    fn auto_indent_code(&self, code: GString, from_line: i32, to_line: i32) -> GString {
+        code
+    }
+    fn add_global_constant(&mut self, name: StringName, value: Variant) {}
+    fn add_named_global_constant(&mut self, name: StringName, value: Variant) {}
+    fn remove_named_global_constant(&mut self, name: StringName) {}
+    fn thread_enter(&mut self) {}
+    fn thread_exit(&mut self) {}
+    fn debug_get_error(&self) -> GString {
+        GString::new()
+    }
+    fn debug_get_stack_level_count(&self) -> i32 {
+        0
+    }
+    fn debug_get_stack_level_line(&self, level: i32) -> i32 {
+        -1
+    }
+    fn debug_get_stack_level_function(&self, level: i32) -> GString {
+        GString::new()
+    }
+    fn debug_get_stack_level_locals(
+        &mut self,
+        level: i32,
+        max_subitems: i32,
+        max_depth: i32,
+    ) -> VarDictionary {
+        VarDictionary::new()
+    }
+    fn debug_get_stack_level_members(
+        &mut self,
+        level: i32,
+        max_subitems: i32,
+        max_depth: i32,
+    ) -> VarDictionary {
+        VarDictionary::new()
+    }
+    /// # Safety
+    /// See [`IScriptLanguageExtension::debug_get_stack_level_instance_rawptr()`][crate::classes::IScriptLanguageExtension::debug_get_stack_level_instance_rawptr].
+    unsafe fn debug_get_stack_level_instance_rawptr(&mut self, level: i32) -> RawPtr<*mut c_void> {
+        // SAFETY: Godot treats null as "no instance at this level".
+        unsafe { RawPtr::null() }
+    }
+    fn debug_get_globals(&mut self, max_subitems: i32, max_depth: i32) -> VarDictionary {
+        VarDictionary::new()
+    }
+    fn debug_parse_stack_level_expression(
+        &mut self,
+        level: i32,
+        expression: GString,
+        max_subitems: i32,
+        max_depth: i32,
+    ) -> GString {
+        GString::new()
+    }
+    fn debug_get_current_stack_info(&mut self) -> Array<VarDictionary> {
+        Array::new()
+    }
+    fn reload_all_scripts(&mut self) {}
+    fn reload_tool_script(&mut self, script: Option<Gd<Script>>, soft_reload: bool) {}
+    /// Default returns [`get_extension()`](Self::get_extension).
+    fn get_recognized_extensions(&self) -> PackedStringArray {
+        PackedStringArray::from([self.get_extension()])
+    }
+    fn get_public_functions(&self) -> Array<VarDictionary> {
+        Array::new()
+    }
+    fn get_public_constants(&self) -> VarDictionary {
+        VarDictionary::new()
+    }
+    fn get_public_annotations(&self) -> Array<VarDictionary> {
+        Array::new()
+    }
+    fn profiling_start(&mut self) {}
+    fn profiling_stop(&mut self) {}
+    /// # Safety
+    /// See [`IScriptLanguageExtension::profiling_get_accumulated_data_rawptr()`][crate::classes::IScriptLanguageExtension::profiling_get_accumulated_data_rawptr].
+    unsafe fn profiling_get_accumulated_data_rawptr(
+        &mut self,
+        info_array: RawPtr<*mut ScriptLanguageExtensionProfilingInfo>,
+        info_max: i32,
+    ) -> i32 {
+        0
+    }
+    /// # Safety
+    /// See [`IScriptLanguageExtension::profiling_get_frame_data_rawptr()`][crate::classes::IScriptLanguageExtension::profiling_get_frame_data_rawptr].
+    unsafe fn profiling_get_frame_data_rawptr(
+        &mut self,
+        info_array: RawPtr<*mut ScriptLanguageExtensionProfilingInfo>,
+        info_max: i32,
+    ) -> i32 {
+        0
+    }
+    fn frame(&mut self) {}
+    fn handles_global_class_type(&self, type_: GString) -> bool {
+        false
+    }
+    fn get_global_class_name(&self, path: GString) -> VarDictionary {
+        VarDictionary::new()
+    }
+
+    #[cfg(since_api = "4.3")]
+    fn profiling_set_save_native_calls(&mut self, enable: bool) {}
+    #[cfg(since_api = "4.3")]
+    fn debug_get_stack_level_source(&self, level: i32) -> GString {
+        GString::new()
+    }
+    #[cfg(since_api = "4.3")]
+    fn can_make_function(&self) -> bool {
+        false
+    }
+    #[cfg(since_api = "4.3")]
+    fn preferred_file_name_casing(&self) -> crate::classes::script_language::ScriptNameCasing {
+        crate::classes::script_language::ScriptNameCasing::AUTO
+    }
+    #[cfg(since_api = "4.4")]
+    fn reload_scripts(&mut self, scripts: crate::builtin::VarArray, soft: bool) {}
+}
+
+/// Virtual methods of [`IScriptExtension`](crate::classes::IScriptExtension), with defaults for all but the essentials.
+///
+/// Counterpart of [`ScriptLanguageDefaults`] for scripts. Implement this trait, overriding the methods your scripts support, and let
+/// [`impl_script_extension!`](crate::obj::script::impl_script_extension) generate the `IScriptExtension` impl which forwards to it.
+///
+/// Only [`get_language()`](Self::get_language) must be implemented. Scripts that can be attached to objects also override
+/// [`can_instantiate()`](Self::can_instantiate) and [`instance_create_rawptr()`](Self::instance_create_rawptr), usually together with
+/// [`create_script_instance()`](crate::obj::script::create_script_instance).
+#[allow(unused_variables)]
+pub trait ScriptExtensionDefaults {
+    fn get_language(&self) -> Option<Gd<ScriptLanguage>>;
+
+    fn editor_can_reload_from_file(&mut self) -> bool {
+        true
+    }
+    fn can_instantiate(&self) -> bool {
+        false
+    }
+    fn get_base_script(&self) -> Option<Gd<Script>> {
+        None
+    }
+    fn get_global_name(&self) -> StringName {
+        StringName::default()
+    }
+    fn inherits_script(&self, script: Gd<Script>) -> bool {
+        false
+    }
+    fn get_instance_base_type(&self) -> StringName {
+        StringName::from("Object")
+    }
+    /// # Safety
+    /// See [`IScriptExtension::instance_create_rawptr()`][crate::classes::IScriptExtension::instance_create_rawptr].
+    unsafe fn instance_create_rawptr(&self, for_object: Gd<Object>) -> RawPtr<*mut c_void> {
+        // SAFETY: Godot treats null as "no instance created".
+        unsafe { RawPtr::null() }
+    }
+    /// # Safety
+    /// See [`IScriptExtension::placeholder_instance_create_rawptr()`][crate::classes::IScriptExtension::placeholder_instance_create_rawptr].
+    unsafe fn placeholder_instance_create_rawptr(
+        &self,
+        for_object: Gd<Object>,
+    ) -> RawPtr<*mut c_void> {
+        // SAFETY: Godot treats null as "no instance created".
+        unsafe { RawPtr::null() }
+    }
+    fn instance_has(&self, object: Gd<Object>) -> bool {
+        false
+    }
+    fn has_source_code(&self) -> bool {
+        false
+    }
+    fn get_source_code(&self) -> GString {
+        GString::new()
+    }
+    fn set_source_code(&mut self, code: GString) {}
+    fn reload(&mut self, keep_state: bool) -> Error {
+        Error::OK
+    }
+    fn get_documentation(&self) -> Array<VarDictionary> {
+        Array::new()
+    }
+    fn has_method(&self, method: StringName) -> bool {
+        false
+    }
+    fn has_static_method(&self, method: StringName) -> bool {
+        false
+    }
+    fn get_method_info(&self, method: StringName) -> VarDictionary {
+        VarDictionary::new()
+    }
+    fn is_tool(&self) -> bool {
+        false
+    }
+    fn is_valid(&self) -> bool {
+        true
+    }
+    fn has_script_signal(&self, signal: StringName) -> bool {
+        false
+    }
+    fn get_script_signal_list(&self) -> Array<VarDictionary> {
+        Array::new()
+    }
+    fn has_property_default_value(&self, property: StringName) -> bool {
+        false
+    }
+    fn get_property_default_value(&self, property: StringName) -> Variant {
+        Variant::nil()
+    }
+    fn update_exports(&mut self) {}
+    fn get_script_method_list(&self) -> Array<VarDictionary> {
+        Array::new()
+    }
+    fn get_script_property_list(&self) -> Array<VarDictionary> {
+        Array::new()
+    }
+    fn get_member_line(&self, member: StringName) -> i32 {
+        -1
+    }
+    fn get_constants(&self) -> VarDictionary {
+        VarDictionary::new()
+    }
+    fn get_members(&self) -> Array<StringName> {
+        Array::new()
+    }
+    fn is_placeholder_fallback_enabled(&self) -> bool {
+        false
+    }
+    fn get_rpc_config(&self) -> Variant {
+        Variant::nil()
+    }
+
+    #[cfg(since_api = "4.4")]
+    fn get_doc_class_name(&self) -> StringName {
+        StringName::default()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Forwarding impls
+
+/// Implements [`IScriptLanguageExtension`](crate::classes::IScriptLanguageExtension) by forwarding to [`ScriptLanguageDefaults`].
+///
+/// Expands to a `#[godot_api] impl IScriptLanguageExtension for $Class` block, registering all virtual methods. As this is the only
+/// `IScriptLanguageExtension` impl of the class, construct it through `#[class(init)]` or [`Gd::from_init_fn()`].
+///
+/// See [`ScriptLanguageDefaults`] for an example.
+#[macro_export]
+macro_rules! impl_script_language_extension {
+    ($Class:ident) => {
+        $crate::__script_language_forward! {
+            $Class;
+
+            fn get_name(&self) -> $crate::builtin::GString {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::get_name(self)
+            }
+            fn init_ext(&mut self) {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::init_ext(self)
+            }
+            fn get_type(&self) -> $crate::builtin::GString {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::get_type(self)
+            }
+            fn get_extension(&self) -> $crate::builtin::GString {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::get_extension(self)
+            }
+            fn finish(&mut self) {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::finish(self)
+            }
+            fn get_reserved_words(&self) -> $crate::builtin::PackedStringArray {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::get_reserved_words(self)
+            }
+            fn is_control_flow_keyword(&self, keyword: $crate::builtin::GString) -> bool {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::is_control_flow_keyword(self, keyword)
+            }
+            fn get_comment_delimiters(&self) -> $crate::builtin::PackedStringArray {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::get_comment_delimiters(self)
+            }
+            fn get_string_delimiters(&self) -> $crate::builtin::PackedStringArray {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::get_string_delimiters(self)
+            }
+            fn make_template(&self, template: $crate::builtin::GString, class_name: $crate::builtin::GString, base_class_name: $crate::builtin::GString) -> Option<$crate::obj::Gd<$crate::classes::Script>> {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::make_template(self, template, class_name, base_class_name)
+            }
+            fn get_built_in_templates(&self, object: $crate::builtin::StringName) -> $crate::builtin::Array<$crate::builtin::VarDictionary> {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::get_built_in_templates(self, object)
+            }
+            fn is_using_templates(&mut self) -> bool {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::is_using_templates(self)
+            }
+            fn validate(&self, script: $crate::builtin::GString, path: $crate::builtin::GString, validate_functions: bool, validate_errors: bool, validate_warnings: bool, validate_safe_lines: bool) -> $crate::builtin::VarDictionary {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::validate(self, script, path, validate_functions, validate_errors, validate_warnings, validate_safe_lines)
+            }
+            fn validate_path(&self, path: $crate::builtin::GString) -> $crate::builtin::GString {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::validate_path(self, path)
+            }
+            fn create_script(&self) -> Option<$crate::obj::Gd<$crate::classes::Object>> {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::create_script(self)
+            }
+            fn has_named_classes(&self) -> bool {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::has_named_classes(self)
+            }
+            fn supports_builtin_mode(&self) -> bool {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::supports_builtin_mode(self)
+            }
+            fn supports_documentation(&self) -> bool {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::supports_documentation(self)
+            }
+            fn can_inherit_from_file(&self) -> bool {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::can_inherit_from_file(self)
+            }
+            fn find_function(&self, class_name: $crate::builtin::GString, function_name: $crate::builtin::GString) -> i32 {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::find_function(self, class_name, function_name)
+            }
+            fn make_function(&self, class_name: $crate::builtin::GString, function_name: $crate::builtin::GString, function_args: $crate::builtin::PackedStringArray) -> $crate::builtin::GString {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::make_function(self, class_name, function_name, function_args)
+            }
+            fn open_in_external_editor(&mut self, script: Option<$crate::obj::Gd<$crate::classes::Script>>, line: i32, column: i32) -> $crate::global::Error {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::open_in_external_editor(self, script, line, column)
+            }
+            fn overrides_external_editor(&mut self) -> bool {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::overrides_external_editor(self)
+            }
+            fn complete_code(&self, code: $crate::builtin::GString, path: $crate::builtin::GString, owner: Option<$crate::obj::Gd<$crate::classes::Object>>) -> $crate::builtin::VarDictionary {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::complete_code(self, code, path, owner)
+            }
+            fn lookup_code(&self, code: $crate::builtin::GString, symbol: $crate::builtin::GString, path: $crate::builtin::GString, owner: Option<$crate::obj::Gd<$crate::classes::Object>>) -> $crate::builtin::VarDictionary {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::lookup_code(self, code, symbol, path, owner)
+            }
+            fn auto_indent_code(&self, code: $crate::builtin::GString, from_line: i32, to_line: i32) -> $crate::builtin::GString {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::auto_indent_code(self, code, from_line, to_line)
+            }
+            fn add_global_constant(&mut self, name: $crate::builtin::StringName, value: $crate::builtin::Variant) {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::add_global_constant(self, name, value)
+            }
+            fn add_named_global_constant(&mut self, name: $crate::builtin::StringName, value: $crate::builtin::Variant) {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::add_named_global_constant(self, name, value)
+            }
+            fn remove_named_global_constant(&mut self, name: $crate::builtin::StringName) {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::remove_named_global_constant(self, name)
+            }
+            fn thread_enter(&mut self) {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::thread_enter(self)
+            }
+            fn thread_exit(&mut self) {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::thread_exit(self)
+            }
+            fn debug_get_error(&self) -> $crate::builtin::GString {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::debug_get_error(self)
+            }
+            fn debug_get_stack_level_count(&self) -> i32 {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::debug_get_stack_level_count(self)
+            }
+            fn debug_get_stack_level_line(&self, level: i32) -> i32 {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::debug_get_stack_level_line(self, level)
+            }
+            fn debug_get_stack_level_function(&self, level: i32) -> $crate::builtin::GString {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::debug_get_stack_level_function(self, level)
+            }
+            fn debug_get_stack_level_locals(&mut self, level: i32, max_subitems: i32, max_depth: i32) -> $crate::builtin::VarDictionary {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::debug_get_stack_level_locals(self, level, max_subitems, max_depth)
+            }
+            fn debug_get_stack_level_members(&mut self, level: i32, max_subitems: i32, max_depth: i32) -> $crate::builtin::VarDictionary {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::debug_get_stack_level_members(self, level, max_subitems, max_depth)
+            }
+            unsafe fn debug_get_stack_level_instance_rawptr(&mut self, level: i32) -> $crate::meta::RawPtr<*mut ::std::ffi::c_void> {
+                // SAFETY: same contract as the forwarded method.
+                unsafe { <$Class as $crate::obj::script::ScriptLanguageDefaults>::debug_get_stack_level_instance_rawptr(self, level) }
+            }
+            fn debug_get_globals(&mut self, max_subitems: i32, max_depth: i32) -> $crate::builtin::VarDictionary {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::debug_get_globals(self, max_subitems, max_depth)
+            }
+            fn debug_parse_stack_level_expression(&mut self, level: i32, expression: $crate::builtin::GString, max_subitems: i32, max_depth: i32) -> $crate::builtin::GString {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::debug_parse_stack_level_expression(self, level, expression, max_subitems, max_depth)
+            }
+            fn debug_get_current_stack_info(&mut self) -> $crate::builtin::Array<$crate::builtin::VarDictionary> {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::debug_get_current_stack_info(self)
+            }
+            fn reload_all_scripts(&mut self) {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::reload_all_scripts(self)
+            }
+            fn reload_tool_script(&mut self, script: Option<$crate::obj::Gd<$crate::classes::Script>>, soft_reload: bool) {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::reload_tool_script(self, script, soft_reload)
+            }
+            fn get_recognized_extensions(&self) -> $crate::builtin::PackedStringArray {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::get_recognized_extensions(self)
+            }
+            fn get_public_functions(&self) -> $crate::builtin::Array<$crate::builtin::VarDictionary> {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::get_public_functions(self)
+            }
+            fn get_public_constants(&self) -> $crate::builtin::VarDictionary {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::get_public_constants(self)
+            }
+            fn get_public_annotations(&self) -> $crate::builtin::Array<$crate::builtin::VarDictionary> {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::get_public_annotations(self)
+            }
+            fn profiling_start(&mut self) {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::profiling_start(self)
+            }
+            fn profiling_stop(&mut self) {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::profiling_stop(self)
+            }
+            unsafe fn profiling_get_accumulated_data_rawptr(&mut self, info_array: $crate::meta::RawPtr<*mut $crate::classes::native::ScriptLanguageExtensionProfilingInfo>, info_max: i32) -> i32 {
+                // SAFETY: same contract as the forwarded method.
+                unsafe { <$Class as $crate::obj::script::ScriptLanguageDefaults>::profiling_get_accumulated_data_rawptr(self, info_array, info_max) }
+            }
+            unsafe fn profiling_get_frame_data_rawptr(&mut self, info_array: $crate::meta::RawPtr<*mut $crate::classes::native::ScriptLanguageExtensionProfilingInfo>, info_max: i32) -> i32 {
+                // SAFETY: same contract as the forwarded method.
+                unsafe { <$Class as $crate::obj::script::ScriptLanguageDefaults>::profiling_get_frame_data_rawptr(self, info_array, info_max) }
+            }
+            fn frame(&mut self) {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::frame(self)
+            }
+            fn handles_global_class_type(&self, type_: $crate::builtin::GString) -> bool {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::handles_global_class_type(self, type_)
+            }
+            fn get_global_class_name(&self, path: $crate::builtin::GString) -> $crate::builtin::VarDictionary {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::get_global_class_name(self, path)
+            }
+        }
+    };
+}
+
+/// Implements [`IScriptExtension`](crate::classes::IScriptExtension) by forwarding to [`ScriptExtensionDefaults`].
+///
+/// Expands to a `#[godot_api] impl IScriptExtension for $Class` block, registering all virtual methods. As this is the only
+/// `IScriptExtension` impl of the class, construct it through `#[class(init)]` or [`Gd::from_init_fn()`].
+#[macro_export]
+macro_rules! impl_script_extension {
+    ($Class:ident) => {
+        $crate::__script_extension_forward! {
+            $Class;
+
+            fn editor_can_reload_from_file(&mut self) -> bool {
+                <$Class as $crate::obj::script::ScriptExtensionDefaults>::editor_can_reload_from_file(self)
+            }
+            fn can_instantiate(&self) -> bool {
+                <$Class as $crate::obj::script::ScriptExtensionDefaults>::can_instantiate(self)
+            }
+            fn get_base_script(&self) -> Option<$crate::obj::Gd<$crate::classes::Script>> {
+                <$Class as $crate::obj::script::ScriptExtensionDefaults>::get_base_script(self)
+            }
+            fn get_global_name(&self) -> $crate::builtin::StringName {
+                <$Class as $crate::obj::script::ScriptExtensionDefaults>::get_global_name(self)
+            }
+            fn inherits_script(&self, script: $crate::obj::Gd<$crate::classes::Script>) -> bool {
+                <$Class as $crate::obj::script::ScriptExtensionDefaults>::inherits_script(self, script)
+            }
+            fn get_instance_base_type(&self) -> $crate::builtin::StringName {
+                <$Class as $crate::obj::script::ScriptExtensionDefaults>::get_instance_base_type(self)
+            }
+            unsafe fn instance_create_rawptr(&self, for_object: $crate::obj::Gd<$crate::classes::Object>) -> $crate::meta::RawPtr<*mut ::std::ffi::c_void> {
+                // SAFETY: same contract as the forwarded method.
+                unsafe { <$Class as $crate::obj::script::ScriptExtensionDefaults>::instance_create_rawptr(self, for_object) }
+            }
+            unsafe fn placeholder_instance_create_rawptr(&self, for_object: $crate::obj::Gd<$crate::classes::Object>) -> $crate::meta::RawPtr<*mut ::std::ffi::c_void> {
+                // SAFETY: same contract as the forwarded method.
+                unsafe { <$Class as $crate::obj::script::ScriptExtensionDefaults>::placeholder_instance_create_rawptr(self, for_object) }
+            }
+            fn instance_has(&self, object: $crate::obj::Gd<$crate::classes::Object>) -> bool {
+                <$Class as $crate::obj::script::ScriptExtensionDefaults>::instance_has(self, object)
+            }
+            fn has_source_code(&self) -> bool {
+                <$Class as $crate::obj::script::ScriptExtensionDefaults>::has_source_code(self)
+            }
+            fn get_source_code(&self) -> $crate::builtin::GString {
+                <$Class as $crate::obj::script::ScriptExtensionDefaults>::get_source_code(self)
+            }
+            fn set_source_code(&mut self, code: $crate::builtin::GString) {
+                <$Class as $crate::obj::script::ScriptExtensionDefaults>::set_source_code(self, code)
+            }
+            fn reload(&mut self, keep_state: bool) -> $crate::global::Error {
+                <$Class as $crate::obj::script::ScriptExtensionDefaults>::reload(self, keep_state)
+            }
+            fn get_documentation(&self) -> $crate::builtin::Array<$crate::builtin::VarDictionary> {
+                <$Class as $crate::obj::script::ScriptExtensionDefaults>::get_documentation(self)
+            }
+            fn has_method(&self, method: $crate::builtin::StringName) -> bool {
+                <$Class as $crate::obj::script::ScriptExtensionDefaults>::has_method(self, method)
+            }
+            fn has_static_method(&self, method: $crate::builtin::StringName) -> bool {
+                <$Class as $crate::obj::script::ScriptExtensionDefaults>::has_static_method(self, method)
+            }
+            fn get_method_info(&self, method: $crate::builtin::StringName) -> $crate::builtin::VarDictionary {
+                <$Class as $crate::obj::script::ScriptExtensionDefaults>::get_method_info(self, method)
+            }
+            fn is_tool(&self) -> bool {
+                <$Class as $crate::obj::script::ScriptExtensionDefaults>::is_tool(self)
+            }
+            fn is_valid(&self) -> bool {
+                <$Class as $crate::obj::script::ScriptExtensionDefaults>::is_valid(self)
+            }
+            fn get_language(&self) -> Option<$crate::obj::Gd<$crate::classes::ScriptLanguage>> {
+                <$Class as $crate::obj::script::ScriptExtensionDefaults>::get_language(self)
+            }
+            fn has_script_signal(&self, signal: $crate::builtin::StringName) -> bool {
+                <$Class as $crate::obj::script::ScriptExtensionDefaults>::has_script_signal(self, signal)
+            }
+            fn get_script_signal_list(&self) -> $crate::builtin::Array<$crate::builtin::VarDictionary> {
+                <$Class as $crate::obj::script::ScriptExtensionDefaults>::get_script_signal_list(self)
+            }
+            fn has_property_default_value(&self, property: $crate::builtin::StringName) -> bool {
+                <$Class as $crate::obj::script::ScriptExtensionDefaults>::has_property_default_value(self, property)
+            }
+            fn get_property_default_value(&self, property: $crate::builtin::StringName) -> $crate::builtin::Variant {
+                <$Class as $crate::obj::script::ScriptExtensionDefaults>::get_property_default_value(self, property)
+            }
+            fn update_exports(&mut self) {
+                <$Class as $crate::obj::script::ScriptExtensionDefaults>::update_exports(self)
+            }
+            fn get_script_method_list(&self) -> $crate::builtin::Array<$crate::builtin::VarDictionary> {
+                <$Class as $crate::obj::script::ScriptExtensionDefaults>::get_script_method_list(self)
+            }
+            fn get_script_property_list(&self) -> $crate::builtin::Array<$crate::builtin::VarDictionary> {
+                <$Class as $crate::obj::script::ScriptExtensionDefaults>::get_script_property_list(self)
+            }
+            fn get_member_line(&self, member: $crate::builtin::StringName) -> i32 {
+                <$Class as $crate::obj::script::ScriptExtensionDefaults>::get_member_line(self, member)
+            }
+            fn get_constants(&self) -> $crate::builtin::VarDictionary {
+                <$Class as $crate::obj::script::ScriptExtensionDefaults>::get_constants(self)
+            }
+            fn get_members(&self) -> $crate::builtin::Array<$crate::builtin::StringName> {
+                <$Class as $crate::obj::script::ScriptExtensionDefaults>::get_members(self)
+            }
+            fn is_placeholder_fallback_enabled(&self) -> bool {
+                <$Class as $crate::obj::script::ScriptExtensionDefaults>::is_placeholder_fallback_enabled(self)
+            }
+            fn get_rpc_config(&self) -> $crate::builtin::Variant {
+                <$Class as $crate::obj::script::ScriptExtensionDefaults>::get_rpc_config(self)
+            }
+        }
+    };
+}
+
+// Version-specific virtual methods are appended here, with the cfg evaluated when compiling godot-core rather than in the user crate.
+// Every method's `self` parameter and its use must come from the same macro definition, as `self` is hygienic in `macro_rules!`.
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(before_api = "4.3")]
+macro_rules! __script_language_forward {
+    ($Class:ident; $($methods:tt)*) => {
+        $crate::__script_language_forward_4_4! { $Class; $($methods)* }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(since_api = "4.3")]
+macro_rules! __script_language_forward {
+    ($Class:ident; $($methods:tt)*) => {
+        $crate::__script_language_forward_4_4! {
+            $Class;
+            $($methods)*
+
+            fn profiling_set_save_native_calls(&mut self, enable: bool) {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::profiling_set_save_native_calls(self, enable)
+            }
+            fn debug_get_stack_level_source(&self, level: i32) -> $crate::builtin::GString {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::debug_get_stack_level_source(self, level)
+            }
+            fn can_make_function(&self) -> bool {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::can_make_function(self)
+            }
+            fn preferred_file_name_casing(&self) -> $crate::classes::script_language::ScriptNameCasing {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::preferred_file_name_casing(self)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(before_api = "4.4")]
+macro_rules! __script_language_forward_4_4 {
+    ($Class:ident; $($methods:tt)*) => {
+        #[::godot::register::godot_api]
+        impl $crate::classes::IScriptLanguageExtension for $Class {
+            $($methods)*
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(since_api = "4.4")]
+macro_rules! __script_language_forward_4_4 {
+    ($Class:ident; $($methods:tt)*) => {
+        #[::godot::register::godot_api]
+        impl $crate::classes::IScriptLanguageExtension for $Class {
+            $($methods)*
+
+            fn reload_scripts(&mut self, scripts: $crate::builtin::VarArray, soft: bool) {
+                <$Class as $crate::obj::script::ScriptLanguageDefaults>::reload_scripts(self, scripts, soft)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(before_api = "4.4")]
+macro_rules! __script_extension_forward {
+    ($Class:ident; $($methods:tt)*) => {
+        #[::godot::register::godot_api]
+        impl $crate::classes::IScriptExtension for $Class {
+            $($methods)*
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(since_api = "4.4")]
+macro_rules! __script_extension_forward {
+    ($Class:ident; $($methods:tt)*) => {
+        #[::godot::register::godot_api]
+        impl $crate::classes::IScriptExtension for $Class {
+            $($methods)*
+
+            fn get_doc_class_name(&self) -> $crate::builtin::StringName {
+                <$Class as $crate::obj::script::ScriptExtensionDefaults>::get_doc_class_name(self)
+            }
+        }
+    };
+}
@@ -0,0 +1,133 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{GString, PackedStringArray, StringName, Variant};
+use crate::classes::{FileAccess, Script};
+use crate::global::Error;
+use crate::meta::ToGodot;
+use crate::obj::{Gd, GodotClass, Inherits};
+
+/// Typed description of how script files of a language are loaded from disk.
+///
+/// Implement this trait to describe your script file format, then delegate the virtual methods of your
+/// [`IResourceFormatLoader`](crate::classes::IResourceFormatLoader) implementation to the provided functions. Godot will then load
+/// your script files (e.g. when attaching them to nodes or opening scenes) through [`load_script()`](Self::load_script).
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::classes::{IResourceFormatLoader, ResourceFormatLoader, ScriptExtension};
+/// # use godot::global::Error;
+/// use godot::obj::script::ScriptFormatLoader;
+///
+/// # #[derive(GodotClass)]
+/// # #[class(init, base=ScriptExtension, tool)]
+/// # struct MyScript { base: Base<ScriptExtension> }
+/// #[derive(GodotClass)]
+/// #[class(init, base=ResourceFormatLoader, tool)]
+/// struct MyScriptLoader {
+///     base: Base<ResourceFormatLoader>,
+/// }
+///
+/// impl ScriptFormatLoader for MyScriptLoader {
+///     type Script = MyScript;
+///     const EXTENSIONS: &'static [&'static str] = &["dsl"];
+///
+///     fn load_script(path: &GString, source: GString) -> Result<Gd<MyScript>, Error> {
+///         // Parse `source`, create the script object...
+/// #       unimplemented!()
+///     }
+/// }
+///
+/// #[godot_api]
+/// impl IResourceFormatLoader for MyScriptLoader {
+///     fn get_recognized_extensions(&self) -> PackedStringArray {
+///         Self::recognized_extensions()
+///     }
+///
+///     fn handles_type(&self, type_: StringName) -> bool {
+///         Self::handles_script_type(&type_)
+///     }
+///
+///     fn get_resource_type(&self, path: GString) -> GString {
+///         Self::script_type_for_path(&path)
+///     }
+///
+///     fn load(&self, path: GString, _original_path: GString, _use_sub_threads: bool, _cache_mode: i32) -> Variant {
+///         Self::load_from_path(&path)
+///     }
+/// }
+/// ```
+///
+/// The loader must then be registered with [`ResourceLoader::add_resource_format_loader()`](crate::classes::ResourceLoader::add_resource_format_loader),
+/// typically during initialization of your library.
+pub trait ScriptFormatLoader {
+    /// The script class created by this loader.
+    type Script: Inherits<Script>;
+
+    /// File extensions (without leading dot) recognized as scripts of this format, e.g. `&["dsl"]`.
+    const EXTENSIONS: &'static [&'static str];
+
+    /// Creates a script object from the file at `path` with contents `source`.
+    ///
+    /// Returning an error makes the load fail; Godot reports it to the caller of `ResourceLoader::load()`.
+    fn load_script(path: &GString, source: GString) -> Result<Gd<Self::Script>, Error>;
+
+    /// Implementation for [`IResourceFormatLoader::get_recognized_extensions()`](crate::classes::IResourceFormatLoader::get_recognized_extensions).
+    fn recognized_extensions() -> PackedStringArray {
+        Self::EXTENSIONS
+            .iter()
+            .map(|ext| GString::from(*ext))
+            .collect()
+    }
+
+    /// Implementation for [`IResourceFormatLoader::handles_type()`](crate::classes::IResourceFormatLoader::handles_type).
+    ///
+    /// Accepts both the generic `Script` type and the concrete script class.
+    fn handles_script_type(type_: &StringName) -> bool {
+        *type_ == Script::class_id().to_string_name()
+            || *type_ == Self::Script::class_id().to_string_name()
+    }
+
+    /// Implementation for [`IResourceFormatLoader::get_resource_type()`](crate::classes::IResourceFormatLoader::get_resource_type).
+    ///
+    /// Returns the script class name if `path` has one of the recognized extensions, and an empty string otherwise.
+    fn script_type_for_path(path: &GString) -> GString {
+        if has_extension(&path.to_string(), Self::EXTENSIONS) {
+            Self::Script::class_id().to_gstring()
+        } else {
+            GString::new()
+        }
+    }
+
+    /// Implementation for [`IResourceFormatLoader::load()`](crate::classes::IResourceFormatLoader::load).
+    ///
+    /// Reads the file at `path` and passes its contents to [`load_script()`](Self::load_script). Errors are returned as integer variants,
+    /// as expected by Godot.
+    fn load_from_path(path: &GString) -> Variant {
+        let source = FileAccess::get_file_as_string(path);
+        let open_error = FileAccess::get_open_error();
+        if open_error != Error::OK {
+            return open_error.to_variant();
+        }
+
+        match Self::load_script(path, source) {
+            Ok(script) => script.to_variant(),
+            Err(err) => err.to_variant(),
+        }
+    }
+}
+
+fn has_extension(path: &str, extensions: &[&str]) -> bool {
+    let Some((_, ext)) = path.rsplit_once('.') else {
+        return false;
+    };
+
+    extensions
+        .iter()
+        .any(|candidate| candidate.eq_ignore_ascii_case(ext))
+}
@@ -11,6 +11,13 @@
 //! the [`IScriptExtension` trait][crate::classes::IScriptExtension].
 //!
 //! See [`ScriptInstance`](trait.ScriptInstance.html) for usage.
+//!
+//! Beyond the raw script instance, this module offers building blocks that most script language implementations need:
+//! - [`ScriptLanguageDefaults`] and [`ScriptExtensionDefaults`] with default implementations of the many required virtual methods of
+//!   `ScriptLanguageExtension` and `ScriptExtension`, wired up via [`impl_script_language_extension!`] and [`impl_script_extension!`].
+//! - [`ScriptRegistry`] to map `Gd<Script>` objects to Rust-side script data.
+//! - [`PlaceholderScriptInstance`] for placeholder instances, as used by the editor for non-tool scripts.
+//! - [`ScriptFormatLoader`] to load script files of a custom extension through Godot's `ResourceLoader`.
 
 use std::ffi::c_void;
 use std::ops::{Deref, DerefMut};
@@ -27,6 +34,18 @@ use crate::meta::{MethodInfo, PropertyInfo, RawPtr};
 use crate::obj::{Base, Gd, GodotClass};
 use crate::sys;
 
+mod defaults;
+mod format_loader;
+mod placeholder;
+mod registry;
+
+pub use defaults::{ScriptExtensionDefaults, ScriptLanguageDefaults};
+pub use format_loader::ScriptFormatLoader;
+pub use placeholder::PlaceholderScriptInstance;
+pub use registry::ScriptRegistry;
+
+pub use crate::{impl_script_extension, impl_script_language_extension};
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Public re-exports.
 
@@ -124,10 +143,20 @@ pub trait ScriptInstance: Sized {
     ///
     /// If this function and [IScriptExtension::is_placeholder_fallback_enabled] return true, Godot will call [`Self::property_set_fallback`]
     /// instead of [`Self::set_property`].
-    fn is_placeholder(&self) -> bool;
+    ///
+    /// The default implementation returns `false`. See [`PlaceholderScriptInstance`] for a ready-made placeholder.
+    fn is_placeholder(&self) -> bool {
+        false
+    }
 
     /// Validation function for the engine to verify if the script exposes a certain method.
-    fn has_method(&self, method: StringName) -> bool;
+    ///
+    /// The default implementation looks up `method` in [`get_method_list()`][Self::get_method_list].
+    fn has_method(&self, method: StringName) -> bool {
+        self.get_method_list()
+            .iter()
+            .any(|info| info.method_name == method)
+    }
 
     /// Lets the engine get a reference to the script this instance was created for.
     ///
@@ -137,36 +166,101 @@ pub trait ScriptInstance: Sized {
     fn get_script(&self) -> &Gd<Script>;
 
     /// Lets the engine fetch the type of a particular property.
-    fn get_property_type(&self, name: StringName) -> VariantType;
+    ///
+    /// The default implementation looks up `name` in [`get_property_list()`][Self::get_property_list], returning `NIL` if not found.
+    fn get_property_type(&self, name: StringName) -> VariantType {
+        self.get_property_list()
+            .into_iter()
+            .find(|info| info.property_name == name)
+            .map_or(VariantType::NIL, |info| info.variant_type)
+    }
 
     /// String representation of the script instance.
     fn to_string(&self) -> GString;
 
     /// A dump of all property names and values that are exposed to the engine.
-    fn get_property_state(&self) -> Vec<(StringName, Variant)>;
+    ///
+    /// The default implementation reads every property of [`get_property_list()`][Self::get_property_list] via
+    /// [`get_property()`][Self::get_property].
+    fn get_property_state(&self) -> Vec<(StringName, Variant)> {
+        self.get_property_list()
+            .into_iter()
+            .filter_map(|info| {
+                let value = self.get_property(info.property_name.clone())?;
+                Some((info.property_name, value))
+            })
+            .collect()
+    }
 
     /// Lets the engine get a reference to the [`ScriptLanguage`] this instance belongs to.
     fn get_language(&self) -> Gd<ScriptLanguage>;
 
     /// Callback from the engine when the reference count of the base object has been decreased. When this method returns `true` the engine will
     /// not free the object the script is attached to.
-    fn on_refcount_decremented(&self) -> bool;
+    ///
+    /// The default implementation returns `true`.
+    fn on_refcount_decremented(&self) -> bool {
+        true
+    }
 
     /// Callback from the engine when the reference count of the base object has been increased.
-    fn on_refcount_incremented(&self);
+    ///
+    /// The default implementation does nothing.
+    fn on_refcount_incremented(&self) {}
 
     /// The engine may call this function if it failed to get a property value via [`ScriptInstance::get_property`] or the native type's getter.
-    fn property_get_fallback(&self, name: StringName) -> Option<Variant>;
+    ///
+    /// The default implementation returns `None`.
+    fn property_get_fallback(&self, _name: StringName) -> Option<Variant> {
+        None
+    }
 
     /// The engine may call this function if [`IScriptExtension::is_placeholder_fallback_enabled`] is enabled.
-    fn property_set_fallback(this: SiMut<Self>, name: StringName, value: &Variant) -> bool;
+    ///
+    /// The default implementation returns `false`, i.e. the assignment is not handled.
+    fn property_set_fallback(_this: SiMut<Self>, _name: StringName, _value: &Variant) -> bool {
+        false
+    }
+
+    /// Whether the property `name` can be reverted to a default value in the editor inspector.
+    ///
+    /// The default implementation returns `true` if [`property_get_revert()`][Self::property_get_revert] provides a value.
+    fn property_can_revert(&self, name: StringName) -> bool {
+        self.property_get_revert(name).is_some()
+    }
+
+    /// The value that the property `name` is reset to, when the revert button in the editor inspector is pressed.
+    ///
+    /// Return `None` if the property has no revert value. The default implementation returns `None` for all properties.
+    fn property_get_revert(&self, _name: StringName) -> Option<Variant> {
+        None
+    }
+
+    /// Lets the script adjust a property's info (hint, usage, etc.) before the engine uses it, e.g. for the editor inspector.
+    ///
+    /// This is called for every property of the object, not just the ones from [`get_property_list()`][Self::get_property_list].
+    /// The default implementation leaves the property unchanged.
+    fn validate_property(&self, _property: &mut PropertyInfo) {}
+
+    /// Callback from the engine when the base object receives a notification (e.g. `Node::NOTIFICATION_READY`).
+    ///
+    /// `reversed` is `true` if the notification is propagated from derived to base classes, such as for `NOTIFICATION_PREDELETE`.
+    /// The default implementation ignores all notifications.
+    fn on_notification(_this: SiMut<Self>, _what: i32, _reversed: bool) {}
 
     /// This function will be called to handle calls to [`Object::get_method_argument_count`](crate::classes::Object::get_method_argument_count)
     /// and `Callable::get_argument_count`.
     ///
     /// If `None` is returned the public methods will return `0`.
+    ///
+    /// The default implementation counts the arguments of the matching entry in [`get_method_list()`][Self::get_method_list].
     #[cfg(since_api = "4.3")]
-    fn get_method_argument_count(&self, _method: StringName) -> Option<u32>;
+    fn get_method_argument_count(&self, method: StringName) -> Option<u32> {
+        self.get_method_list()
+            .into_iter()
+            .find(|info| info.method_name == method)
+            .and_then(|info| u32::try_from(info.arguments.len()).ok())
+    }
 }
 
 #[cfg(before_api = "4.3")]
@@ -259,8 +353,8 @@ pub unsafe fn create_script_instance<T: ScriptInstance>(
 
         get_class_category_func: None, // not yet implemented.
 
-        property_can_revert_func: None, // unimplemented until needed.
-        property_get_revert_func: None, // unimplemented until needed.
+        property_can_revert_func: Some(script_instance_info::property_can_revert_func::<T>),
+        property_get_revert_func: Some(script_instance_info::property_get_revert_func::<T>),
 
         get_owner_func: None,
         get_property_state_func: Some(script_instance_info::get_property_state_func::<T>),
@@ -271,12 +365,12 @@ pub unsafe fn create_script_instance<T: ScriptInstance>(
         #[cfg(since_api = "4.3")]
         free_method_list_func: Some(script_instance_info::free_method_list_func),
         get_property_type_func: Some(script_instance_info::get_property_type_func::<T>),
-        validate_property_func: None, // not yet implemented.
+        validate_property_func: Some(script_instance_info::validate_property_func::<T>),
 
         has_method_func: Some(script_instance_info::has_method_func::<T>),
 
         call_func: Some(script_instance_info::call_func::<T>),
-        notification_func: None, // not yet implemented.
+        notification_func: Some(script_instance_info::notification_func::<T>),
 
         to_string_func: Some(script_instance_info::to_string_func::<T>),
 
@@ -1012,6 +1106,124 @@ mod script_instance_info {
         result
     }
 
+    /// # Safety
+    ///
+    /// - `p_instance` must point to a live immutable [`ScriptInstanceData<T>`] for the duration of this function call
+    /// - `p_name` must be a valid [`StringName`] pointer.
+    pub(super) unsafe extern "C" fn property_can_revert_func<T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+        p_name: sys::GDExtensionConstStringNamePtr,
+    ) -> sys::GDExtensionBool {
+        // SAFETY: `p_name` is a valid [`StringName`] pointer.
+        let name = unsafe { StringName::new_from_string_sys(p_name) };
+        let ctx = || {
+            format!(
+                "error when calling {}::property_can_revert",
+                type_name::<T>()
+            )
+        };
+
+        let can_revert = handle_panic(ctx, || {
+            // SAFETY: `p_instance` points to a live immutable `ScriptInstanceData<T>` for the duration of this call.
+            unsafe { ScriptInstanceData::<T>::borrow_script_sys(p_instance) }
+                .borrow()
+                .property_can_revert(name)
+        })
+        .unwrap_or_default();
+
+        bool_to_sys(can_revert)
+    }
+
+    /// # Safety
+    ///
+    /// - `p_instance` must point to a live immutable [`ScriptInstanceData<T>`] for the duration of this function call
+    /// - `p_name` must be a valid [`StringName`] pointer.
+    /// - It must be safe to move a `Variant` into `r_ret`.
+    pub(super) unsafe extern "C" fn property_get_revert_func<T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+        p_name: sys::GDExtensionConstStringNamePtr,
+        r_ret: sys::GDExtensionVariantPtr,
+    ) -> sys::GDExtensionBool {
+        // SAFETY: `p_name` is a valid [`StringName`] pointer.
+        let name = unsafe { StringName::new_from_string_sys(p_name) };
+        let ctx = || {
+            format!(
+                "error when calling {}::property_get_revert",
+                type_name::<T>()
+            )
+        };
+
+        let return_value = handle_panic(ctx, || {
+            // SAFETY: `p_instance` points to a live immutable `ScriptInstanceData<T>` for the duration of this call.
+            unsafe { ScriptInstanceData::<T>::borrow_script_sys(p_instance) }
+                .borrow()
+                .property_get_revert(name)
+        });
+
+        match return_value {
+            Ok(Some(variant)) => {
+                // SAFETY: It is safe to move a `Variant` into `r_ret`.
+                unsafe { variant.move_into_var_ptr(r_ret) };
+                SYS_TRUE
+            }
+            _ => SYS_FALSE,
+        }
+    }
+
+    /// # Safety
+    ///
+    /// - `p_instance` must point to a live immutable [`ScriptInstanceData<T>`] for the duration of this function call
+    /// - `p_property` must point to a valid [`sys::GDExtensionPropertyInfo`] for the duration of this function call.
+    pub(super) unsafe extern "C" fn validate_property_func<T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+        p_property: *mut sys::GDExtensionPropertyInfo,
+    ) -> sys::GDExtensionBool {
+        let ctx = || format!("error when calling {}::validate_property", type_name::<T>());
+
+        // SAFETY: `p_property` is valid for the duration of this call.
+        let mut property = unsafe { PropertyInfo::new_from_sys(p_property) };
+
+        let result = handle_panic(ctx, || {
+            // SAFETY: `p_instance` points to a live immutable `ScriptInstanceData<T>` for the duration of this call.
+            unsafe { ScriptInstanceData::<T>::borrow_script_sys(p_instance) }
+                .borrow()
+                .validate_property(&mut property);
+        });
+
+        // On panic, leave the engine's property info untouched.
+        if result.is_err() {
+            return SYS_FALSE;
+        }
+
+        // SAFETY: `p_property` remains valid and has not been consumed.
+        unsafe { property.move_into_property_info_ptr(p_property) };
+
+        SYS_TRUE
+    }
+
+    /// # Safety
+    ///
+    /// - `p_instance` must point to a live immutable [`ScriptInstanceData<T>`] for the duration of this function call
+    pub(super) unsafe extern "C" fn notification_func<T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+        p_what: i32,
+        p_reversed: sys::GDExtensionBool,
+    ) {
+        let ctx = || format!("error when calling {}::on_notification", type_name::<T>());
+        let reversed = sys::conv::bool_from_sys(p_reversed);
+
+        handle_panic(ctx, || {
+            // SAFETY: `p_instance` points to a live immutable `ScriptInstanceData<T>` for the duration of this call.
+            let instance = unsafe { ScriptInstanceData::<T>::borrow_script_sys(p_instance) };
+            let mut guard = instance.borrow_mut();
+
+            let instance_guard = SiMut::new(instance.cell_ref(), &mut guard, &instance.base);
+
+            ScriptInstance::on_notification(instance_guard, p_what, reversed);
+        })
+        .unwrap_or_default();
+    }
+
     /// # Safety
     ///
     /// - `p_instance` must point to a live immutable [`ScriptInstanceData<T>`] for the duration of this function call
@@ -0,0 +1,143 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+
+use super::{ScriptInstance, SiMut};
+use crate::builtin::{GString, StringName, Variant};
+use crate::classes::{Object, Script, ScriptLanguage};
+use crate::meta::error::CallErrorType;
+use crate::meta::{MethodInfo, PropertyInfo};
+use crate::obj::Gd;
+
+/// Ready-made script instance for scripts which cannot (or must not) run, typically in the editor.
+///
+/// Godot creates placeholder instances for non-tool scripts opened in the editor, through
+/// [`IScriptExtension::placeholder_instance_create_rawptr()`](crate::classes::IScriptExtension::placeholder_instance_create_rawptr).
+/// A placeholder does not execute any code, but exposes the script's properties to the inspector and stores their values, so that they
+/// can be edited and saved with the scene.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::classes::{ScriptExtension, ScriptLanguage};
+/// # use godot::meta::{PropertyInfo, RawPtr};
+/// # use godot::obj::script::{create_script_instance, PlaceholderScriptInstance};
+/// # fn language() -> Gd<ScriptLanguage> { unimplemented!() }
+/// # fn placeholder(script: Gd<ScriptExtension>, for_object: Gd<Object>) -> RawPtr<*mut std::ffi::c_void> {
+/// // Inside IScriptExtension::placeholder_instance_create_rawptr():
+/// let instance = PlaceholderScriptInstance::new(script.upcast(), language())
+///     .with_property(PropertyInfo::new_export::<i64>("health"), 100.to_variant());
+///
+/// unsafe { create_script_instance(instance, for_object) }
+/// # }
+/// ```
+pub struct PlaceholderScriptInstance {
+    script: Gd<Script>,
+    language: Gd<ScriptLanguage>,
+    properties: Vec<PropertyInfo>,
+    defaults: HashMap<StringName, Variant>,
+    values: HashMap<StringName, Variant>,
+}
+
+impl PlaceholderScriptInstance {
+    /// Creates a placeholder for `script` without any properties.
+    pub fn new(script: Gd<Script>, language: Gd<ScriptLanguage>) -> Self {
+        Self {
+            script,
+            language,
+            properties: Vec::new(),
+            defaults: HashMap::new(),
+            values: HashMap::new(),
+        }
+    }
+
+    /// Exposes a property with the given default value.
+    ///
+    /// The default is used as the property's initial value, and as the value to revert to in the inspector.
+    pub fn with_property(mut self, property: PropertyInfo, default_value: Variant) -> Self {
+        self.defaults
+            .insert(property.property_name.clone(), default_value);
+        self.properties.push(property);
+        self
+    }
+
+    fn is_known_property(&self, name: &StringName) -> bool {
+        self.defaults.contains_key(name)
+    }
+}
+
+impl ScriptInstance for PlaceholderScriptInstance {
+    type Base = Object;
+
+    fn class_name(&self) -> GString {
+        GString::from(&self.script.get_instance_base_type())
+    }
+
+    fn set_property(mut this: SiMut<Self>, name: StringName, value: &Variant) -> bool {
+        if !this.is_known_property(&name) {
+            return false;
+        }
+
+        this.values.insert(name, value.clone());
+        true
+    }
+
+    fn get_property(&self, name: StringName) -> Option<Variant> {
+        self.values
+            .get(&name)
+            .or_else(|| self.defaults.get(&name))
+            .cloned()
+    }
+
+    fn get_property_list(&self) -> Vec<PropertyInfo> {
+        self.properties.clone()
+    }
+
+    fn get_method_list(&self) -> Vec<MethodInfo> {
+        Vec::new()
+    }
+
+    fn call(
+        _this: SiMut<Self>,
+        _method: StringName,
+        _args: &[&Variant],
+    ) -> Result<Variant, CallErrorType> {
+        // Placeholders never run script code.
+        Err(CallErrorType::InvalidMethod)
+    }
+
+    fn is_placeholder(&self) -> bool {
+        true
+    }
+
+    fn get_script(&self) -> &Gd<Script> {
+        &self.script
+    }
+
+    fn to_string(&self) -> GString {
+        format!("<{} (placeholder)>", self.class_name()).into()
+    }
+
+    fn get_language(&self) -> Gd<ScriptLanguage> {
+        self.language.clone()
+    }
+
+    fn property_get_fallback(&self, name: StringName) -> Option<Variant> {
+        self.values.get(&name).cloned()
+    }
+
+    fn property_set_fallback(mut this: SiMut<Self>, name: StringName, value: &Variant) -> bool {
+        // Keep values of properties the script no longer declares, so they are not lost on save (like Godot's own placeholders).
+        this.values.insert(name, value.clone());
+        true
+    }
+
+    fn property_get_revert(&self, name: StringName) -> Option<Variant> {
+        self.defaults.get(&name).cloned()
+    }
+}
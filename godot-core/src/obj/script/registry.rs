@@ -0,0 +1,125 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::classes::Script;
+use crate::obj::{Gd, Inherits, InstanceId};
+use crate::sys::Global;
+
+/// Maps Godot script objects to Rust-side data, such as a parsed AST or compiled bytecode.
+///
+/// Script languages often need to get from a `Gd<Script>` (which is all that the engine hands out, e.g. in
+/// [`ScriptInstance::get_script()`](super::ScriptInstance::get_script) or in `ScriptLanguageExtension` callbacks) back to the data that
+/// was produced when loading the script. `ScriptRegistry` stores this data keyed by the script's instance ID, and can be used as a `static`.
+///
+/// Entries are handed out as `Arc<D>`, so that no lock is held while user code runs -- this allows re-entrant calls into the engine,
+/// which in turn may access the registry.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::classes::Script;
+/// use godot::obj::script::ScriptRegistry;
+///
+/// struct CompiledScript {
+///     source: String,
+///     // ...
+/// }
+///
+/// static SCRIPTS: ScriptRegistry<CompiledScript> = ScriptRegistry::new();
+///
+/// fn on_reload(script: &Gd<Script>, source: String) {
+///     SCRIPTS.insert(script, CompiledScript { source });
+/// }
+///
+/// fn source_of(script: &Gd<Script>) -> Option<String> {
+///     SCRIPTS.get(script).map(|compiled| compiled.source.clone())
+/// }
+/// ```
+pub struct ScriptRegistry<D> {
+    entries: Global<HashMap<InstanceId, Arc<D>>>,
+}
+
+impl<D: Send + Sync> ScriptRegistry<D> {
+    /// Creates an empty registry. Usable in `static` context.
+    pub const fn new() -> Self {
+        Self {
+            entries: Global::default(),
+        }
+    }
+
+    /// Associates `data` with `script`, returning the previous entry if any.
+    pub fn insert<S>(&self, script: &Gd<S>, data: D) -> Option<Arc<D>>
+    where
+        S: Inherits<Script>,
+    {
+        self.entries
+            .lock()
+            .insert(script.instance_id(), Arc::new(data))
+    }
+
+    /// Returns the data associated with `script`, if any.
+    pub fn get<S>(&self, script: &Gd<S>) -> Option<Arc<D>>
+    where
+        S: Inherits<Script>,
+    {
+        self.entries.lock().get(&script.instance_id()).cloned()
+    }
+
+    /// Returns `true` if data is associated with `script`.
+    pub fn contains<S>(&self, script: &Gd<S>) -> bool
+    where
+        S: Inherits<Script>,
+    {
+        self.entries.lock().contains_key(&script.instance_id())
+    }
+
+    /// Removes and returns the data associated with `script`.
+    ///
+    /// Call this when the script is freed, e.g. from `Drop` of your `ScriptExtension` class.
+    pub fn remove<S>(&self, script: &Gd<S>) -> Option<Arc<D>>
+    where
+        S: Inherits<Script>,
+    {
+        self.entries.lock().remove(&script.instance_id())
+    }
+
+    /// Removes all entries whose script object has been freed.
+    ///
+    /// Useful as a fallback if scripts are not reliably removed via [`remove()`](Self::remove).
+    pub fn prune_freed(&self) {
+        let removed: Vec<Arc<D>> = {
+            let mut entries = self.entries.lock();
+            let freed: Vec<InstanceId> = entries
+                .keys()
+                .copied()
+                .filter(|id| !id.lookup_validity())
+                .collect();
+
+            freed.iter().filter_map(|id| entries.remove(id)).collect()
+        };
+
+        // Drop outside the lock, in case `D::drop()` accesses the registry.
+        drop(removed);
+    }
+
+    /// Removes all entries, e.g. on language finalization.
+    pub fn clear(&self) {
+        let entries = std::mem::take(&mut *self.entries.lock());
+
+        // Drop outside the lock, in case `D::drop()` accesses the registry.
+        drop(entries);
+    }
+}
+
+impl<D: Send + Sync> Default for ScriptRegistry<D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,266 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::mem::ManuallyDrop;
+use std::sync::Arc;
+
+use crate::builtin::Callable;
+use crate::obj::{bounds, Bounds, Gd, GdMut, GdRef, GodotClass, InstanceId, ThreadSafeClass};
+use crate::storage::{InstanceStorage, Storage};
+use crate::sys;
+
+/// Handle to a user-defined object that can be sent to and shared between threads.
+///
+/// `ThreadSafeGd<T>` gives worker threads access to the **Rust part** of an object (the `T` struct), while keeping the **engine part**
+/// confined to the main thread. Use it to run game logic on worker threads, e.g. simulation, path finding or AI, operating on state
+/// stored in your `#[derive(GodotClass)]` structs.
+///
+/// The class must be declared with `#[class(thread_safe)]` and inherit `RefCounted`. The handle holds a strong reference, so the object
+/// stays alive as long as any handle exists.
+///
+/// # Concurrency model
+/// - [`bind()`][Self::bind] and [`bind_mut()`][Self::bind_mut] may be called from any thread. They go through the object's **blocking**
+///   cell: instead of panicking when the instance is already bound elsewhere, they wait until the conflicting guard is released. Standard
+///   Rust aliasing rules are upheld at all times: many `GdRef` guards, or a single `GdMut` guard.
+/// - Re-entrant binds on the _same_ thread are still detected and panic, exactly like with [`Gd`], as they would otherwise deadlock.
+/// - Cloning a handle and binding it do not call into the engine.
+/// - Engine APIs are only reachable through [`to_gd()`][Self::to_gd], which checks at runtime that it is called on the main thread.
+///   Godot's scene tree and most classes are not thread-safe; do not use the `Gd<T>` obtained on the main thread in other threads.
+///   Inside `T`, [`base()`][crate::obj::WithBaseField::base], [`base_mut()`][crate::obj::WithBaseField::base_mut] and
+///   [`to_gd()`][crate::obj::WithBaseField::to_gd] perform the same check. Other engine objects reachable from `T` are not checked.
+/// - Creating a handle must happen on the main thread. If the last handle is dropped on another thread, its reference to the engine
+///   object is released on the main thread instead, during idle time of the current frame.
+///
+/// Holding guards across long-running work blocks other threads (including the main thread, when GDScript calls into `T`), so keep
+/// guards short-lived.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::obj::ThreadSafeGd;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=RefCounted, thread_safe)]
+/// struct Simulation {
+///     steps: u64,
+/// }
+///
+/// fn run_in_background(sim: Gd<Simulation>) {
+///     let handle = ThreadSafeGd::new(sim);
+///     let mut worker_handle = handle.clone();
+///
+///     let worker = std::thread::spawn(move || {
+///         for _ in 0..100 {
+///             worker_handle.bind_mut().steps += 1;
+///         }
+///     });
+///
+///     worker.join().unwrap();
+///     godot_print!("steps: {}", handle.bind().steps);
+/// }
+/// ```
+pub struct ThreadSafeGd<T>
+where
+    T: ThreadSafeClass + Bounds<Memory = bounds::MemRefCounted>,
+{
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> ThreadSafeGd<T>
+where
+    T: ThreadSafeClass + Bounds<Memory = bounds::MemRefCounted>,
+{
+    /// Wraps `gd` in a handle that can be moved to other threads.
+    ///
+    /// # Panics
+    /// If called outside the main thread, or if the object is already destroyed.
+    #[track_caller]
+    pub fn new(gd: Gd<T>) -> Self {
+        assert_main_thread::<T>("new");
+
+        let instance_id = gd.instance_id();
+
+        // Resolved once here, so binds on other threads need no engine call. The strong reference held in `gd` keeps the storage alive.
+        let storage: *const InstanceStorage<T> = gd
+            .raw
+            .storage()
+            .expect("ThreadSafeGd::new(): instance storage not found");
+
+        Self {
+            shared: Arc::new(Shared {
+                gd: ManuallyDrop::new(gd),
+                storage,
+                instance_id,
+            }),
+        }
+    }
+
+    /// ID of the wrapped object. Available on every thread.
+    pub fn instance_id(&self) -> InstanceId {
+        self.shared.instance_id
+    }
+
+    /// Shared access to the Rust instance, from any thread.
+    ///
+    /// Blocks while another thread holds a [`bind_mut()`][Self::bind_mut] guard.
+    ///
+    /// # Panics
+    /// If the current thread already holds a conflicting guard.
+    #[track_caller]
+    pub fn bind(&self) -> GdRef<'_, T> {
+        GdRef::from_guard(self.storage().get())
+    }
+
+    /// Exclusive access to the Rust instance, from any thread.
+    ///
+    /// Blocks while another thread holds a [`bind()`][Self::bind] or `bind_mut()` guard.
+    ///
+    /// # Panics
+    /// If the current thread already holds a conflicting guard.
+    #[track_caller]
+    pub fn bind_mut(&mut self) -> GdMut<'_, T> {
+        GdMut::from_guard(self.storage().get_mut())
+    }
+
+    /// Returns the `Gd<T>` pointer, for engine API access.
+    ///
+    /// # Panics
+    /// If called outside the main thread. See [`try_to_gd()`][Self::try_to_gd] for a fallible version.
+    #[track_caller]
+    pub fn to_gd(&self) -> Gd<T> {
+        assert_main_thread::<T>("to_gd");
+        (*self.shared.gd).clone()
+    }
+
+    /// Returns the `Gd<T>` pointer if called on the main thread, otherwise `None`.
+    pub fn try_to_gd(&self) -> Option<Gd<T>> {
+        if sys::is_main_thread() {
+            Some((*self.shared.gd).clone())
+        } else {
+            None
+        }
+    }
+
+    fn storage(&self) -> &InstanceStorage<T> {
+        // SAFETY: the pointer was obtained from a live object in `new()`. `Shared` holds a strong reference to the ref-counted object, which
+        // can thus not be destroyed before the last handle is dropped.
+        unsafe { &*self.shared.storage }
+    }
+}
+
+impl<T> Clone for ThreadSafeGd<T>
+where
+    T: ThreadSafeClass + Bounds<Memory = bounds::MemRefCounted>,
+{
+    fn clone(&self) -> Self {
+        // Only the Arc is cloned; the engine reference count stays the same, so this is fine on any thread.
+        Self {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<T> fmt::Debug for ThreadSafeGd<T>
+where
+    T: ThreadSafeClass + Bounds<Memory = bounds::MemRefCounted>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Don't debug-print `Gd`, which queries the engine for the dynamic class.
+        f.debug_struct("ThreadSafeGd")
+            .field("class", &T::class_id())
+            .field("instance_id", &self.shared.instance_id)
+            .finish()
+    }
+}
+
+// SAFETY: all methods reachable from other threads (clone, instance_id, bind, bind_mut) only touch the Arc, the cached instance ID and
+// the instance storage. For `T: ThreadSafeClass`, the storage uses the blocking cell, and the derive macro verifies that all fields except
+// the base are `Send + Sync`. The engine object is only accessed on the main thread: `to_gd` checks at runtime, and dropping the last
+// handle defers the release to the main thread.
+unsafe impl<T> Send for ThreadSafeGd<T> where
+    T: ThreadSafeClass + Bounds<Memory = bounds::MemRefCounted>
+{
+}
+
+// SAFETY: see `Send` above; `bind()` only needs `&self` and is synchronized by the blocking cell.
+unsafe impl<T> Sync for ThreadSafeGd<T> where
+    T: ThreadSafeClass + Bounds<Memory = bounds::MemRefCounted>
+{
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// State shared by all clones of a `ThreadSafeGd`.
+struct Shared<T>
+where
+    T: ThreadSafeClass + Bounds<Memory = bounds::MemRefCounted>,
+{
+    /// Dropped manually, only on the main thread.
+    gd: ManuallyDrop<Gd<T>>,
+    storage: *const InstanceStorage<T>,
+    instance_id: InstanceId,
+}
+
+impl<T> Drop for Shared<T>
+where
+    T: ThreadSafeClass + Bounds<Memory = bounds::MemRefCounted>,
+{
+    fn drop(&mut self) {
+        // SAFETY: `gd` is not used after this point.
+        let gd = unsafe { ManuallyDrop::take(&mut self.gd) };
+
+        if sys::is_main_thread() {
+            drop(gd);
+        } else {
+            release_on_main_thread(gd);
+        }
+    }
+}
+
+/// Releases the reference held by `gd` during idle time on the main thread, as the reference count must not change on other threads.
+///
+/// If the engine shuts down before the deferred call runs, the reference is released when Godot destroys the pending callable.
+fn release_on_main_thread<T>(gd: Gd<T>)
+where
+    T: ThreadSafeClass + Bounds<Memory = bounds::MemRefCounted>,
+{
+    struct MainThreadGd<T: ThreadSafeClass>(Gd<T>);
+
+    // SAFETY: the `Gd` is only moved through the deferred call, which runs and drops it on the main thread.
+    unsafe impl<T: ThreadSafeClass> Send for MainThreadGd<T> {}
+
+    let gd = MainThreadGd(gd);
+    let callable = Callable::from_once_sync_fn("ThreadSafeGd::release", move |_| drop(gd));
+    callable.call_deferred(&[]);
+}
+
+/// Panics if a `#[class(thread_safe)]` object accesses the engine outside the main thread.
+///
+/// Used by `WithBaseField` methods, which are reachable from worker threads through [`ThreadSafeGd::bind_mut()`]. No-op for other classes.
+#[doc(hidden)]
+#[track_caller]
+pub fn __check_engine_access<T: GodotClass>(method: &str) {
+    if T::__IS_THREAD_SAFE {
+        assert!(
+            sys::is_main_thread(),
+            "{}::{method}() must be called on the main thread; \
+            thread_safe classes can only access the engine from the main thread",
+            std::any::type_name::<T>()
+        );
+    }
+}
+
+#[track_caller]
+fn assert_main_thread<T>(method: &str) {
+    assert!(
+        sys::is_main_thread(),
+        "ThreadSafeGd<{}>::{method}() must be called on the main thread; \
+        engine APIs are not accessible from worker threads",
+        std::any::type_name::<T>()
+    );
+}
@@ -45,6 +45,10 @@ where
     /// It must not be less than `Base::INIT_LEVEL`.
     const INIT_LEVEL: InitLevel = <Self::Base as GodotClass>::INIT_LEVEL;
 
+    /// Whether the user instance is stored in a blocking cell, so it can be bound from multiple threads. See [`ThreadSafeClass`].
+    #[doc(hidden)]
+    const __IS_THREAD_SAFE: bool = false;
+
     /// Returns whether `Self` inherits from `Base`.
     ///
     /// This is reflexive, i.e `Self` inherits from itself.
@@ -370,7 +374,8 @@ pub trait WithBaseField: GodotClass + Bounds<Declarer = bounds::DeclUser> {
     /// calling `to_gd()` is still running; that would lead to a double borrow panic.
     ///
     /// # Panics
-    /// If called during initialization (the `init()` function or `Gd::from_init_fn()`). Use [`Base::to_init_gd()`] instead.
+    /// - If called during initialization (the `init()` function or `Gd::from_init_fn()`). Use [`Base::to_init_gd()`] instead.
+    /// - For `#[class(thread_safe)]` classes, if called outside the main thread.
     #[track_caller]
     fn to_gd(&self) -> Gd<Self>;
 
@@ -431,8 +436,13 @@ pub trait WithBaseField: GodotClass + Bounds<Declarer = bounds::DeclUser> {
     /// ```
     ///
     /// For this, use [`base_mut()`](WithBaseField::base_mut()) instead.
+    ///
+    /// # Panics
+    /// For `#[class(thread_safe)]` classes, if called outside the main thread.
     #[track_caller]
     fn base(&self) -> BaseRef<'_, Self> {
+        crate::obj::thread_safe_gd::__check_engine_access::<Self>("base");
+
         // SAFETY: lifetime is bound to self through BaseRef, ensuring the object remains valid.
         let passive_gd = unsafe { self.base_field().constructed_passive() };
         BaseRef::new(passive_gd, self)
@@ -514,9 +524,14 @@ pub trait WithBaseField: GodotClass + Bounds<Declarer = bounds::DeclUser> {
     /// }
     /// # }
     /// ```
+    ///
+    /// # Panics
+    /// For `#[class(thread_safe)]` classes, if called outside the main thread.
     #[allow(clippy::let_unit_value)]
     #[track_caller]
     fn base_mut(&mut self) -> BaseMut<'_, Self> {
+        crate::obj::thread_safe_gd::__check_engine_access::<Self>("base_mut");
+
         // We need to construct this first, as the mut-borrow below will block all other access.
         // SAFETY: lifetime is re-established at the bottom BaseMut construction, since return type of this fn has lifetime bound to instance.
         let passive_gd = unsafe { self.base_field().constructed_passive() };
@@ -658,6 +673,29 @@ pub trait WithUserSignals: WithSignals + WithBaseField {
     fn signals(&mut self) -> Self::SignalCollection<'_, Self>;
 }
 
+/// Implemented for all user-defined classes, to access their `#[func]` methods in a typed way.
+///
+/// See [`TypedFunc`](crate::obj::TypedFunc) for an example.
+pub trait WithFuncs: GodotClass + Bounds<Declarer = bounds::DeclUser> {
+    /// The associated struct with one method per `#[func]` taking a receiver, each returning a [`TypedFunc`](crate::obj::TypedFunc).
+    type FuncCollection;
+
+    /// Returns the collection of `#[func]` methods.
+    ///
+    /// For a `#[func] fn my_method(&mut self, a: i32) -> bool`, `MyClass::funcs().my_method()` returns a
+    /// `TypedFunc<MyClass, (i32,), bool>`, which can be invoked with [`Gd::call_func()`].
+    fn funcs() -> Self::FuncCollection;
+}
+
+/// Marks user-defined classes whose Rust instance may be bound from multiple threads.
+///
+/// Implemented by `#[class(thread_safe)]`; required by [`ThreadSafeGd<T>`](crate::obj::ThreadSafeGd). Such classes store their instance in
+/// a blocking cell: concurrent `bind()`/`bind_mut()` calls from different threads wait for each other instead of panicking.
+///
+/// # Safety
+/// [`GodotClass::__IS_THREAD_SAFE`] must be `true`. Use the proc-macro attribute rather than implementing this trait manually.
+pub unsafe trait ThreadSafeClass: GodotClass + Bounds<Declarer = bounds::DeclUser> {}
+
 /// Extension trait for all reference-counted classes.
 pub trait NewGd: GodotClass {
     /// Return a new, ref-counted `Gd` containing a default-constructed instance.
@@ -788,6 +826,58 @@ where
     }
 }
 
+/// Preserves Rust-side state of user class instances across hot reloads.
+///
+/// When the library is hot-reloaded in the editor, Godot only carries over property values with `STORAGE` usage (such as `#[export]`
+/// fields). All other Rust fields are lost: the Rust part of each instance is re-created through `init()`.
+///
+/// Classes annotated with [`#[class(hot_reload_state)]`](../register/derive.GodotClass.html#hot-reload-state) implement this trait to save
+/// additional state before the old library is unloaded, and restore it into the freshly created instance afterwards. The state is a
+/// [`Variant`][crate::builtin::Variant], so anything convertible via [`ToGodot`][crate::meta::ToGodot] works -- e.g. a dictionary, or a
+/// `PackedByteArray` with a serde-encoded struct.
+///
+/// Restoring happens right after `init()`; properties saved by Godot are applied afterward and thus take precedence.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::obj::HotReloadState;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node, hot_reload_state)]
+/// struct Enemy {
+///     #[export]
+///     speed: f32, // Restored by Godot.
+///
+///     hit_count: i64, // Restored through HotReloadState.
+/// }
+///
+/// impl HotReloadState for Enemy {
+///     fn save_hot_reload_state(&self) -> Variant {
+///         self.hit_count.to_variant()
+///     }
+///
+///     fn restore_hot_reload_state(&mut self, state: Variant) {
+///         self.hit_count = state.to();
+///     }
+/// }
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`#[class(hot_reload_state)]` requires `{Self}` to implement `HotReloadState`",
+    label = "missing `impl HotReloadState for {Self}`"
+)]
+pub trait HotReloadState: GodotClass + Bounds<Declarer = bounds::DeclUser> {
+    /// Captures the state of this instance, before the library is unloaded.
+    ///
+    /// Returning `Variant::nil()` skips restoring for this instance.
+    fn save_hot_reload_state(&self) -> crate::builtin::Variant;
+
+    /// Applies a state previously returned by [`save_hot_reload_state()`][Self::save_hot_reload_state] to the recreated instance.
+    ///
+    /// Note that the struct layout may have changed in between, so the conversion should be tolerant against missing or extra data.
+    fn restore_hot_reload_state(&mut self, state: crate::builtin::Variant);
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 /// Capability traits, providing dedicated functionalities for Godot classes
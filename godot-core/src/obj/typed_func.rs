@@ -0,0 +1,139 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use crate::builtin::StringName;
+use crate::meta::{FromGodot, OutParamTuple};
+use crate::obj::{Gd, GodotClass};
+
+/// Typed reference to a `#[func]` method of the user-defined class `C`.
+///
+/// Obtained through [`WithFuncs::funcs()`][crate::obj::WithFuncs::funcs], and invoked with [`Gd::call_func()`] on any `Gd` whose dynamic
+/// class is `C` (or derived from it), for example a `Gd<Object>` or `Gd<Node>`. Compared to [`Object::call()`][crate::classes::Object::call],
+/// this is checked at compile time and skips the `Variant` conversions of arguments and return value, as well as the method lookup by name:
+/// the Rust method is invoked directly.
+///
+/// If a script attached to the object defines a method with the same name, the call is dispatched to the script instead (Godot 4.3+),
+/// like `Object::call()` would do. The same applies to `#[func(virtual)]` methods overridden in scripts.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Player {
+///     health: i64,
+/// }
+///
+/// #[godot_api]
+/// impl Player {
+///     #[func]
+///     fn take_damage(&mut self, amount: i64) -> i64 {
+///         self.health -= amount;
+///         self.health
+///     }
+/// }
+///
+/// fn hit(mut target: Gd<Node>) {
+///     let health = target.call_func(Player::funcs().take_damage(), (10,));
+///     godot_print!("remaining health: {health}");
+/// }
+/// ```
+pub struct TypedFunc<C: GodotClass, Params, Ret> {
+    godot_name: &'static StringName,
+    godot_name_str: &'static str,
+    #[cfg_attr(before_api = "4.3", allow(dead_code))] // Only needed for script dispatch.
+    class_name_str: &'static str,
+    #[cfg_attr(before_api = "4.3", allow(dead_code))]
+    is_script_virtual: bool,
+    rust_fn: fn(&mut Gd<C>, Params) -> Ret,
+}
+
+impl<C: GodotClass, Params, Ret> TypedFunc<C, Params, Ret> {
+    #[doc(hidden)]
+    pub fn __new(
+        godot_name: &'static StringName,
+        godot_name_str: &'static str,
+        class_name_str: &'static str,
+        is_script_virtual: bool,
+        rust_fn: fn(&mut Gd<C>, Params) -> Ret,
+    ) -> Self {
+        Self {
+            godot_name,
+            godot_name_str,
+            class_name_str,
+            is_script_virtual,
+            rust_fn,
+        }
+    }
+
+    /// Name under which the method is registered in Godot.
+    pub fn godot_name(&self) -> &'static StringName {
+        self.godot_name
+    }
+
+    /// Calls the method on `object`. See [`Gd::call_func()`].
+    pub(crate) fn call_on<T: GodotClass>(&self, object: &Gd<T>, args: Params) -> Ret
+    where
+        Params: OutParamTuple,
+        Ret: FromGodot,
+    {
+        let Ok(cast) = object.raw.ffi_cast::<C>() else {
+            panic!(
+                "call_func(): method `{}` belongs to class {}, but called on object of class {}",
+                self.godot_name_str,
+                C::class_id(),
+                object.dynamic_class_string(),
+            )
+        };
+
+        // Script-virtual methods dispatch to the script themselves.
+        #[cfg(since_api = "4.3")]
+        if !self.is_script_virtual {
+            let object_ptr = object.raw.obj_sys();
+            let method_sname_ptr = self.godot_name.string_sys();
+
+            // SAFETY: `object_ptr` is alive, as verified by the cast.
+            if unsafe { crate::private::has_virtual_script_method(object_ptr, method_sname_ptr) } {
+                // SAFETY: the script has a method with this name; argument and return types are checked by Godot's varcall.
+                return unsafe {
+                    crate::meta::Signature::<Params, Ret>::out_script_virtual_call(
+                        self.class_name_str,
+                        self.godot_name_str,
+                        method_sname_ptr,
+                        object_ptr,
+                        args,
+                    )
+                };
+            }
+        }
+
+        // SAFETY: `object` keeps the instance alive for the duration of the call.
+        let mut instance = unsafe { cast.into_passive_dest() };
+        (self.rust_fn)(&mut instance, args)
+    }
+}
+
+impl<C: GodotClass, Params, Ret> Clone for TypedFunc<C, Params, Ret> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: GodotClass, Params, Ret> Copy for TypedFunc<C, Params, Ret> {}
+
+impl<C: GodotClass, Params, Ret> fmt::Debug for TypedFunc<C, Params, Ret> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "TypedFunc({}::{})",
+            self.class_name_str, self.godot_name_str
+        )
+    }
+}
@@ -30,6 +30,7 @@ mod reexport_pub {
     #[cfg(feature = "trace")]
     pub use crate::meta::trace;
     pub use crate::obj::rtti::ObjectRtti;
+    pub use crate::obj::thread_safe_gd::__check_engine_access;
     pub use crate::registry::callbacks;
     pub use crate::registry::plugin::{
         ClassPlugin, DynTraitImpl, ErasedDynGd, ErasedRegisterFn, ITraitImpl, InherentImpl,
@@ -0,0 +1,751 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! C# wrapper classes, for calling Rust classes from Godot's .NET build without string-based `GodotObject.Call()`.
+//!
+//! Each Rust class becomes a `partial class` that holds the Godot object as `Instance` and forwards typed members to it. Wrappers of
+//! Rust classes inheriting other Rust classes derive from the wrapper of the base class.
+
+use std::fmt::Write as _;
+
+use super::model::{Argument, Class, Enum, ExtensionApi, Method, Property, Signal};
+
+pub(crate) fn to_csharp(api: &ExtensionApi, namespace: &str) -> String {
+    let mut out = String::new();
+    out.push_str(
+        "// <auto-generated>\n\
+         // Generated by godot-rust from the classes registered by the extension. Do not edit manually.\n\
+         // </auto-generated>\n\n\
+         #nullable enable\n\n\
+         using System;\n\
+         using Godot;\n\n",
+    );
+    writeln!(out, "namespace {namespace};").unwrap();
+
+    for class in &api.classes {
+        out.push('\n');
+        ClassWriter {
+            api,
+            class,
+            out: &mut out,
+        }
+        .write();
+    }
+
+    out
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Classes
+
+struct ClassWriter<'a> {
+    api: &'a ExtensionApi,
+    class: &'a Class,
+    out: &'a mut String,
+}
+
+impl ClassWriter<'_> {
+    fn write(mut self) {
+        let class = self.class;
+        let name = &class.name;
+        let parent = self.api.find_class(&class.inherits);
+
+        let base_clause = parent.map_or(String::new(), |parent| format!(" : {}", parent.name));
+        let hides = if parent.is_some() { "new " } else { "" };
+
+        self.line(
+            0,
+            &format!("/// <summary>Typed wrapper for the Rust class <c>{name}</c>.</summary>"),
+        );
+        self.line(0, &format!("public partial class {name}{base_clause}"));
+        self.line(0, "{");
+        self.line(
+            1,
+            &format!("public static {hides}readonly StringName NativeName = \"{name}\";"),
+        );
+        self.blank();
+
+        self.write_construction(parent.is_some());
+
+        for enum_ in &class.enums {
+            self.write_enum(enum_);
+        }
+
+        if !class.constants.is_empty() {
+            for constant in &class.constants {
+                let const_name = to_pascal_case(&constant.name);
+                self.line(
+                    1,
+                    &format!("public const long {const_name} = {};", constant.value),
+                );
+            }
+            self.blank();
+        }
+
+        for property in &class.properties {
+            self.write_property(property);
+        }
+
+        for signal in &class.signals {
+            self.write_signal(signal);
+        }
+
+        for method in &class.methods {
+            self.write_method(method);
+        }
+
+        let methods = class.methods.iter().map(|m| &m.name);
+        let properties = class.properties.iter().map(|p| &p.name);
+        let signals = class.signals.iter().map(|s| &s.name);
+        self.write_name_table("MethodName", methods);
+        self.write_name_table("PropertyName", properties);
+        self.write_name_table("SignalName", signals);
+
+        // Drop blank line before the closing brace.
+        if self.out.ends_with("\n\n") {
+            self.out.pop();
+        }
+        self.line(0, "}");
+    }
+
+    fn write_construction(&mut self, has_wrapper_parent: bool) {
+        let class = self.class;
+        let name = &class.name;
+
+        if !has_wrapper_parent {
+            self.line(1, "/// <summary>The Godot object being wrapped.</summary>");
+            self.line(1, "public GodotObject Instance { get; }");
+            self.blank();
+        }
+
+        self.line(1, &format!("public {name}(GodotObject instance)"));
+        if has_wrapper_parent {
+            self.line(2, ": base(instance)");
+            self.line(1, "{");
+        } else {
+            self.line(1, "{");
+            self.line(2, "ArgumentNullException.ThrowIfNull(instance);");
+        }
+        self.line(2, "if (!instance.IsClass(NativeName))");
+        self.line(3, "throw new ArgumentException($\"Expected {NativeName}, got {instance.GetClass()}.\", nameof(instance));");
+        if !has_wrapper_parent {
+            self.blank();
+            self.line(2, "Instance = instance;");
+        }
+        self.line(1, "}");
+        self.blank();
+
+        if class.is_instantiable {
+            let hides = if self.any_ancestor(|class| class.is_instantiable) {
+                "new "
+            } else {
+                ""
+            };
+
+            self.line(1, &format!("public static {hides}{name} Instantiate()"));
+            self.line(
+                2,
+                "=> new(ClassDB.Instantiate(NativeName).AsGodotObject());",
+            );
+            self.blank();
+        }
+    }
+
+    fn write_enum(&mut self, enum_: &Enum) {
+        if enum_.is_bitfield {
+            self.line(1, "[Flags]");
+        }
+        self.line(1, &format!("public enum {} : long", enum_.name));
+        self.line(1, "{");
+
+        let value_names = enum_value_names(enum_);
+        for (value, value_name) in enum_.values.iter().zip(value_names) {
+            self.line(2, &format!("{value_name} = {},", value.value));
+        }
+
+        self.line(1, "}");
+        self.blank();
+    }
+
+    fn write_property(&mut self, property: &Property) {
+        let ty = self.cs_type(&property.ty);
+        let name = to_pascal_case(&property.name);
+        let key = format!("PropertyName.{name}");
+
+        // Before Godot 4.4, accessors are unknown; assume the property is writable.
+        let is_read_only = property.getter.is_some() && property.setter.is_none();
+
+        self.line(1, &format!("public {} {name}", ty.declaration()));
+        self.line(1, "{");
+        self.line(
+            2,
+            &format!(
+                "get => {};",
+                ty.variant_to_cs(&format!("Instance.Get({key})"))
+            ),
+        );
+        if !is_read_only {
+            self.line(
+                2,
+                &format!("set => Instance.Set({key}, {});", ty.cs_to_variant("value")),
+            );
+        }
+        self.line(1, "}");
+        self.blank();
+    }
+
+    fn write_signal(&mut self, signal: &Signal) {
+        let name = to_pascal_case(&signal.name);
+
+        // Callable.From() supports delegates with up to 5 parameters.
+        if signal.arguments.len() > 5 {
+            self.line(1, &format!("// Signal `{}` has more than 5 parameters; connect via Instance.Connect(SignalName.{name}, ...).", signal.name));
+            self.blank();
+            return;
+        }
+
+        // Signal parameters are marshalled by Godot, so wrappers are passed as their engine base class.
+        let param_types: Vec<String> = signal
+            .arguments
+            .iter()
+            .map(|arg| self.cs_variant_type(&arg.ty).name)
+            .collect();
+
+        let delegate = if param_types.is_empty() {
+            "Action".to_string()
+        } else {
+            format!("Action<{}>", param_types.join(", "))
+        };
+
+        let key = format!("SignalName.{name}");
+        self.line(1, &format!("public event {delegate} {name}"));
+        self.line(1, "{");
+        self.line(
+            2,
+            &format!("add => Instance.Connect({key}, Callable.From(value));"),
+        );
+        self.line(
+            2,
+            &format!("remove => Instance.Disconnect({key}, Callable.From(value));"),
+        );
+        self.line(1, "}");
+        self.blank();
+    }
+
+    fn write_method(&mut self, method: &Method) {
+        let name = to_pascal_case(&method.name);
+        let return_ty = method.return_type.as_deref().map(|ty| self.cs_type(ty));
+
+        let args: Vec<(String, CsType)> = method
+            .arguments
+            .iter()
+            .map(|arg| (to_camel_case(&arg.name), self.cs_type(&arg.ty)))
+            .collect();
+
+        let defaults = self.default_literals(&method.arguments, &args);
+
+        let mut params: Vec<String> = args
+            .iter()
+            .zip(&defaults)
+            .map(|((arg_name, ty), default)| match default {
+                // Engine objects are non-nullable in Godot's C# API, unless they default to null.
+                Some(default) if ty.kind == Kind::EngineObject && default == "null" => {
+                    format!("{}? {arg_name} = null", ty.name)
+                }
+                Some(default) => format!("{} {arg_name} = {default}", ty.declaration()),
+                None => format!("{} {arg_name}", ty.declaration()),
+            })
+            .collect();
+
+        let mut call_args: Vec<String> = args
+            .iter()
+            .map(|(arg_name, ty)| ty.cs_to_variant(arg_name))
+            .collect();
+
+        if method.is_vararg {
+            params.push("params Variant[] varargs".to_string());
+        }
+
+        let call_args = match (method.is_vararg, call_args.is_empty()) {
+            (true, _) => {
+                call_args.push(".. varargs".to_string());
+                format!(", [{}]", call_args.join(", "))
+            }
+            (false, true) => String::new(),
+            (false, false) => format!(", {}", call_args.join(", ")),
+        };
+
+        let call = if method.is_static {
+            format!("ClassDB.ClassCallStatic(NativeName, MethodName.{name}{call_args})")
+        } else {
+            format!("Instance.Call(MethodName.{name}{call_args})")
+        };
+
+        let (return_decl, body) = match &return_ty {
+            Some(ty) => (ty.declaration(), ty.variant_to_cs(&call)),
+            None => ("void".to_string(), call),
+        };
+
+        let qualifiers = if method.is_static { "static " } else { "" };
+        let params = params.join(", ");
+
+        self.line(
+            1,
+            &format!("public {qualifiers}{return_decl} {name}({params})"),
+        );
+        self.line(2, &format!("=> {body};"));
+        self.blank();
+    }
+
+    fn write_name_table<'n>(&mut self, table: &str, names: impl Iterator<Item = &'n String>) {
+        let names: Vec<&String> = names.collect();
+        if names.is_empty() {
+            return;
+        }
+
+        let hides = if self.any_ancestor(|class| Self::has_name_table(class, table)) {
+            "new "
+        } else {
+            ""
+        };
+
+        self.line(1, &format!("public {hides}static class {table}"));
+        self.line(1, "{");
+        for name in names {
+            let member = to_pascal_case(name);
+            self.line(
+                2,
+                &format!("public static readonly StringName {member} = \"{name}\";"),
+            );
+        }
+        self.line(1, "}");
+        self.blank();
+    }
+
+    fn has_name_table(class: &Class, table: &str) -> bool {
+        match table {
+            "MethodName" => !class.methods.is_empty(),
+            "PropertyName" => !class.properties.is_empty(),
+            _ => !class.signals.is_empty(),
+        }
+    }
+
+    /// Whether any base class that is also a Rust class (and thus has a wrapper) satisfies `predicate`.
+    fn any_ancestor(&self, predicate: impl Fn(&Class) -> bool) -> bool {
+        let mut current = self.api.find_class(&self.class.inherits);
+        while let Some(class) = current {
+            if predicate(class) {
+                return true;
+            }
+            current = self.api.find_class(&class.inherits);
+        }
+
+        false
+    }
+
+    /// C# default values for `arguments`. C# requires all optional parameters to be trailing, so a default that has no C# constant
+    /// representation also drops the defaults of all preceding parameters.
+    fn default_literals(
+        &self,
+        arguments: &[Argument],
+        args: &[(String, CsType)],
+    ) -> Vec<Option<String>> {
+        let mut defaults = vec![None; arguments.len()];
+
+        for (i, (arg, (_, ty))) in arguments.iter().zip(args).enumerate().rev() {
+            let Some(literal) = arg
+                .default_value
+                .as_deref()
+                .and_then(|value| ty.default_literal(value))
+            else {
+                break;
+            };
+            defaults[i] = Some(literal);
+        }
+
+        defaults
+    }
+
+    fn line(&mut self, indent: usize, text: &str) {
+        for _ in 0..indent {
+            self.out.push_str("    ");
+        }
+        self.out.push_str(text);
+        self.out.push('\n');
+    }
+
+    fn blank(&mut self) {
+        self.out.push('\n');
+    }
+
+    // ------------------------------------------------------------------------------------------------------------------------------------------
+    // Types
+
+    fn cs_type(&self, godot_ty: &str) -> CsType {
+        if let Some(enum_path) = godot_ty
+            .strip_prefix("enum::")
+            .or_else(|| godot_ty.strip_prefix("bitfield::"))
+        {
+            return self.cs_enum_type(enum_path);
+        }
+
+        if let Some(elem) = godot_ty.strip_prefix("typedarray::") {
+            let elem = self.cs_variant_type(elem).name;
+            return CsType::new(format!("Godot.Collections.Array<{elem}>"), Kind::Other);
+        }
+
+        if let Some(key_value) = godot_ty.strip_prefix("typeddictionary::") {
+            let (key, value) = key_value.split_once(';').unwrap_or((key_value, "Variant"));
+            let key = self.cs_variant_type(key).name;
+            let value = self.cs_variant_type(value).name;
+            return CsType::new(
+                format!("Godot.Collections.Dictionary<{key}, {value}>"),
+                Kind::Other,
+            );
+        }
+
+        if self.api.find_class(godot_ty).is_some() {
+            return CsType::new(godot_ty.to_string(), Kind::Wrapper);
+        }
+
+        match builtin_type(godot_ty) {
+            Some((name, kind)) => CsType::new(name.to_string(), kind),
+            None => CsType::new(engine_class_name(godot_ty), Kind::EngineObject),
+        }
+    }
+
+    /// Like [`Self::cs_type()`], but replaces wrappers with their engine base class, for positions that Godot marshals itself.
+    fn cs_variant_type(&self, godot_ty: &str) -> CsType {
+        let mut ty = godot_ty;
+        while let Some(class) = self.api.find_class(ty) {
+            ty = &class.inherits;
+        }
+
+        self.cs_type(ty)
+    }
+
+    fn cs_enum_type(&self, enum_path: &str) -> CsType {
+        match enum_path.split_once('.') {
+            // Global enums have the same names in C#.
+            None => CsType::new(enum_path.to_string(), Kind::Enum),
+            Some(("Variant", _)) => CsType::new(enum_path.to_string(), Kind::Enum),
+            Some((class, _)) if self.api.find_class(class).is_some() => {
+                CsType::new(enum_path.to_string(), Kind::Enum)
+            }
+
+            // C# renames some enums nested in engine classes (e.g. `Node.ProcessModeEnum`), so fall back to the underlying integer.
+            Some(_) => CsType::new("long".to_string(), Kind::Int),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// C# types
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum Kind {
+    Bool,
+    Int,
+    Float,
+    String,
+    Enum,
+    Variant,
+    EngineObject,
+    Wrapper,
+    Other,
+}
+
+struct CsType {
+    name: String,
+    kind: Kind,
+}
+
+impl CsType {
+    fn new(name: String, kind: Kind) -> Self {
+        Self { name, kind }
+    }
+
+    /// Type as written in declarations; wrappers are nullable, since they cannot wrap a null object.
+    fn declaration(&self) -> String {
+        match self.kind {
+            Kind::Wrapper => format!("{}?", self.name),
+            _ => self.name.clone(),
+        }
+    }
+
+    fn cs_to_variant(&self, expr: &str) -> String {
+        match self.kind {
+            Kind::Wrapper => format!("Variant.From({expr}?.Instance)"),
+            _ => format!("Variant.From({expr})"),
+        }
+    }
+
+    fn variant_to_cs(&self, expr: &str) -> String {
+        match self.kind {
+            Kind::Wrapper => format!(
+                "{expr}.AsGodotObject() is {{ }} __obj ? new {}(__obj) : null",
+                self.name
+            ),
+            _ => format!("{expr}.As<{}>()", self.name),
+        }
+    }
+
+    /// Converts a `var_to_str()` default value to a C# constant, if possible.
+    fn default_literal(&self, value: &str) -> Option<String> {
+        let literal = match self.kind {
+            Kind::Bool if matches!(value, "true" | "false") => value.to_string(),
+            Kind::Int => value.parse::<i64>().ok()?.to_string(),
+            Kind::Float if value.parse::<f64>().ok()?.is_finite() => value.to_string(),
+            // Escape sequences of var_to_str() are a subset of C#'s.
+            Kind::String if value.starts_with('"') && value.ends_with('"') => value.to_string(),
+            Kind::Enum => format!("({}){}", self.name, value.parse::<i64>().ok()?),
+            Kind::Variant if value == "null" => "default".to_string(),
+            Kind::EngineObject | Kind::Wrapper if value == "null" => "null".to_string(),
+            _ => return None,
+        };
+
+        Some(literal)
+    }
+}
+
+fn builtin_type(godot_ty: &str) -> Option<(&str, Kind)> {
+    let mapped = match godot_ty {
+        "bool" => ("bool", Kind::Bool),
+        "int" => ("long", Kind::Int),
+        "float" => ("double", Kind::Float),
+        "String" => ("string", Kind::String),
+        "Variant" => ("Variant", Kind::Variant),
+        "Object" => ("GodotObject", Kind::EngineObject),
+
+        "Vector2i" => ("Vector2I", Kind::Other),
+        "Vector3i" => ("Vector3I", Kind::Other),
+        "Vector4i" => ("Vector4I", Kind::Other),
+        "Rect2i" => ("Rect2I", Kind::Other),
+        "AABB" => ("Aabb", Kind::Other),
+        "RID" => ("Rid", Kind::Other),
+        "Array" => ("Godot.Collections.Array", Kind::Other),
+        "Dictionary" => ("Godot.Collections.Dictionary", Kind::Other),
+
+        "PackedByteArray" => ("byte[]", Kind::Other),
+        "PackedInt32Array" => ("int[]", Kind::Other),
+        "PackedInt64Array" => ("long[]", Kind::Other),
+        "PackedFloat32Array" => ("float[]", Kind::Other),
+        "PackedFloat64Array" => ("double[]", Kind::Other),
+        "PackedStringArray" => ("string[]", Kind::Other),
+        "PackedVector2Array" => ("Vector2[]", Kind::Other),
+        "PackedVector3Array" => ("Vector3[]", Kind::Other),
+        "PackedVector4Array" => ("Vector4[]", Kind::Other),
+        "PackedColorArray" => ("Color[]", Kind::Other),
+
+        "StringName" | "NodePath" | "Vector2" | "Vector3" | "Vector4" | "Rect2" | "Transform2D"
+        | "Transform3D" | "Plane" | "Quaternion" | "Basis" | "Projection" | "Color"
+        | "Callable" | "Signal" => (godot_ty, Kind::Other),
+
+        _ => return None,
+    };
+
+    Some(mapped)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Naming
+
+/// Engine class name as exposed in Godot's C# bindings, e.g. `HTTPRequest` -> `HttpRequest`, `Object` -> `GodotObject`.
+///
+/// Acronyms of 3+ letters are capitalized as words; 2-letter ones (`OS`, `XRServer`) stay upper-case.
+fn engine_class_name(class_name: &str) -> String {
+    if class_name == "Object" {
+        return "GodotObject".to_string();
+    }
+
+    let chars: Vec<char> = class_name.chars().collect();
+    let mut out = String::with_capacity(class_name.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        if !chars[i].is_ascii_uppercase() {
+            out.push(chars[i]);
+            i += 1;
+            continue;
+        }
+
+        let run_end = (i..chars.len())
+            .find(|&j| !chars[j].is_ascii_uppercase())
+            .unwrap_or(chars.len());
+
+        // An upper-case letter followed by lower-case starts the next word, e.g. `R` in `HTTPRequest`.
+        let acronym_end = if run_end < chars.len() && chars[run_end].is_ascii_lowercase() {
+            run_end - 1
+        } else {
+            run_end
+        };
+
+        let acronym = &chars[i..acronym_end];
+        if acronym.len() > 2 {
+            out.push(acronym[0]);
+            out.extend(acronym[1..].iter().map(|c| c.to_ascii_lowercase()));
+        } else {
+            out.extend(acronym);
+        }
+
+        out.extend(&chars[acronym_end..run_end]);
+        i = run_end;
+    }
+
+    out
+}
+
+/// `snake_case` or `UPPER_CASE` to `PascalCase`. Leading underscores are kept, as in Godot's C# bindings (`_Ready`).
+fn to_pascal_case(name: &str) -> String {
+    let trimmed = name.trim_start_matches('_');
+    let mut out = "_".repeat(name.len() - trimmed.len());
+
+    for word in trimmed.split('_').filter(|word| !word.is_empty()) {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            out.push(first.to_ascii_uppercase());
+            out.extend(chars.map(|c| c.to_ascii_lowercase()));
+        }
+    }
+
+    out
+}
+
+fn to_camel_case(name: &str) -> String {
+    let pascal = to_pascal_case(name.trim_start_matches('_'));
+    let mut chars = pascal.chars();
+
+    let camel = match chars.next() {
+        Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+        None => "arg".to_string(),
+    };
+
+    if CSHARP_KEYWORDS.contains(&camel.as_str()) {
+        format!("@{camel}")
+    } else {
+        camel
+    }
+}
+
+/// Names of enumerators, with the prefix shared by all of them removed (`MODE_IDLE`, `MODE_RUN` -> `Idle`, `Run`), like Godot's C#
+/// bindings do.
+fn enum_value_names(enum_: &Enum) -> Vec<String> {
+    let words: Vec<Vec<&str>> = enum_
+        .values
+        .iter()
+        .map(|value| value.name.split('_').collect())
+        .collect();
+
+    let prefix_len = match words.split_first() {
+        Some((first, rest)) if !rest.is_empty() => rest.iter().fold(first.len(), |len, other| {
+            first
+                .iter()
+                .zip(other)
+                .take(len)
+                .take_while(|(a, b)| a == b)
+                .count()
+        }),
+        _ => 0,
+    };
+
+    words
+        .iter()
+        .map(|words| {
+            // Keep one word of the prefix if the name would otherwise be empty or start with a digit.
+            let mut start = prefix_len.min(words.len());
+            let starts_badly = words
+                .get(start)
+                .is_none_or(|word| word.starts_with(|c: char| c.is_ascii_digit()));
+
+            if starts_badly {
+                start = start.saturating_sub(1);
+            }
+
+            to_pascal_case(&words[start..].join("_"))
+        })
+        .collect()
+}
+
+const CSHARP_KEYWORDS: &[&str] = &[
+    "abstract",
+    "as",
+    "base",
+    "bool",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "checked",
+    "class",
+    "const",
+    "continue",
+    "decimal",
+    "default",
+    "delegate",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "event",
+    "explicit",
+    "extern",
+    "false",
+    "finally",
+    "fixed",
+    "float",
+    "for",
+    "foreach",
+    "goto",
+    "if",
+    "implicit",
+    "in",
+    "int",
+    "interface",
+    "internal",
+    "is",
+    "lock",
+    "long",
+    "namespace",
+    "new",
+    "null",
+    "object",
+    "operator",
+    "out",
+    "override",
+    "params",
+    "private",
+    "protected",
+    "public",
+    "readonly",
+    "ref",
+    "return",
+    "sbyte",
+    "sealed",
+    "short",
+    "sizeof",
+    "stackalloc",
+    "static",
+    "string",
+    "struct",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "uint",
+    "ulong",
+    "unchecked",
+    "unsafe",
+    "ushort",
+    "using",
+    "virtual",
+    "void",
+    "volatile",
+    "while",
+];
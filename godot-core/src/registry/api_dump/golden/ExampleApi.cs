@@ -0,0 +1,180 @@
+// <auto-generated>
+// Generated by godot-rust from the classes registered by the extension. Do not edit manually.
+// </auto-generated>
+
+#nullable enable
+
+using System;
+using Godot;
+
+namespace Game.Rust;
+
+/// <summary>Typed wrapper for the Rust class <c>Creature</c>.</summary>
+public partial class Creature
+{
+    public static readonly StringName NativeName = "Creature";
+
+    /// <summary>The Godot object being wrapped.</summary>
+    public GodotObject Instance { get; }
+
+    public Creature(GodotObject instance)
+    {
+        ArgumentNullException.ThrowIfNull(instance);
+        if (!instance.IsClass(NativeName))
+            throw new ArgumentException($"Expected {NativeName}, got {instance.GetClass()}.", nameof(instance));
+
+        Instance = instance;
+    }
+
+    public static Creature Instantiate()
+        => new(ClassDB.Instantiate(NativeName).AsGodotObject());
+
+    public enum Mood : long
+    {
+        Calm = 0,
+        Angry = 1,
+        Mood2Faced = 2,
+    }
+
+    [Flags]
+    public enum Abilities : long
+    {
+        Swim = 1,
+        Fly = 2,
+    }
+
+    public const long MaxHealth = 100;
+
+    public long Health
+    {
+        get => Instance.Get(PropertyName.Health).As<long>();
+        set => Instance.Set(PropertyName.Health, Variant.From(value));
+    }
+
+    public string Name
+    {
+        get => Instance.Get(PropertyName.Name).As<string>();
+    }
+
+    public string[] Tags
+    {
+        get => Instance.Get(PropertyName.Tags).As<string[]>();
+        set => Instance.Set(PropertyName.Tags, Variant.From(value));
+    }
+
+    public event Action Died
+    {
+        add => Instance.Connect(SignalName.Died, Callable.From(value));
+        remove => Instance.Disconnect(SignalName.Died, Callable.From(value));
+    }
+
+    public event Action<long, RefCounted> HealthChanged
+    {
+        add => Instance.Connect(SignalName.HealthChanged, Callable.From(value));
+        remove => Instance.Disconnect(SignalName.HealthChanged, Callable.From(value));
+    }
+
+    public long GetHealth()
+        => Instance.Call(MethodName.GetHealth).As<long>();
+
+    public void SetHealth(long health)
+        => Instance.Call(MethodName.SetHealth, Variant.From(health));
+
+    public string GetName()
+        => Instance.Call(MethodName.GetName).As<string>();
+
+    public string Greet(Creature? other, long times = 3, bool loud = false)
+        => Instance.Call(MethodName.Greet, Variant.From(other?.Instance), Variant.From(times), Variant.From(loud)).As<string>();
+
+    public void Paint(string label, Color color, double strength = 0.5)
+        => Instance.Call(MethodName.Paint, Variant.From(label), Variant.From(color), Variant.From(strength));
+
+    public void SetMood(Creature.Mood mood = (Creature.Mood)1)
+        => Instance.Call(MethodName.SetMood, Variant.From(mood));
+
+    public void SetProcessMode(long mode)
+        => Instance.Call(MethodName.SetProcessMode, Variant.From(mode));
+
+    public Error GetError(Creature.Abilities abilities)
+        => Instance.Call(MethodName.GetError, Variant.From(abilities)).As<Error>();
+
+    public Godot.Collections.Array<RefCounted> GetOffspring()
+        => Instance.Call(MethodName.GetOffspring).As<Godot.Collections.Array<RefCounted>>();
+
+    public static Creature? Spawn(Godot.Collections.Dictionary @params)
+        => ClassDB.ClassCallStatic(NativeName, MethodName.Spawn, Variant.From(@params)).AsGodotObject() is { } __obj ? new Creature(__obj) : null;
+
+    public void Log(StringName message, params Variant[] varargs)
+        => Instance.Call(MethodName.Log, [Variant.From(message), .. varargs]);
+
+    public static class MethodName
+    {
+        public static readonly StringName GetHealth = "get_health";
+        public static readonly StringName SetHealth = "set_health";
+        public static readonly StringName GetName = "get_name";
+        public static readonly StringName Greet = "greet";
+        public static readonly StringName Paint = "paint";
+        public static readonly StringName SetMood = "set_mood";
+        public static readonly StringName SetProcessMode = "set_process_mode";
+        public static readonly StringName GetError = "get_error";
+        public static readonly StringName GetOffspring = "get_offspring";
+        public static readonly StringName Spawn = "spawn";
+        public static readonly StringName Log = "log";
+    }
+
+    public static class PropertyName
+    {
+        public static readonly StringName Health = "health";
+        public static readonly StringName Name = "name";
+        public static readonly StringName Tags = "tags";
+    }
+
+    public static class SignalName
+    {
+        public static readonly StringName Died = "died";
+        public static readonly StringName HealthChanged = "health_changed";
+    }
+}
+
+/// <summary>Typed wrapper for the Rust class <c>Dragon</c>.</summary>
+public partial class Dragon : Creature
+{
+    public static new readonly StringName NativeName = "Dragon";
+
+    public Dragon(GodotObject instance)
+        : base(instance)
+    {
+        if (!instance.IsClass(NativeName))
+            throw new ArgumentException($"Expected {NativeName}, got {instance.GetClass()}.", nameof(instance));
+    }
+
+    public static new Dragon Instantiate()
+        => new(ClassDB.Instantiate(NativeName).AsGodotObject());
+
+    public event Action<Vector3I> Landed
+    {
+        add => Instance.Connect(SignalName.Landed, Callable.From(value));
+        remove => Instance.Disconnect(SignalName.Landed, Callable.From(value));
+    }
+
+    public bool BreatheFire(Node3D? target = null)
+        => Instance.Call(MethodName.BreatheFire, Variant.From(target)).As<bool>();
+
+    public HttpRequest GetRequest()
+        => Instance.Call(MethodName.GetRequest).As<HttpRequest>();
+
+    public StringName GetOsName(OS os)
+        => Instance.Call(MethodName.GetOsName, Variant.From(os)).As<StringName>();
+
+    public new static class MethodName
+    {
+        public static readonly StringName BreatheFire = "breathe_fire";
+        public static readonly StringName GetRequest = "get_request";
+        public static readonly StringName GetOsName = "get_os_name";
+    }
+
+    public new static class SignalName
+    {
+        public static readonly StringName Landed = "landed";
+    }
+}
@@ -0,0 +1,243 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Output in the schema of `extension_api.json`.
+
+use std::fmt::Write as _;
+
+use super::model::{Argument, Class, Constant, ExtensionApi, Method, Property, Signal};
+
+pub(crate) fn to_json(api: &ExtensionApi) -> String {
+    let header = &api.header;
+    let header = Json::object([
+        ("version_major", Json::Int(header.version_major)),
+        ("version_minor", Json::Int(header.version_minor)),
+        ("version_patch", Json::Int(header.version_patch)),
+        ("version_status", Json::string(&header.version_status)),
+        ("version_build", Json::string(&header.version_build)),
+        ("version_full_name", Json::string(&header.version_full_name)),
+        ("precision", Json::string(header.precision)),
+    ]);
+
+    let classes = api.classes.iter().map(class_to_json).collect();
+    let root = Json::object([("header", header), ("classes", Json::Array(classes))]);
+
+    let mut out = String::new();
+    root.write(&mut out, 0);
+    out.push('\n');
+    out
+}
+
+fn class_to_json(class: &Class) -> Json {
+    let mut fields = vec![
+        ("name", Json::string(&class.name)),
+        ("is_refcounted", Json::Bool(class.is_refcounted)),
+        ("is_instantiable", Json::Bool(class.is_instantiable)),
+        ("inherits", Json::string(&class.inherits)),
+        ("api_type", Json::string(class.api_type)),
+    ];
+
+    let enums = class.enums.iter().map(|enum_| {
+        Json::object([
+            ("name", Json::string(&enum_.name)),
+            ("is_bitfield", Json::Bool(enum_.is_bitfield)),
+            (
+                "values",
+                Json::Array(enum_.values.iter().map(constant_to_json).collect()),
+            ),
+        ])
+    });
+
+    // Like in extension_api.json, empty sections are left out.
+    push_non_empty(&mut fields, "enums", enums);
+    push_non_empty(
+        &mut fields,
+        "constants",
+        class.constants.iter().map(constant_to_json),
+    );
+    push_non_empty(
+        &mut fields,
+        "methods",
+        class.methods.iter().map(method_to_json),
+    );
+    push_non_empty(
+        &mut fields,
+        "signals",
+        class.signals.iter().map(signal_to_json),
+    );
+    push_non_empty(
+        &mut fields,
+        "properties",
+        class.properties.iter().map(property_to_json),
+    );
+
+    Json::Object(fields)
+}
+
+fn constant_to_json(constant: &Constant) -> Json {
+    Json::object([
+        ("name", Json::string(&constant.name)),
+        ("value", Json::Int(constant.value)),
+    ])
+}
+
+fn method_to_json(method: &Method) -> Json {
+    let mut fields = vec![
+        ("name", Json::string(&method.name)),
+        ("is_const", Json::Bool(method.is_const)),
+        ("is_static", Json::Bool(method.is_static)),
+        ("is_vararg", Json::Bool(method.is_vararg)),
+        ("is_virtual", Json::Bool(method.is_virtual)),
+    ];
+
+    if let Some(ty) = &method.return_type {
+        fields.push(("return_value", Json::object([("type", Json::string(ty))])));
+    }
+
+    push_non_empty(
+        &mut fields,
+        "arguments",
+        method.arguments.iter().map(argument_to_json),
+    );
+    Json::Object(fields)
+}
+
+fn signal_to_json(signal: &Signal) -> Json {
+    let mut fields = vec![("name", Json::string(&signal.name))];
+
+    push_non_empty(
+        &mut fields,
+        "arguments",
+        signal.arguments.iter().map(argument_to_json),
+    );
+    Json::Object(fields)
+}
+
+fn argument_to_json(arg: &Argument) -> Json {
+    let mut fields = vec![
+        ("name", Json::string(&arg.name)),
+        ("type", Json::string(&arg.ty)),
+    ];
+
+    if let Some(default) = &arg.default_value {
+        fields.push(("default_value", Json::string(default)));
+    }
+
+    Json::Object(fields)
+}
+
+fn property_to_json(property: &Property) -> Json {
+    let mut fields = vec![
+        ("type", Json::string(&property.ty)),
+        ("name", Json::string(&property.name)),
+    ];
+
+    if let Some(setter) = &property.setter {
+        fields.push(("setter", Json::string(setter)));
+    }
+    if let Some(getter) = &property.getter {
+        fields.push(("getter", Json::string(getter)));
+    }
+
+    Json::Object(fields)
+}
+
+fn push_non_empty(
+    fields: &mut Vec<(&'static str, Json)>,
+    key: &'static str,
+    entries: impl Iterator<Item = Json>,
+) {
+    let entries: Vec<Json> = entries.collect();
+    if !entries.is_empty() {
+        fields.push((key, Json::Array(entries)));
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Serialization
+
+/// Minimal JSON tree; objects keep insertion order, so the output follows the key order of `extension_api.json`.
+enum Json {
+    Bool(bool),
+    Int(i64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(&'static str, Json)>),
+}
+
+impl Json {
+    fn string(s: impl std::fmt::Display) -> Self {
+        Self::String(s.to_string())
+    }
+
+    fn object<const N: usize>(fields: [(&'static str, Json); N]) -> Self {
+        Self::Object(fields.into())
+    }
+
+    /// Writes the value with tab indentation, like Godot's `--dump-extension-api`.
+    fn write(&self, out: &mut String, depth: usize) {
+        match self {
+            Json::Bool(b) => write!(out, "{b}").unwrap(),
+            Json::Int(i) => write!(out, "{i}").unwrap(),
+            Json::String(s) => write_escaped(out, s),
+            Json::Array(elems) => Self::write_seq(out, depth, ('[', ']'), elems, |out, elem| {
+                elem.write(out, depth + 1)
+            }),
+            Json::Object(fields) => {
+                Self::write_seq(out, depth, ('{', '}'), fields, |out, (key, value)| {
+                    write_escaped(out, key);
+                    out.push_str(": ");
+                    value.write(out, depth + 1);
+                })
+            }
+        }
+    }
+
+    fn write_seq<T>(
+        out: &mut String,
+        depth: usize,
+        (open, close): (char, char),
+        items: &[T],
+        mut write_item: impl FnMut(&mut String, &T),
+    ) {
+        out.push(open);
+        if items.is_empty() {
+            out.push(close);
+            return;
+        }
+
+        for (i, item) in items.iter().enumerate() {
+            out.push_str(if i == 0 { "\n" } else { ",\n" });
+            push_indent(out, depth + 1);
+            write_item(out, item);
+        }
+
+        out.push('\n');
+        push_indent(out, depth);
+        out.push(close);
+    }
+}
+
+fn push_indent(out: &mut String, depth: usize) {
+    out.extend(std::iter::repeat_n('\t', depth));
+}
+
+fn write_escaped(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
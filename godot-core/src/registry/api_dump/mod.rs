@@ -0,0 +1,63 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Machine-readable descriptions of the classes registered by this extension.
+//!
+//! Godot's own `--dump-extension-api` only covers engine classes. This module describes the Rust classes, so that external tools
+//! (GDScript stubs, C# glue, documentation sites, API diffs between versions) can consume them without running the engine.
+//!
+//! The metadata is read back from Godot's `ClassDB`, so the functions here must be called after the classes are registered (i.e. once
+//! the extension's init level has been reached). Compared to the engine's own dump, a few fields are not available and thus omitted:
+//! - Method `hash` and the `meta` of arguments/return values (e.g. `int32`).
+//! - Property `getter`/`setter` before Godot 4.4.
+//! - RPC configurations, since they are applied per instance rather than per class.
+
+mod csharp;
+mod json;
+mod model;
+mod query;
+
+#[cfg(test)]
+mod tests;
+
+use std::path::Path;
+
+/// Serializes all classes registered by this extension to JSON, following the schema of Godot's `extension_api.json`.
+///
+/// The output has a `header` with the running Godot version and a `classes` array with one entry per registered Rust class,
+/// sorted by name. Each class lists its own enums, constants, methods, signals and properties -- inherited members are not repeated,
+/// like in `extension_api.json`. Default argument values are formatted with `var_to_str()`.
+pub fn extension_api_json() -> String {
+    json::to_json(&query::query_extension_api())
+}
+
+/// Writes [`extension_api_json()`] to a file at `path`.
+pub fn write_extension_api_json(path: impl AsRef<Path>) -> std::io::Result<()> {
+    std::fs::write(path, extension_api_json())
+}
+
+/// Generates C# wrappers for all classes registered by this extension, for use in Godot's .NET build.
+///
+/// Each class becomes a `partial class` in `namespace`, wrapping a `GodotObject` as its `Instance` property. It exposes:
+/// - typed methods (static methods require Godot 4.4, as they use `ClassDB.ClassCallStatic()`),
+/// - properties, forwarding to `GodotObject.Get()`/`Set()`,
+/// - signals as C# events,
+/// - enums and integer constants,
+/// - `MethodName`/`PropertyName`/`SignalName` tables of cached `StringName`s, as in Godot's own C# classes.
+///
+/// Classes inheriting another Rust class derive from its wrapper. Enums nested in engine classes are exposed as `long`, since Godot's C#
+/// bindings rename some of them. Default arguments are kept where they have a C# constant representation.
+///
+/// The output is a single C# 12 source file.
+pub fn csharp_wrappers(namespace: &str) -> String {
+    csharp::to_csharp(&query::query_extension_api(), namespace)
+}
+
+/// Writes [`csharp_wrappers()`] to a file at `path`.
+pub fn write_csharp_wrappers(path: impl AsRef<Path>, namespace: &str) -> std::io::Result<()> {
+    std::fs::write(path, csharp_wrappers(namespace))
+}
@@ -0,0 +1,89 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Engine-independent description of the registered API, shared by all output formats.
+//!
+//! Type names are stored as in `extension_api.json`, e.g. `int`, `Node`, `enum::Node.ProcessMode` or `typedarray::Node`.
+
+pub(crate) struct ExtensionApi {
+    pub header: Header,
+    pub classes: Vec<Class>,
+}
+
+pub(crate) struct Header {
+    pub version_major: i64,
+    pub version_minor: i64,
+    pub version_patch: i64,
+    pub version_status: String,
+    pub version_build: String,
+    pub version_full_name: String,
+    pub precision: &'static str,
+}
+
+pub(crate) struct Class {
+    pub name: String,
+    pub is_refcounted: bool,
+    pub is_instantiable: bool,
+    pub inherits: String,
+    pub api_type: &'static str,
+    pub enums: Vec<Enum>,
+    pub constants: Vec<Constant>,
+    pub methods: Vec<Method>,
+    pub signals: Vec<Signal>,
+    pub properties: Vec<Property>,
+}
+
+pub(crate) struct Enum {
+    pub name: String,
+    pub is_bitfield: bool,
+    pub values: Vec<Constant>,
+}
+
+pub(crate) struct Constant {
+    pub name: String,
+    pub value: i64,
+}
+
+pub(crate) struct Method {
+    pub name: String,
+    pub is_const: bool,
+    pub is_static: bool,
+    pub is_vararg: bool,
+    pub is_virtual: bool,
+
+    /// `None` for `void`.
+    pub return_type: Option<String>,
+    pub arguments: Vec<Argument>,
+}
+
+pub(crate) struct Signal {
+    pub name: String,
+    pub arguments: Vec<Argument>,
+}
+
+pub(crate) struct Argument {
+    pub name: String,
+    pub ty: String,
+
+    /// Default value, formatted with `var_to_str()`.
+    pub default_value: Option<String>,
+}
+
+pub(crate) struct Property {
+    pub name: String,
+    pub ty: String,
+
+    /// Accessor methods; `None` if there is none, or if Godot cannot report it (before 4.4).
+    pub setter: Option<String>,
+    pub getter: Option<String>,
+}
+
+impl ExtensionApi {
+    pub fn find_class(&self, name: &str) -> Option<&Class> {
+        self.classes.iter().find(|class| class.name == name)
+    }
+}
@@ -0,0 +1,350 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Reads the metadata of registered classes back from Godot's `ClassDB`.
+
+use super::model::{
+    Argument, Class, Constant, Enum, ExtensionApi, Header, Method, Property, Signal,
+};
+use crate::builtin::{GString, PackedStringArray, StringName, VarDictionary, VariantType};
+use crate::classes::ClassDb;
+use crate::global::{MethodFlags, PropertyHint, PropertyUsageFlags};
+use crate::meta::{MethodInfo, PropertyInfo};
+use crate::obj::{EngineBitfield, EngineEnum, Gd, Singleton};
+use crate::registry::class;
+
+pub(crate) fn query_extension_api() -> ExtensionApi {
+    let classes = class::loaded_class_ids()
+        .into_iter()
+        .map(|class_id| query_class(&class_id.to_string_name()))
+        .collect();
+
+    ExtensionApi {
+        header: query_header(),
+        classes,
+    }
+}
+
+fn query_header() -> Header {
+    let info = crate::classes::Engine::singleton().get_version_info();
+    let precision = if cfg!(feature = "double-precision") {
+        "double"
+    } else {
+        "single"
+    };
+
+    Header {
+        version_major: info.at_as::<_, i64>("major"),
+        version_minor: info.at_as::<_, i64>("minor"),
+        version_patch: info.at_as::<_, i64>("patch"),
+        version_status: info.at_as::<_, GString>("status").to_string(),
+        version_build: info.at_as::<_, GString>("build").to_string(),
+        version_full_name: format!("Godot Engine v{}", info.at_as::<_, GString>("string")),
+        precision,
+    }
+}
+
+fn query_class(class_name: &StringName) -> Class {
+    let mut db = ClassDb::singleton();
+
+    Class {
+        name: class_name.to_string(),
+        is_refcounted: db.is_parent_class(class_name, "RefCounted"),
+        is_instantiable: db.can_instantiate(class_name),
+        inherits: db.get_parent_class(class_name).to_string(),
+        api_type: api_type(&mut db, class_name),
+        enums: query_enums(&mut db, class_name),
+        constants: query_constants(&mut db, class_name),
+        methods: query_methods(&mut db, class_name),
+        signals: query_signals(&mut db, class_name),
+        properties: query_properties(&mut db, class_name),
+    }
+}
+
+#[cfg(since_api = "4.4")]
+fn api_type(db: &mut Gd<ClassDb>, class_name: &StringName) -> &'static str {
+    use crate::classes::class_db::ApiType;
+
+    match db.class_get_api_type(class_name) {
+        ApiType::EDITOR_EXTENSION => "editor_extension",
+        _ => "extension",
+    }
+}
+
+#[cfg(before_api = "4.4")]
+fn api_type(_db: &mut Gd<ClassDb>, _class_name: &StringName) -> &'static str {
+    "extension"
+}
+
+fn query_enums(db: &mut Gd<ClassDb>, class_name: &StringName) -> Vec<Enum> {
+    let enum_names = db
+        .class_get_enum_list_ex(class_name)
+        .no_inheritance(true)
+        .done();
+
+    to_names(&enum_names)
+        .iter()
+        .map(|enum_name| {
+            let constants = db
+                .class_get_enum_constants_ex(class_name, enum_name)
+                .no_inheritance(true)
+                .done();
+
+            let values = to_names(&constants)
+                .iter()
+                .map(|constant| query_constant(db, class_name, constant))
+                .collect();
+
+            Enum {
+                name: enum_name.to_string(),
+                is_bitfield: is_bitfield(db, class_name, enum_name),
+                values,
+            }
+        })
+        .collect()
+}
+
+#[cfg(since_api = "4.3")]
+fn is_bitfield(db: &mut Gd<ClassDb>, class_name: &StringName, enum_name: &StringName) -> bool {
+    db.is_class_enum_bitfield_ex(class_name, enum_name)
+        .no_inheritance(true)
+        .done()
+}
+
+#[cfg(before_api = "4.3")]
+fn is_bitfield(_db: &mut Gd<ClassDb>, _class_name: &StringName, _enum_name: &StringName) -> bool {
+    false
+}
+
+fn query_constants(db: &mut Gd<ClassDb>, class_name: &StringName) -> Vec<Constant> {
+    let constants = db
+        .class_get_integer_constant_list_ex(class_name)
+        .no_inheritance(true)
+        .done();
+
+    let mut result = vec![];
+    for constant in to_names(&constants) {
+        let enum_name = db
+            .class_get_integer_constant_enum_ex(class_name, &constant)
+            .no_inheritance(true)
+            .done();
+
+        // Enumerators are listed under their enum, not as standalone constants.
+        if enum_name.is_empty() {
+            result.push(query_constant(db, class_name, &constant));
+        }
+    }
+
+    result
+}
+
+/// Converts names returned by `ClassDB` to the argument type of its other methods.
+fn to_names(names: &PackedStringArray) -> Vec<StringName> {
+    names.as_slice().iter().map(StringName::from).collect()
+}
+
+fn query_constant(
+    db: &mut Gd<ClassDb>,
+    class_name: &StringName,
+    constant: &StringName,
+) -> Constant {
+    Constant {
+        name: constant.to_string(),
+        value: db.class_get_integer_constant(class_name, constant),
+    }
+}
+
+fn query_methods(db: &mut Gd<ClassDb>, class_name: &StringName) -> Vec<Method> {
+    let methods = db
+        .class_get_method_list_ex(class_name)
+        .no_inheritance(true)
+        .done();
+
+    methods
+        .iter_shared()
+        .map(|dict| to_method(&MethodInfo::from_dictionary(&dict)))
+        .collect()
+}
+
+fn to_method(method: &MethodInfo) -> Method {
+    let flags = method.flags;
+
+    // Default values apply to the trailing arguments.
+    let first_default = method
+        .arguments
+        .len()
+        .saturating_sub(method.default_arguments.len());
+
+    let arguments = method
+        .arguments
+        .iter()
+        .enumerate()
+        .map(|(i, arg)| {
+            let default_value = i
+                .checked_sub(first_default)
+                .and_then(|index| method.default_arguments.get(index))
+                .map(|default| crate::global::var_to_str(default).to_string());
+
+            Argument {
+                default_value,
+                ..to_argument(arg)
+            }
+        })
+        .collect();
+
+    Method {
+        name: method.method_name.to_string(),
+        is_const: flags.is_set(MethodFlags::CONST),
+        is_static: flags.is_set(MethodFlags::STATIC),
+        is_vararg: flags.is_set(MethodFlags::VARARG),
+        is_virtual: flags.is_set(MethodFlags::VIRTUAL),
+        return_type: type_string(&method.return_type),
+        arguments,
+    }
+}
+
+fn to_argument(arg: &PropertyInfo) -> Argument {
+    Argument {
+        name: arg.property_name.to_string(),
+        ty: type_string_or_variant(arg),
+        default_value: None,
+    }
+}
+
+fn query_signals(db: &mut Gd<ClassDb>, class_name: &StringName) -> Vec<Signal> {
+    let signals = db
+        .class_get_signal_list_ex(class_name)
+        .no_inheritance(true)
+        .done();
+
+    signals
+        .iter_shared()
+        .map(|dict| {
+            let signal = MethodInfo::from_dictionary(&dict);
+
+            Signal {
+                name: signal.method_name.to_string(),
+                arguments: signal.arguments.iter().map(to_argument).collect(),
+            }
+        })
+        .collect()
+}
+
+fn query_properties(db: &mut Gd<ClassDb>, class_name: &StringName) -> Vec<Property> {
+    let properties = db
+        .class_get_property_list_ex(class_name)
+        .no_inheritance(true)
+        .done();
+
+    properties
+        .iter_shared()
+        .map(|dict: VarDictionary| PropertyInfo::from_dictionary(&dict))
+        .filter(|property| !is_editor_grouping(property))
+        .map(|property| query_property(db, class_name, &property))
+        .collect()
+}
+
+/// Groups, subgroups and categories only structure the inspector; they are not actual properties.
+fn is_editor_grouping(property: &PropertyInfo) -> bool {
+    [
+        PropertyUsageFlags::GROUP,
+        PropertyUsageFlags::SUBGROUP,
+        PropertyUsageFlags::CATEGORY,
+    ]
+    .into_iter()
+    .any(|flag| property.usage.is_set(flag))
+}
+
+#[cfg(since_api = "4.4")]
+fn query_property(
+    db: &mut Gd<ClassDb>,
+    class_name: &StringName,
+    property: &PropertyInfo,
+) -> Property {
+    let name = &property.property_name;
+    let non_empty = |accessor: StringName| (!accessor.is_empty()).then(|| accessor.to_string());
+
+    Property {
+        name: name.to_string(),
+        ty: type_string_or_variant(property),
+        setter: non_empty(db.class_get_property_setter(class_name, name)),
+        getter: non_empty(db.class_get_property_getter(class_name, name)),
+    }
+}
+
+#[cfg(before_api = "4.4")]
+fn query_property(
+    _db: &mut Gd<ClassDb>,
+    _class_name: &StringName,
+    property: &PropertyInfo,
+) -> Property {
+    Property {
+        name: property.property_name.to_string(),
+        ty: type_string_or_variant(property),
+        setter: None,
+        getter: None,
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Type names
+
+/// Returns the type as written in `extension_api.json`, or `None` for `void`.
+fn type_string(info: &PropertyInfo) -> Option<String> {
+    let usage = info.usage;
+    let hint = &info.hint_info;
+    let class_name = info.class_id.to_cow_str();
+
+    let ty = match info.variant_type {
+        VariantType::NIL if usage.is_set(PropertyUsageFlags::NIL_IS_VARIANT) => "Variant".into(),
+        VariantType::NIL => return None,
+
+        VariantType::OBJECT => {
+            let is_typed_by_hint = matches!(
+                hint.hint,
+                PropertyHint::RESOURCE_TYPE | PropertyHint::NODE_TYPE
+            );
+
+            if is_typed_by_hint && !hint.hint_string.is_empty() {
+                hint.hint_string.to_string()
+            } else if !class_name.is_empty() {
+                class_name.to_string()
+            } else {
+                "Object".into()
+            }
+        }
+
+        VariantType::INT if usage.is_set(PropertyUsageFlags::CLASS_IS_BITFIELD) => {
+            format!("bitfield::{class_name}")
+        }
+        VariantType::INT if usage.is_set(PropertyUsageFlags::CLASS_IS_ENUM) => {
+            format!("enum::{class_name}")
+        }
+
+        VariantType::ARRAY
+            if hint.hint == PropertyHint::ARRAY_TYPE && !hint.hint_string.is_empty() =>
+        {
+            format!("typedarray::{}", hint.hint_string)
+        }
+
+        #[cfg(since_api = "4.4")]
+        VariantType::DICTIONARY
+            if hint.hint == PropertyHint::DICTIONARY_TYPE && !hint.hint_string.is_empty() =>
+        {
+            format!("typeddictionary::{}", hint.hint_string)
+        }
+
+        other => crate::global::type_string(i64::from(other.ord())).to_string(),
+    };
+
+    Some(ty)
+}
+
+/// Like [`type_string()`], for positions where `void` is not possible (arguments, properties).
+fn type_string_or_variant(info: &PropertyInfo) -> String {
+    type_string(info).unwrap_or_else(|| "Variant".into())
+}
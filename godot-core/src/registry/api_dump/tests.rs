@@ -0,0 +1,223 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Golden-file tests for the output formats. Run with `GDRUST_BLESS=1` to update the expected files after intended changes.
+
+use std::path::PathBuf;
+
+use super::model::{
+    Argument, Class, Constant, Enum, ExtensionApi, Header, Method, Property, Signal,
+};
+use super::{csharp, json};
+
+#[test]
+fn json_matches_golden_file() {
+    let actual = json::to_json(&example_api());
+    assert_golden("example_api.json", &actual);
+}
+
+#[test]
+fn csharp_matches_golden_file() {
+    let actual = csharp::to_csharp(&example_api(), "Game.Rust");
+    assert_golden("ExampleApi.cs", &actual);
+}
+
+fn assert_golden(file_name: &str, actual: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("src/registry/api_dump/golden")
+        .join(file_name);
+
+    if std::env::var("GDRUST_BLESS").is_ok_and(|value| value == "1") {
+        std::fs::write(&path, actual).expect("write golden file");
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&path).expect("read golden file");
+    assert!(
+        expected == actual,
+        "output differs from {}; rerun with GDRUST_BLESS=1 if the change is intended.\n\nActual:\n{actual}",
+        path.display()
+    );
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Example API
+
+fn example_api() -> ExtensionApi {
+    ExtensionApi {
+        header: Header {
+            version_major: 4,
+            version_minor: 4,
+            version_patch: 1,
+            version_status: "stable".to_string(),
+            version_build: "official".to_string(),
+            version_full_name: "Godot Engine v4.4.1.stable.official".to_string(),
+            precision: "single",
+        },
+        classes: vec![creature(), dragon()],
+    }
+}
+
+/// Class with a bit of everything, inheriting an engine class.
+fn creature() -> Class {
+    Class {
+        name: "Creature".to_string(),
+        is_refcounted: true,
+        is_instantiable: true,
+        inherits: "RefCounted".to_string(),
+        api_type: "extension",
+        enums: vec![
+            Enum {
+                name: "Mood".to_string(),
+                is_bitfield: false,
+                values: vec![
+                    constant("MOOD_CALM", 0),
+                    constant("MOOD_ANGRY", 1),
+                    constant("MOOD_2_FACED", 2),
+                ],
+            },
+            Enum {
+                name: "Abilities".to_string(),
+                is_bitfield: true,
+                values: vec![constant("SWIM", 1), constant("FLY", 2)],
+            },
+        ],
+        constants: vec![constant("MAX_HEALTH", 100)],
+        methods: vec![
+            method("get_health", Some("int"), vec![]),
+            method("set_health", None, vec![arg("health", "int")]),
+            method("get_name", Some("String"), vec![]),
+            method(
+                "greet",
+                Some("String"),
+                vec![
+                    arg("other", "Creature"),
+                    arg_default("times", "int", "3"),
+                    arg_default("loud", "bool", "false"),
+                ],
+            ),
+            method(
+                "paint",
+                None,
+                vec![
+                    arg_default("label", "String", "\"fresh \\\"paint\\\"\""),
+                    arg_default("color", "Color", "Color(1, 1, 1, 1)"),
+                    arg_default("strength", "float", "0.5"),
+                ],
+            ),
+            method(
+                "set_mood",
+                None,
+                vec![arg_default("mood", "enum::Creature.Mood", "1")],
+            ),
+            method(
+                "set_process_mode",
+                None,
+                vec![arg("mode", "enum::Node.ProcessMode")],
+            ),
+            method(
+                "get_error",
+                Some("enum::Error"),
+                vec![arg("abilities", "bitfield::Creature.Abilities")],
+            ),
+            method("get_offspring", Some("typedarray::Creature"), vec![]),
+            Method {
+                is_static: true,
+                ..method("spawn", Some("Creature"), vec![arg("params", "Dictionary")])
+            },
+            Method {
+                is_vararg: true,
+                ..method("log", None, vec![arg("message", "StringName")])
+            },
+        ],
+        signals: vec![
+            Signal {
+                name: "died".to_string(),
+                arguments: vec![],
+            },
+            Signal {
+                name: "health_changed".to_string(),
+                arguments: vec![arg("new_health", "int"), arg("attacker", "Creature")],
+            },
+        ],
+        properties: vec![
+            property("health", "int", Some("set_health"), Some("get_health")),
+            property("name", "String", None, Some("get_name")),
+            property("tags", "PackedStringArray", None, None),
+        ],
+    }
+}
+
+/// Class inheriting another Rust class.
+fn dragon() -> Class {
+    Class {
+        name: "Dragon".to_string(),
+        is_refcounted: true,
+        is_instantiable: true,
+        inherits: "Creature".to_string(),
+        api_type: "extension",
+        enums: vec![],
+        constants: vec![],
+        methods: vec![
+            method(
+                "breathe_fire",
+                Some("bool"),
+                vec![arg_default("target", "Node3D", "null")],
+            ),
+            method("get_request", Some("HTTPRequest"), vec![]),
+            method("get_os_name", Some("StringName"), vec![arg("os", "OS")]),
+        ],
+        signals: vec![Signal {
+            name: "landed".to_string(),
+            arguments: vec![arg("position", "Vector3i")],
+        }],
+        properties: vec![],
+    }
+}
+
+fn constant(name: &str, value: i64) -> Constant {
+    Constant {
+        name: name.to_string(),
+        value,
+    }
+}
+
+fn method(name: &str, return_type: Option<&str>, arguments: Vec<Argument>) -> Method {
+    Method {
+        name: name.to_string(),
+        is_const: false,
+        is_static: false,
+        is_vararg: false,
+        is_virtual: false,
+        return_type: return_type.map(str::to_string),
+        arguments,
+    }
+}
+
+fn arg(name: &str, ty: &str) -> Argument {
+    Argument {
+        name: name.to_string(),
+        ty: ty.to_string(),
+        default_value: None,
+    }
+}
+
+fn arg_default(name: &str, ty: &str, default_value: &str) -> Argument {
+    Argument {
+        default_value: Some(default_value.to_string()),
+        ..arg(name, ty)
+    }
+}
+
+fn property(name: &str, ty: &str, setter: Option<&str>, getter: Option<&str>) -> Property {
+    Property {
+        name: name.to_string(),
+        ty: ty.to_string(),
+        setter: setter.map(str::to_string),
+        getter: getter.map(str::to_string),
+    }
+}
@@ -75,8 +75,18 @@ pub unsafe extern "C" fn recreate<T: cap::GodotDefault>(
     _class_userdata: *mut std::ffi::c_void,
     object: sys::GDExtensionObjectPtr,
 ) -> sys::GDExtensionClassInstancePtr {
-    create_rust_part_for_existing_godot_part(T::__godot_user_init, object, |_| {})
-        .unwrap_or(std::ptr::null_mut())
+    let Ok(instance_ptr) =
+        create_rust_part_for_existing_godot_part(T::__godot_user_init, object, |_| {})
+    else {
+        return std::ptr::null_mut();
+    };
+
+    // For #[class(hot_reload_state)], hand over the state which was saved before the library was unloaded.
+    // SAFETY: Godot passes a valid object pointer, whose Rust part has just been linked.
+    let obj = unsafe { Gd::<Object>::from_obj_sys(object) };
+    crate::registry::hot_reload::restore_state(T::class_id(), obj);
+
+    instance_ptr
 }
 
 /// Workaround for <https://github.com/godot-rust/gdext/issues/874> before Godot 4.5.
@@ -170,6 +180,8 @@ where
         );
     }
 
+    crate::registry::hot_reload::on_instance_created(class_name, base_copy.instance_id_unchecked());
+
     postinit(base_ptr);
 
     // No std::mem::forget(base_copy) here, since Base may stores other fields that need deallocation.
@@ -183,6 +195,9 @@ pub unsafe extern "C" fn free<T: GodotClass>(
     {
         let storage = as_storage::<T>(instance);
         storage.mark_destroyed_by_godot();
+
+        let instance_id = storage.base().instance_id_unchecked();
+        crate::registry::hot_reload::on_instance_freed(T::class_id(), instance_id);
     } // Ref no longer valid once next statement is executed.
 
     crate::storage::destroy_storage::<T>(instance);
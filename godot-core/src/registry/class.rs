@@ -17,8 +17,9 @@ use crate::meta::error::FromGodotError;
 use crate::meta::ClassId;
 use crate::obj::{cap, DynGd, Gd, GodotClass, Singleton};
 use crate::private::{ClassPlugin, PluginItem};
-use crate::registry::callbacks;
+use crate::registry::hot_reload::HotReloadStateFns;
 use crate::registry::plugin::{DynTraitImpl, ErasedRegisterFn, ITraitImpl, InherentImpl, Struct};
+use crate::registry::{callbacks, hot_reload, shared};
 use crate::{classes, godot_error, godot_warn, sys};
 
 /// Returns a lock to a global map of loaded classes, by initialization level.
@@ -46,6 +47,14 @@ fn global_loaded_classes_by_name() -> GlobalGuard<'static, HashMap<ClassId, Clas
     lock_or_panic(&LOADED_CLASSES_BY_NAME, "loaded classes (by name)")
 }
 
+/// Returns the IDs of all currently loaded classes, sorted by name.
+pub(crate) fn loaded_class_ids() -> Vec<ClassId> {
+    let mut class_ids: Vec<ClassId> = global_loaded_classes_by_name().keys().copied().collect();
+    class_ids.sort_by_key(|class_id| class_id.to_string());
+
+    class_ids
+}
+
 fn global_dyn_traits_by_typeid() -> GlobalGuard<'static, HashMap<any::TypeId, Vec<DynTraitImpl>>> {
     static DYN_TRAITS_BY_TYPEID: Global<HashMap<any::TypeId, Vec<DynTraitImpl>>> =
         Global::default();
@@ -72,7 +81,9 @@ fn global_dyn_traits_by_trait_and_class(
 pub struct LoadedClass {
     name: ClassId,
     is_editor_plugin: bool,
+    is_shared: bool,
     unregister_singleton_fn: Option<fn()>,
+    has_hot_reload_state: bool,
 }
 
 /// Represents a class which is currently loaded and retained in memory -- including metadata.
@@ -107,6 +118,7 @@ struct ClassRegistrationInfo {
     user_virtual_fn: Option<GodotGetVirtual>, // Optional (set if there is a `#[godot_api] impl I*`)
     register_singleton_fn: Option<fn()>,
     unregister_singleton_fn: Option<fn()>,
+    hot_reload_state_fns: Option<HotReloadStateFns>,
 
     /// Godot low-level class creation parameters.
     godot_params: GodotCreationInfo,
@@ -114,6 +126,7 @@ struct ClassRegistrationInfo {
     #[allow(dead_code)] // Currently unused; may be useful for diagnostics in the future.
     init_level: InitLevel,
     is_editor_plugin: bool,
+    is_shared: bool,
 
     /// One entry for each `dyn Trait` implemented (and registered) for this class.
     dynify_fns_by_trait: HashMap<any::TypeId, DynTraitImpl>,
@@ -192,10 +205,12 @@ pub(crate) fn register_class<
         godot_params,
         init_level: T::INIT_LEVEL,
         is_editor_plugin: false,
+        is_shared: false,
         dynify_fns_by_trait: HashMap::new(),
         component_already_filled: Default::default(), // [false; N]
         register_singleton_fn: None,
         unregister_singleton_fn: None,
+        hot_reload_state_fns: None,
     });
 }
 
@@ -288,8 +303,14 @@ fn register_classes_and_dyn_traits(
         let loaded_class = LoadedClass {
             name: class_name,
             is_editor_plugin: info.is_editor_plugin,
+            is_shared: info.is_shared,
             unregister_singleton_fn: info.unregister_singleton_fn,
+            has_hot_reload_state: info.hot_reload_state_fns.is_some(),
         };
+
+        if let Some(fns) = info.hot_reload_state_fns {
+            hot_reload::register_class(class_name, fns);
+        }
         let metadata = ClassMetadata {};
 
         // Transpose Class->Trait relations to Trait->Class relations.
@@ -315,6 +336,22 @@ fn register_classes_and_dyn_traits(
 }
 
 pub fn unregister_classes(init_level: InitLevel) {
+    // Save hot-reload state before anything is unregistered. This runs user code, so registry locks must not be held.
+    let hot_reload_classes: Vec<ClassId> = global_loaded_classes_by_init_level()
+        .get(&init_level)
+        .map(|classes| {
+            classes
+                .iter()
+                .filter(|class| class.has_hot_reload_state)
+                .map(|class| class.name)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    for class_name in hot_reload_classes {
+        hot_reload::unregister_class(class_name);
+    }
+
     let mut loaded_classes_by_level = global_loaded_classes_by_init_level();
     let mut loaded_classes_by_name = global_loaded_classes_by_name();
     let mut dyn_traits_by_typeid = global_dyn_traits_by_typeid();
@@ -394,8 +431,8 @@ pub(crate) fn try_dynify_object<T: GodotClass, D: ?Sized + 'static>(
 /// Responsible for creating hint_string for [`DynGd<T, D>`][crate::obj::DynGd] properties which works with [`PropertyHint::NODE_TYPE`][crate::global::PropertyHint::NODE_TYPE] or [`PropertyHint::RESOURCE_TYPE`][crate::global::PropertyHint::RESOURCE_TYPE].
 ///
 /// Godot offers very limited capabilities when it comes to validating properties in the editor if given class isn't a tool.
-/// Proper hint string combined with `PropertyHint::RESOURCE_TYPE` allows to limit selection only to valid classes - those registered as implementors of given `DynGd<T, D>`'s `D` trait.
-/// Godot editor allows to export only one node type with `PropertyHint::NODE_TYPE` – therefore we are returning only the base class.
+/// Proper hint string combined with `PropertyHint::RESOURCE_TYPE` or `PropertyHint::NODE_TYPE` allows to limit selection only to valid
+/// classes - those registered as implementors of given `DynGd<T, D>`'s `D` trait.
 ///
 /// See also [Godot docs for PropertyHint](https://docs.godotengine.org/en/stable/classes/class_@globalscope.html#enum-globalscope-propertyhint).
 pub(crate) fn get_dyn_property_hint_string<T, D>() -> String
@@ -403,21 +440,46 @@ where
     T: GodotClass,
     D: ?Sized + 'static,
 {
-    // Exporting multiple node types is not supported.
-    if T::inherits::<classes::Node>() {
-        return T::class_id().to_string();
-    }
-
-    let typeid = any::TypeId::of::<D>();
-    let dyn_traits_by_typeid = global_dyn_traits_by_typeid();
+    let is_node = T::inherits::<classes::Node>();
 
-    let Some(relations) = dyn_traits_by_typeid.get(&typeid) else {
+    let Some(implementors) = dyn_implementors_inheriting::<T, D>() else {
         let trait_name = sys::short_type_name::<D>();
         godot_warn!(
             "godot-rust: No class has been linked to trait {trait_name} with #[godot_dyn]."
         );
-        return String::new();
+
+        // An empty NODE_TYPE hint would allow any node; fall back to the base class instead.
+        return if is_node {
+            T::class_id().to_string()
+        } else {
+            String::new()
+        };
     };
+
+    if is_node && implementors.is_empty() {
+        return T::class_id().to_string();
+    }
+
+    // Node-type hints are parsed as a plain comma-separated list of class names.
+    let separator = if is_node { "," } else { ", " };
+
+    join_with(implementors.iter(), separator, |class_id| {
+        class_id.to_cow_str()
+    })
+}
+
+/// Returns all registered implementors of `D` which inherit `T`, or `None` if `D` has no implementors at all.
+///
+/// For example, don't include Nodes or Objects while creating a hint string for Resource.
+fn dyn_implementors_inheriting<T, D>() -> Option<Vec<ClassId>>
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    let typeid = any::TypeId::of::<D>();
+    let dyn_traits_by_typeid = global_dyn_traits_by_typeid();
+
+    let relations = dyn_traits_by_typeid.get(&typeid)?;
     assert!(
         !relations.is_empty(),
         "Trait {trait_name} has been registered as DynGd Trait \
@@ -426,24 +488,45 @@ where
         trait_name = sys::short_type_name::<D>()
     );
 
-    // Include only implementors inheriting given T.
-    // For example – don't include Nodes or Objects while creating hint_string for Resource.
-    let relations_iter = relations.iter().filter_map(|implementor| {
-        if implementor.parent_class_name? == T::class_id()
-            || ClassDb::singleton().is_parent_class(
-                &implementor.parent_class_name?.to_string_name(),
-                &T::class_id().to_string_name(),
-            )
-        {
-            Some(implementor)
-        } else {
-            None
+    let implementors = relations
+        .iter()
+        .filter(|implementor| {
+            implementor.parent_class_name.is_some_and(|parent| {
+                parent == T::class_id()
+                    || ClassDb::singleton()
+                        .is_parent_class(&parent.to_string_name(), &T::class_id().to_string_name())
+            })
+        })
+        .map(|implementor| *implementor.class_name())
+        .collect();
+
+    Some(implementors)
+}
+
+/// Describes why an object of class `dynamic_class` cannot be stored in a `DynGd<T, D>`, listing the valid implementors.
+///
+/// Used when the editor or GDScript assigns a non-implementing object to a `DynGd` property.
+pub(crate) fn describe_dyn_assignment_failure<T, D>(dynamic_class: &str) -> String
+where
+    T: GodotClass,
+    D: ?Sized + 'static,
+{
+    let trait_name = sys::short_type_name::<D>();
+    let base_class = T::class_id();
+
+    let implementors = match dyn_implementors_inheriting::<T, D>() {
+        Some(implementors) if !implementors.is_empty() => {
+            join_with(implementors.iter(), ", ", |class_id| {
+                format!("`{class_id}`")
+            })
         }
-    });
+        _ => String::from("(none)"),
+    };
 
-    join_with(relations_iter, ", ", |dyn_trait| {
-        dyn_trait.class_name().to_cow_str()
-    })
+    format!(
+        "cannot assign object of class `{dynamic_class}` to DynGd<{base_class}, {trait_name}>: \
+        class does not implement the trait.\n  Valid classes: {implementors}"
+    )
 }
 
 /// Populate `c` with all the relevant data from `component` (depending on component type).
@@ -465,14 +548,18 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             is_tool,
             is_editor_plugin,
             is_internal,
+            is_shared,
             is_instantiable,
             reference_fn,
             unreference_fn,
+            hot_reload_state_fns,
         }) => {
             c.parent_class_name = Some(base_class_name);
+            c.hot_reload_state_fns = hot_reload_state_fns;
             c.default_virtual_fn = default_get_virtual_fn;
             c.register_properties_fn = Some(register_properties_fn);
             c.is_editor_plugin = is_editor_plugin;
+            c.is_shared = is_shared;
             c.register_singleton_fn = register_singleton_fn;
             c.unregister_singleton_fn = unregister_singleton_fn;
 
@@ -647,10 +734,19 @@ fn register_class_raw(mut info: ClassRegistrationInfo) {
     // 1. Methods and constants.
     // 2. Properties (they may depend on get/set methods).
     // 3. User-defined registration function (intuitively, user expects their own code to run after proc-macro generated code).
+    if info.is_shared {
+        shared::begin_class(class_name);
+    }
+
     if let Some(register_fn) = info.register_methods_constants_fn {
         (register_fn.raw)(&mut class_builder);
     }
 
+    // Publish once all methods are registered, but before user code in `user_register_fn` might look it up.
+    if info.is_shared {
+        shared::publish_class(class_name);
+    }
+
     if let Some(register_fn) = info.register_properties_fn {
         (register_fn.raw)(&mut class_builder);
     }
@@ -677,6 +773,11 @@ fn unregister_class_raw(class: LoadedClass) {
         out!("> Editor plugin removed");
     }
 
+    // Other extensions must not find the class table anymore, once the class is gone.
+    if class.is_shared {
+        shared::unpublish_class(class_name);
+    }
+
     // Similarly to EditorPlugin – given instance is being freed and will not be recreated
     // during hot reload (a new, independent one will be created instead).
     if let Some(unregister_singleton_fn) = class.unregister_singleton_fn {
@@ -723,9 +824,11 @@ fn default_registration_info(class_name: ClassId) -> ClassRegistrationInfo {
         user_virtual_fn: None,
         register_singleton_fn: None,
         unregister_singleton_fn: None,
+        hot_reload_state_fns: None,
         godot_params: default_creation_info(),
         init_level: InitLevel::Scene,
         is_editor_plugin: false,
+        is_shared: false,
         dynify_fns_by_trait: HashMap::new(),
         component_already_filled: Default::default(), // [false; N]
     }
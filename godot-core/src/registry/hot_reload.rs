@@ -0,0 +1,171 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Preservation of Rust-side instance state across hot reloads, for classes marked `#[class(hot_reload_state)]`.
+//!
+//! During hot reload, Godot only carries over property values with `STORAGE` usage. The Rust part of each instance is freed and
+//! recreated via `init()` afterwards. To keep other state alive, godot-rust tracks all live instances of opted-in classes. Upon
+//! deinitialization of the class, it asks each instance for its state (see [`HotReloadState`]) and stores the result in a hidden
+//! metadata entry of the Godot object -- which survives unloading of the library. When Godot recreates the Rust part, the state is
+//! read back from the metadata, removed, and handed to the new instance.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use sys::{Global, GlobalGuard};
+
+use crate::builtin::{StringName, Variant};
+use crate::classes::{Engine, Object};
+use crate::meta::ClassId;
+use crate::obj::{Gd, HotReloadState, Inherits, InstanceId, Singleton};
+use crate::private::handle_panic;
+use crate::sys;
+
+/// Name of the metadata entry which holds the saved state between unload and recreation.
+///
+/// Starts with an underscore, so that the editor does not display it in the inspector.
+const STATE_META_KEY: &str = "_gdext_hot_reload_state";
+
+/// Fast path: avoid locking the global for every object construction, as long as no class has opted in.
+static ANY_CLASS_OPTED_IN: AtomicBool = AtomicBool::new(false);
+
+/// Type-erased save/restore functions for a class implementing [`HotReloadState`].
+#[derive(Copy, Clone, Debug)]
+pub struct HotReloadStateFns {
+    save_fn: fn(Gd<Object>) -> Variant,
+    restore_fn: fn(Gd<Object>, Variant),
+}
+
+impl HotReloadStateFns {
+    pub(crate) fn new<T>() -> Self
+    where
+        T: HotReloadState + Inherits<Object>,
+    {
+        Self {
+            save_fn: |obj| obj.cast::<T>().bind().save_hot_reload_state(),
+            restore_fn: |obj, state| obj.cast::<T>().bind_mut().restore_hot_reload_state(state),
+        }
+    }
+}
+
+struct TrackedClass {
+    fns: HotReloadStateFns,
+    live_instances: HashSet<InstanceId>,
+}
+
+fn global_tracked_classes() -> GlobalGuard<'static, HashMap<ClassId, TrackedClass>> {
+    static TRACKED_CLASSES: Global<HashMap<ClassId, TrackedClass>> = Global::default();
+
+    TRACKED_CLASSES.lock()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Registration
+
+/// Starts tracking instances of `class_id`. Called during class registration.
+pub(crate) fn register_class(class_id: ClassId, fns: HotReloadStateFns) {
+    global_tracked_classes().insert(
+        class_id,
+        TrackedClass {
+            fns,
+            live_instances: HashSet::new(),
+        },
+    );
+
+    ANY_CLASS_OPTED_IN.store(true, Ordering::Release);
+}
+
+/// Saves the state of all live instances of `class_id` into their metadata, then stops tracking the class.
+///
+/// Called during class deregistration, while the library is still fully functional.
+pub(crate) fn unregister_class(class_id: ClassId) {
+    let Some(tracked) = global_tracked_classes().remove(&class_id) else {
+        return;
+    };
+
+    // Hot reload only happens in the editor; don't run user code on regular shutdown.
+    if !Engine::singleton().is_editor_hint() {
+        return;
+    }
+
+    // Lock is released at this point: user code may construct or free other tracked objects.
+    for instance_id in tracked.live_instances {
+        let Ok(mut obj) = Gd::<Object>::try_from_instance_id(instance_id) else {
+            continue;
+        };
+
+        let save_fn = tracked.fns.save_fn;
+        let obj_copy = obj.clone();
+        let context = || format!("panic while saving hot-reload state of {class_id}");
+        let code = move || save_fn(obj_copy);
+        let Ok(state) = handle_panic(context, std::panic::AssertUnwindSafe(code)) else {
+            continue;
+        };
+
+        // Nil signals "nothing to restore".
+        if !state.is_nil() {
+            obj.set_meta(&meta_key(), &state);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Instance lifecycle
+
+/// Records a newly constructed (or recreated) instance.
+pub(crate) fn on_instance_created(class_id: ClassId, instance_id: InstanceId) {
+    if !ANY_CLASS_OPTED_IN.load(Ordering::Acquire) {
+        return;
+    }
+
+    if let Some(tracked) = global_tracked_classes().get_mut(&class_id) {
+        tracked.live_instances.insert(instance_id);
+    }
+}
+
+/// Forgets an instance whose Rust part is being destroyed.
+pub(crate) fn on_instance_freed(class_id: ClassId, instance_id: InstanceId) {
+    if !ANY_CLASS_OPTED_IN.load(Ordering::Acquire) {
+        return;
+    }
+
+    if let Some(tracked) = global_tracked_classes().get_mut(&class_id) {
+        tracked.live_instances.remove(&instance_id);
+    }
+}
+
+/// After the Rust part of `obj` has been recreated by a hot reload, hands over the state saved before unloading (if any).
+pub(crate) fn restore_state(class_id: ClassId, mut obj: Gd<Object>) {
+    if !ANY_CLASS_OPTED_IN.load(Ordering::Acquire) {
+        return;
+    }
+
+    let Some(fns) = global_tracked_classes()
+        .get(&class_id)
+        .map(|tracked| tracked.fns)
+    else {
+        return;
+    };
+
+    let key = meta_key();
+    if !obj.has_meta(&key) {
+        return;
+    }
+
+    let state = obj.get_meta(&key);
+    obj.remove_meta(&key);
+
+    // On panic, the instance keeps the state from its init() constructor.
+    let restore_fn = fns.restore_fn;
+    let context = || format!("panic while restoring hot-reload state of {class_id}");
+    let code = move || restore_fn(obj, state);
+    let _ = handle_panic(context, std::panic::AssertUnwindSafe(code));
+}
+
+fn meta_key() -> StringName {
+    StringName::from(STATE_META_KEY)
+}
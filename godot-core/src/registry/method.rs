@@ -131,6 +131,16 @@ impl ClassMethodInfo {
             self.register_virtual_class_method(method_info_sys, return_value_sys);
         } else {
             self.register_nonvirtual_class_method(method_info_sys);
+
+            // Script-virtual methods are not shared, since the ptrcall would bypass script overrides.
+            crate::registry::shared::on_method_registered(
+                self.class_id,
+                &self.method_name,
+                &self.arguments,
+                self.return_value.as_ref(),
+                self.method_flags.is_set(MethodFlags::STATIC),
+                self.ptrcall_func,
+            );
         }
     }
 
@@ -8,14 +8,18 @@
 // Note: final re-exports from godot-core are in lib.rs, mod register::private.
 // These are public here for simplicity, but many are not imported by the main crate.
 
+pub mod api_dump;
 pub mod callbacks;
 pub mod class;
 pub mod constant;
 pub mod method;
 pub mod plugin;
 pub mod property;
+pub mod shared;
 pub mod signal;
 
+pub(crate) mod hot_reload;
+
 // RpcConfig uses MultiplayerPeer::TransferMode and MultiplayerApi::RpcMode, which are only enabled in `codegen-full` feature.
 #[cfg(feature = "codegen-full")]
 mod rpc_config;
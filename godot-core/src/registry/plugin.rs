@@ -11,11 +11,12 @@ use std::{any, fmt};
 use crate::init::InitLevel;
 use crate::meta::ClassId;
 use crate::obj::{
-    bounds, cap, Bounds, DynGd, Gd, GodotClass, Inherits, NewAlloc, Singleton, UserClass,
-    UserSingleton,
+    bounds, cap, Bounds, DynGd, Gd, GodotClass, HotReloadState, Inherits, NewAlloc, Singleton,
+    UserClass, UserSingleton,
 };
 use crate::registry::callbacks;
 use crate::registry::class::GodotGetVirtual;
+use crate::registry::hot_reload::HotReloadStateFns;
 use crate::{classes, sys};
 
 // TODO(bromeon): some information coming from the proc-macro API is deferred through PluginItem, while others is directly
@@ -202,8 +203,14 @@ pub struct Struct {
     /// Whether `#[class(internal)]` was used.
     pub(crate) is_internal: bool,
 
+    /// Whether `#[class(shared)]` was used.
+    pub(crate) is_shared: bool,
+
     /// Whether the class has a default constructor.
     pub(crate) is_instantiable: bool,
+
+    /// `#[class(hot_reload_state)]`
+    pub(crate) hot_reload_state_fns: Option<HotReloadStateFns>,
 }
 
 impl Struct {
@@ -224,7 +231,9 @@ impl Struct {
             is_tool: false,
             is_editor_plugin: false,
             is_internal: false,
+            is_shared: false,
             is_instantiable: false,
+            hot_reload_state_fns: None,
             // While Godot doesn't do anything with these callbacks for non-RefCounted classes, we can avoid instantiating them in Rust.
             reference_fn: refcounted.then_some(callbacks::reference::<T>),
             unreference_fn: refcounted.then_some(callbacks::unreference::<T>),
@@ -301,10 +310,26 @@ impl Struct {
         self
     }
 
+    pub fn with_shared(mut self) -> Self {
+        self.is_shared = true;
+        self
+    }
+
     pub fn with_instantiable(mut self) -> Self {
         self.is_instantiable = true;
         self
     }
+
+    pub fn with_hot_reload_state<T>(mut self) -> Self
+    where
+        T: HotReloadState + Inherits<classes::Object>,
+    {
+        set(
+            &mut self.hot_reload_state_fns,
+            HotReloadStateFns::new::<T>(),
+        );
+        self
+    }
 }
 
 /// Stores registration functions for methods, constants, and documentation from inherent `#[godot_api]` impl blocks.
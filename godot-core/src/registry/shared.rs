@@ -0,0 +1,857 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Typed calls between separately compiled Rust extensions.
+//!
+//! A `Gd<T>` only works for classes of the extension that registered `T`: the Rust instance is found through instance storage that is
+//! private to each library. Another extension loaded into the same project can thus only reach those classes via `Object::call()`
+//! and `Variant` conversions.
+//!
+//! Classes declared with `#[class(shared)]` additionally publish a small C-ABI table of their `#[func]` methods. Other extensions can
+//! declare a typed proxy with [`shared_proxy!`][crate::shared_proxy], which looks up this table at runtime and calls methods through
+//! the ptrcall calling convention -- no `Variant` conversions, no dependency on the providing crate, and no requirement that both
+//! sides use the same godot-rust version.
+//!
+//! # Providing extension
+//! ```no_run
+//! use godot::prelude::*;
+//!
+//! #[derive(GodotClass)]
+//! #[class(init, shared)]
+//! struct Inventory {
+//!     items: Vec<GString>,
+//! }
+//!
+//! #[godot_api]
+//! impl Inventory {
+//!     #[func]
+//!     fn add_item(&mut self, name: GString) -> i64 {
+//!         self.items.push(name);
+//!         self.items.len() as i64
+//!     }
+//! }
+//! ```
+//!
+//! # Consuming extension
+//! ```no_run
+//! use godot::prelude::*;
+//! use godot::register::shared::shared_proxy;
+//!
+//! shared_proxy! {
+//!     /// `Inventory` class from the inventory extension.
+//!     pub struct Inventory(class = "Inventory") {
+//!         fn add_item(&mut self, name: GString) -> i64;
+//!     }
+//! }
+//!
+//! fn give_sword(object: Gd<Object>) {
+//!     let mut inventory = Inventory::from_object(object);
+//!     let count = inventory.add_item("sword".into());
+//!     godot_print!("{count} items");
+//! }
+//! ```
+//!
+//! # Compatibility
+//! Methods are matched by name and signature when a proxy is created. A signature consists of the Godot types of parameters and return
+//! value (e.g. `int` for both `i32` and `i64`), plus class names for objects, which must be identical on both sides.
+//! `#[func(virtual)]` methods and `#[opt]` default values are not available through proxies.
+//!
+//! Looking up shared classes requires Godot 4.4 or later on the consuming side, as it relies on `ClassDB.class_call_static()`.
+//! Classes can be shared with all supported Godot versions.
+//!
+//! # Unloading
+//! When the providing extension unregisters the class (on unload or hot reload), its table is marked as dead. Existing proxies then
+//! report `false` from `is_alive()` and panic when called, instead of jumping into unloaded code. Calls must happen on the main thread,
+//! so that unloading cannot interleave with a call.
+
+use std::collections::HashMap;
+use std::ffi::{c_char, c_void, CStr, CString};
+use std::fmt;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use sys::{Global, GlobalGuard};
+
+use crate::builtin::{StringName, Variant};
+use crate::classes::Object;
+use crate::global::MethodFlags;
+use crate::meta::{
+    ClassId, EngineFromGodot, GodotConvert, GodotType, OutParamTuple, ParamTuple, Signature,
+};
+use crate::obj::Gd;
+use crate::registry::method::MethodParamOrReturnInfo;
+use crate::sys;
+
+#[cfg(since_api = "4.4")]
+pub use crate::shared_proxy;
+
+/// Version of the C-ABI layout. Tables with a different version are rejected.
+///
+/// Must be increased on every change to [`ClassTable`] or [`MethodEntry`].
+const ABI_VERSION: u32 = 1;
+
+/// Static method registered on each shared class, returning the table address as `int` (0 once the class is unpublished).
+///
+/// The table is looked up through `ClassDB`, because methods registered there cannot be added or overridden by scripts -- unlike
+/// e.g. metadata. Starts with an underscore, so that it is hidden from the editor's documentation and autocompletion.
+const TABLE_METHOD: &str = "_gdext_shared_class_table";
+
+/// Published by the providing extension, read by consuming extensions. Layout must stay stable within an [`ABI_VERSION`].
+///
+/// Allocated with Godot's allocator and never freed, so that consumers can still read `is_alive` after the provider has been unloaded.
+#[repr(C)]
+struct ClassTable {
+    abi_version: u32,
+
+    /// Cleared by the providing extension before its class is unregistered. All other fields are only valid while this is set.
+    is_alive: AtomicBool,
+
+    method_count: usize,
+    methods: *const MethodEntry,
+
+    /// Calls `methods[method_index]` on `object` (null for static methods). Returns false if `object` is not an instance of the class.
+    /// Must only be called while `is_alive` is set.
+    call: unsafe extern "C" fn(
+        table: *const ClassTable,
+        method_index: usize,
+        object: sys::GDExtensionObjectPtr,
+        args: *const sys::GDExtensionConstTypePtr,
+        ret: sys::GDExtensionTypePtr,
+    ) -> sys::GDExtensionBool,
+
+    // Only accessed by the providing extension.
+    class_tag: *mut c_void,
+}
+
+#[repr(C)]
+struct MethodEntry {
+    /// Name under which the method is registered in Godot. UTF-8, NUL-terminated.
+    name: *const c_char,
+
+    /// Signature descriptor, see [`signature_descriptor()`]. ASCII, NUL-terminated.
+    signature: *const c_char,
+
+    is_static: sys::GDExtensionBool,
+
+    // Only accessed by the providing extension.
+    ptrcall_fn: sys::GDExtensionClassMethodPtrCall,
+}
+
+/// Describes the parts of a signature that affect the ptrcall ABI and type safety, e.g. `2(4,24:Node)` for `fn(GString, Gd<Node>) -> i64`.
+///
+/// Uses variant type ordinals instead of names, since these are stable across Godot and godot-rust versions.
+fn signature_descriptor(
+    params: &[MethodParamOrReturnInfo],
+    ret: Option<&MethodParamOrReturnInfo>,
+) -> String {
+    use crate::obj::EngineEnum as _;
+
+    let describe = |info: &MethodParamOrReturnInfo| {
+        let info = &info.info;
+        let ord = info.variant_type.ord();
+
+        if info.variant_type == sys::VariantType::OBJECT {
+            format!("{ord}:{}", info.class_id)
+        } else {
+            ord.to_string()
+        }
+    };
+
+    let ret = ret.map(describe).unwrap_or_else(|| "void".to_string());
+    let params: Vec<String> = params.iter().map(describe).collect();
+
+    format!("{ret}({})", params.join(","))
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Providing side
+
+/// Owns the data that a published table points to. The table itself is never freed, see [`ClassTable`].
+struct PublishedClass {
+    table: NonNull<ClassTable>,
+    _methods: Vec<MethodEntry>,
+    _strings: Vec<CString>,
+}
+
+// SAFETY: the raw pointers in the table only point into heap data owned by the same `PublishedClass`, which is never mutated after
+// creation, apart from the atomic `is_alive` flag. Consuming extensions only read it.
+unsafe impl Send for PublishedClass {}
+
+struct PendingMethod {
+    name: CString,
+    signature: CString,
+    is_static: bool,
+    ptrcall_fn: sys::GDExtensionClassMethodPtrCall,
+}
+
+#[derive(Default)]
+struct SharedClasses {
+    /// Classes whose methods are currently being registered, with the methods collected so far.
+    pending: HashMap<ClassId, Vec<PendingMethod>>,
+    published: HashMap<ClassId, PublishedClass>,
+}
+
+fn global_shared_classes() -> GlobalGuard<'static, SharedClasses> {
+    static SHARED_CLASSES: Global<SharedClasses> = Global::default();
+
+    SHARED_CLASSES.lock()
+}
+
+/// Starts collecting methods of `class_id`. Called during class registration, before methods are registered.
+pub(crate) fn begin_class(class_id: ClassId) {
+    global_shared_classes().pending.insert(class_id, Vec::new());
+}
+
+/// Records a method if its class is shared. Called for every registered method.
+pub(crate) fn on_method_registered(
+    class_id: ClassId,
+    method_name: &StringName,
+    params: &[MethodParamOrReturnInfo],
+    ret: Option<&MethodParamOrReturnInfo>,
+    is_static: bool,
+    ptrcall_fn: sys::GDExtensionClassMethodPtrCall,
+) {
+    let mut shared = global_shared_classes();
+    let Some(methods) = shared.pending.get_mut(&class_id) else {
+        return;
+    };
+
+    let signature = signature_descriptor(params, ret);
+    methods.push(PendingMethod {
+        name: CString::new(method_name.to_string()).expect("method name contains NUL"),
+        signature: CString::new(signature).expect("signature is ASCII"),
+        is_static,
+        ptrcall_fn,
+    });
+}
+
+/// Publishes the table with all methods collected since [`begin_class()`].
+pub(crate) fn publish_class(class_id: ClassId) {
+    let methods = global_shared_classes()
+        .pending
+        .remove(&class_id)
+        .unwrap_or_default();
+
+    let mut strings = Vec::with_capacity(2 * methods.len());
+    let entries: Vec<MethodEntry> = methods
+        .into_iter()
+        .map(|method| {
+            let entry = MethodEntry {
+                name: method.name.as_ptr(),
+                signature: method.signature.as_ptr(),
+                is_static: sys::conv::bool_to_sys(method.is_static),
+                ptrcall_fn: method.ptrcall_fn,
+            };
+
+            // Moving a CString does not move its heap buffer, so the pointers stay valid.
+            strings.push(method.name);
+            strings.push(method.signature);
+            entry
+        })
+        .collect();
+
+    // SAFETY: the class has just been registered.
+    let class_tag = unsafe { sys::interface_fn!(classdb_get_class_tag)(class_id.string_sys()) };
+
+    let table = ClassTable {
+        abi_version: ABI_VERSION,
+        is_alive: AtomicBool::new(true),
+        method_count: entries.len(),
+        methods: entries.as_ptr(),
+        call: call_shared_method,
+        class_tag,
+    };
+
+    // Godot's allocator outlives all extensions, unlike the one of this library (which may be statically linked into it).
+    // SAFETY: `mem_alloc` has no preconditions. Godot allocations are aligned for any primitive type, like `malloc`.
+    let table_ptr = unsafe { sys::interface_fn!(mem_alloc)(std::mem::size_of::<ClassTable>()) };
+    let table_ptr = NonNull::new(table_ptr.cast::<ClassTable>()).expect("out of memory");
+
+    // SAFETY: `table_ptr` points to a fresh allocation of the right size and alignment.
+    unsafe { table_ptr.as_ptr().write(table) };
+
+    register_table_method(class_id, table_ptr);
+
+    global_shared_classes().published.insert(
+        class_id,
+        PublishedClass {
+            table: table_ptr,
+            _methods: entries,
+            _strings: strings,
+        },
+    );
+}
+
+/// Withdraws the table of a shared class. Called before the class is unregistered.
+///
+/// The table is marked as dead, but not freed: consumers may still hold a pointer to it.
+pub(crate) fn unpublish_class(class_id: ClassId) {
+    let Some(published) = global_shared_classes().published.remove(&class_id) else {
+        return;
+    };
+
+    // SAFETY: tables are never freed. Release ordering pairs with the Acquire load in `SharedClass::is_alive()`.
+    unsafe { published.table.as_ref() }
+        .is_alive
+        .store(false, Ordering::Release);
+}
+
+/// Marks the table of `class_name` as dead, as if the providing extension had been unloaded.
+///
+/// The class stays registered, but can no longer be found or called through shared proxies.
+#[cfg(feature = "trace")] // itest only.
+#[doc(hidden)]
+pub fn __unpublish_for_test(class_name: &str) {
+    unpublish_class(ClassId::__dynamic(class_name));
+}
+
+/// Registers [`TABLE_METHOD`] on the class, returning the address of `table`.
+fn register_table_method(class_id: ClassId, table: NonNull<ClassTable>) {
+    use crate::obj::EngineBitfield as _;
+
+    let method_name = StringName::from(TABLE_METHOD);
+    let mut return_info_sys = <i64 as GodotType>::property_info("").property_sys();
+
+    let method_info_sys = sys::GDExtensionClassMethodInfo {
+        name: sys::SysPtr::force_mut(method_name.string_sys()),
+        method_userdata: table.as_ptr().cast::<c_void>(),
+        call_func: Some(table_method_call),
+        ptrcall_func: Some(table_method_ptrcall),
+        method_flags: crate::builtin::to_u32(MethodFlags::STATIC.ord()),
+        has_return_value: sys::conv::SYS_TRUE,
+        return_value_info: std::ptr::addr_of_mut!(return_info_sys),
+        return_value_metadata: <i64 as GodotType>::param_metadata(),
+        argument_count: 0,
+        arguments_info: std::ptr::null_mut(),
+        arguments_metadata: std::ptr::null_mut(),
+        default_argument_count: 0,
+        default_arguments: std::ptr::null_mut(),
+    };
+
+    // SAFETY: all pointers in `method_info_sys` are valid for the duration of the call; null pointers are accompanied by zero counts.
+    unsafe {
+        sys::interface_fn!(classdb_register_extension_class_method)(
+            sys::get_library(),
+            class_id.string_sys(),
+            std::ptr::addr_of!(method_info_sys),
+        )
+    }
+}
+
+/// Address of the table in `method_userdata`, or 0 if the class has been unpublished.
+fn table_address(method_userdata: *mut c_void) -> i64 {
+    let table = method_userdata.cast::<ClassTable>();
+
+    // SAFETY: `method_userdata` is the table passed in `register_table_method()`, which is never freed.
+    let is_alive = unsafe { (*table).is_alive.load(Ordering::Acquire) };
+
+    if is_alive {
+        table as i64
+    } else {
+        0
+    }
+}
+
+unsafe extern "C" fn table_method_call(
+    method_userdata: *mut c_void,
+    _instance: sys::GDExtensionClassInstancePtr,
+    _args: *const sys::GDExtensionConstVariantPtr,
+    _arg_count: sys::GDExtensionInt,
+    ret: sys::GDExtensionVariantPtr,
+    _err: *mut sys::GDExtensionCallError,
+) {
+    let address = Variant::from(table_address(method_userdata));
+
+    // SAFETY: Godot passes a valid variant pointer for the return value.
+    unsafe { address.move_into_var_ptr(ret) };
+}
+
+unsafe extern "C" fn table_method_ptrcall(
+    method_userdata: *mut c_void,
+    _instance: sys::GDExtensionClassInstancePtr,
+    _args: *const sys::GDExtensionConstTypePtr,
+    ret: sys::GDExtensionTypePtr,
+) {
+    // SAFETY: the method is registered with an `int` return value, so `ret` points to an `i64`.
+    unsafe { *ret.cast::<i64>() = table_address(method_userdata) };
+}
+
+/// Implementation of [`ClassTable::call`], runs in the providing extension.
+unsafe extern "C" fn call_shared_method(
+    table: *const ClassTable,
+    method_index: usize,
+    object: sys::GDExtensionObjectPtr,
+    args: *const sys::GDExtensionConstTypePtr,
+    ret: sys::GDExtensionTypePtr,
+) -> sys::GDExtensionBool {
+    // SAFETY: consumers only pass tables obtained from `published`, with an index below `method_count`.
+    let (table, method) = unsafe {
+        let table = &*table;
+        (table, &*table.methods.add(method_index))
+    };
+
+    let Some(ptrcall_fn) = method.ptrcall_fn else {
+        return sys::conv::SYS_FALSE;
+    };
+
+    let instance_ptr = if sys::conv::bool_from_sys(method.is_static) {
+        std::ptr::null_mut()
+    } else {
+        // Reject objects of other classes; their instance binding would not be an `InstanceStorage` of this class.
+        // SAFETY: `object` is a live object, validated by the consumer.
+        let is_instance = !object.is_null()
+            && unsafe { !sys::interface_fn!(object_cast_to)(object, table.class_tag).is_null() };
+
+        if !is_instance {
+            return sys::conv::SYS_FALSE;
+        }
+
+        // The binding for this library's token is the instance storage, see `callbacks::create_custom()`.
+        let callbacks = crate::storage::nop_instance_callbacks();
+
+        // SAFETY: `object` is a live instance of a class registered by this library.
+        let binding = unsafe {
+            sys::interface_fn!(object_get_instance_binding)(
+                object,
+                sys::get_library().cast::<c_void>(),
+                &callbacks,
+            )
+        };
+
+        if binding.is_null() {
+            return sys::conv::SYS_FALSE;
+        }
+
+        binding.cast()
+    };
+
+    // SAFETY: signatures were matched by the consumer, so `args` and `ret` have the layout expected by the registered ptrcall function.
+    unsafe { ptrcall_fn(std::ptr::null_mut(), instance_ptr, args, ret) };
+    sys::conv::SYS_TRUE
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Consuming side
+
+/// Error while resolving a shared class or method, see [`SharedClass::find()`] and [`SharedClass::method()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SharedClassError {
+    /// No loaded extension shares a class with this name.
+    ClassNotFound { class_name: String },
+
+    /// The class is shared by an extension built against an incompatible godot-rust version.
+    AbiMismatch {
+        class_name: String,
+        expected: u32,
+        actual: u32,
+    },
+
+    /// The object is not an instance of the shared class.
+    WrongClass {
+        class_name: String,
+        actual_class: String,
+    },
+
+    /// The shared class has no `#[func]` with this name.
+    MethodNotFound {
+        class_name: String,
+        method_name: String,
+    },
+
+    /// The providing extension has unregistered the class since it was found, e.g. due to unloading or hot reload.
+    ClassUnloaded { class_name: String },
+
+    /// The method exists, but with different parameter or return types.
+    SignatureMismatch {
+        class_name: String,
+        method_name: String,
+        expected: String,
+        actual: String,
+    },
+}
+
+impl fmt::Display for SharedClassError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ClassNotFound { class_name } => {
+                write!(f, "no loaded extension shares class `{class_name}`")
+            }
+            Self::AbiMismatch {
+                class_name,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "class `{class_name}` is shared with ABI version {actual}, but this extension expects version {expected}"
+            ),
+            Self::ClassUnloaded { class_name } => {
+                write!(f, "shared class `{class_name}` has been unloaded by its providing extension")
+            }
+            Self::WrongClass {
+                class_name,
+                actual_class,
+            } => write!(
+                f,
+                "object of class `{actual_class}` is not an instance of shared class `{class_name}`"
+            ),
+            Self::MethodNotFound {
+                class_name,
+                method_name,
+            } => write!(f, "shared class `{class_name}` has no method `{method_name}`"),
+            Self::SignatureMismatch {
+                class_name,
+                method_name,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "method `{class_name}::{method_name}` has signature `{actual}`, but proxy declares `{expected}`"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SharedClassError {}
+
+/// Handle to the table of a class shared by another extension.
+#[derive(Copy, Clone, Debug)]
+pub struct SharedClass {
+    table: NonNull<ClassTable>,
+    class_name: &'static str,
+}
+
+impl SharedClass {
+    /// Looks up the class `class_name`, as published by another (or the same) extension.
+    ///
+    /// Requires Godot 4.4 or later.
+    #[cfg(since_api = "4.4")]
+    pub fn find(class_name: &'static str) -> Result<Self, SharedClassError> {
+        use crate::classes::ClassDb;
+        use crate::obj::Singleton as _;
+
+        let not_found = || SharedClassError::ClassNotFound {
+            class_name: class_name.to_string(),
+        };
+
+        // Without inheritance: a derived class is only shared if it is declared with `#[class(shared)]` itself.
+        let mut class_db = ClassDb::singleton();
+        let is_shared = class_db
+            .class_has_method_ex(class_name, TABLE_METHOD)
+            .no_inheritance(true)
+            .done();
+
+        if !is_shared {
+            return Err(not_found());
+        }
+
+        // The table method can only be registered by native code, i.e. the extension providing the class. Scripts cannot add static
+        // methods to ClassDB classes, so the address cannot be forged from GDScript.
+        let address = class_db
+            .class_call_static(class_name, TABLE_METHOD, &[])
+            .try_to::<i64>()
+            .unwrap_or(0);
+
+        // 0 if the class has been unpublished.
+        let Some(table) = NonNull::new(address as *mut ClassTable) else {
+            return Err(not_found());
+        };
+
+        // Only read the version before checking it; the rest of the layout may differ.
+        // SAFETY: the address is returned by the table method of the providing extension, and tables are never freed.
+        // `abi_version` is the first field in all versions.
+        let abi_version = unsafe { table.as_ref().abi_version };
+        if abi_version != ABI_VERSION {
+            return Err(SharedClassError::AbiMismatch {
+                class_name: class_name.to_string(),
+                expected: ABI_VERSION,
+                actual: abi_version,
+            });
+        }
+
+        Ok(Self { table, class_name })
+    }
+
+    /// Name of the shared class in Godot.
+    pub fn class_name(&self) -> &'static str {
+        self.class_name
+    }
+
+    /// Whether the providing extension still provides the class.
+    ///
+    /// Returns `false` once the class has been unregistered, e.g. because the providing extension was unloaded or hot-reloaded.
+    /// Methods of a dead class panic when called; look the class up again with [`find()`](Self::find) after a reload.
+    pub fn is_alive(&self) -> bool {
+        // SAFETY: tables are never freed, so the flag can always be read. Acquire pairs with the Release store in `unpublish_class()`.
+        unsafe { self.table.as_ref() }
+            .is_alive
+            .load(Ordering::Acquire)
+    }
+
+    /// Checks that `object` is an instance of this class (or a subclass).
+    pub fn check_instance(&self, object: &Gd<Object>) -> Result<(), SharedClassError> {
+        if object.is_class(self.class_name) {
+            Ok(())
+        } else {
+            Err(SharedClassError::WrongClass {
+                class_name: self.class_name.to_string(),
+                actual_class: object.get_class().to_string(),
+            })
+        }
+    }
+
+    /// Resolves a `#[func]` by name, checking that its signature matches `Params` and `Ret`.
+    pub fn method<Params, Ret>(
+        &self,
+        method_name: &'static str,
+    ) -> Result<SharedMethod<Params, Ret>, SharedClassError>
+    where
+        Params: ParamTuple,
+        Ret: GodotConvert,
+    {
+        let param_names = vec![""; Params::LEN];
+        let params = Signature::<Params, Ret>::param_names(&param_names);
+        let ret = Ret::Via::return_info();
+        let expected = signature_descriptor(&params, ret.as_ref());
+
+        let Some(table) = self.live_table() else {
+            return Err(SharedClassError::ClassUnloaded {
+                class_name: self.class_name.to_string(),
+            });
+        };
+
+        // SAFETY: `methods` points to `method_count` entries with NUL-terminated strings, see `publish_class()`.
+        let methods = unsafe { std::slice::from_raw_parts(table.methods, table.method_count) };
+        let found = methods.iter().enumerate().find(|(_, method)| {
+            // SAFETY: see above.
+            unsafe { CStr::from_ptr(method.name) }.to_bytes() == method_name.as_bytes()
+        });
+
+        let Some((index, method)) = found else {
+            return Err(SharedClassError::MethodNotFound {
+                class_name: self.class_name.to_string(),
+                method_name: method_name.to_string(),
+            });
+        };
+
+        // SAFETY: see above.
+        let actual = unsafe { CStr::from_ptr(method.signature) }.to_string_lossy();
+        if actual != expected {
+            return Err(SharedClassError::SignatureMismatch {
+                class_name: self.class_name.to_string(),
+                method_name: method_name.to_string(),
+                expected,
+                actual: actual.into_owned(),
+            });
+        }
+
+        Ok(SharedMethod {
+            class: *self,
+            index,
+            method_name,
+            is_static: sys::conv::bool_from_sys(method.is_static),
+            _signature: PhantomData,
+        })
+    }
+
+    /// The table, if the class is still provided.
+    fn live_table(&self) -> Option<&ClassTable> {
+        // SAFETY: validated in `find()`; tables are never freed. Fields other than `is_alive` are only read while it is set.
+        self.is_alive().then(|| unsafe { self.table.as_ref() })
+    }
+}
+
+/// A `#[func]` of a shared class, resolved with a matching signature. Obtained via [`SharedClass::method()`].
+pub struct SharedMethod<Params, Ret> {
+    class: SharedClass,
+    index: usize,
+    method_name: &'static str,
+    is_static: bool,
+    _signature: PhantomData<fn(Params) -> Ret>,
+}
+
+impl<Params, Ret> SharedMethod<Params, Ret>
+where
+    Params: OutParamTuple,
+    Ret: EngineFromGodot,
+{
+    /// Calls the method on `object`, or as a static function if `object` is `None`.
+    ///
+    /// # Panics
+    /// - If `object` is dead, or not an instance of the shared class.
+    /// - If `object` is `None` for a non-static method, or `Some` for a static one.
+    /// - If the providing extension has unregistered the class, see [`SharedClass::is_alive()`].
+    pub fn call(&self, object: Option<&Gd<Object>>, args: Params) -> Ret {
+        assert_eq!(
+            object.is_none(),
+            self.is_static,
+            "shared method `{}::{}`: object must be provided exactly for non-static methods",
+            self.class.class_name,
+            self.method_name,
+        );
+
+        let Some(table) = self.class.live_table() else {
+            panic!(
+                "shared method `{}::{}` called after the providing extension unregistered the class",
+                self.class.class_name, self.method_name,
+            )
+        };
+
+        let validated_obj = object.map(|object| object.raw.validated_object());
+
+        // SAFETY: the signature was checked in `SharedClass::method()`, so `args` and `Ret` match the ptrcall layout of the method.
+        unsafe {
+            Signature::<Params, Ret>::out_shared_ptrcall(
+                self.class.class_name,
+                self.method_name,
+                args,
+                |explicit_args, return_ptr| {
+                    let is_called = (table.call)(
+                        table,
+                        self.index,
+                        crate::obj::ValidatedObject::object_ptr(validated_obj.as_ref()),
+                        explicit_args.as_ptr(),
+                        return_ptr,
+                    );
+
+                    assert!(
+                        sys::conv::bool_from_sys(is_called),
+                        "shared method `{}::{}` called on an object of another class",
+                        self.class.class_name,
+                        self.method_name,
+                    );
+                },
+            )
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Proxy declaration
+
+/// Declares a typed proxy for a class shared by another extension.
+///
+/// The proxy wraps a `Gd<Object>` and offers the declared methods, which are forwarded through the providing extension's C-ABI table
+/// (see [module docs](crate::registry::shared)). All methods are resolved and checked once, when the proxy is created.
+///
+/// Methods take `&self` or `&mut self` and up to the usual number of parameters. Static functions can be called via
+/// [`SharedClass::method()`].
+///
+/// Requires Godot 4.4 or later.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::register::shared::shared_proxy;
+///
+/// shared_proxy! {
+///     /// Inventory class from the inventory extension.
+///     pub struct Inventory(class = "Inventory") {
+///         fn add_item(&mut self, name: GString) -> i64;
+///         fn item_count(&self) -> i64;
+///         fn clear(&mut self);
+///     }
+/// }
+///
+/// fn inspect(object: Gd<Object>) {
+///     match Inventory::try_from_object(object) {
+///         Ok(inventory) => godot_print!("{} items", inventory.item_count()),
+///         Err(err) => godot_error!("{err}"),
+///     }
+/// }
+/// ```
+#[cfg(since_api = "4.4")]
+#[macro_export]
+macro_rules! shared_proxy {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $Proxy:ident(class = $class_name:literal) {
+            $(
+                $(#[$fn_attr:meta])*
+                fn $method:ident(& $($receiver:ident)+ $(, $param:ident : $Param:ty)* $(,)?) $(-> $Ret:ty)?;
+            )*
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $Proxy {
+            __object: $crate::obj::Gd<$crate::classes::Object>,
+            __class: $crate::registry::shared::SharedClass,
+            $(
+                $method: $crate::registry::shared::SharedMethod<
+                    ($($Param,)*),
+                    $crate::shared_proxy!(@ret $($Ret)?)
+                >,
+            )*
+        }
+
+        #[allow(dead_code)]
+        impl $Proxy {
+            /// Name of the shared class in Godot.
+            pub const CLASS_NAME: &'static str = $class_name;
+
+            /// Creates a proxy for `object`, resolving all declared methods.
+            ///
+            /// Fails if no loaded extension shares the class, if `object` is not an instance of it, or if a method is missing or has
+            /// a different signature.
+            pub fn try_from_object(
+                object: $crate::obj::Gd<$crate::classes::Object>,
+            ) -> ::std::result::Result<Self, $crate::registry::shared::SharedClassError> {
+                let class = $crate::registry::shared::SharedClass::find($class_name)?;
+                class.check_instance(&object)?;
+
+                ::std::result::Result::Ok(Self {
+                    $(
+                        $method: class.method(stringify!($method))?,
+                    )*
+                    __object: object,
+                    __class: class,
+                })
+            }
+
+            /// Like [`try_from_object()`](Self::try_from_object), but panics on error.
+            pub fn from_object(object: $crate::obj::Gd<$crate::classes::Object>) -> Self {
+                match Self::try_from_object(object) {
+                    ::std::result::Result::Ok(proxy) => proxy,
+                    ::std::result::Result::Err(err) => panic!("{err}"),
+                }
+            }
+
+            /// The wrapped object.
+            pub fn object(&self) -> &$crate::obj::Gd<$crate::classes::Object> {
+                &self.__object
+            }
+
+            /// Whether the providing extension still provides the class. Method calls panic once this returns `false`.
+            pub fn is_alive(&self) -> bool {
+                self.__class.is_alive()
+            }
+
+            $(
+                $crate::shared_proxy!(@method
+                    [$(#[$fn_attr])*] $method [$($receiver)+] ($($param : $Param),*) [$crate::shared_proxy!(@ret $($Ret)?)]
+                );
+            )*
+        }
+    };
+
+    (@ret) => { () };
+    (@ret $Ret:ty) => { $Ret };
+
+    (@method [$(#[$fn_attr:meta])*] $method:ident [mut self] ($($param:ident : $Param:ty),*) [$Ret:ty]) => {
+        $(#[$fn_attr])*
+        pub fn $method(&mut self, $($param: $Param),*) -> $Ret {
+            self.$method.call(::std::option::Option::Some(&self.__object), ($($param,)*))
+        }
+    };
+
+    (@method [$(#[$fn_attr:meta])*] $method:ident [self] ($($param:ident : $Param:ty),*) [$Ret:ty]) => {
+        $(#[$fn_attr])*
+        pub fn $method(&self, $($param: $Param),*) -> $Ret {
+            self.$method.call(::std::option::Option::Some(&self.__object), ($($param,)*))
+        }
+    };
+}
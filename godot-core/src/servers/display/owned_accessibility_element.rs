@@ -20,6 +20,7 @@ impl OwnedAccessibilityElement {
     pub fn new(window_id: i32, role: crate::classes::display_server::AccessibilityRole) -> Self {
         let mut server = DisplayServer::singleton();
         let rid = server.accessibility_create_element(window_id, role);
+        // SAFETY: `rid` was just created by the server and is only owned by the returned wrapper.
         unsafe { Self::from_rid(rid) }
     }
 }
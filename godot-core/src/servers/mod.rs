@@ -9,6 +9,9 @@
 
 pub mod rendering;
 
+pub(crate) mod rid_tracker;
+pub use rid_tracker::{debug_live_rids, LiveRidCount};
+
 #[cfg(feature = "codegen-full")]
 pub mod display;
 
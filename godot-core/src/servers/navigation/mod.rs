@@ -6,7 +6,12 @@
  */
 
 //! RAII wrappers for `NavigationServer2D` and `NavigationServer3D` resources.
+//!
+//! Path queries are built with [`NavPathQuery2D`] and [`NavPathQuery3D`], and return a [`NavPath`] holding points together with
+//! per-point metadata. Since Godot 4.4, `query_path_async()` returns a future instead. Agents deliver their avoidance results to Rust
+//! closures, see [`OwnedAgent3D::set_avoidance_callback()`].
 
+mod nav_path;
 pub mod owned_agent_2d;
 pub mod owned_agent_3d;
 pub mod owned_link_2d;
@@ -17,6 +22,12 @@ pub mod owned_obstacle_2d;
 pub mod owned_obstacle_3d;
 pub mod owned_region_2d;
 pub mod owned_region_3d;
+mod path_query_2d;
+mod path_query_3d;
+
+#[cfg(since_api = "4.4")]
+pub use nav_path::NavPathFuture;
+pub use nav_path::{NavPath, NavSegmentType};
 
 pub use owned_agent_2d::OwnedAgent2D;
 pub use owned_agent_3d::OwnedAgent3D;
@@ -28,6 +39,8 @@ pub use owned_obstacle_2d::OwnedObstacle2D;
 pub use owned_obstacle_3d::OwnedObstacle3D;
 pub use owned_region_2d::OwnedRegion2D;
 pub use owned_region_3d::OwnedRegion3D;
+pub use path_query_2d::NavPathQuery2D;
+pub use path_query_3d::NavPathQuery3D;
 
 impl crate::classes::NavigationServer2D {
     /// Creates a new navigation map and returns a wrapper that will free it on drop.
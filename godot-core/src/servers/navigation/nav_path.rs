@@ -0,0 +1,156 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{real, Array, Rid, Vector2, Vector3};
+use crate::obj::InstanceId;
+
+/// Kind of navigation primitive that a path point lies on.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum NavSegmentType {
+    /// The point is on a navigation region polygon.
+    Region,
+
+    /// The point is on a navigation link.
+    Link,
+}
+
+/// Result of a navigation path query, see [`NavPathQuery2D`](super::NavPathQuery2D) and [`NavPathQuery3D`](super::NavPathQuery3D).
+///
+/// `V` is `Vector2` or `Vector3`. Apart from `points`, the vectors are only filled if the corresponding metadata was requested in the
+/// query (which is the default); they have one entry per point.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NavPath<V> {
+    /// Path points, from start to target.
+    pub points: Vec<V>,
+
+    /// Type of primitive each point lies on.
+    pub types: Vec<NavSegmentType>,
+
+    /// RID of the region or link each point lies on.
+    pub rids: Vec<Rid>,
+
+    /// Object that owns the region or link each point lies on, e.g. a `NavigationRegion3D` node.
+    ///
+    /// `None` for primitives created directly through the navigation server.
+    pub owners: Vec<Option<InstanceId>>,
+}
+
+impl<V> NavPath<V> {
+    pub(super) fn from_parts(
+        points: Vec<V>,
+        types: &[i32],
+        rids: Array<Rid>,
+        owner_ids: &[i64],
+    ) -> Self {
+        // Matches PathSegmentType in NavigationPathQueryResult2D/3D.
+        let types = types
+            .iter()
+            .map(|&ty| match ty {
+                1 => NavSegmentType::Link,
+                _ => NavSegmentType::Region,
+            })
+            .collect();
+
+        let owners = owner_ids
+            .iter()
+            .map(|&id| InstanceId::try_from_i64(id))
+            .collect();
+
+        Self {
+            points,
+            types,
+            rids: rids.iter_shared().collect(),
+            owners,
+        }
+    }
+
+    /// Returns `true` if no path was found.
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Number of points.
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+}
+
+impl NavPath<Vector2> {
+    /// Sum of the distances between consecutive points.
+    pub fn length(&self) -> real {
+        self.points
+            .windows(2)
+            .map(|pair| pair[0].distance_to(pair[1]))
+            .sum()
+    }
+}
+
+impl NavPath<Vector3> {
+    /// Sum of the distances between consecutive points.
+    pub fn length(&self) -> real {
+        self.points
+            .windows(2)
+            .map(|pair| pair[0].distance_to(pair[1]))
+            .sum()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Async queries
+
+#[cfg(since_api = "4.4")]
+pub use query_async::NavPathFuture;
+
+#[cfg(since_api = "4.4")]
+mod query_async {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    use super::NavPath;
+    use crate::builtin::Callable;
+    use crate::task::CallableFuture;
+
+    /// Future returned by `query_path_async()` on the navigation servers.
+    ///
+    /// Resolves to the path once the server invokes the query's callback, or to `None` if the server discarded the query without
+    /// invoking it, e.g. because the map RID was invalid. Await it inside a [`godot::task::spawn()`](crate::task::spawn) task.
+    pub struct NavPathFuture<V> {
+        // The callback only marks the query as finished. It does not touch the result object, so that it can be invoked on a worker
+        // thread; the result is read once the future is polled.
+        inner: CallableFuture<()>,
+        read_result: Box<dyn Fn() -> NavPath<V>>,
+    }
+
+    impl<V> NavPathFuture<V> {
+        /// Returns the future together with the callback to pass to the server. `read_result` converts the query result object.
+        pub(in crate::servers::navigation) fn new(
+            read_result: impl Fn() -> NavPath<V> + 'static,
+        ) -> (Self, Callable) {
+            let (inner, callback) = CallableFuture::new("NavPathFuture", |_args| ());
+
+            let future = Self {
+                inner,
+                read_result: Box::new(read_result),
+            };
+
+            (future, callback)
+        }
+    }
+
+    impl<V> Future for NavPathFuture<V> {
+        type Output = Option<NavPath<V>>;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let this = self.get_mut();
+
+            Pin::new(&mut this.inner)
+                .poll(cx)
+                .map(|finished| finished.map(|()| (this.read_result)()))
+        }
+    }
+}
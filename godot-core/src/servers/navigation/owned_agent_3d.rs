@@ -5,6 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use crate::builtin::{real, Callable, Rid, Vector3};
 use crate::classes::NavigationServer3D;
 use crate::obj::Singleton;
 
@@ -21,6 +22,70 @@ impl OwnedAgent3D {
     /// See `NavigationServer3D.agent_create()`.
     pub fn new() -> Self {
         let rid = NavigationServer3D::singleton().agent_create();
-        Self { rid }
+        // SAFETY: `rid` was just created by the server and is only owned by the returned wrapper.
+        unsafe { Self::from_rid(rid) }
+    }
+
+    /// Places the agent on a navigation map, or removes it from its map if `map` is invalid.
+    ///
+    /// See `NavigationServer3D.agent_set_map()`.
+    pub fn set_map(&mut self, map: Rid) {
+        NavigationServer3D::singleton().agent_set_map(self.rid, map);
+    }
+
+    /// Enables or disables avoidance. Only agents with avoidance enabled receive avoidance callbacks.
+    ///
+    /// See `NavigationServer3D.agent_set_avoidance_enabled()`.
+    pub fn set_avoidance_enabled(&mut self, enabled: bool) {
+        NavigationServer3D::singleton().agent_set_avoidance_enabled(self.rid, enabled);
+    }
+
+    /// Sets the position of the agent.
+    ///
+    /// See `NavigationServer3D.agent_set_position()`.
+    pub fn set_position(&mut self, position: Vector3) {
+        NavigationServer3D::singleton().agent_set_position(self.rid, position);
+    }
+
+    /// Sets the velocity the agent wants to move with; avoidance computes a safe velocity from it.
+    ///
+    /// See `NavigationServer3D.agent_set_velocity()`.
+    pub fn set_velocity(&mut self, velocity: Vector3) {
+        NavigationServer3D::singleton().agent_set_velocity(self.rid, velocity);
+    }
+
+    /// Sets the radius used for avoidance.
+    ///
+    /// See `NavigationServer3D.agent_set_radius()`.
+    pub fn set_radius(&mut self, radius: real) {
+        NavigationServer3D::singleton().agent_set_radius(self.rid, radius);
+    }
+
+    /// Sets the maximum speed, which also bounds the safe velocity.
+    ///
+    /// See `NavigationServer3D.agent_set_max_speed()`.
+    pub fn set_max_speed(&mut self, max_speed: real) {
+        NavigationServer3D::singleton().agent_set_max_speed(self.rid, max_speed);
+    }
+
+    /// Registers a function that receives the safe velocity after each avoidance step.
+    ///
+    /// Replaces any previous callback. The callback is invoked on the main thread, during the navigation server's update.
+    ///
+    /// See `NavigationServer3D.agent_set_avoidance_callback()`.
+    pub fn set_avoidance_callback(&mut self, mut callback: impl FnMut(Vector3) + 'static) {
+        let callable = Callable::from_fn("avoidance_callback", move |args| {
+            if let Some(Ok(safe_velocity)) = args.first().map(|arg| arg.try_to::<Vector3>()) {
+                callback(safe_velocity);
+            }
+        });
+
+        NavigationServer3D::singleton().agent_set_avoidance_callback(self.rid, &callable);
+    }
+
+    /// Removes the avoidance callback.
+    pub fn clear_avoidance_callback(&mut self) {
+        NavigationServer3D::singleton()
+            .agent_set_avoidance_callback(self.rid, &Callable::invalid());
     }
 }
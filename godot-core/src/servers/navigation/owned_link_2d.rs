@@ -21,6 +21,7 @@ impl OwnedLink2D {
     /// See `NavigationServer2D.link_create()`.
     pub fn new() -> Self {
         let rid = NavigationServer2D::singleton().link_create();
-        Self { rid }
+        // SAFETY: `rid` was just created by the server and is only owned by the returned wrapper.
+        unsafe { Self::from_rid(rid) }
     }
 }
@@ -22,6 +22,7 @@ impl OwnedLink3D {
     /// See `NavigationServer3D.link_create()`.
     pub fn new() -> Self {
         let rid = NavigationServer3D::singleton().link_create();
-        Self { rid }
+        // SAFETY: `rid` was just created by the server and is only owned by the returned wrapper.
+        unsafe { Self::from_rid(rid) }
     }
 }
@@ -5,8 +5,10 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use crate::builtin::Vector2;
 use crate::classes::NavigationServer2D;
 use crate::obj::Singleton;
+use crate::servers::navigation::NavPathQuery2D;
 
 crate::obj::impl_owned_rid!(
     OwnedMap2D,
@@ -22,6 +24,21 @@ impl OwnedMap2D {
     /// See `NavigationServer2D.map_create()`.
     pub fn new() -> Self {
         let rid = NavigationServer2D::singleton().map_create();
-        Self { rid }
+        // SAFETY: `rid` was just created by the server and is only owned by the returned wrapper.
+        unsafe { Self::from_rid(rid) }
+    }
+
+    /// Activates or deactivates the map. Only active maps are updated and can be queried.
+    ///
+    /// See `NavigationServer2D.map_set_active()`.
+    pub fn set_active(&mut self, active: bool) {
+        NavigationServer2D::singleton().map_set_active(self.rid, active);
+    }
+
+    /// Returns a path query on this map, from `start` to `target`.
+    ///
+    /// Configure it further and run it through [`NavigationServer2D::query_path_typed()`].
+    pub fn path_query(&self, start: Vector2, target: Vector2) -> NavPathQuery2D {
+        NavPathQuery2D::new(self.rid, start, target)
     }
 }
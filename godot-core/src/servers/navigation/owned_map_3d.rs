@@ -5,8 +5,10 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use crate::builtin::Vector3;
 use crate::classes::NavigationServer3D;
 use crate::obj::Singleton;
+use crate::servers::navigation::NavPathQuery3D;
 
 crate::obj::impl_owned_rid!(
     OwnedMap3D,
@@ -22,6 +24,21 @@ impl OwnedMap3D {
     /// See `NavigationServer3D.map_create()`.
     pub fn new() -> Self {
         let rid = NavigationServer3D::singleton().map_create();
-        Self { rid }
+        // SAFETY: `rid` was just created by the server and is only owned by the returned wrapper.
+        unsafe { Self::from_rid(rid) }
+    }
+
+    /// Activates or deactivates the map. Only active maps are updated and can be queried.
+    ///
+    /// See `NavigationServer3D.map_set_active()`.
+    pub fn set_active(&mut self, active: bool) {
+        NavigationServer3D::singleton().map_set_active(self.rid, active);
+    }
+
+    /// Returns a path query on this map, from `start` to `target`.
+    ///
+    /// Configure it further and run it through [`NavigationServer3D::query_path_typed()`].
+    pub fn path_query(&self, start: Vector3, target: Vector3) -> NavPathQuery3D {
+        NavPathQuery3D::new(self.rid, start, target)
     }
 }
@@ -22,6 +22,7 @@ impl OwnedRegion2D {
     /// See `NavigationServer2D.region_create()`.
     pub fn new() -> Self {
         let rid = NavigationServer2D::singleton().region_create();
-        Self { rid }
+        // SAFETY: `rid` was just created by the server and is only owned by the returned wrapper.
+        unsafe { Self::from_rid(rid) }
     }
 }
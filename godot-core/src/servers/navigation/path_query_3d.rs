@@ -0,0 +1,170 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#[cfg(since_api = "4.3")]
+use crate::builtin::real;
+use crate::builtin::{Rid, Vector3};
+use crate::classes::navigation_path_query_parameters_3d::{
+    PathMetadataFlags, PathPostProcessing, PathfindingAlgorithm,
+};
+use crate::classes::{NavigationPathQueryParameters3D, NavigationPathQueryResult3D};
+use crate::obj::{Gd, NewGd};
+use crate::servers::navigation::NavPath;
+#[cfg(since_api = "4.4")]
+use crate::servers::navigation::NavPathFuture;
+
+/// Typed builder for `NavigationPathQueryParameters3D`.
+///
+/// Execute the query with [`NavigationServer3D::query_path_typed()`](crate::classes::NavigationServer3D::query_path_typed), or
+/// asynchronously with `query_path_async()`.
+///
+/// The query stores its settings as plain values; the `NavigationPathQueryParameters3D` object is only created when the query runs.
+/// Cloning a query thus yields an independent copy.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::classes::NavigationServer3D;
+/// # use godot::obj::Singleton;
+/// # use godot::navigation::NavPathQuery3D;
+/// # fn find(map: Rid) {
+/// let query = NavPathQuery3D::new(map, Vector3::ZERO, Vector3::new(10.0, 0.0, 5.0))
+///     .navigation_layers(0b11);
+///
+/// let path = NavigationServer3D::singleton().query_path_typed(&query);
+/// for point in &path.points {
+///     godot_print!("{point}");
+/// }
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct NavPathQuery3D {
+    map: Rid,
+    start: Vector3,
+    target: Vector3,
+    navigation_layers: Option<u32>,
+    algorithm: Option<PathfindingAlgorithm>,
+    postprocessing: Option<PathPostProcessing>,
+    metadata: Option<PathMetadataFlags>,
+    #[cfg(since_api = "4.3")]
+    simplify_epsilon: Option<real>,
+}
+
+impl NavPathQuery3D {
+    /// Creates a query for a path on `map`, from `start` to `target`.
+    pub fn new(map: Rid, start: Vector3, target: Vector3) -> Self {
+        Self {
+            map,
+            start,
+            target,
+            navigation_layers: None,
+            algorithm: None,
+            postprocessing: None,
+            metadata: None,
+            #[cfg(since_api = "4.3")]
+            simplify_epsilon: None,
+        }
+    }
+
+    /// Restricts the query to regions with one of the given navigation layers.
+    pub fn navigation_layers(mut self, layers: u32) -> Self {
+        self.navigation_layers = Some(layers);
+        self
+    }
+
+    /// Sets the pathfinding algorithm.
+    pub fn algorithm(mut self, algorithm: PathfindingAlgorithm) -> Self {
+        self.algorithm = Some(algorithm);
+        self
+    }
+
+    /// Sets how the raw polygon corridor is turned into path points.
+    pub fn postprocessing(mut self, postprocessing: PathPostProcessing) -> Self {
+        self.postprocessing = Some(postprocessing);
+        self
+    }
+
+    /// Selects which per-point metadata is collected into [`NavPath::types`], [`NavPath::rids`] and [`NavPath::owners`].
+    pub fn metadata(mut self, flags: PathMetadataFlags) -> Self {
+        self.metadata = Some(flags);
+        self
+    }
+
+    /// Simplifies the path, removing points that deviate less than `epsilon` from a straight line.
+    #[cfg(since_api = "4.3")]
+    pub fn simplify(mut self, epsilon: real) -> Self {
+        self.simplify_epsilon = Some(epsilon);
+        self
+    }
+
+    /// Creates a new parameters object with the settings of this query, for settings without a dedicated method.
+    ///
+    /// Changes to the returned object do not affect the query. Run it with `NavigationServer3D.query_path()`.
+    pub fn to_parameters(&self) -> Gd<NavigationPathQueryParameters3D> {
+        let mut parameters = NavigationPathQueryParameters3D::new_gd();
+        parameters.set_map(self.map);
+        parameters.set_start_position(self.start);
+        parameters.set_target_position(self.target);
+
+        if let Some(layers) = self.navigation_layers {
+            parameters.set_navigation_layers(layers);
+        }
+        if let Some(algorithm) = self.algorithm {
+            parameters.set_pathfinding_algorithm(algorithm);
+        }
+        if let Some(postprocessing) = self.postprocessing {
+            parameters.set_path_postprocessing(postprocessing);
+        }
+        if let Some(flags) = self.metadata {
+            parameters.set_metadata_flags(flags);
+        }
+        #[cfg(since_api = "4.3")]
+        if let Some(epsilon) = self.simplify_epsilon {
+            parameters.set_simplify_path(true);
+            parameters.set_simplify_epsilon(epsilon);
+        }
+
+        parameters
+    }
+}
+
+impl crate::classes::NavigationServer3D {
+    /// Runs a path query and returns the typed result.
+    ///
+    /// See `NavigationServer3D.query_path()`.
+    pub fn query_path_typed(&mut self, query: &NavPathQuery3D) -> NavPath<Vector3> {
+        let result = NavigationPathQueryResult3D::new_gd();
+        self.query_path(&query.to_parameters(), &result);
+
+        path_from_result(&result)
+    }
+
+    /// Runs a path query, and returns a future that resolves once the server reports the query as finished.
+    ///
+    /// See `NavigationServer3D.query_path()` with the `callback` parameter.
+    #[cfg(since_api = "4.4")]
+    pub fn query_path_async(&mut self, query: &NavPathQuery3D) -> NavPathFuture<Vector3> {
+        let result = NavigationPathQueryResult3D::new_gd();
+        let read_result = result.clone();
+        let (future, callback) = NavPathFuture::new(move || path_from_result(&read_result));
+
+        self.query_path_ex(&query.to_parameters(), &result)
+            .callback(&callback)
+            .done();
+
+        future
+    }
+}
+
+fn path_from_result(result: &Gd<NavigationPathQueryResult3D>) -> NavPath<Vector3> {
+    NavPath::from_parts(
+        result.get_path().as_slice().to_vec(),
+        result.get_path_types().as_slice(),
+        result.get_path_rids(),
+        result.get_path_owner_ids().as_slice(),
+    )
+}
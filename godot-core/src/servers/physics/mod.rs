@@ -5,7 +5,19 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-//! RAII wrappers for `PhysicsServer2D` and `PhysicsServer3D` resources.
+//! RAII wrappers for `PhysicsServer2D` and `PhysicsServer3D` resources, and typed space queries.
+//!
+//! The query types extend `PhysicsDirectSpaceState2D`/`3D` with methods such as `cast_ray()`, which take builder-style parameters
+//! ([`RayQuery3D`], [`ShapeQuery3D`], ...) and return structs ([`RayHit3D`], [`ShapeHit3D`], [`MotionResult`], ...) instead of
+//! dictionaries. The direct space state is available from nodes (`get_world_3d()` -> `get_direct_space_state()`) or from an
+//! [`OwnedSpace3D`] via [`direct_state()`](OwnedSpace3D::direct_state).
+//!
+//! Bodies and areas track the resources they depend on. Shapes and spaces are shared through `Arc`, e.g.
+//! [`OwnedBody3D::add_shape()`] and [`OwnedBody3D::set_space()`] keep a reference to the shape and space, which are only freed once
+//! no body or area uses them anymore. A body is always freed on the server before its shapes and space.
+//!
+//! Parameters and states are set through typed enums such as [`BodyParam3D`] and [`BodyState3D`], which pair each parameter with a
+//! value of the right type.
 
 pub mod owned_area_2d;
 pub mod owned_body_2d;
@@ -20,6 +32,12 @@ pub mod owned_shape_3d;
 pub mod owned_soft_body_3d;
 pub mod owned_space_3d;
 
+mod params_2d;
+mod params_3d;
+mod query_common;
+mod space_query_2d;
+mod space_query_3d;
+
 pub use owned_area_2d::OwnedArea2D;
 pub use owned_body_2d::OwnedBody2D;
 pub use owned_joint_2d::OwnedJoint2D;
@@ -33,6 +51,12 @@ pub use owned_shape_3d::OwnedShape3D;
 pub use owned_soft_body_3d::OwnedSoftBody3D;
 pub use owned_space_3d::OwnedSpace3D;
 
+pub use params_2d::{AreaParam2D, BodyParam2D, BodyState2D};
+pub use params_3d::{AreaParam3D, BodyParam3D, BodyState3D};
+pub use query_common::MotionResult;
+pub use space_query_2d::{RayHit2D, RayQuery2D, RestInfo2D, ShapeHit2D, ShapeQuery2D};
+pub use space_query_3d::{RayHit3D, RayQuery3D, RestInfo3D, ShapeHit3D, ShapeQuery3D};
+
 impl crate::classes::PhysicsServer2D {
     /// Creates a new space and returns a wrapper that will free it on drop.
     pub fn space_create_owned(&mut self) -> OwnedSpace2D {
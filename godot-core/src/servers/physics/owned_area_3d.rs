@@ -5,14 +5,24 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::sync::Arc;
+
+use crate::builtin::{to_i32_from_usize, Rid, Transform3D};
 use crate::classes::PhysicsServer3D;
 use crate::obj::Singleton;
+use crate::servers::physics::{AreaParam3D, OwnedShape3D, OwnedSpace3D};
 
 crate::obj::impl_owned_rid!(
     OwnedArea3D,
     PhysicsServer3D,
-    "A RAII wrapper for a 3D physics area RID that is owned by this type.\nThe area is freed when this object is dropped.",
-    @default
+    "A RAII wrapper for a 3D physics area RID that is owned by this type.\nThe area is freed when this object is dropped.\n\n\
+    Shapes and the space added through this wrapper are kept alive for as long as the area uses them, and are only released after \
+    the area has been freed.",
+    @default,
+    deps {
+        shapes: Vec<Arc<OwnedShape3D>>,
+        space: Option<Arc<OwnedSpace3D>>,
+    }
 );
 
 impl OwnedArea3D {
@@ -21,6 +31,108 @@ impl OwnedArea3D {
     /// See `PhysicsServer3D.area_create()`.
     pub fn new() -> Self {
         let rid = PhysicsServer3D::singleton().area_create();
-        Self { rid }
+        // SAFETY: `rid` was just created by the server and is only owned by the returned wrapper.
+        unsafe { Self::from_rid(rid) }
+    }
+
+    /// Attaches `shape` to the area, keeping it alive until it is removed or the area is dropped.
+    ///
+    /// Shapes are indexed in the order they are added, see [`OwnedBody3D::add_shape()`][super::OwnedBody3D::add_shape].
+    pub fn add_shape(&mut self, shape: &Arc<OwnedShape3D>, transform: Transform3D) {
+        PhysicsServer3D::singleton()
+            .area_add_shape_ex(self.rid, shape.rid())
+            .transform(transform)
+            .done();
+
+        self.shapes.push(Arc::clone(shape));
+    }
+
+    /// Detaches the shape at `index` and returns it.
+    ///
+    /// # Panics
+    /// If `index` is out of bounds.
+    pub fn remove_shape(&mut self, index: usize) -> Arc<OwnedShape3D> {
+        let shape = self.shapes.remove(index);
+        PhysicsServer3D::singleton().area_remove_shape(self.rid, to_i32_from_usize(index));
+
+        shape
+    }
+
+    /// Detaches all shapes from the area.
+    pub fn clear_shapes(&mut self) {
+        PhysicsServer3D::singleton().area_clear_shapes(self.rid);
+        self.shapes.clear();
+    }
+
+    /// Shapes currently attached to the area, in index order.
+    pub fn shapes(&self) -> &[Arc<OwnedShape3D>] {
+        &self.shapes
+    }
+
+    /// Sets the transform of the shape at `index`, relative to the area.
+    ///
+    /// # Panics
+    /// If `index` is out of bounds.
+    pub fn set_shape_transform(&mut self, index: usize, transform: Transform3D) {
+        self.check_shape_index(index);
+        PhysicsServer3D::singleton().area_set_shape_transform(
+            self.rid,
+            to_i32_from_usize(index),
+            transform,
+        );
+    }
+
+    /// Enables or disables the shape at `index`.
+    ///
+    /// # Panics
+    /// If `index` is out of bounds.
+    pub fn set_shape_disabled(&mut self, index: usize, disabled: bool) {
+        self.check_shape_index(index);
+        PhysicsServer3D::singleton().area_set_shape_disabled(
+            self.rid,
+            to_i32_from_usize(index),
+            disabled,
+        );
+    }
+
+    /// Places the area in `space`, keeping the space alive until the area leaves it or is dropped.
+    pub fn set_space(&mut self, space: &Arc<OwnedSpace3D>) {
+        PhysicsServer3D::singleton().area_set_space(self.rid, space.rid());
+        self.space = Some(Arc::clone(space));
+    }
+
+    /// Removes the area from its space, if any.
+    pub fn clear_space(&mut self) {
+        PhysicsServer3D::singleton().area_set_space(self.rid, Rid::Invalid);
+        self.space = None;
+    }
+
+    /// Space the area is currently in, if set through [`set_space()`][Self::set_space].
+    pub fn space(&self) -> Option<&Arc<OwnedSpace3D>> {
+        self.space.as_ref()
+    }
+
+    /// Sets the transform of the area.
+    ///
+    /// See `PhysicsServer3D.area_set_transform()`.
+    pub fn set_transform(&mut self, transform: Transform3D) {
+        PhysicsServer3D::singleton().area_set_transform(self.rid, transform);
+    }
+
+    /// Sets an area parameter, such as gravity or damping.
+    ///
+    /// See `PhysicsServer3D.area_set_param()`.
+    pub fn set_param(&mut self, param: AreaParam3D) {
+        let (param, value) = param.into_parts();
+        PhysicsServer3D::singleton().area_set_param(self.rid, param, &value);
+    }
+
+    #[track_caller]
+    fn check_shape_index(&self, index: usize) {
+        assert!(
+            index < self.shapes.len(),
+            "shape index {index} out of bounds for area with {} shapes",
+            self.shapes.len()
+        );
     }
 }
@@ -5,14 +5,25 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::sync::Arc;
+
+use crate::builtin::{to_i32_from_usize, Rid, Transform2D, Variant};
+use crate::classes::physics_server_2d::{BodyMode, BodyState};
 use crate::classes::PhysicsServer2D;
 use crate::obj::Singleton;
+use crate::servers::physics::{BodyParam2D, BodyState2D, OwnedShape2D, OwnedSpace2D};
 
 crate::obj::impl_owned_rid!(
     OwnedBody2D,
     PhysicsServer2D,
-    "A RAII wrapper for a 2D physics body RID that is owned by this type.\nThe body is freed when this object is dropped.",
-    @default
+    "A RAII wrapper for a 2D physics body RID that is owned by this type.\nThe body is freed when this object is dropped.\n\n\
+    Shapes and the space added through this wrapper are kept alive for as long as the body uses them, and are only released after \
+    the body has been freed.",
+    @default,
+    deps {
+        shapes: Vec<Arc<OwnedShape2D>>,
+        space: Option<Arc<OwnedSpace2D>>,
+    }
 );
 
 impl OwnedBody2D {
@@ -21,6 +32,124 @@ impl OwnedBody2D {
     /// See `PhysicsServer2D.body_create()`.
     pub fn new() -> Self {
         let rid = PhysicsServer2D::singleton().body_create();
-        Self { rid }
+        // SAFETY: `rid` was just created by the server and is only owned by the returned wrapper.
+        unsafe { Self::from_rid(rid) }
+    }
+
+    /// Attaches `shape` to the body, keeping it alive until it is removed or the body is dropped.
+    ///
+    /// Shapes are indexed in the order they are added. Mixing this with `PhysicsServer2D.body_add_shape()` or
+    /// `body_remove_shape()` on the same body invalidates the indices tracked here.
+    pub fn add_shape(&mut self, shape: &Arc<OwnedShape2D>, transform: Transform2D) {
+        PhysicsServer2D::singleton()
+            .body_add_shape_ex(self.rid, shape.rid())
+            .transform(transform)
+            .done();
+
+        self.shapes.push(Arc::clone(shape));
+    }
+
+    /// Detaches the shape at `index` and returns it.
+    ///
+    /// # Panics
+    /// If `index` is out of bounds.
+    pub fn remove_shape(&mut self, index: usize) -> Arc<OwnedShape2D> {
+        let shape = self.shapes.remove(index);
+        PhysicsServer2D::singleton().body_remove_shape(self.rid, to_i32_from_usize(index));
+
+        shape
+    }
+
+    /// Detaches all shapes from the body.
+    pub fn clear_shapes(&mut self) {
+        PhysicsServer2D::singleton().body_clear_shapes(self.rid);
+        self.shapes.clear();
+    }
+
+    /// Shapes currently attached to the body, in index order.
+    pub fn shapes(&self) -> &[Arc<OwnedShape2D>] {
+        &self.shapes
+    }
+
+    /// Sets the transform of the shape at `index`, relative to the body.
+    ///
+    /// # Panics
+    /// If `index` is out of bounds.
+    pub fn set_shape_transform(&mut self, index: usize, transform: Transform2D) {
+        self.check_shape_index(index);
+        PhysicsServer2D::singleton().body_set_shape_transform(
+            self.rid,
+            to_i32_from_usize(index),
+            transform,
+        );
+    }
+
+    /// Enables or disables collisions for the shape at `index`.
+    ///
+    /// # Panics
+    /// If `index` is out of bounds.
+    pub fn set_shape_disabled(&mut self, index: usize, disabled: bool) {
+        self.check_shape_index(index);
+        PhysicsServer2D::singleton().body_set_shape_disabled(
+            self.rid,
+            to_i32_from_usize(index),
+            disabled,
+        );
+    }
+
+    /// Places the body in `space`, keeping the space alive until the body leaves it or is dropped.
+    pub fn set_space(&mut self, space: &Arc<OwnedSpace2D>) {
+        PhysicsServer2D::singleton().body_set_space(self.rid, space.rid());
+        self.space = Some(Arc::clone(space));
+    }
+
+    /// Removes the body from its space, if any.
+    pub fn clear_space(&mut self) {
+        PhysicsServer2D::singleton().body_set_space(self.rid, Rid::Invalid);
+        self.space = None;
+    }
+
+    /// Space the body is currently in, if set through [`set_space()`][Self::set_space].
+    pub fn space(&self) -> Option<&Arc<OwnedSpace2D>> {
+        self.space.as_ref()
+    }
+
+    /// Sets the body mode (static, kinematic, rigid, ...).
+    ///
+    /// See `PhysicsServer2D.body_set_mode()`.
+    pub fn set_mode(&mut self, mode: BodyMode) {
+        PhysicsServer2D::singleton().body_set_mode(self.rid, mode);
+    }
+
+    /// Sets a body parameter, such as mass or friction.
+    ///
+    /// See `PhysicsServer2D.body_set_param()`.
+    pub fn set_param(&mut self, param: BodyParam2D) {
+        let (param, value) = param.into_parts();
+        PhysicsServer2D::singleton().body_set_param(self.rid, param, &value);
+    }
+
+    /// Sets a body state, such as its transform or linear velocity.
+    ///
+    /// See `PhysicsServer2D.body_set_state()`.
+    pub fn set_state(&mut self, state: BodyState2D) {
+        let (state, value) = state.into_parts();
+        PhysicsServer2D::singleton().body_set_state(self.rid, state, &value);
+    }
+
+    /// Returns a body state, such as its transform or linear velocity.
+    ///
+    /// See `PhysicsServer2D.body_get_state()`.
+    pub fn state(&self, state: BodyState) -> Variant {
+        PhysicsServer2D::singleton().body_get_state(self.rid, state)
+    }
+
+    #[track_caller]
+    fn check_shape_index(&self, index: usize) {
+        assert!(
+            index < self.shapes.len(),
+            "shape index {index} out of bounds for body with {} shapes",
+            self.shapes.len()
+        );
     }
 }
@@ -20,6 +20,7 @@ impl OwnedJoint2D {
     pub fn new() -> Self {
         let mut server = PhysicsServer2D::singleton();
         let rid = server.joint_create();
+        // SAFETY: `rid` was just created by the server and is only owned by the returned wrapper.
         unsafe { Self::from_rid(rid) }
     }
 }
@@ -32,6 +32,7 @@ impl OwnedShape2D {
             ShapeType::CONCAVE_POLYGON => server.concave_polygon_shape_create(),
             _ => panic!("Unsupported shape type"),
         };
-        Self { rid }
+        // SAFETY: `rid` was just created by the server and is only owned by the returned wrapper.
+        unsafe { Self::from_rid(rid) }
     }
 }
@@ -34,6 +34,7 @@ impl OwnedShape3D {
             ShapeType::CUSTOM => server.custom_shape_create(),
             _ => panic!("Unsupported shape type"),
         };
-        Self { rid }
+        // SAFETY: `rid` was just created by the server and is only owned by the returned wrapper.
+        unsafe { Self::from_rid(rid) }
     }
 }
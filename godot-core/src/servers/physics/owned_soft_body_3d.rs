@@ -21,6 +21,7 @@ impl OwnedSoftBody3D {
     /// See `PhysicsServer3D.soft_body_create()`.
     pub fn new() -> Self {
         let rid = PhysicsServer3D::singleton().soft_body_create();
-        Self { rid }
+        // SAFETY: `rid` was just created by the server and is only owned by the returned wrapper.
+        unsafe { Self::from_rid(rid) }
     }
 }
@@ -21,6 +21,7 @@ impl OwnedSpace2D {
     /// See `PhysicsServer2D.space_create()`.
     pub fn new() -> Self {
         let rid = PhysicsServer2D::singleton().space_create();
-        Self { rid }
+        // SAFETY: `rid` was just created by the server and is only owned by the returned wrapper.
+        unsafe { Self::from_rid(rid) }
     }
 }
@@ -0,0 +1,113 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{real, Transform2D, Variant, Vector2};
+use crate::classes::physics_server_2d::{
+    AreaParameter, AreaSpaceOverrideMode, BodyDampMode, BodyParameter, BodyState,
+};
+use crate::meta::ToGodot;
+
+/// Parameter of a 2D physics body, together with its value. See [`OwnedBody2D::set_param()`][super::OwnedBody2D::set_param].
+///
+/// Each variant corresponds to one `PhysicsServer2D.BodyParameter` constant.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum BodyParam2D {
+    Bounce(real),
+    Friction(real),
+    Mass(real),
+    Inertia(real),
+    CenterOfMass(Vector2),
+    GravityScale(real),
+    LinearDampMode(BodyDampMode),
+    AngularDampMode(BodyDampMode),
+    LinearDamp(real),
+    AngularDamp(real),
+}
+
+impl BodyParam2D {
+    pub(super) fn into_parts(self) -> (BodyParameter, Variant) {
+        match self {
+            Self::Bounce(v) => (BodyParameter::BOUNCE, v.to_variant()),
+            Self::Friction(v) => (BodyParameter::FRICTION, v.to_variant()),
+            Self::Mass(v) => (BodyParameter::MASS, v.to_variant()),
+            Self::Inertia(v) => (BodyParameter::INERTIA, v.to_variant()),
+            Self::CenterOfMass(v) => (BodyParameter::CENTER_OF_MASS, v.to_variant()),
+            Self::GravityScale(v) => (BodyParameter::GRAVITY_SCALE, v.to_variant()),
+            Self::LinearDampMode(v) => (BodyParameter::LINEAR_DAMP_MODE, v.to_variant()),
+            Self::AngularDampMode(v) => (BodyParameter::ANGULAR_DAMP_MODE, v.to_variant()),
+            Self::LinearDamp(v) => (BodyParameter::LINEAR_DAMP, v.to_variant()),
+            Self::AngularDamp(v) => (BodyParameter::ANGULAR_DAMP, v.to_variant()),
+        }
+    }
+}
+
+/// State of a 2D physics body, together with its value. See [`OwnedBody2D::set_state()`][super::OwnedBody2D::set_state].
+///
+/// Each variant corresponds to one `PhysicsServer2D.BodyState` constant.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum BodyState2D {
+    Transform(Transform2D),
+    LinearVelocity(Vector2),
+    AngularVelocity(real),
+    Sleeping(bool),
+    CanSleep(bool),
+}
+
+impl BodyState2D {
+    pub(super) fn into_parts(self) -> (BodyState, Variant) {
+        match self {
+            Self::Transform(v) => (BodyState::TRANSFORM, v.to_variant()),
+            Self::LinearVelocity(v) => (BodyState::LINEAR_VELOCITY, v.to_variant()),
+            Self::AngularVelocity(v) => (BodyState::ANGULAR_VELOCITY, v.to_variant()),
+            Self::Sleeping(v) => (BodyState::SLEEPING, v.to_variant()),
+            Self::CanSleep(v) => (BodyState::CAN_SLEEP, v.to_variant()),
+        }
+    }
+}
+
+/// Parameter of a 2D physics area, together with its value. See [`OwnedArea2D::set_param()`][super::OwnedArea2D::set_param].
+///
+/// Each variant corresponds to one `PhysicsServer2D.AreaParameter` constant.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum AreaParam2D {
+    GravityOverrideMode(AreaSpaceOverrideMode),
+    Gravity(real),
+    GravityVector(Vector2),
+    GravityIsPoint(bool),
+    GravityPointUnitDistance(real),
+    LinearDampOverrideMode(AreaSpaceOverrideMode),
+    LinearDamp(real),
+    AngularDampOverrideMode(AreaSpaceOverrideMode),
+    AngularDamp(real),
+    Priority(i32),
+}
+
+impl AreaParam2D {
+    pub(super) fn into_parts(self) -> (AreaParameter, Variant) {
+        match self {
+            Self::GravityOverrideMode(v) => (AreaParameter::GRAVITY_OVERRIDE_MODE, v.to_variant()),
+            Self::Gravity(v) => (AreaParameter::GRAVITY, v.to_variant()),
+            Self::GravityVector(v) => (AreaParameter::GRAVITY_VECTOR, v.to_variant()),
+            Self::GravityIsPoint(v) => (AreaParameter::GRAVITY_IS_POINT, v.to_variant()),
+            Self::GravityPointUnitDistance(v) => {
+                (AreaParameter::GRAVITY_POINT_UNIT_DISTANCE, v.to_variant())
+            }
+            Self::LinearDampOverrideMode(v) => {
+                (AreaParameter::LINEAR_DAMP_OVERRIDE_MODE, v.to_variant())
+            }
+            Self::LinearDamp(v) => (AreaParameter::LINEAR_DAMP, v.to_variant()),
+            Self::AngularDampOverrideMode(v) => {
+                (AreaParameter::ANGULAR_DAMP_OVERRIDE_MODE, v.to_variant())
+            }
+            Self::AngularDamp(v) => (AreaParameter::ANGULAR_DAMP, v.to_variant()),
+            Self::Priority(v) => (AreaParameter::PRIORITY, v.to_variant()),
+        }
+    }
+}
@@ -0,0 +1,123 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{real, Transform3D, Variant, Vector3};
+use crate::classes::physics_server_3d::{
+    AreaParameter, AreaSpaceOverrideMode, BodyDampMode, BodyParameter, BodyState,
+};
+use crate::meta::ToGodot;
+
+/// Parameter of a 3D physics body, together with its value. See [`OwnedBody3D::set_param()`][super::OwnedBody3D::set_param].
+///
+/// Each variant corresponds to one `PhysicsServer3D.BodyParameter` constant.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum BodyParam3D {
+    Bounce(real),
+    Friction(real),
+    Mass(real),
+    Inertia(Vector3),
+    CenterOfMass(Vector3),
+    GravityScale(real),
+    LinearDampMode(BodyDampMode),
+    AngularDampMode(BodyDampMode),
+    LinearDamp(real),
+    AngularDamp(real),
+}
+
+impl BodyParam3D {
+    pub(super) fn into_parts(self) -> (BodyParameter, Variant) {
+        match self {
+            Self::Bounce(v) => (BodyParameter::BOUNCE, v.to_variant()),
+            Self::Friction(v) => (BodyParameter::FRICTION, v.to_variant()),
+            Self::Mass(v) => (BodyParameter::MASS, v.to_variant()),
+            Self::Inertia(v) => (BodyParameter::INERTIA, v.to_variant()),
+            Self::CenterOfMass(v) => (BodyParameter::CENTER_OF_MASS, v.to_variant()),
+            Self::GravityScale(v) => (BodyParameter::GRAVITY_SCALE, v.to_variant()),
+            Self::LinearDampMode(v) => (BodyParameter::LINEAR_DAMP_MODE, v.to_variant()),
+            Self::AngularDampMode(v) => (BodyParameter::ANGULAR_DAMP_MODE, v.to_variant()),
+            Self::LinearDamp(v) => (BodyParameter::LINEAR_DAMP, v.to_variant()),
+            Self::AngularDamp(v) => (BodyParameter::ANGULAR_DAMP, v.to_variant()),
+        }
+    }
+}
+
+/// State of a 3D physics body, together with its value. See [`OwnedBody3D::set_state()`][super::OwnedBody3D::set_state].
+///
+/// Each variant corresponds to one `PhysicsServer3D.BodyState` constant.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum BodyState3D {
+    Transform(Transform3D),
+    LinearVelocity(Vector3),
+    AngularVelocity(Vector3),
+    Sleeping(bool),
+    CanSleep(bool),
+}
+
+impl BodyState3D {
+    pub(super) fn into_parts(self) -> (BodyState, Variant) {
+        match self {
+            Self::Transform(v) => (BodyState::TRANSFORM, v.to_variant()),
+            Self::LinearVelocity(v) => (BodyState::LINEAR_VELOCITY, v.to_variant()),
+            Self::AngularVelocity(v) => (BodyState::ANGULAR_VELOCITY, v.to_variant()),
+            Self::Sleeping(v) => (BodyState::SLEEPING, v.to_variant()),
+            Self::CanSleep(v) => (BodyState::CAN_SLEEP, v.to_variant()),
+        }
+    }
+}
+
+/// Parameter of a 3D physics area, together with its value. See [`OwnedArea3D::set_param()`][super::OwnedArea3D::set_param].
+///
+/// Each variant corresponds to one `PhysicsServer3D.AreaParameter` constant.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum AreaParam3D {
+    GravityOverrideMode(AreaSpaceOverrideMode),
+    Gravity(real),
+    GravityVector(Vector3),
+    GravityIsPoint(bool),
+    GravityPointUnitDistance(real),
+    LinearDampOverrideMode(AreaSpaceOverrideMode),
+    LinearDamp(real),
+    AngularDampOverrideMode(AreaSpaceOverrideMode),
+    AngularDamp(real),
+    Priority(i32),
+    WindForceMagnitude(real),
+    WindSource(Vector3),
+    WindDirection(Vector3),
+    WindAttenuationFactor(real),
+}
+
+impl AreaParam3D {
+    pub(super) fn into_parts(self) -> (AreaParameter, Variant) {
+        match self {
+            Self::GravityOverrideMode(v) => (AreaParameter::GRAVITY_OVERRIDE_MODE, v.to_variant()),
+            Self::Gravity(v) => (AreaParameter::GRAVITY, v.to_variant()),
+            Self::GravityVector(v) => (AreaParameter::GRAVITY_VECTOR, v.to_variant()),
+            Self::GravityIsPoint(v) => (AreaParameter::GRAVITY_IS_POINT, v.to_variant()),
+            Self::GravityPointUnitDistance(v) => {
+                (AreaParameter::GRAVITY_POINT_UNIT_DISTANCE, v.to_variant())
+            }
+            Self::LinearDampOverrideMode(v) => {
+                (AreaParameter::LINEAR_DAMP_OVERRIDE_MODE, v.to_variant())
+            }
+            Self::LinearDamp(v) => (AreaParameter::LINEAR_DAMP, v.to_variant()),
+            Self::AngularDampOverrideMode(v) => {
+                (AreaParameter::ANGULAR_DAMP_OVERRIDE_MODE, v.to_variant())
+            }
+            Self::AngularDamp(v) => (AreaParameter::ANGULAR_DAMP, v.to_variant()),
+            Self::Priority(v) => (AreaParameter::PRIORITY, v.to_variant()),
+            Self::WindForceMagnitude(v) => (AreaParameter::WIND_FORCE_MAGNITUDE, v.to_variant()),
+            Self::WindSource(v) => (AreaParameter::WIND_SOURCE, v.to_variant()),
+            Self::WindDirection(v) => (AreaParameter::WIND_DIRECTION, v.to_variant()),
+            Self::WindAttenuationFactor(v) => {
+                (AreaParameter::WIND_ATTENUATION_FACTOR, v.to_variant())
+            }
+        }
+    }
+}
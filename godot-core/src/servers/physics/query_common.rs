@@ -0,0 +1,65 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{Array, Rid, VarDictionary};
+use crate::classes::Object;
+use crate::obj::{Gd, InstanceId};
+
+/// Result of a shape motion cast, see `cast_motion_result()` on the direct space states.
+///
+/// Both values are fractions of the query's motion vector, in the range `0.0..=1.0`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct MotionResult {
+    /// How far the shape can move without colliding.
+    pub safe_fraction: f32,
+    /// How far the shape can move until it collides (slightly further than `safe_fraction`).
+    pub unsafe_fraction: f32,
+}
+
+impl MotionResult {
+    /// Returns `true` if the shape can perform the full motion without colliding.
+    pub fn is_unobstructed(&self) -> bool {
+        self.safe_fraction >= 1.0
+    }
+
+    /// Parses the `[safe, unsafe]` array returned by `cast_motion()`.
+    ///
+    /// Godot returns `[1, 1]` if there is no collision, and an empty array if the query failed (e.g. invalid shape); the latter yields `None`.
+    pub(crate) fn from_fractions(fractions: &[f32]) -> Option<Self> {
+        match fractions {
+            [safe_fraction, unsafe_fraction, ..] => Some(Self {
+                safe_fraction: *safe_fraction,
+                unsafe_fraction: *unsafe_fraction,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Reads `collider_id` and `collider` from a query result. Returns `None` if the dictionary is empty, i.e. not a hit.
+pub(super) fn collider_from_dict(
+    dict: &VarDictionary,
+) -> Option<(Option<InstanceId>, Option<Gd<Object>>)> {
+    if dict.is_empty() {
+        return None;
+    }
+
+    // ID 0 and a null collider are reported for objects created directly through the physics server, without an attached node.
+    let collider_id = dict
+        .get_as::<_, i64>("collider_id")
+        .and_then(InstanceId::try_from_i64);
+
+    let collider = dict
+        .get("collider")
+        .and_then(|variant| variant.try_to::<Gd<Object>>().ok());
+
+    Some((collider_id, collider))
+}
+
+pub(super) fn exclude_array(exclude: &[Rid]) -> Array<Rid> {
+    exclude.iter().copied().collect()
+}
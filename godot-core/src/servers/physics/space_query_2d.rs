@@ -0,0 +1,344 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use super::query_common::{collider_from_dict, exclude_array, MotionResult};
+use crate::builtin::{Rid, Transform2D, VarDictionary, Vector2};
+use crate::classes::{
+    Object, PhysicsDirectSpaceState2D, PhysicsRayQueryParameters2D, PhysicsServer2D,
+    PhysicsShapeQueryParameters2D,
+};
+use crate::obj::{Gd, InstanceId, NewGd, Singleton};
+use crate::servers::physics::OwnedSpace2D;
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Query parameters
+
+/// Builder for a 2D ray query, see [`PhysicsDirectSpaceState2D::cast_ray()`].
+///
+/// Typed alternative to [`PhysicsRayQueryParameters2D`]. Defaults match Godot: all collision layers, bodies only.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::classes::PhysicsDirectSpaceState2D;
+/// use godot::physics::RayQuery2D;
+///
+/// # fn example(mut space: Gd<PhysicsDirectSpaceState2D>) {
+/// let query = RayQuery2D::new(Vector2::ZERO, Vector2::new(0.0, 10.0))
+///     .with_collision_mask(0b101)
+///     .with_areas(true);
+///
+/// if let Some(hit) = space.cast_ray(&query) {
+///     godot_print!("hit {:?} at {}", hit.collider_id, hit.position);
+/// }
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct RayQuery2D {
+    pub from: Vector2,
+    pub to: Vector2,
+    pub collision_mask: u32,
+    pub exclude: Vec<Rid>,
+    pub collide_with_bodies: bool,
+    pub collide_with_areas: bool,
+    pub hit_from_inside: bool,
+}
+
+impl RayQuery2D {
+    /// Ray from `from` to `to`, in global coordinates.
+    pub fn new(from: Vector2, to: Vector2) -> Self {
+        Self {
+            from,
+            to,
+            collision_mask: u32::MAX,
+            exclude: Vec::new(),
+            collide_with_bodies: true,
+            collide_with_areas: false,
+            hit_from_inside: false,
+        }
+    }
+
+    /// Only collide with objects in these physics layers.
+    pub fn with_collision_mask(mut self, collision_mask: u32) -> Self {
+        self.collision_mask = collision_mask;
+        self
+    }
+
+    /// Ignores the given objects (by their RID, see `CollisionObject2D::get_rid()`).
+    pub fn with_exclude(mut self, exclude: impl IntoIterator<Item = Rid>) -> Self {
+        self.exclude = exclude.into_iter().collect();
+        self
+    }
+
+    /// Whether physics bodies are considered. Default `true`.
+    pub fn with_bodies(mut self, collide_with_bodies: bool) -> Self {
+        self.collide_with_bodies = collide_with_bodies;
+        self
+    }
+
+    /// Whether areas are considered. Default `false`.
+    pub fn with_areas(mut self, collide_with_areas: bool) -> Self {
+        self.collide_with_areas = collide_with_areas;
+        self
+    }
+
+    /// Whether the ray hits shapes it starts inside of (with zero normal). Default `false`.
+    pub fn with_hit_from_inside(mut self, hit_from_inside: bool) -> Self {
+        self.hit_from_inside = hit_from_inside;
+        self
+    }
+
+    /// Converts to the engine's parameter object.
+    pub fn to_parameters(&self) -> Gd<PhysicsRayQueryParameters2D> {
+        let mut params = PhysicsRayQueryParameters2D::new_gd();
+        params.set_from(self.from);
+        params.set_to(self.to);
+        params.set_collision_mask(self.collision_mask);
+        params.set_exclude(&exclude_array(&self.exclude));
+        params.set_collide_with_bodies(self.collide_with_bodies);
+        params.set_collide_with_areas(self.collide_with_areas);
+        params.set_hit_from_inside(self.hit_from_inside);
+        params
+    }
+}
+
+/// Builder for 2D shape queries, see [`PhysicsDirectSpaceState2D::intersect_shape_hits()`] and related methods.
+///
+/// Typed alternative to [`PhysicsShapeQueryParameters2D`]. The shape is given as a RID, e.g. from an
+/// [`OwnedShape2D`](crate::servers::physics::OwnedShape2D) or `Shape2D::get_rid()`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShapeQuery2D {
+    pub shape: Rid,
+    pub transform: Transform2D,
+    pub motion: Vector2,
+    pub margin: f32,
+    pub collision_mask: u32,
+    pub exclude: Vec<Rid>,
+    pub collide_with_bodies: bool,
+    pub collide_with_areas: bool,
+}
+
+impl ShapeQuery2D {
+    /// Query with `shape` placed at `transform`.
+    pub fn new(shape: Rid, transform: Transform2D) -> Self {
+        Self {
+            shape,
+            transform,
+            motion: Vector2::ZERO,
+            margin: 0.0,
+            collision_mask: u32::MAX,
+            exclude: Vec::new(),
+            collide_with_bodies: true,
+            collide_with_areas: false,
+        }
+    }
+
+    /// Motion of the shape, used by [`cast_motion_result()`][PhysicsDirectSpaceState2D::cast_motion_result].
+    pub fn with_motion(mut self, motion: Vector2) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Collision margin for the shape.
+    pub fn with_margin(mut self, margin: f32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Only collide with objects in these physics layers.
+    pub fn with_collision_mask(mut self, collision_mask: u32) -> Self {
+        self.collision_mask = collision_mask;
+        self
+    }
+
+    /// Ignores the given objects (by their RID).
+    pub fn with_exclude(mut self, exclude: impl IntoIterator<Item = Rid>) -> Self {
+        self.exclude = exclude.into_iter().collect();
+        self
+    }
+
+    /// Whether physics bodies are considered. Default `true`.
+    pub fn with_bodies(mut self, collide_with_bodies: bool) -> Self {
+        self.collide_with_bodies = collide_with_bodies;
+        self
+    }
+
+    /// Whether areas are considered. Default `false`.
+    pub fn with_areas(mut self, collide_with_areas: bool) -> Self {
+        self.collide_with_areas = collide_with_areas;
+        self
+    }
+
+    /// Converts to the engine's parameter object.
+    pub fn to_parameters(&self) -> Gd<PhysicsShapeQueryParameters2D> {
+        let mut params = PhysicsShapeQueryParameters2D::new_gd();
+        params.set_shape_rid(self.shape);
+        params.set_transform(self.transform);
+        params.set_motion(self.motion);
+        params.set_margin(self.margin);
+        params.set_collision_mask(self.collision_mask);
+        params.set_exclude(&exclude_array(&self.exclude));
+        params.set_collide_with_bodies(self.collide_with_bodies);
+        params.set_collide_with_areas(self.collide_with_areas);
+        params
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Results
+
+/// Result of a 2D ray query.
+#[derive(Clone, Debug)]
+pub struct RayHit2D {
+    /// Intersection point, in global coordinates.
+    pub position: Vector2,
+    /// Surface normal at the intersection point. Zero if the ray started inside the shape.
+    pub normal: Vector2,
+    /// ID of the colliding object, or `None` for objects created directly through the physics server.
+    pub collider_id: Option<InstanceId>,
+    /// The colliding object, or `None` if there is no attached object or it has been freed.
+    pub collider: Option<Gd<Object>>,
+    /// RID of the colliding object.
+    pub rid: Rid,
+    /// Shape index within the colliding object.
+    pub shape: i32,
+}
+
+impl RayHit2D {
+    /// Parses the dictionary returned by [`PhysicsDirectSpaceState2D::intersect_ray()`].
+    ///
+    /// Returns `None` for an empty dictionary (no hit).
+    pub fn from_dictionary(dict: &VarDictionary) -> Option<Self> {
+        let (collider_id, collider) = collider_from_dict(dict)?;
+
+        Some(Self {
+            position: dict.get_as("position")?,
+            normal: dict.get_as("normal")?,
+            collider_id,
+            collider,
+            rid: dict.get_as("rid")?,
+            shape: dict.get_as("shape")?,
+        })
+    }
+}
+
+/// One object overlapping a 2D shape query.
+#[derive(Clone, Debug)]
+pub struct ShapeHit2D {
+    /// ID of the colliding object, or `None` for objects created directly through the physics server.
+    pub collider_id: Option<InstanceId>,
+    /// The colliding object, or `None` if there is no attached object or it has been freed.
+    pub collider: Option<Gd<Object>>,
+    /// RID of the colliding object.
+    pub rid: Rid,
+    /// Shape index within the colliding object.
+    pub shape: i32,
+}
+
+impl ShapeHit2D {
+    /// Parses one element of the array returned by [`PhysicsDirectSpaceState2D::intersect_shape()`].
+    pub fn from_dictionary(dict: &VarDictionary) -> Option<Self> {
+        let (collider_id, collider) = collider_from_dict(dict)?;
+
+        Some(Self {
+            collider_id,
+            collider,
+            rid: dict.get_as("rid")?,
+            shape: dict.get_as("shape")?,
+        })
+    }
+}
+
+/// Closest contact of a 2D shape query, see [`PhysicsDirectSpaceState2D::rest_info()`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct RestInfo2D {
+    /// Intersection point, in global coordinates.
+    pub point: Vector2,
+    /// Surface normal of the colliding object at the intersection point.
+    pub normal: Vector2,
+    /// ID of the colliding object, or `None` for objects created directly through the physics server.
+    pub collider_id: Option<InstanceId>,
+    /// RID of the colliding object.
+    pub rid: Rid,
+    /// Shape index within the colliding object.
+    pub shape: i32,
+    /// Velocity of the colliding object, if it is a body.
+    pub linear_velocity: Vector2,
+}
+
+impl RestInfo2D {
+    /// Parses the dictionary returned by [`PhysicsDirectSpaceState2D::get_rest_info()`].
+    ///
+    /// Returns `None` for an empty dictionary (no contact).
+    pub fn from_dictionary(dict: &VarDictionary) -> Option<Self> {
+        let (collider_id, _) = collider_from_dict(dict)?;
+
+        Some(Self {
+            point: dict.get_as("point")?,
+            normal: dict.get_as("normal")?,
+            collider_id,
+            rid: dict.get_as("rid")?,
+            shape: dict.get_as("shape")?,
+            linear_velocity: dict.get_as("linear_velocity").unwrap_or(Vector2::ZERO),
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Typed queries
+
+/// _Typed queries, returning Rust structs instead of dictionaries._ <br><br>
+impl PhysicsDirectSpaceState2D {
+    /// Casts a ray, returning the closest hit.
+    ///
+    /// Typed version of [`intersect_ray()`][Self::intersect_ray].
+    pub fn cast_ray(&mut self, query: &RayQuery2D) -> Option<RayHit2D> {
+        let dict = self.intersect_ray(&query.to_parameters());
+        RayHit2D::from_dictionary(&dict)
+    }
+
+    /// Returns up to `max_results` objects overlapping the query shape.
+    ///
+    /// Typed version of [`intersect_shape()`][Self::intersect_shape]. The query's motion is ignored.
+    pub fn intersect_shape_hits(
+        &mut self,
+        query: &ShapeQuery2D,
+        max_results: i32,
+    ) -> Vec<ShapeHit2D> {
+        self.intersect_shape_ex(&query.to_parameters())
+            .max_results(max_results)
+            .done()
+            .iter_shared()
+            .filter_map(|dict| ShapeHit2D::from_dictionary(&dict))
+            .collect()
+    }
+
+    /// Moves the query shape along its motion, and reports how far it can go without colliding.
+    ///
+    /// Typed version of [`cast_motion()`][Self::cast_motion]. Returns `None` if the query failed, e.g. because the shape is invalid.
+    pub fn cast_motion_result(&mut self, query: &ShapeQuery2D) -> Option<MotionResult> {
+        let fractions = self.cast_motion(&query.to_parameters());
+        MotionResult::from_fractions(fractions.as_slice())
+    }
+
+    /// Returns the closest contact of the query shape with another object, if any.
+    ///
+    /// Typed version of [`get_rest_info()`][Self::get_rest_info].
+    pub fn rest_info(&mut self, query: &ShapeQuery2D) -> Option<RestInfo2D> {
+        let dict = self.get_rest_info(&query.to_parameters());
+        RestInfo2D::from_dictionary(&dict)
+    }
+}
+
+impl OwnedSpace2D {
+    /// Direct access to the space for queries. See `PhysicsServer2D.space_get_direct_state()`.
+    ///
+    /// Returns `None` if the space is not accessible right now, e.g. during a physics step on another thread.
+    pub fn direct_state(&self) -> Option<Gd<PhysicsDirectSpaceState2D>> {
+        PhysicsServer2D::singleton().space_get_direct_state(self.rid())
+    }
+}
@@ -0,0 +1,356 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use super::query_common::{collider_from_dict, exclude_array, MotionResult};
+use crate::builtin::{Rid, Transform3D, VarDictionary, Vector3};
+use crate::classes::{
+    Object, PhysicsDirectSpaceState3D, PhysicsRayQueryParameters3D, PhysicsServer3D,
+    PhysicsShapeQueryParameters3D,
+};
+use crate::obj::{Gd, InstanceId, NewGd, Singleton};
+use crate::servers::physics::OwnedSpace3D;
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Query parameters
+
+/// Builder for a 3D ray query, see [`PhysicsDirectSpaceState3D::cast_ray()`].
+///
+/// Typed alternative to [`PhysicsRayQueryParameters3D`]. Defaults match Godot: all collision layers, bodies only.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::classes::PhysicsDirectSpaceState3D;
+/// use godot::physics::RayQuery3D;
+///
+/// # fn example(mut space: Gd<PhysicsDirectSpaceState3D>) {
+/// let query = RayQuery3D::new(Vector3::ZERO, Vector3::new(0.0, -10.0, 0.0))
+///     .with_collision_mask(0b101)
+///     .with_areas(true);
+///
+/// if let Some(hit) = space.cast_ray(&query) {
+///     godot_print!("hit {:?} at {}", hit.collider_id, hit.position);
+/// }
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct RayQuery3D {
+    pub from: Vector3,
+    pub to: Vector3,
+    pub collision_mask: u32,
+    pub exclude: Vec<Rid>,
+    pub collide_with_bodies: bool,
+    pub collide_with_areas: bool,
+    pub hit_from_inside: bool,
+    pub hit_back_faces: bool,
+}
+
+impl RayQuery3D {
+    /// Ray from `from` to `to`, in global coordinates.
+    pub fn new(from: Vector3, to: Vector3) -> Self {
+        Self {
+            from,
+            to,
+            collision_mask: u32::MAX,
+            exclude: Vec::new(),
+            collide_with_bodies: true,
+            collide_with_areas: false,
+            hit_from_inside: false,
+            hit_back_faces: true,
+        }
+    }
+
+    /// Only collide with objects in these physics layers.
+    pub fn with_collision_mask(mut self, collision_mask: u32) -> Self {
+        self.collision_mask = collision_mask;
+        self
+    }
+
+    /// Ignores the given objects (by their RID, see `CollisionObject3D::get_rid()`).
+    pub fn with_exclude(mut self, exclude: impl IntoIterator<Item = Rid>) -> Self {
+        self.exclude = exclude.into_iter().collect();
+        self
+    }
+
+    /// Whether physics bodies are considered. Default `true`.
+    pub fn with_bodies(mut self, collide_with_bodies: bool) -> Self {
+        self.collide_with_bodies = collide_with_bodies;
+        self
+    }
+
+    /// Whether areas are considered. Default `false`.
+    pub fn with_areas(mut self, collide_with_areas: bool) -> Self {
+        self.collide_with_areas = collide_with_areas;
+        self
+    }
+
+    /// Whether the ray hits shapes it starts inside of (with zero normal). Default `false`.
+    pub fn with_hit_from_inside(mut self, hit_from_inside: bool) -> Self {
+        self.hit_from_inside = hit_from_inside;
+        self
+    }
+
+    /// Whether the ray hits back faces of concave polygon shapes. Default `true`.
+    pub fn with_hit_back_faces(mut self, hit_back_faces: bool) -> Self {
+        self.hit_back_faces = hit_back_faces;
+        self
+    }
+
+    /// Converts to the engine's parameter object.
+    pub fn to_parameters(&self) -> Gd<PhysicsRayQueryParameters3D> {
+        let mut params = PhysicsRayQueryParameters3D::new_gd();
+        params.set_from(self.from);
+        params.set_to(self.to);
+        params.set_collision_mask(self.collision_mask);
+        params.set_exclude(&exclude_array(&self.exclude));
+        params.set_collide_with_bodies(self.collide_with_bodies);
+        params.set_collide_with_areas(self.collide_with_areas);
+        params.set_hit_from_inside(self.hit_from_inside);
+        params.set_hit_back_faces(self.hit_back_faces);
+        params
+    }
+}
+
+/// Builder for 3D shape queries, see [`PhysicsDirectSpaceState3D::intersect_shape_hits()`] and related methods.
+///
+/// Typed alternative to [`PhysicsShapeQueryParameters3D`]. The shape is given as a RID, e.g. from an
+/// [`OwnedShape3D`](crate::servers::physics::OwnedShape3D) or `Shape3D::get_rid()`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShapeQuery3D {
+    pub shape: Rid,
+    pub transform: Transform3D,
+    pub motion: Vector3,
+    pub margin: f32,
+    pub collision_mask: u32,
+    pub exclude: Vec<Rid>,
+    pub collide_with_bodies: bool,
+    pub collide_with_areas: bool,
+}
+
+impl ShapeQuery3D {
+    /// Query with `shape` placed at `transform`.
+    pub fn new(shape: Rid, transform: Transform3D) -> Self {
+        Self {
+            shape,
+            transform,
+            motion: Vector3::ZERO,
+            margin: 0.0,
+            collision_mask: u32::MAX,
+            exclude: Vec::new(),
+            collide_with_bodies: true,
+            collide_with_areas: false,
+        }
+    }
+
+    /// Motion of the shape, used by [`cast_motion_result()`][PhysicsDirectSpaceState3D::cast_motion_result].
+    pub fn with_motion(mut self, motion: Vector3) -> Self {
+        self.motion = motion;
+        self
+    }
+
+    /// Collision margin for the shape.
+    pub fn with_margin(mut self, margin: f32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Only collide with objects in these physics layers.
+    pub fn with_collision_mask(mut self, collision_mask: u32) -> Self {
+        self.collision_mask = collision_mask;
+        self
+    }
+
+    /// Ignores the given objects (by their RID).
+    pub fn with_exclude(mut self, exclude: impl IntoIterator<Item = Rid>) -> Self {
+        self.exclude = exclude.into_iter().collect();
+        self
+    }
+
+    /// Whether physics bodies are considered. Default `true`.
+    pub fn with_bodies(mut self, collide_with_bodies: bool) -> Self {
+        self.collide_with_bodies = collide_with_bodies;
+        self
+    }
+
+    /// Whether areas are considered. Default `false`.
+    pub fn with_areas(mut self, collide_with_areas: bool) -> Self {
+        self.collide_with_areas = collide_with_areas;
+        self
+    }
+
+    /// Converts to the engine's parameter object.
+    pub fn to_parameters(&self) -> Gd<PhysicsShapeQueryParameters3D> {
+        let mut params = PhysicsShapeQueryParameters3D::new_gd();
+        params.set_shape_rid(self.shape);
+        params.set_transform(self.transform);
+        params.set_motion(self.motion);
+        params.set_margin(self.margin);
+        params.set_collision_mask(self.collision_mask);
+        params.set_exclude(&exclude_array(&self.exclude));
+        params.set_collide_with_bodies(self.collide_with_bodies);
+        params.set_collide_with_areas(self.collide_with_areas);
+        params
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Results
+
+/// Result of a 3D ray query.
+#[derive(Clone, Debug)]
+pub struct RayHit3D {
+    /// Intersection point, in global coordinates.
+    pub position: Vector3,
+    /// Surface normal at the intersection point. Zero if the ray started inside the shape.
+    pub normal: Vector3,
+    /// ID of the colliding object, or `None` for objects created directly through the physics server.
+    pub collider_id: Option<InstanceId>,
+    /// The colliding object, or `None` if there is no attached object or it has been freed.
+    pub collider: Option<Gd<Object>>,
+    /// RID of the colliding object.
+    pub rid: Rid,
+    /// Shape index within the colliding object.
+    pub shape: i32,
+    /// Face index of the hit triangle, for concave polygon shapes; `-1` otherwise.
+    pub face_index: i32,
+}
+
+impl RayHit3D {
+    /// Parses the dictionary returned by [`PhysicsDirectSpaceState3D::intersect_ray()`].
+    ///
+    /// Returns `None` for an empty dictionary (no hit).
+    pub fn from_dictionary(dict: &VarDictionary) -> Option<Self> {
+        let (collider_id, collider) = collider_from_dict(dict)?;
+
+        Some(Self {
+            position: dict.get_as("position")?,
+            normal: dict.get_as("normal")?,
+            collider_id,
+            collider,
+            rid: dict.get_as("rid")?,
+            shape: dict.get_as("shape")?,
+            face_index: dict.get_as("face_index").unwrap_or(-1),
+        })
+    }
+}
+
+/// One object overlapping a 3D shape query.
+#[derive(Clone, Debug)]
+pub struct ShapeHit3D {
+    /// ID of the colliding object, or `None` for objects created directly through the physics server.
+    pub collider_id: Option<InstanceId>,
+    /// The colliding object, or `None` if there is no attached object or it has been freed.
+    pub collider: Option<Gd<Object>>,
+    /// RID of the colliding object.
+    pub rid: Rid,
+    /// Shape index within the colliding object.
+    pub shape: i32,
+}
+
+impl ShapeHit3D {
+    /// Parses one element of the array returned by [`PhysicsDirectSpaceState3D::intersect_shape()`].
+    pub fn from_dictionary(dict: &VarDictionary) -> Option<Self> {
+        let (collider_id, collider) = collider_from_dict(dict)?;
+
+        Some(Self {
+            collider_id,
+            collider,
+            rid: dict.get_as("rid")?,
+            shape: dict.get_as("shape")?,
+        })
+    }
+}
+
+/// Closest contact of a 3D shape query, see [`PhysicsDirectSpaceState3D::rest_info()`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct RestInfo3D {
+    /// Intersection point, in global coordinates.
+    pub point: Vector3,
+    /// Surface normal of the colliding object at the intersection point.
+    pub normal: Vector3,
+    /// ID of the colliding object, or `None` for objects created directly through the physics server.
+    pub collider_id: Option<InstanceId>,
+    /// RID of the colliding object.
+    pub rid: Rid,
+    /// Shape index within the colliding object.
+    pub shape: i32,
+    /// Velocity of the colliding object, if it is a body.
+    pub linear_velocity: Vector3,
+}
+
+impl RestInfo3D {
+    /// Parses the dictionary returned by [`PhysicsDirectSpaceState3D::get_rest_info()`].
+    ///
+    /// Returns `None` for an empty dictionary (no contact).
+    pub fn from_dictionary(dict: &VarDictionary) -> Option<Self> {
+        let (collider_id, _) = collider_from_dict(dict)?;
+
+        Some(Self {
+            point: dict.get_as("point")?,
+            normal: dict.get_as("normal")?,
+            collider_id,
+            rid: dict.get_as("rid")?,
+            shape: dict.get_as("shape")?,
+            linear_velocity: dict.get_as("linear_velocity").unwrap_or(Vector3::ZERO),
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Typed queries
+
+/// _Typed queries, returning Rust structs instead of dictionaries._ <br><br>
+impl PhysicsDirectSpaceState3D {
+    /// Casts a ray, returning the closest hit.
+    ///
+    /// Typed version of [`intersect_ray()`][Self::intersect_ray].
+    pub fn cast_ray(&mut self, query: &RayQuery3D) -> Option<RayHit3D> {
+        let dict = self.intersect_ray(&query.to_parameters());
+        RayHit3D::from_dictionary(&dict)
+    }
+
+    /// Returns up to `max_results` objects overlapping the query shape.
+    ///
+    /// Typed version of [`intersect_shape()`][Self::intersect_shape]. The query's motion is ignored.
+    pub fn intersect_shape_hits(
+        &mut self,
+        query: &ShapeQuery3D,
+        max_results: i32,
+    ) -> Vec<ShapeHit3D> {
+        self.intersect_shape_ex(&query.to_parameters())
+            .max_results(max_results)
+            .done()
+            .iter_shared()
+            .filter_map(|dict| ShapeHit3D::from_dictionary(&dict))
+            .collect()
+    }
+
+    /// Moves the query shape along its motion, and reports how far it can go without colliding.
+    ///
+    /// Typed version of [`cast_motion()`][Self::cast_motion]. Returns `None` if the query failed, e.g. because the shape is invalid.
+    pub fn cast_motion_result(&mut self, query: &ShapeQuery3D) -> Option<MotionResult> {
+        let fractions = self.cast_motion(&query.to_parameters());
+        MotionResult::from_fractions(fractions.as_slice())
+    }
+
+    /// Returns the closest contact of the query shape with another object, if any.
+    ///
+    /// Typed version of [`get_rest_info()`][Self::get_rest_info].
+    pub fn rest_info(&mut self, query: &ShapeQuery3D) -> Option<RestInfo3D> {
+        let dict = self.get_rest_info(&query.to_parameters());
+        RestInfo3D::from_dictionary(&dict)
+    }
+}
+
+impl OwnedSpace3D {
+    /// Direct access to the space for queries. See `PhysicsServer3D.space_get_direct_state()`.
+    ///
+    /// Returns `None` if the space is not accessible right now, e.g. during a physics step on another thread.
+    pub fn direct_state(&self) -> Option<Gd<PhysicsDirectSpaceState3D>> {
+        PhysicsServer3D::singleton().space_get_direct_state(self.rid())
+    }
+}
@@ -0,0 +1,462 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::ops::{Deref, DerefMut};
+
+use crate::builtin::{
+    Color, PackedArray, PackedColorArray, PackedInt32Array, PackedVector2Array, Rect2, Rid,
+    Transform2D, Vector2,
+};
+use crate::classes::rendering_server::NinePatchAxisMode;
+use crate::classes::RenderingServer;
+use crate::meta::PackedArrayElement;
+use crate::obj::{Gd, Singleton};
+
+/// Fluent builder for `RenderingServer.canvas_item_add_*()` draw commands.
+///
+/// Takes slices instead of packed arrays. The slices are copied into scratch [`CanvasDrawBuffers`]; to reuse those instead of
+/// creating new packed arrays every frame, keep a `CanvasDrawBuffers` around and pass it to [`with_buffers()`][Self::with_buffers] or
+/// [`OwnedCanvasItem::draw_with()`](super::OwnedCanvasItem::draw_with).
+///
+/// Commands are sent to the server immediately; there is no need to finish the builder.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::rendering::{CanvasDrawBuffers, OwnedCanvasItem};
+/// fn redraw(item: &mut OwnedCanvasItem, buffers: &mut CanvasDrawBuffers, path: &[Vector2]) {
+///     item.draw_with(buffers)
+///         .clear()
+///         .z_index(2)
+///         .rect(Rect2::new(Vector2::ZERO, Vector2::new(64.0, 64.0)), Color::BLACK)
+///         .polyline(path, &[Color::WHITE], 2.0, true)
+///         .circle(Vector2::new(32.0, 32.0), 4.0, Color::RED);
+/// }
+/// ```
+pub struct CanvasItemDraw<'a> {
+    canvas_item: Rid,
+    server: Gd<RenderingServer>,
+    buffers: BuffersRef<'a>,
+}
+
+impl<'a> CanvasItemDraw<'a> {
+    /// Starts drawing on `canvas_item`, which can be any canvas item RID, e.g. from `CanvasItem.get_canvas_item()`.
+    ///
+    /// Uses temporary scratch buffers; see [`with_buffers()`][Self::with_buffers] for reusable ones.
+    pub fn new(canvas_item: Rid) -> Self {
+        Self {
+            canvas_item,
+            server: RenderingServer::singleton(),
+            buffers: BuffersRef::Owned(CanvasDrawBuffers::new()),
+        }
+    }
+
+    /// Starts drawing on `canvas_item`, copying slices into `buffers` instead of fresh arrays.
+    pub fn with_buffers(canvas_item: Rid, buffers: &'a mut CanvasDrawBuffers) -> Self {
+        Self {
+            canvas_item,
+            server: RenderingServer::singleton(),
+            buffers: BuffersRef::Borrowed(buffers),
+        }
+    }
+
+    /// Removes all previously added draw commands from the canvas item.
+    ///
+    /// See `RenderingServer.canvas_item_clear()`.
+    pub fn clear(&mut self) -> &mut Self {
+        self.server.canvas_item_clear(self.canvas_item);
+        self
+    }
+
+    /// Sets the Z index of the canvas item, relative to its parent.
+    ///
+    /// See `RenderingServer.canvas_item_set_z_index()`.
+    pub fn z_index(&mut self, z_index: i32) -> &mut Self {
+        self.server
+            .canvas_item_set_z_index(self.canvas_item, z_index);
+        self
+    }
+
+    /// If `ignore` is true, subsequent commands ignore the clip rectangle of the canvas item.
+    ///
+    /// See `RenderingServer.canvas_item_add_clip_ignore()`.
+    pub fn clip_ignore(&mut self, ignore: bool) -> &mut Self {
+        self.server
+            .canvas_item_add_clip_ignore(self.canvas_item, ignore);
+        self
+    }
+
+    /// Applies `transform` to all subsequent commands.
+    ///
+    /// See `RenderingServer.canvas_item_add_set_transform()`.
+    pub fn transform(&mut self, transform: Transform2D) -> &mut Self {
+        self.server
+            .canvas_item_add_set_transform(self.canvas_item, transform);
+        self
+    }
+
+    /// Draws a line. A negative `width` draws a thin, 1-pixel line regardless of scaling.
+    ///
+    /// See `RenderingServer.canvas_item_add_line()`.
+    pub fn line(&mut self, from: Vector2, to: Vector2, color: Color, width: f32) -> &mut Self {
+        self.server
+            .canvas_item_add_line_ex(self.canvas_item, from, to, color)
+            .width(width)
+            .done();
+        self
+    }
+
+    /// Draws connected line segments through `points`.
+    ///
+    /// `colors` holds either a single color for the whole line, or one color per point.
+    ///
+    /// See `RenderingServer.canvas_item_add_polyline()`.
+    pub fn polyline(
+        &mut self,
+        points: &[Vector2],
+        colors: &[Color],
+        width: f32,
+        antialiased: bool,
+    ) -> &mut Self {
+        let buffers = &mut *self.buffers;
+        fill(&mut buffers.points, points);
+        fill(&mut buffers.colors, colors);
+
+        self.server
+            .canvas_item_add_polyline_ex(self.canvas_item, &buffers.points, &buffers.colors)
+            .width(width)
+            .antialiased(antialiased)
+            .done();
+        self
+    }
+
+    /// Draws disconnected line segments, each between two consecutive `points`.
+    ///
+    /// `colors` holds either a single color, or one color per segment.
+    ///
+    /// See `RenderingServer.canvas_item_add_multiline()`.
+    pub fn multiline(&mut self, points: &[Vector2], colors: &[Color], width: f32) -> &mut Self {
+        let buffers = &mut *self.buffers;
+        fill(&mut buffers.points, points);
+        fill(&mut buffers.colors, colors);
+
+        self.server
+            .canvas_item_add_multiline_ex(self.canvas_item, &buffers.points, &buffers.colors)
+            .width(width)
+            .done();
+        self
+    }
+
+    /// Draws a filled rectangle.
+    ///
+    /// See `RenderingServer.canvas_item_add_rect()`.
+    pub fn rect(&mut self, rect: Rect2, color: Color) -> &mut Self {
+        self.server
+            .canvas_item_add_rect(self.canvas_item, rect, color);
+        self
+    }
+
+    /// Draws a filled circle.
+    ///
+    /// See `RenderingServer.canvas_item_add_circle()`.
+    pub fn circle(&mut self, position: Vector2, radius: f32, color: Color) -> &mut Self {
+        self.server
+            .canvas_item_add_circle(self.canvas_item, position, radius, color);
+        self
+    }
+
+    /// Draws a filled polygon, which is triangulated by Godot.
+    ///
+    /// `colors` holds either a single color, or one color per point.
+    ///
+    /// See `RenderingServer.canvas_item_add_polygon()`.
+    pub fn polygon(&mut self, points: &[Vector2], colors: &[Color]) -> &mut Self {
+        let buffers = &mut *self.buffers;
+        fill(&mut buffers.points, points);
+        fill(&mut buffers.colors, colors);
+
+        self.server
+            .canvas_item_add_polygon(self.canvas_item, &buffers.points, &buffers.colors);
+        self
+    }
+
+    /// Draws a textured polygon with one UV coordinate per point.
+    ///
+    /// See `RenderingServer.canvas_item_add_polygon()`.
+    pub fn polygon_textured(
+        &mut self,
+        points: &[Vector2],
+        colors: &[Color],
+        uvs: &[Vector2],
+        texture: Rid,
+    ) -> &mut Self {
+        let buffers = &mut *self.buffers;
+        fill(&mut buffers.points, points);
+        fill(&mut buffers.colors, colors);
+        fill(&mut buffers.uvs, uvs);
+
+        self.server
+            .canvas_item_add_polygon_ex(self.canvas_item, &buffers.points, &buffers.colors)
+            .uvs(&buffers.uvs)
+            .texture(texture)
+            .done();
+        self
+    }
+
+    /// Draws triangles given by `indices` into `points`, without triangulation. Pass an empty `uvs` slice for untextured triangles.
+    ///
+    /// See `RenderingServer.canvas_item_add_triangle_array()`.
+    pub fn triangle_array(
+        &mut self,
+        indices: &[i32],
+        points: &[Vector2],
+        colors: &[Color],
+        uvs: &[Vector2],
+        texture: Rid,
+    ) -> &mut Self {
+        let buffers = &mut *self.buffers;
+        fill(&mut buffers.indices, indices);
+        fill(&mut buffers.points, points);
+        fill(&mut buffers.colors, colors);
+        fill(&mut buffers.uvs, uvs);
+
+        self.server
+            .canvas_item_add_triangle_array_ex(
+                self.canvas_item,
+                &buffers.indices,
+                &buffers.points,
+                &buffers.colors,
+            )
+            .uvs(&buffers.uvs)
+            .texture(texture)
+            .done();
+        self
+    }
+
+    /// Draws a primitive of 1 to 4 points: a point, a line, a triangle or a quad.
+    ///
+    /// `colors` and `uvs` must be empty or have as many elements as `points`.
+    ///
+    /// See `RenderingServer.canvas_item_add_primitive()`.
+    pub fn primitive(
+        &mut self,
+        points: &[Vector2],
+        colors: &[Color],
+        uvs: &[Vector2],
+        texture: Rid,
+    ) -> &mut Self {
+        let buffers = &mut *self.buffers;
+        fill(&mut buffers.points, points);
+        fill(&mut buffers.colors, colors);
+        fill(&mut buffers.uvs, uvs);
+
+        self.server.canvas_item_add_primitive(
+            self.canvas_item,
+            &buffers.points,
+            &buffers.colors,
+            &buffers.uvs,
+            texture,
+        );
+        self
+    }
+
+    /// Draws `texture` stretched to `rect`, tinted by `modulate`.
+    ///
+    /// See `RenderingServer.canvas_item_add_texture_rect()`.
+    pub fn texture_rect(&mut self, rect: Rect2, texture: Rid, modulate: Color) -> &mut Self {
+        self.server
+            .canvas_item_add_texture_rect_ex(self.canvas_item, rect, texture)
+            .modulate(modulate)
+            .done();
+        self
+    }
+
+    /// Draws `texture` repeated to fill `rect`, tinted by `modulate`.
+    ///
+    /// See `RenderingServer.canvas_item_add_texture_rect()`.
+    pub fn texture_rect_tiled(&mut self, rect: Rect2, texture: Rid, modulate: Color) -> &mut Self {
+        self.server
+            .canvas_item_add_texture_rect_ex(self.canvas_item, rect, texture)
+            .tile(true)
+            .modulate(modulate)
+            .done();
+        self
+    }
+
+    /// Draws the `src_rect` region of `texture` into `rect`, tinted by `modulate`.
+    ///
+    /// See `RenderingServer.canvas_item_add_texture_rect_region()`.
+    pub fn texture_rect_region(
+        &mut self,
+        rect: Rect2,
+        texture: Rid,
+        src_rect: Rect2,
+        modulate: Color,
+    ) -> &mut Self {
+        self.server
+            .canvas_item_add_texture_rect_region_ex(self.canvas_item, rect, texture, src_rect)
+            .modulate(modulate)
+            .done();
+        self
+    }
+
+    /// Draws the `src_rect` region of a multichannel signed distance field texture, e.g. for font glyphs.
+    ///
+    /// See `RenderingServer.canvas_item_add_msdf_texture_rect_region()`.
+    pub fn msdf_texture_rect_region(
+        &mut self,
+        rect: Rect2,
+        texture: Rid,
+        src_rect: Rect2,
+        modulate: Color,
+    ) -> &mut Self {
+        self.server
+            .canvas_item_add_msdf_texture_rect_region_ex(self.canvas_item, rect, texture, src_rect)
+            .modulate(modulate)
+            .done();
+        self
+    }
+
+    /// Draws a nine-patch: the corners of `source` keep their size, while edges and center stretch to `rect`.
+    ///
+    /// `top_left` and `bottom_right` are the margins of the fixed-size corners, in texture pixels. `x_axis_mode` and `y_axis_mode`
+    /// control how the horizontal and vertical edges and the center fill the space between the corners.
+    ///
+    /// See `RenderingServer.canvas_item_add_nine_patch()`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn nine_patch(
+        &mut self,
+        rect: Rect2,
+        source: Rect2,
+        texture: Rid,
+        top_left: Vector2,
+        bottom_right: Vector2,
+        x_axis_mode: NinePatchAxisMode,
+        y_axis_mode: NinePatchAxisMode,
+        draw_center: bool,
+        modulate: Color,
+    ) -> &mut Self {
+        self.server
+            .canvas_item_add_nine_patch_ex(
+                self.canvas_item,
+                rect,
+                source,
+                texture,
+                top_left,
+                bottom_right,
+            )
+            .x_axis_mode(x_axis_mode)
+            .y_axis_mode(y_axis_mode)
+            .draw_center(draw_center)
+            .modulate(modulate)
+            .done();
+        self
+    }
+
+    /// Draws a 2D mesh with the given transform.
+    ///
+    /// See `RenderingServer.canvas_item_add_mesh()`.
+    pub fn mesh(
+        &mut self,
+        mesh: Rid,
+        transform: Transform2D,
+        modulate: Color,
+        texture: Rid,
+    ) -> &mut Self {
+        self.server
+            .canvas_item_add_mesh_ex(self.canvas_item, mesh)
+            .transform(transform)
+            .modulate(modulate)
+            .texture(texture)
+            .done();
+        self
+    }
+
+    /// Draws all instances of a 2D multimesh.
+    ///
+    /// See `RenderingServer.canvas_item_add_multimesh()`.
+    pub fn multimesh(&mut self, multimesh: Rid, texture: Rid) -> &mut Self {
+        self.server
+            .canvas_item_add_multimesh_ex(self.canvas_item, multimesh)
+            .texture(texture)
+            .done();
+        self
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Scratch buffers
+
+/// Reusable packed arrays for [`CanvasItemDraw`].
+///
+/// Keeping one instance across frames reuses the same packed arrays for all commands: each command overwrites the previous contents
+/// in place. Godot may still allocate, for example when the server keeps a reference to an array and it is copied on write.
+#[derive(Default, Debug)]
+pub struct CanvasDrawBuffers {
+    points: PackedVector2Array,
+    colors: PackedColorArray,
+    uvs: PackedVector2Array,
+    indices: PackedInt32Array,
+}
+
+impl CanvasDrawBuffers {
+    /// Creates empty buffers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Points passed to the most recent command that takes points.
+    pub fn points(&self) -> &PackedVector2Array {
+        &self.points
+    }
+
+    /// Colors passed to the most recent command that takes colors.
+    pub fn colors(&self) -> &PackedColorArray {
+        &self.colors
+    }
+
+    /// UV coordinates passed to the most recent command that takes UVs.
+    pub fn uvs(&self) -> &PackedVector2Array {
+        &self.uvs
+    }
+
+    /// Indices passed to the most recent [`CanvasItemDraw::triangle_array()`] command.
+    pub fn indices(&self) -> &PackedInt32Array {
+        &self.indices
+    }
+}
+
+enum BuffersRef<'a> {
+    Owned(CanvasDrawBuffers),
+    Borrowed(&'a mut CanvasDrawBuffers),
+}
+
+impl Deref for BuffersRef<'_> {
+    type Target = CanvasDrawBuffers;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Owned(buffers) => buffers,
+            Self::Borrowed(buffers) => buffers,
+        }
+    }
+}
+
+impl DerefMut for BuffersRef<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            Self::Owned(buffers) => buffers,
+            Self::Borrowed(buffers) => buffers,
+        }
+    }
+}
+
+/// Overwrites `array` with `values`, in place.
+fn fill<T: PackedArrayElement + Copy>(array: &mut PackedArray<T>, values: &[T]) {
+    array.resize(values.len());
+    array.as_mut_slice().copy_from_slice(values);
+}
@@ -5,7 +5,9 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-//! RAII wrappers for `RenderingServer` resources.
+//! RAII wrappers for `RenderingServer` resources, and the [`CanvasItemDraw`] builder for 2D draw commands.
+
+mod canvas_draw;
 
 pub mod owned_camera;
 pub mod owned_camera_attributes;
@@ -27,6 +29,7 @@ pub mod owned_texture;
 pub mod owned_viewport;
 pub mod owned_voxel_gi;
 
+pub use canvas_draw::{CanvasDrawBuffers, CanvasItemDraw};
 pub use owned_camera::OwnedCamera;
 pub use owned_camera_attributes::OwnedCameraAttributes;
 pub use owned_canvas::OwnedCanvas;
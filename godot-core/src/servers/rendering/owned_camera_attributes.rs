@@ -10,6 +10,7 @@ impl OwnedCameraAttributes {
     #[track_caller]
     pub fn new() -> Self {
         let rid = RenderingServer::singleton().camera_attributes_create();
-        Self { rid }
+        // SAFETY: `rid` was just created by the server and is only owned by the returned wrapper.
+        unsafe { Self::from_rid(rid) }
     }
 }
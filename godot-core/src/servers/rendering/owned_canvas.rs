@@ -11,7 +11,8 @@ impl OwnedCanvas {
     #[track_caller]
     pub fn new() -> Self {
         let rid = RenderingServer::singleton().canvas_create();
-        Self { rid }
+        // SAFETY: `rid` was just created by the server and is only owned by the returned wrapper.
+        unsafe { Self::from_rid(rid) }
     }
 
     /// Sets the mirroring of a canvas item.
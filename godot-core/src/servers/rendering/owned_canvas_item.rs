@@ -1,6 +1,7 @@
 use crate::builtin::{Color, Rect2, Rid, Transform2D, Vector2};
 use crate::classes::RenderingServer;
 use crate::obj::Singleton;
+use crate::servers::rendering::{CanvasDrawBuffers, CanvasItemDraw};
 
 crate::obj::impl_owned_rid!(OwnedCanvasItem, "A RAII wrapper for a canvas item RID that is owned by this type.\nThe canvas item is freed when this object is dropped.", @default);
 
@@ -10,7 +11,18 @@ impl OwnedCanvasItem {
     /// See `RenderingServer.canvas_item_create()`.
     pub fn new() -> Self {
         let rid = RenderingServer::singleton().canvas_item_create();
-        Self { rid }
+        // SAFETY: `rid` was just created by the server and is only owned by the returned wrapper.
+        unsafe { Self::from_rid(rid) }
+    }
+
+    /// Returns a fluent builder for draw commands on this canvas item, see [`CanvasItemDraw`].
+    pub fn draw(&mut self) -> CanvasItemDraw<'_> {
+        CanvasItemDraw::new(self.rid)
+    }
+
+    /// Like [`draw()`][Self::draw], but reuses `buffers` instead of creating new packed arrays for every call.
+    pub fn draw_with<'a>(&'a mut self, buffers: &'a mut CanvasDrawBuffers) -> CanvasItemDraw<'a> {
+        CanvasItemDraw::with_buffers(self.rid, buffers)
     }
 
     /// Sets the parent of the canvas item.
@@ -10,7 +10,8 @@ impl OwnedInstance {
     /// See `RenderingServer.instance_create()`.
     pub fn new() -> Self {
         let rid = RenderingServer::singleton().instance_create();
-        Self { rid }
+        // SAFETY: `rid` was just created by the server and is only owned by the returned wrapper.
+        unsafe { Self::from_rid(rid) }
     }
 
     /// Creates a new instance with base and scenario and returns a wrapper that will free it on drop.
@@ -18,7 +19,8 @@ impl OwnedInstance {
     /// See `RenderingServer.instance_create2()`.
     pub fn new_with_base(base: Rid, scenario: Rid) -> Self {
         let rid = RenderingServer::singleton().instance_create2(base, scenario);
-        Self { rid }
+        // SAFETY: `rid` was just created by the server and is only owned by the returned wrapper.
+        unsafe { Self::from_rid(rid) }
     }
 
     /// Sets the base of the instance.
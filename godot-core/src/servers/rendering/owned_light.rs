@@ -21,7 +21,8 @@ impl OwnedLight {
             LightType::SPOT => server.spot_light_create(),
             _ => panic!("Unsupported light type"),
         };
-        Self { rid }
+        // SAFETY: `rid` was just created by the server and is only owned by the returned wrapper.
+        unsafe { Self::from_rid(rid) }
     }
 
     /// Sets the color of the light.
@@ -10,7 +10,8 @@ impl OwnedMaterial {
     /// See `RenderingServer.material_create()`.
     pub fn new() -> Self {
         let rid = RenderingServer::singleton().material_create();
-        Self { rid }
+        // SAFETY: `rid` was just created by the server and is only owned by the returned wrapper.
+        unsafe { Self::from_rid(rid) }
     }
 
     /// Sets a parameter on the material.
@@ -11,7 +11,8 @@ impl OwnedMesh {
     /// See `RenderingServer.mesh_create()`.
     pub fn new() -> Self {
         let rid = RenderingServer::singleton().mesh_create();
-        Self { rid }
+        // SAFETY: `rid` was just created by the server and is only owned by the returned wrapper.
+        unsafe { Self::from_rid(rid) }
     }
 
     /// Creates a new mesh from surfaces and returns a wrapper that will free it on drop.
@@ -19,7 +20,8 @@ impl OwnedMesh {
     /// See `RenderingServer.mesh_create_from_surfaces()`.
     pub fn new_from_surfaces(surfaces: &Array<VarDictionary>) -> Self {
         let rid = RenderingServer::singleton().mesh_create_from_surfaces(surfaces);
-        Self { rid }
+        // SAFETY: `rid` was just created by the server and is only owned by the returned wrapper.
+        unsafe { Self::from_rid(rid) }
     }
 
     /// Adds a surface to the mesh.
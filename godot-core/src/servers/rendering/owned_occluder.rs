@@ -10,6 +10,7 @@ impl OwnedOccluder {
     #[track_caller]
     pub fn new() -> Self {
         let rid = RenderingServer::singleton().occluder_create();
-        Self { rid }
+        // SAFETY: `rid` was just created by the server and is only owned by the returned wrapper.
+        unsafe { Self::from_rid(rid) }
     }
 }
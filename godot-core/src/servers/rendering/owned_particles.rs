@@ -14,6 +14,7 @@ impl OwnedParticles {
     #[track_caller]
     pub fn new() -> Self {
         let rid = RenderingServer::singleton().particles_create();
-        Self { rid }
+        // SAFETY: `rid` was just created by the server and is only owned by the returned wrapper.
+        unsafe { Self::from_rid(rid) }
     }
 }
@@ -10,7 +10,8 @@ impl OwnedShader {
     #[track_caller]
     pub fn new() -> Self {
         let rid = RenderingServer::singleton().shader_create();
-        Self { rid }
+        // SAFETY: `rid` was just created by the server and is only owned by the returned wrapper.
+        unsafe { Self::from_rid(rid) }
     }
 
     /// Sets the code for the shader.
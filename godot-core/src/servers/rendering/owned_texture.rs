@@ -14,7 +14,8 @@ impl OwnedTexture {
     #[track_caller]
     pub fn new(image: &crate::obj::Gd<crate::classes::Image>) -> Self {
         let rid = RenderingServer::singleton().texture_2d_create(Some(image));
-        Self { rid }
+        // SAFETY: `rid` was just created by the server and is only owned by the returned wrapper.
+        unsafe { Self::from_rid(rid) }
     }
 
     /// Creates a new placeholder texture and returns a wrapper that will free it on drop.
@@ -23,7 +24,8 @@ impl OwnedTexture {
     #[track_caller]
     pub fn new_placeholder() -> Self {
         let rid = RenderingServer::singleton().texture_2d_placeholder_create();
-        Self { rid }
+        // SAFETY: `rid` was just created by the server and is only owned by the returned wrapper.
+        unsafe { Self::from_rid(rid) }
     }
 
     /// Creates a new proxy texture from a `RenderingDevice` texture and returns a wrapper that will free it on drop.
@@ -32,6 +34,7 @@ impl OwnedTexture {
     #[track_caller]
     pub fn new_from_rd(rd_texture: crate::builtin::Rid) -> Self {
         let rid = RenderingServer::singleton().texture_rd_create(rd_texture);
-        Self { rid }
+        // SAFETY: `rid` was just created by the server and is only owned by the returned wrapper.
+        unsafe { Self::from_rid(rid) }
     }
 }
@@ -10,7 +10,8 @@ impl OwnedViewport {
     #[track_caller]
     pub fn new() -> Self {
         let rid = RenderingServer::singleton().viewport_create();
-        Self { rid }
+        // SAFETY: `rid` was just created by the server and is only owned by the returned wrapper.
+        unsafe { Self::from_rid(rid) }
     }
 
     /// Sets the size of the viewport.
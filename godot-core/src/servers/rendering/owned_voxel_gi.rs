@@ -10,6 +10,7 @@ impl OwnedVoxelGI {
     #[track_caller]
     pub fn new() -> Self {
         let rid = RenderingServer::singleton().voxel_gi_create();
-        Self { rid }
+        // SAFETY: `rid` was just created by the server and is only owned by the returned wrapper.
+        unsafe { Self::from_rid(rid) }
     }
 }
@@ -5,16 +5,33 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::builtin::Rid;
-use crate::classes::RenderingDevice;
-use crate::obj::Gd;
-use crate::servers::rendering_device::{OwnedRdPipeline, OwnedRdUniformSet};
+use std::fmt;
+
+use crate::builtin::{GString, PackedByteArray, Rid};
+use crate::classes::rendering_device::{ShaderLanguage, ShaderStage};
+use crate::classes::{RdShaderSource, RenderingDevice, RenderingServer};
+use crate::obj::{Gd, NewGd, Singleton};
+use crate::servers::rendering_device::pod::bytes_of;
+use crate::servers::rendering_device::{
+    OwnedRdPipeline, OwnedRdShader, OwnedRdUniformSet, Pod, UniformSetBuilder,
+};
 
 /// A helper for simplified compute shader dispatch.
+///
+/// Owns the pipeline and its uniform sets, and optionally the shader and a local rendering device (see
+/// [`new_local_from_glsl()`][Self::new_local_from_glsl]). A single dispatch is available through [`dispatch()`][Self::dispatch];
+/// for several passes in one compute list, use [`ComputeList`].
 pub struct ComputePipeline {
     rd: Gd<RenderingDevice>,
-    pipeline: OwnedRdPipeline,
+    shader: Rid,
+    push_constant: Option<PackedByteArray>,
+
+    // Drop order matters: Godot frees dependent uniform sets and pipelines together with their shader, so those must go first.
+    // The local rendering device, if any, is freed last.
     uniform_sets: Vec<OwnedRdUniformSet>,
+    pipeline: OwnedRdPipeline,
+    owned_shader: Option<OwnedRdShader>,
+    local_rd: Option<LocalRenderingDevice>,
 }
 
 impl ComputePipeline {
@@ -23,40 +40,280 @@ impl ComputePipeline {
         let pipeline = rd.compute_pipeline_create_owned(shader);
         Self {
             rd,
-            pipeline,
+            shader,
+            push_constant: None,
             uniform_sets: Vec::new(),
+            pipeline,
+            owned_shader: None,
+            local_rd: None,
         }
     }
 
+    /// Compiles the compute shader `glsl_source` and creates a pipeline for it. The pipeline owns the shader.
+    pub fn from_glsl(
+        mut rd: Gd<RenderingDevice>,
+        glsl_source: &str,
+    ) -> Result<Self, ComputeShaderError> {
+        let shader = compile_compute_shader(&mut rd, glsl_source)?;
+
+        let mut pipeline = Self::new(rd, shader.rid());
+        pipeline.owned_shader = Some(shader);
+        Ok(pipeline)
+    }
+
+    /// Like [`from_glsl()`][Self::from_glsl], but runs on a new local rendering device owned by the pipeline.
+    ///
+    /// Local devices are independent of rendering; work is only executed upon [`submit()`][Self::submit], which makes them suitable
+    /// for GPU computations whose results are read back on the CPU. The device is freed when the pipeline is dropped, so any resources
+    /// created on [`rd()`][Self::rd] must be dropped before that.
+    pub fn new_local_from_glsl(glsl_source: &str) -> Result<Self, ComputeShaderError> {
+        let rd = RenderingServer::singleton()
+            .create_local_rendering_device()
+            .ok_or(ComputeShaderError::NoRenderingDevice)?;
+
+        // Guard frees the device also if compilation fails.
+        let local_rd = LocalRenderingDevice(rd.clone());
+
+        let mut pipeline = Self::from_glsl(rd, glsl_source)?;
+        pipeline.local_rd = Some(local_rd);
+        Ok(pipeline)
+    }
+
+    /// The rendering device this pipeline runs on. Use it to create buffers and textures for the shader.
+    pub fn rd(&self) -> Gd<RenderingDevice> {
+        self.rd.clone()
+    }
+
+    /// The compute shader of this pipeline.
+    pub fn shader(&self) -> Rid {
+        self.shader
+    }
+
     /// Binds a uniform set to the pipeline.
     pub fn bind_uniform_set(&mut self, uniform_set: OwnedRdUniformSet) {
         self.uniform_sets.push(uniform_set);
     }
 
+    /// Creates a uniform set from `uniforms` and binds it at the next set index.
+    ///
+    /// The first call binds set 0, the second set 1, and so on, matching `layout(set = N, ...)` in the shader.
+    pub fn bind_uniforms(&mut self, uniforms: &UniformSetBuilder) {
+        let shader_set = crate::builtin::to_u32(self.uniform_sets.len() as u64);
+        let uniform_set = uniforms.build(&mut self.rd, self.shader, shader_set);
+        self.bind_uniform_set(uniform_set);
+    }
+
+    /// Sets the push constant block, which is passed to every subsequent dispatch.
+    ///
+    /// `T` must match the shader's `layout(push_constant)` block byte for byte. Godot expects the block size to be a multiple of
+    /// 16 bytes, so add padding fields where necessary.
+    pub fn set_push_constant<T: Pod>(&mut self, data: &T) {
+        let bytes = bytes_of(std::slice::from_ref(data));
+        self.push_constant = Some(PackedByteArray::from(bytes));
+    }
+
+    /// Removes the push constant block.
+    pub fn clear_push_constant(&mut self) {
+        self.push_constant = None;
+    }
+
     /// Dispatches the compute shader.
     pub fn dispatch(&mut self, x_groups: u32, y_groups: u32, z_groups: u32) {
-        let compute_list = self.rd.compute_list_begin();
-        self.rd
-            .compute_list_bind_compute_pipeline(compute_list, self.pipeline.rid());
+        let mut list = ComputeList::begin(&self.rd);
+        list.dispatch(self, x_groups, y_groups, z_groups);
+        list.end();
+    }
+
+    /// Submits the compute work and optionally waits for it to finish.
+    pub fn submit(&mut self, wait: bool) {
+        self.rd.submit();
+        if wait {
+            self.rd.sync();
+        }
+    }
+
+    fn bind_to_list(&self, rd: &mut Gd<RenderingDevice>, compute_list: i64) {
+        rd.compute_list_bind_compute_pipeline(compute_list, self.pipeline.rid());
 
         for (i, uniform_set) in self.uniform_sets.iter().enumerate() {
-            self.rd.compute_list_bind_uniform_set(
+            rd.compute_list_bind_uniform_set(
                 compute_list,
                 uniform_set.rid(),
                 crate::builtin::to_u32(i as u64),
             );
         }
 
+        if let Some(push_constant) = &self.push_constant {
+            let size = crate::builtin::to_u32(push_constant.len() as u64);
+            rd.compute_list_set_push_constant(compute_list, push_constant, size);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Compute lists
+
+/// A compute list recording several dispatches, possibly of different pipelines.
+///
+/// Use [`barrier()`][Self::barrier] between passes where one pass reads the output of a previous one. The list is ended by
+/// [`end()`][Self::end], or when dropped.
+///
+/// # Example
+/// ```no_run
+/// # use godot::rendering_device::helper::{ComputeList, ComputePipeline};
+/// # fn run(mut blur: ComputePipeline, composite: ComputePipeline) {
+/// let mut list = ComputeList::begin(&blur.rd());
+/// list.dispatch(&blur, 64, 64, 1)
+///     .barrier()
+///     .dispatch(&composite, 64, 64, 1);
+/// list.end();
+///
+/// blur.submit(true);
+/// # }
+/// ```
+pub struct ComputeList {
+    rd: Gd<RenderingDevice>,
+    compute_list: i64,
+    ended: bool,
+}
+
+impl ComputeList {
+    /// Starts a new compute list on `rd`.
+    ///
+    /// See `RenderingDevice.compute_list_begin()`.
+    pub fn begin(rd: &Gd<RenderingDevice>) -> Self {
+        let mut rd = rd.clone();
+        let compute_list = rd.compute_list_begin();
+
+        Self {
+            rd,
+            compute_list,
+            ended: false,
+        }
+    }
+
+    /// Binds `pipeline` together with its uniform sets and push constant, and dispatches it.
+    ///
+    /// # Panics
+    /// If `pipeline` belongs to a different rendering device than this list.
+    pub fn dispatch(
+        &mut self,
+        pipeline: &ComputePipeline,
+        x_groups: u32,
+        y_groups: u32,
+        z_groups: u32,
+    ) -> &mut Self {
+        assert_eq!(
+            pipeline.rd.instance_id(),
+            self.rd.instance_id(),
+            "ComputeList::dispatch(): pipeline was created on a different RenderingDevice"
+        );
+
+        pipeline.bind_to_list(&mut self.rd, self.compute_list);
         self.rd
-            .compute_list_dispatch(compute_list, x_groups, y_groups, z_groups);
-        self.rd.compute_list_end();
+            .compute_list_dispatch(self.compute_list, x_groups, y_groups, z_groups);
+        self
     }
 
-    /// Submits the compute work and optionally waits for it to finish.
-    pub fn submit(&mut self, wait: bool) {
-        self.rd.submit();
-        if wait {
-            self.rd.sync();
+    /// Makes sure all previous dispatches in this list have finished before subsequent ones start.
+    ///
+    /// See `RenderingDevice.compute_list_add_barrier()`.
+    pub fn barrier(&mut self) -> &mut Self {
+        self.rd.compute_list_add_barrier(self.compute_list);
+        self
+    }
+
+    /// Ends the compute list. Submit the work through the pipeline or rendering device afterwards.
+    pub fn end(mut self) {
+        self.end_list();
+    }
+
+    fn end_list(&mut self) {
+        if !self.ended {
+            self.ended = true;
+            self.rd.compute_list_end();
+        }
+    }
+}
+
+impl Drop for ComputeList {
+    fn drop(&mut self) {
+        self.end_list();
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Shader compilation
+
+/// Compiles the compute shader `glsl_source` to SPIR-V and creates a shader from it.
+///
+/// The source must not contain the `#[compute]` header used by `.glsl` files in the Godot editor.
+pub fn compile_compute_shader(
+    rd: &mut Gd<RenderingDevice>,
+    glsl_source: &str,
+) -> Result<OwnedRdShader, ComputeShaderError> {
+    let mut source = RdShaderSource::new_gd();
+    source.set_language(ShaderLanguage::GLSL);
+    source.set_stage_source(ShaderStage::COMPUTE, glsl_source);
+
+    let spirv = rd
+        .shader_compile_spirv_from_source(&source)
+        .ok_or(ComputeShaderError::Invalid)?;
+
+    let message = spirv.get_stage_compile_error(ShaderStage::COMPUTE);
+    if !message.is_empty() {
+        return Err(ComputeShaderError::Compile {
+            stage: ShaderStage::COMPUTE,
+            message,
+        });
+    }
+
+    let shader = rd.shader_create_from_spirv_owned(&spirv);
+    if shader.rid().is_invalid() {
+        return Err(ComputeShaderError::Invalid);
+    }
+
+    Ok(shader)
+}
+
+/// Error returned when a compute shader cannot be set up.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ComputeShaderError {
+    /// No rendering device is available, e.g. with the Compatibility renderer or in headless mode.
+    NoRenderingDevice,
+
+    /// The GLSL source failed to compile.
+    Compile {
+        /// Shader stage that failed.
+        stage: ShaderStage,
+
+        /// Compiler output, including line numbers.
+        message: GString,
+    },
+
+    /// Godot rejected the compiled shader. Details are printed to the Godot console.
+    Invalid,
+}
+
+impl fmt::Display for ComputeShaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoRenderingDevice => write!(f, "no RenderingDevice available"),
+            Self::Compile { stage, message } => {
+                write!(f, "failed to compile {stage:?} shader stage:\n{message}")
+            }
+            Self::Invalid => write!(f, "failed to create shader from SPIR-V"),
         }
     }
 }
+
+impl std::error::Error for ComputeShaderError {}
+
+/// Frees a local rendering device on drop.
+struct LocalRenderingDevice(Gd<RenderingDevice>);
+
+impl Drop for LocalRenderingDevice {
+    fn drop(&mut self) {
+        self.0.clone().free();
+    }
+}
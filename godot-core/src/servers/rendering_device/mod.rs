@@ -6,6 +6,9 @@
  */
 
 //! RAII wrappers for `RenderingDevice` resources.
+//!
+//! Besides the `Owned*` wrappers, this module provides typed buffers ([`TypedRdBuffer`] for any [`Pod`] element type), a
+//! [`UniformSetBuilder`] and the [`ComputePipeline`](helper::ComputePipeline) helper for compute shader dispatch.
 
 pub mod helper;
 pub mod owned_rd_buffer;
@@ -18,6 +21,10 @@ pub mod owned_rd_texture;
 pub mod owned_rd_uniform_set;
 pub mod owned_rd_vertex_array;
 
+mod pod;
+mod typed_buffer;
+mod uniform_set_builder;
+
 pub use owned_rd_buffer::OwnedRdBuffer;
 pub use owned_rd_framebuffer::OwnedRdFramebuffer;
 pub use owned_rd_index_array::OwnedRdIndexArray;
@@ -27,6 +34,11 @@ pub use owned_rd_shader::OwnedRdShader;
 pub use owned_rd_texture::OwnedRdTexture;
 pub use owned_rd_uniform_set::OwnedRdUniformSet;
 pub use owned_rd_vertex_array::OwnedRdVertexArray;
+pub use pod::Pod;
+#[cfg(since_api = "4.4")]
+pub use typed_buffer::read_async::{RdReadError, RdReadFuture};
+pub use typed_buffer::TypedRdBuffer;
+pub use uniform_set_builder::UniformSetBuilder;
 
 impl crate::classes::RenderingDevice {
     /// Creates a new texture and returns a wrapper that will free it on drop.
@@ -37,6 +49,7 @@ impl crate::classes::RenderingDevice {
     ) -> OwnedRdTexture {
         let mut gd = crate::private::rebuild_gd(self).cast::<crate::classes::RenderingDevice>();
         let rid = gd.texture_create(format, view);
+        // SAFETY: `rid` was just created on this rendering device and is only owned by the returned wrapper.
         unsafe { OwnedRdTexture::from_rid(rid, gd) }
     }
 
@@ -47,6 +60,7 @@ impl crate::classes::RenderingDevice {
     ) -> OwnedRdSampler {
         let mut gd = crate::private::rebuild_gd(self).cast::<crate::classes::RenderingDevice>();
         let rid = gd.sampler_create(state);
+        // SAFETY: `rid` was just created on this rendering device and is only owned by the returned wrapper.
         unsafe { OwnedRdSampler::from_rid(rid, gd) }
     }
 
@@ -57,6 +71,7 @@ impl crate::classes::RenderingDevice {
     ) -> OwnedRdShader {
         let mut gd = crate::private::rebuild_gd(self).cast::<crate::classes::RenderingDevice>();
         let rid = gd.shader_create_from_spirv(spirv_data);
+        // SAFETY: `rid` was just created on this rendering device and is only owned by the returned wrapper.
         unsafe { OwnedRdShader::from_rid(rid, gd) }
     }
 
@@ -64,6 +79,7 @@ impl crate::classes::RenderingDevice {
     pub fn uniform_buffer_create_owned(&mut self, size_bytes: u32) -> OwnedRdBuffer {
         let mut gd = crate::private::rebuild_gd(self).cast::<crate::classes::RenderingDevice>();
         let rid = gd.uniform_buffer_create(size_bytes);
+        // SAFETY: `rid` was just created on this rendering device and is only owned by the returned wrapper.
         unsafe { OwnedRdBuffer::from_rid(rid, gd) }
     }
 
@@ -71,6 +87,7 @@ impl crate::classes::RenderingDevice {
     pub fn storage_buffer_create_owned(&mut self, size_bytes: u32) -> OwnedRdBuffer {
         let mut gd = crate::private::rebuild_gd(self).cast::<crate::classes::RenderingDevice>();
         let rid = gd.storage_buffer_create(size_bytes);
+        // SAFETY: `rid` was just created on this rendering device and is only owned by the returned wrapper.
         unsafe { OwnedRdBuffer::from_rid(rid, gd) }
     }
 
@@ -78,6 +95,7 @@ impl crate::classes::RenderingDevice {
     pub fn vertex_buffer_create_owned(&mut self, size_bytes: u32) -> OwnedRdBuffer {
         let mut gd = crate::private::rebuild_gd(self).cast::<crate::classes::RenderingDevice>();
         let rid = gd.vertex_buffer_create(size_bytes);
+        // SAFETY: `rid` was just created on this rendering device and is only owned by the returned wrapper.
         unsafe { OwnedRdBuffer::from_rid(rid, gd) }
     }
 
@@ -89,6 +107,7 @@ impl crate::classes::RenderingDevice {
     ) -> OwnedRdBuffer {
         let mut gd = crate::private::rebuild_gd(self).cast::<crate::classes::RenderingDevice>();
         let rid = gd.index_buffer_create(size_indices, format);
+        // SAFETY: `rid` was just created on this rendering device and is only owned by the returned wrapper.
         unsafe { OwnedRdBuffer::from_rid(rid, gd) }
     }
 
@@ -121,6 +140,7 @@ impl crate::classes::RenderingDevice {
     ) -> OwnedRdFramebuffer {
         let mut gd = crate::private::rebuild_gd(self).cast::<crate::classes::RenderingDevice>();
         let rid = gd.framebuffer_create(textures);
+        // SAFETY: `rid` was just created on this rendering device and is only owned by the returned wrapper.
         unsafe { OwnedRdFramebuffer::from_rid(rid, gd) }
     }
 
@@ -133,6 +153,7 @@ impl crate::classes::RenderingDevice {
     ) -> OwnedRdUniformSet {
         let mut gd = crate::private::rebuild_gd(self).cast::<crate::classes::RenderingDevice>();
         let rid = gd.uniform_set_create(uniforms, shader, shader_set);
+        // SAFETY: `rid` was just created on this rendering device and is only owned by the returned wrapper.
         unsafe { OwnedRdUniformSet::from_rid(rid, gd) }
     }
 
@@ -143,6 +164,7 @@ impl crate::classes::RenderingDevice {
     ) -> OwnedRdPipeline {
         let mut gd = crate::private::rebuild_gd(self).cast::<crate::classes::RenderingDevice>();
         let rid = gd.compute_pipeline_create(shader);
+        // SAFETY: `rid` was just created on this rendering device and is only owned by the returned wrapper.
         unsafe { OwnedRdPipeline::from_rid(rid, gd) }
     }
 
@@ -178,6 +200,7 @@ impl crate::classes::RenderingDevice {
             stencil_state,
             color_blend_state,
         );
+        // SAFETY: `rid` was just created on this rendering device and is only owned by the returned wrapper.
         unsafe { OwnedRdPipeline::from_rid(rid, gd) }
     }
 
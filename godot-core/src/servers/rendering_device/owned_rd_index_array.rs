@@ -26,6 +26,7 @@ impl OwnedRdIndexArray {
     ) -> Self {
         let mut server = server.clone();
         let rid = server.index_array_create(index_buffer, index_offset, index_count);
+        // SAFETY: `rid` was just created on this rendering device and is only owned by the returned wrapper.
         unsafe { Self::from_rid(rid, server) }
     }
 }
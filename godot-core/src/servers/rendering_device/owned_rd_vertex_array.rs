@@ -27,6 +27,7 @@ impl OwnedRdVertexArray {
     ) -> Self {
         let mut server = server.clone();
         let rid = server.vertex_array_create(vertex_count, vertex_format, src_buffers);
+        // SAFETY: `rid` was just created on this rendering device and is only owned by the returned wrapper.
         unsafe { Self::from_rid(rid, server) }
     }
 
@@ -46,6 +47,7 @@ impl OwnedRdVertexArray {
             src_buffers.to_ffi(),
             offsets.to_ffi(),
         );
+        // SAFETY: `rid` was just created on this rendering device and is only owned by the returned wrapper.
         unsafe { Self::from_rid(rid, server) }
     }
 }
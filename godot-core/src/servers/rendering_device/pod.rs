@@ -0,0 +1,118 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+/// "Plain old data": types that can be copied to and from GPU buffers as raw bytes.
+///
+/// Used by [`TypedRdBuffer<T>`](super::TypedRdBuffer) to upload and read back data without manual byte casting. Follows the same
+/// contract as `bytemuck::Pod`. With the `bytemuck` feature, every `bytemuck::Pod` type implements this trait, so your own types only
+/// need `#[derive(bytemuck::Pod, bytemuck::Zeroable)]`. Without it, the trait is implemented for integer and float primitives, arrays
+/// thereof, and the vector and color builtins.
+///
+/// # Layout
+/// Data is copied as-is, so the Rust layout must match the one declared in the shader. GLSL buffer layouts (`std140`/`std430`) may
+/// require padding that Rust types don't have; e.g. a `vec3` array element occupies 16 bytes in `std430`, so use `Vector4` or explicit
+/// padding fields for such data.
+///
+/// `Vector2`, `Vector3` and `Vector4` consist of [`real`](crate::builtin::real) components. With the `double-precision` feature, these
+/// are `f64`, matching GLSL `dvec2`/`dvec3`/`dvec4` instead of `vec2`/`vec3`/`vec4`; e.g. `Vector3` then occupies 24 bytes. Use
+/// `[f32; N]` or `Color` for single-precision shader data in that case.
+///
+/// # Safety
+/// Implementors must be `#[repr(C)]` or `#[repr(transparent)]` (or primitive), must not contain padding bytes, and every bit pattern
+/// of the right size must be a valid value. In particular, no `bool`, `char`, references, pointers or enums.
+pub unsafe trait Pod: Copy + Send + Sync + 'static {}
+
+// SAFETY: `bytemuck::Pod` has the same contract. Send + Sync are required separately, as raw byte data may be sent to other threads.
+#[cfg(feature = "interop-bytemuck")]
+unsafe impl<T: bytemuck::Pod + Send + Sync> Pod for T {}
+
+#[cfg(not(feature = "interop-bytemuck"))]
+mod impls {
+    use super::Pod;
+    use crate::builtin::{Color, Vector2, Vector2i, Vector3, Vector3i, Vector4, Vector4i};
+
+    macro_rules! impl_pod {
+        ($($ty:ty),* $(,)?) => {
+            $(
+                // SAFETY: primitives and `#[repr(C)]` structs of a single primitive type, no padding, all bit patterns valid.
+                unsafe impl Pod for $ty {}
+            )*
+        };
+    }
+
+    impl_pod!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+    impl_pod!(Vector2, Vector3, Vector4, Vector2i, Vector3i, Vector4i, Color);
+
+    // SAFETY: arrays have no padding between elements, and are valid for every bit pattern if their element type is.
+    unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
+}
+
+/// Views a slice of `T` as its raw bytes.
+pub(crate) fn bytes_of<T: Pod>(data: &[T]) -> &[u8] {
+    // SAFETY: `T: Pod` has no padding, so all bytes are initialized. u8 has alignment 1, and the length covers exactly the slice.
+    unsafe { std::slice::from_raw_parts(data.as_ptr().cast::<u8>(), size_of_val(data)) }
+}
+
+/// Copies raw bytes into a vector of `T`. Trailing bytes that don't make up a full element are ignored.
+pub(crate) fn vec_from_bytes<T: Pod>(bytes: &[u8]) -> Vec<T> {
+    let elem_size = size_of::<T>();
+    if elem_size == 0 {
+        return Vec::new();
+    }
+
+    let len = bytes.len() / elem_size;
+    let mut result = Vec::<T>::with_capacity(len);
+
+    // SAFETY: the vector has capacity for `len` elements; copying bytewise avoids alignment requirements on `bytes`. Any bit pattern
+    // is a valid `T`, so the elements are initialized after the copy.
+    unsafe {
+        std::ptr::copy_nonoverlapping(
+            bytes.as_ptr(),
+            result.as_mut_ptr().cast::<u8>(),
+            len * elem_size,
+        );
+        result.set_len(len);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builtin::{real, Vector2i, Vector3};
+
+    #[test]
+    fn pod_roundtrip() {
+        let values = [1.5f32, -2.0, 1e10];
+        let bytes = bytes_of(&values);
+        assert_eq!(bytes.len(), 12);
+        assert_eq!(&bytes[0..4], &1.5f32.to_ne_bytes());
+
+        let back: Vec<f32> = vec_from_bytes(bytes);
+        assert_eq!(back, values);
+    }
+
+    #[test]
+    fn pod_from_unaligned_bytes() {
+        let mut bytes = vec![0u8];
+        bytes.extend_from_slice(bytes_of(&[Vector2i::new(7, -3), Vector2i::new(1, 2)]));
+
+        // Trailing byte is ignored; source is misaligned by one byte.
+        bytes.push(0xff);
+        let back: Vec<Vector2i> = vec_from_bytes(&bytes[1..]);
+        assert_eq!(back, [Vector2i::new(7, -3), Vector2i::new(1, 2)]);
+    }
+
+    #[test]
+    fn pod_vector3_layout() {
+        // Components are `real`, i.e. f64 with `double-precision`.
+        let bytes = bytes_of(&[Vector3::new(1.0, 2.0, 3.0)]);
+        assert_eq!(bytes.len(), 3 * size_of::<real>());
+        assert_eq!(&bytes[..size_of::<real>()], &(1.0 as real).to_ne_bytes());
+    }
+}
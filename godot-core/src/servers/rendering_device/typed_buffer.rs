@@ -0,0 +1,291 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::marker::PhantomData;
+
+use crate::builtin::{PackedByteArray, Rid};
+use crate::classes::RenderingDevice;
+use crate::obj::Gd;
+use crate::servers::rendering_device::pod::{bytes_of, vec_from_bytes};
+use crate::servers::rendering_device::{OwnedRdBuffer, Pod};
+
+/// A rendering device buffer holding `len` elements of type `T`.
+///
+/// Wraps an [`OwnedRdBuffer`] and converts between `&[T]` and the raw bytes that `RenderingDevice` works with. Create one through
+/// [`RenderingDevice::storage_buffer_create_typed()`] or [`RenderingDevice::uniform_buffer_create_typed()`].
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::classes::RenderingServer;
+/// # use godot::obj::Singleton;
+/// let mut rd = RenderingServer::singleton().create_local_rendering_device().unwrap();
+///
+/// let mut buffer = rd.storage_buffer_create_typed(&[1.0f32, 2.0, 3.0]);
+/// buffer.write(&[4.0, 5.0, 6.0]);
+///
+/// let values: Vec<f32> = buffer.read();
+/// assert_eq!(values, [4.0, 5.0, 6.0]);
+/// ```
+#[derive(Debug)]
+pub struct TypedRdBuffer<T: Pod> {
+    buffer: OwnedRdBuffer,
+    len: usize,
+    _element: PhantomData<T>,
+}
+
+impl<T: Pod> TypedRdBuffer<T> {
+    /// Wraps an existing buffer which holds `len` elements of `T`.
+    ///
+    /// The buffer must be at least `len * size_of::<T>()` bytes large; otherwise, reads and writes fail with Godot errors.
+    pub fn from_buffer(buffer: OwnedRdBuffer, len: usize) -> Self {
+        Self {
+            buffer,
+            len,
+            _element: PhantomData,
+        }
+    }
+
+    /// Number of `T` elements in the buffer.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the buffer holds no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Size of the buffer in bytes.
+    pub fn size_bytes(&self) -> usize {
+        self.len * size_of::<T>()
+    }
+
+    /// Returns the underlying RID of the buffer.
+    pub fn rid(&self) -> Rid {
+        self.buffer.rid()
+    }
+
+    /// Returns the untyped buffer.
+    pub fn buffer(&self) -> &OwnedRdBuffer {
+        &self.buffer
+    }
+
+    /// Returns the untyped buffer, consuming `self`.
+    pub fn into_buffer(self) -> OwnedRdBuffer {
+        self.buffer
+    }
+
+    /// Overwrites the buffer, starting at the first element.
+    ///
+    /// # Panics
+    /// If `data` has more elements than the buffer.
+    #[track_caller]
+    pub fn write(&mut self, data: &[T]) {
+        self.write_at(0, data);
+    }
+
+    /// Overwrites the elements starting at `index`.
+    ///
+    /// # Panics
+    /// If `index + data.len()` exceeds the buffer length.
+    #[track_caller]
+    pub fn write_at(&mut self, index: usize, data: &[T]) {
+        assert!(
+            index + data.len() <= self.len,
+            "TypedRdBuffer::write_at(): writing {} elements at index {index} exceeds buffer length {}",
+            data.len(),
+            self.len
+        );
+
+        let offset = Self::byte_count(index);
+        self.buffer.update_data(bytes_of(data), offset);
+    }
+
+    /// Reads back the whole buffer. Blocks until the GPU has finished all pending work on the buffer.
+    ///
+    /// Only the first `len` elements are read, even if the underlying buffer is larger.
+    pub fn read(&self) -> Vec<T> {
+        // Godot interprets a size of 0 as "until the end of the buffer".
+        if self.is_empty() {
+            return Vec::new();
+        }
+
+        let packed = self
+            .buffer
+            .server()
+            .buffer_get_data_ex(self.rid())
+            .size_bytes(Self::byte_count(self.len))
+            .done();
+
+        vec_from_bytes(packed.as_slice())
+    }
+
+    /// Reads back the whole buffer asynchronously, without stalling the GPU.
+    ///
+    /// The returned future resolves once the rendering device has processed the request, which happens during frame processing (or
+    /// upon `sync()` for local rendering devices). Await it inside a [`godot::task::spawn()`](crate::task::spawn) task.
+    ///
+    /// See `RenderingDevice.buffer_get_data_async()`.
+    #[cfg(since_api = "4.4")]
+    pub fn read_async(&self) -> read_async::RdReadFuture<T> {
+        read_async::RdReadFuture::request(
+            self.buffer.server(),
+            self.rid(),
+            self.len,
+            Self::byte_count(self.len),
+        )
+    }
+
+    /// Byte size of `elements` elements of `T`, as expected by `RenderingDevice` offsets and sizes.
+    #[track_caller]
+    fn byte_count(elements: usize) -> u32 {
+        let bytes = elements
+            .checked_mul(size_of::<T>())
+            .expect("TypedRdBuffer: byte size overflows usize");
+
+        crate::builtin::to_u32_from_usize(bytes)
+    }
+}
+
+impl<T: Pod> AsRef<Rid> for TypedRdBuffer<T> {
+    fn as_ref(&self) -> &Rid {
+        self.buffer.as_ref()
+    }
+}
+
+impl crate::classes::RenderingDevice {
+    /// Creates a storage buffer initialized with `data`, and returns a typed wrapper that will free it on drop.
+    pub fn storage_buffer_create_typed<T: Pod>(&mut self, data: &[T]) -> TypedRdBuffer<T> {
+        let mut gd = crate::private::rebuild_gd(self).cast::<RenderingDevice>();
+        let packed = PackedByteArray::from(bytes_of(data));
+        let rid = gd
+            .storage_buffer_create_ex(crate::builtin::to_u32_from_usize(packed.len()))
+            .data(&packed)
+            .done();
+
+        // SAFETY: `rid` was just created on `gd` with room for exactly `data.len()` elements, and is only owned by the returned wrapper.
+        unsafe { Self::typed_buffer(gd, rid, data.len()) }
+    }
+
+    /// Creates a uniform buffer initialized with `data`, and returns a typed wrapper that will free it on drop.
+    pub fn uniform_buffer_create_typed<T: Pod>(&mut self, data: &[T]) -> TypedRdBuffer<T> {
+        let mut gd = crate::private::rebuild_gd(self).cast::<RenderingDevice>();
+        let packed = PackedByteArray::from(bytes_of(data));
+        let rid = gd
+            .uniform_buffer_create_ex(crate::builtin::to_u32_from_usize(packed.len()))
+            .data(&packed)
+            .done();
+
+        // SAFETY: `rid` was just created on `gd` with room for exactly `data.len()` elements, and is only owned by the returned wrapper.
+        unsafe { Self::typed_buffer(gd, rid, data.len()) }
+    }
+
+    /// # Safety
+    /// `rid` must be a buffer created on `gd` that holds `len` elements of `T`, and must not be freed elsewhere.
+    unsafe fn typed_buffer<T: Pod>(
+        gd: Gd<RenderingDevice>,
+        rid: Rid,
+        len: usize,
+    ) -> TypedRdBuffer<T> {
+        // SAFETY: upheld by the caller.
+        let buffer = unsafe { OwnedRdBuffer::from_rid(rid, gd) };
+        TypedRdBuffer::from_buffer(buffer, len)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Async readback
+
+#[cfg(since_api = "4.4")]
+pub mod read_async {
+    use std::fmt;
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    use crate::builtin::{PackedByteArray, Rid};
+    use crate::classes::RenderingDevice;
+    use crate::global::Error;
+    use crate::obj::Gd;
+    use crate::servers::rendering_device::pod::vec_from_bytes;
+    use crate::servers::rendering_device::Pod;
+    use crate::task::CallableFuture;
+
+    /// Future returned by [`TypedRdBuffer::read_async()`](super::TypedRdBuffer::read_async).
+    ///
+    /// Resolves to the buffer contents, or to an error if the rendering device rejected or dropped the request.
+    pub struct RdReadFuture<T: Pod> {
+        // Resolves to `None` if the callback's argument is not a byte array.
+        inner: CallableFuture<Option<Vec<T>>>,
+        rejected: Option<Error>,
+    }
+
+    impl<T: Pod> RdReadFuture<T> {
+        /// Requests the first `len` elements (`size_bytes` bytes) of `buffer`.
+        pub(super) fn request(
+            mut rd: Gd<RenderingDevice>,
+            buffer: Rid,
+            len: usize,
+            size_bytes: u32,
+        ) -> Self {
+            // A size of 0 makes Godot return the whole buffer, so the data is truncated here as well.
+            let (inner, callback) = CallableFuture::new("RdReadFuture", move |args| {
+                let bytes = args.first()?.try_to::<PackedByteArray>().ok()?;
+                let mut data = vec_from_bytes(bytes.as_slice());
+                data.truncate(len);
+                Some(data)
+            });
+
+            let result = rd
+                .buffer_get_data_async_ex(buffer, &callback)
+                .size_bytes(size_bytes)
+                .done();
+
+            Self {
+                inner,
+                rejected: (result != Error::OK).then_some(result),
+            }
+        }
+    }
+
+    impl<T: Pod> Future for RdReadFuture<T> {
+        type Output = Result<Vec<T>, RdReadError>;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let this = self.get_mut();
+            if let Some(err) = this.rejected.take() {
+                return Poll::Ready(Err(RdReadError::Rejected(err)));
+            }
+
+            Pin::new(&mut this.inner)
+                .poll(cx)
+                .map(|data| data.flatten().ok_or(RdReadError::Cancelled))
+        }
+    }
+
+    /// Error returned by [`RdReadFuture`].
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub enum RdReadError {
+        /// `buffer_get_data_async()` refused the request, e.g. because the buffer RID is invalid.
+        Rejected(Error),
+
+        /// The rendering device discarded the request without delivering data, e.g. because it was freed.
+        Cancelled,
+    }
+
+    impl fmt::Display for RdReadError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Self::Rejected(err) => write!(f, "buffer readback request rejected: {err:?}"),
+                Self::Cancelled => write!(f, "buffer readback cancelled before data arrived"),
+            }
+        }
+    }
+
+    impl std::error::Error for RdReadError {}
+}
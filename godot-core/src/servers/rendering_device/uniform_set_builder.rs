@@ -0,0 +1,119 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::{Array, Rid};
+use crate::classes::rendering_device::UniformType;
+use crate::classes::{RdUniform, RenderingDevice};
+use crate::obj::{Gd, NewGd};
+use crate::servers::rendering_device::OwnedRdUniformSet;
+
+/// Builder for uniform sets, replacing hand-built `Array<Gd<RdUniform>>`.
+///
+/// Each method adds one uniform at the given binding. Resources are passed as anything that exposes a RID, such as
+/// [`OwnedRdBuffer`](super::OwnedRdBuffer), [`TypedRdBuffer`](super::TypedRdBuffer), [`OwnedRdTexture`](super::OwnedRdTexture)
+/// or a plain [`Rid`].
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::classes::RenderingDevice;
+/// # use godot::rendering_device::UniformSetBuilder;
+/// # fn build(mut rd: Gd<RenderingDevice>, shader: Rid, input: Rid, output: Rid) {
+/// let uniform_set = UniformSetBuilder::new()
+///     .storage_buffer(0, &input)
+///     .storage_buffer(1, &output)
+///     .build(&mut rd, shader, 0);
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct UniformSetBuilder {
+    uniforms: Array<Gd<RdUniform>>,
+}
+
+impl UniformSetBuilder {
+    /// Creates an empty builder.
+    pub fn new() -> Self {
+        Self {
+            uniforms: Array::new(),
+        }
+    }
+
+    /// Adds a storage buffer (`buffer` block in GLSL).
+    pub fn storage_buffer(self, binding: i32, buffer: &impl AsRef<Rid>) -> Self {
+        self.with_uniform(UniformType::STORAGE_BUFFER, binding, &[*buffer.as_ref()])
+    }
+
+    /// Adds a uniform buffer (`uniform` block in GLSL).
+    pub fn uniform_buffer(self, binding: i32, buffer: &impl AsRef<Rid>) -> Self {
+        self.with_uniform(UniformType::UNIFORM_BUFFER, binding, &[*buffer.as_ref()])
+    }
+
+    /// Adds a sampled texture without sampler (`texture2D` in GLSL).
+    pub fn texture(self, binding: i32, texture: &impl AsRef<Rid>) -> Self {
+        self.with_uniform(UniformType::TEXTURE, binding, &[*texture.as_ref()])
+    }
+
+    /// Adds a storage image (`image2D` in GLSL).
+    pub fn image(self, binding: i32, texture: &impl AsRef<Rid>) -> Self {
+        self.with_uniform(UniformType::IMAGE, binding, &[*texture.as_ref()])
+    }
+
+    /// Adds a sampler (`sampler` in GLSL).
+    pub fn sampler(self, binding: i32, sampler: &impl AsRef<Rid>) -> Self {
+        self.with_uniform(UniformType::SAMPLER, binding, &[*sampler.as_ref()])
+    }
+
+    /// Adds a combined texture and sampler (`sampler2D` in GLSL).
+    pub fn sampler_with_texture(
+        self,
+        binding: i32,
+        sampler: &impl AsRef<Rid>,
+        texture: &impl AsRef<Rid>,
+    ) -> Self {
+        self.with_uniform(
+            UniformType::SAMPLER_WITH_TEXTURE,
+            binding,
+            &[*sampler.as_ref(), *texture.as_ref()],
+        )
+    }
+
+    /// Adds a uniform of any type, with the given resource IDs.
+    ///
+    /// Use this for uniform types that don't have a dedicated method.
+    pub fn with_uniform(mut self, uniform_type: UniformType, binding: i32, ids: &[Rid]) -> Self {
+        let mut uniform = RdUniform::new_gd();
+        uniform.set_uniform_type(uniform_type);
+        uniform.set_binding(binding);
+        for &id in ids {
+            uniform.add_id(id);
+        }
+
+        self.uniforms.push(&uniform);
+        self
+    }
+
+    /// Returns the uniforms added so far, e.g. to pass to `RenderingDevice.uniform_set_create()` directly.
+    pub fn uniforms(&self) -> &Array<Gd<RdUniform>> {
+        &self.uniforms
+    }
+
+    /// Creates the uniform set for set index `shader_set` of `shader`.
+    pub fn build(
+        &self,
+        rd: &mut Gd<RenderingDevice>,
+        shader: Rid,
+        shader_set: u32,
+    ) -> OwnedRdUniformSet {
+        rd.uniform_set_create_owned(&self.uniforms, shader, shader_set)
+    }
+}
+
+impl Default for UniformSetBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,142 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Debug registry of RIDs currently owned by `Owned*` RAII wrappers.
+//!
+//! Only active with `safeguards_strict`; in other builds, nothing is recorded and [`debug_live_rids()`] returns an empty list.
+
+use crate::builtin::Rid;
+
+/// Number of live RIDs of one resource kind, as returned by [`debug_live_rids()`].
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct LiveRidCount {
+    /// Server class that manages the resource, e.g. `"RenderingServer"`.
+    pub server: &'static str,
+
+    /// Name of the owning wrapper type, e.g. `"OwnedCanvasItem"`.
+    pub kind: &'static str,
+
+    /// How many RIDs of this kind are currently alive.
+    pub count: usize,
+}
+
+/// Returns all RIDs currently held by `Owned*` wrappers, grouped by server and resource kind.
+///
+/// Entries are sorted by server and kind; kinds without live RIDs are omitted. Intended for tests and leak hunting: compare the result
+/// before and after a piece of code to check that it doesn't retain server resources.
+///
+/// RIDs are only tracked in builds with strict safeguards (the default in Debug). Otherwise, this always returns an empty list.
+/// RIDs obtained through raw `*_create()` calls are not tracked, unless they are adopted via `from_rid()`.
+pub fn debug_live_rids() -> Vec<LiveRidCount> {
+    imp::live_rids()
+}
+
+/// Records a RID that has been handed to an `Owned*` wrapper.
+#[inline]
+pub(crate) fn on_create(server: &'static str, kind: &'static str, rid: Rid) {
+    if rid.is_valid() {
+        imp::on_create(server, kind, rid);
+    }
+}
+
+/// Removes a RID that has been freed by its `Owned*` wrapper.
+#[inline]
+pub(crate) fn on_free(rid: Rid) {
+    if rid.is_valid() {
+        imp::on_free(rid);
+    }
+}
+
+/// Prints a warning for every owned RID that is still alive, then clears the registry.
+///
+/// Called when the `Servers` init level is unloaded, after which the engine destroys its servers.
+pub(crate) fn report_leaks() {
+    imp::report_leaks();
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+#[cfg(safeguards_strict)]
+mod imp {
+    use std::backtrace::Backtrace;
+    use std::collections::{BTreeMap, HashMap};
+
+    use super::LiveRidCount;
+    use crate::builtin::Rid;
+    use crate::sys::Global;
+
+    struct TrackedRid {
+        server: &'static str,
+        kind: &'static str,
+        backtrace: Backtrace,
+    }
+
+    // Godot allocates RIDs from one global counter, so they are unique across servers.
+    static LIVE_RIDS: Global<HashMap<Rid, TrackedRid>> = Global::default();
+
+    pub fn on_create(server: &'static str, kind: &'static str, rid: Rid) {
+        // Like borrow tracking, only captures if RUST_BACKTRACE is set, as force_capture() is slow.
+        let tracked = TrackedRid {
+            server,
+            kind,
+            backtrace: Backtrace::capture(),
+        };
+
+        LIVE_RIDS.lock().insert(rid, tracked);
+    }
+
+    pub fn on_free(rid: Rid) {
+        LIVE_RIDS.lock().remove(&rid);
+    }
+
+    pub fn live_rids() -> Vec<LiveRidCount> {
+        let mut counts = BTreeMap::<(&'static str, &'static str), usize>::new();
+        for tracked in LIVE_RIDS.lock().values() {
+            *counts.entry((tracked.server, tracked.kind)).or_default() += 1;
+        }
+
+        counts
+            .into_iter()
+            .map(|((server, kind), count)| LiveRidCount {
+                server,
+                kind,
+                count,
+            })
+            .collect()
+    }
+
+    pub fn report_leaks() {
+        // Take the entries out first, so printing doesn't happen under the lock.
+        let leaked = std::mem::take(&mut *LIVE_RIDS.lock());
+
+        for (rid, tracked) in leaked {
+            let backtrace = crate::format_backtrace!("creation of leaked RID", &tracked.backtrace);
+            crate::godot_warn!(
+                "{kind} {rid:?} was not freed before {server} shutdown: its owner was leaked or outlived the extension.\n{backtrace}",
+                kind = tracked.kind,
+                server = tracked.server,
+            );
+        }
+    }
+}
+
+#[cfg(not(safeguards_strict))]
+mod imp {
+    use super::LiveRidCount;
+    use crate::builtin::Rid;
+
+    pub fn on_create(_server: &'static str, _kind: &'static str, _rid: Rid) {}
+
+    pub fn on_free(_rid: Rid) {}
+
+    pub fn live_rids() -> Vec<LiveRidCount> {
+        Vec::new()
+    }
+
+    pub fn report_leaks() {}
+}
@@ -0,0 +1,114 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::ops::Range;
+
+use crate::builtin::{Array, PackedInt32Array, Rect2, Rid, VarDictionary, Vector2};
+use crate::classes::native::{CaretInfo, Glyph};
+use crate::classes::text_server::{Direction, GraphemeFlag};
+use crate::meta::FromGodot;
+use crate::obj::EngineBitfield;
+
+impl Glyph {
+    /// Offset of the glyph relative to its pen position.
+    pub fn offset(&self) -> Vector2 {
+        Vector2::new(self.x_off, self.y_off)
+    }
+
+    /// Flags of the grapheme this glyph belongs to, e.g. whether it is a space or a virtual glyph.
+    pub fn grapheme_flags(&self) -> GraphemeFlag {
+        GraphemeFlag::from_ord(self.flags as u64)
+    }
+
+    /// Range of characters in the source text that this glyph covers.
+    pub fn char_range(&self) -> Range<usize> {
+        self.start.max(0) as usize..self.end.max(0) as usize
+    }
+
+    /// Decodes one element of `TextServer.shaped_text_get_glyphs()`.
+    ///
+    /// The public `TextServer` API returns glyphs as dictionaries; only `TextServerExtension` implementations see the raw `Glyph`
+    /// array. Missing keys keep the defaults of the engine's `Glyph` struct.
+    pub(crate) fn from_dict(dict: &VarDictionary) -> Self {
+        let offset = get_or(dict, "offset", Vector2::ZERO);
+
+        Self {
+            start: get_or(dict, "start", -1),
+            end: get_or(dict, "end", -1),
+            count: get_or(dict, "count", 0),
+            repeat: get_or(dict, "repeat", 1),
+            flags: get_or(dict, "flags", 0),
+            x_off: offset.x,
+            y_off: offset.y,
+            advance: get_or(dict, "advance", 0.0),
+            font_rid: get_or(dict, "font_rid", Rid::Invalid),
+            font_size: get_or(dict, "font_size", 0),
+            index: get_or(dict, "index", 0),
+            span_index: get_or(dict, "span_index", -1),
+        }
+    }
+}
+
+impl CaretInfo {
+    /// Decodes the result of `TextServer.shaped_text_get_carets()`.
+    pub(crate) fn from_dict(dict: &VarDictionary) -> Self {
+        Self {
+            leading_caret: get_or(dict, "leading_rect", Rect2::default()),
+            trailing_caret: get_or(dict, "trailing_rect", Rect2::default()),
+            leading_direction: get_or(dict, "leading_direction", Direction::AUTO),
+            trailing_direction: get_or(dict, "trailing_direction", Direction::AUTO),
+        }
+    }
+}
+
+fn get_or<T: FromGodot>(dict: &VarDictionary, key: &str, default: T) -> T {
+    dict.get_as::<_, T>(key).unwrap_or(default)
+}
+
+/// Iterator over the glyphs of a shaped text, see [`OwnedShapedText::glyphs()`](super::OwnedShapedText::glyphs).
+pub struct Glyphs {
+    glyphs: Array<VarDictionary>,
+    range: Range<usize>,
+}
+
+impl Glyphs {
+    pub(super) fn new(glyphs: Array<VarDictionary>) -> Self {
+        let range = 0..glyphs.len();
+        Self { glyphs, range }
+    }
+}
+
+impl Iterator for Glyphs {
+    type Item = Glyph;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.range.next()?;
+        Some(Glyph::from_dict(&self.glyphs.at(index)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for Glyphs {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let index = self.range.next_back()?;
+        Some(Glyph::from_dict(&self.glyphs.at(index)))
+    }
+}
+
+impl ExactSizeIterator for Glyphs {}
+
+/// Converts the flat `[start, end, start, end, ...]` arrays returned by the break functions into ranges.
+pub(super) fn ranges_from_pairs(pairs: &PackedInt32Array) -> Vec<Range<usize>> {
+    pairs
+        .as_slice()
+        .chunks_exact(2)
+        .map(|pair| pair[0].max(0) as usize..pair[1].max(0) as usize)
+        .collect()
+}
@@ -6,11 +6,17 @@
  */
 
 //! RAII wrappers for `TextServer` resources.
+//!
+//! [`OwnedFont`] configures font data, size-related metrics and variations. [`OwnedShapedText`] takes strings and inline objects,
+//! shapes them, and exposes the result as [`Glyph`](crate::classes::native::Glyph) values, line and word breaks, and caret
+//! positions, which is enough to lay out and draw text without going through `Label` or `TextLine`.
 
+mod glyph;
 pub mod owned_font;
 pub mod owned_shaped_text;
 
-pub use owned_font::OwnedFont;
+pub use glyph::Glyphs;
+pub use owned_font::{FontVariationAxis, OwnedFont};
 pub use owned_shaped_text::OwnedShapedText;
 
 impl crate::classes::TextServer {
@@ -18,6 +24,7 @@ impl crate::classes::TextServer {
     pub fn create_font_owned(&mut self) -> OwnedFont {
         let mut gd = crate::private::rebuild_gd(self).cast::<crate::classes::TextServer>();
         let rid = gd.create_font();
+        // SAFETY: `rid` was just created by this text server and is only owned by the returned wrapper.
         unsafe { OwnedFont::from_rid(rid, gd) }
     }
 
@@ -25,6 +32,7 @@ impl crate::classes::TextServer {
     pub fn create_shaped_text_owned(&mut self) -> OwnedShapedText {
         let mut gd = crate::private::rebuild_gd(self).cast::<crate::classes::TextServer>();
         let rid = gd.create_shaped_text();
+        // SAFETY: `rid` was just created by this text server and is only owned by the returned wrapper.
         unsafe { OwnedShapedText::from_rid(rid, gd) }
     }
 }
@@ -5,9 +5,192 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use crate::builtin::{GString, PackedByteArray, VarDictionary, Vector3i};
+use crate::classes::text_server::{FontAntialiasing, Hinting, SubpixelPositioning};
+
 crate::obj::impl_owned_rid!(
     OwnedFont,
     TextServer,
     instance,
     "A RAII wrapper for a text server font RID that is owned by this type.\nThe font is freed when this object is dropped."
 );
+
+impl OwnedFont {
+    /// Sets the font source data, e.g. the contents of a TTF, OTF or WOFF file.
+    ///
+    /// This method copies the data into a new Godot `PackedByteArray`. If you already have a `PackedByteArray`,
+    /// use [`set_data_packed()`][Self::set_data_packed] instead to avoid the copy.
+    pub fn set_data(&mut self, data: &[u8]) {
+        self.set_data_packed(&PackedByteArray::from(data));
+    }
+
+    /// Sets the font source data from a Godot `PackedByteArray`.
+    ///
+    /// See `TextServer.font_set_data()`.
+    pub fn set_data_packed(&mut self, data: &PackedByteArray) {
+        self.server.font_set_data(self.rid, data);
+    }
+
+    /// Selects the face within a font collection (`.ttc`/`.otc`) file.
+    ///
+    /// See `TextServer.font_set_face_index()`.
+    pub fn set_face_index(&mut self, face_index: i64) {
+        self.server.font_set_face_index(self.rid, face_index);
+    }
+
+    /// Sets the font name.
+    ///
+    /// See `TextServer.font_set_name()`.
+    pub fn set_name(&mut self, name: &str) {
+        self.server.font_set_name(self.rid, name);
+    }
+
+    /// Returns the font family name.
+    ///
+    /// See `TextServer.font_get_name()`.
+    pub fn name(&self) -> GString {
+        self.server.font_get_name(self.rid)
+    }
+
+    /// Sets a fixed size for bitmap fonts, or `0` for scalable fonts.
+    ///
+    /// See `TextServer.font_set_fixed_size()`.
+    pub fn set_fixed_size(&mut self, fixed_size: i64) {
+        self.server.font_set_fixed_size(self.rid, fixed_size);
+    }
+
+    /// Sets the antialiasing mode.
+    ///
+    /// See `TextServer.font_set_antialiasing()`.
+    pub fn set_antialiasing(&mut self, antialiasing: FontAntialiasing) {
+        self.server.font_set_antialiasing(self.rid, antialiasing);
+    }
+
+    /// Sets the hinting mode.
+    ///
+    /// See `TextServer.font_set_hinting()`.
+    pub fn set_hinting(&mut self, hinting: Hinting) {
+        self.server.font_set_hinting(self.rid, hinting);
+    }
+
+    /// Sets the subpixel glyph positioning mode.
+    ///
+    /// See `TextServer.font_set_subpixel_positioning()`.
+    pub fn set_subpixel_positioning(&mut self, positioning: SubpixelPositioning) {
+        self.server
+            .font_set_subpixel_positioning(self.rid, positioning);
+    }
+
+    /// Enables or disables multichannel signed distance field rendering.
+    ///
+    /// See `TextServer.font_set_multichannel_signed_distance_field()`.
+    pub fn set_msdf(&mut self, msdf: bool) {
+        self.server
+            .font_set_multichannel_signed_distance_field(self.rid, msdf);
+    }
+
+    /// Sets the emboldening strength; `0.0` disables it, negative values make the font thinner.
+    ///
+    /// See `TextServer.font_set_embolden()`.
+    pub fn set_embolden(&mut self, strength: f64) {
+        self.server.font_set_embolden(self.rid, strength);
+    }
+
+    /// Sets the variation coordinates of a variable font, as pairs of axis name and value.
+    ///
+    /// Axis names are OpenType tags such as `"wght"` or `"wdth"`, see [`variation_axes()`][Self::variation_axes]. Axes that are not
+    /// listed use their default value.
+    ///
+    /// See `TextServer.font_set_variation_coordinates()`.
+    pub fn set_variations(&mut self, variations: &[(&str, f64)]) {
+        let mut coordinates = VarDictionary::new();
+        for &(axis, value) in variations {
+            let tag = self.server.name_to_tag(axis);
+            coordinates.set(tag, value);
+        }
+
+        self.server
+            .font_set_variation_coordinates(self.rid, &coordinates);
+    }
+
+    /// Resets all variation axes to their defaults.
+    pub fn clear_variations(&mut self) {
+        self.set_variations(&[]);
+    }
+
+    /// Variation axes supported by the font; empty for non-variable fonts.
+    ///
+    /// See `TextServer.font_supported_variation_list()`.
+    pub fn variation_axes(&self) -> Vec<FontVariationAxis> {
+        let axes = self.server.font_supported_variation_list(self.rid);
+
+        axes.iter_shared()
+            .filter_map(|(tag, range)| {
+                let tag = tag.try_to::<i64>().ok()?;
+                let range = range.try_to::<Vector3i>().ok()?;
+
+                Some(FontVariationAxis {
+                    name: self.server.tag_to_name(tag),
+                    tag,
+                    min: range.x,
+                    max: range.y,
+                    default: range.z,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns `true` if the font has a glyph for `ch`.
+    ///
+    /// See `TextServer.font_has_char()`.
+    pub fn has_char(&self, ch: char) -> bool {
+        self.server.font_has_char(self.rid, ch as i64)
+    }
+
+    /// Distance from the baseline to the top of the font, at the given font size.
+    ///
+    /// See `TextServer.font_get_ascent()`.
+    pub fn ascent(&self, size: i64) -> f64 {
+        self.server.font_get_ascent(self.rid, size)
+    }
+
+    /// Distance from the baseline to the bottom of the font, at the given font size.
+    ///
+    /// See `TextServer.font_get_descent()`.
+    pub fn descent(&self, size: i64) -> f64 {
+        self.server.font_get_descent(self.rid, size)
+    }
+
+    /// Position of the underline below the baseline, at the given font size.
+    ///
+    /// See `TextServer.font_get_underline_position()`.
+    pub fn underline_position(&self, size: i64) -> f64 {
+        self.server.font_get_underline_position(self.rid, size)
+    }
+
+    /// Thickness of the underline, at the given font size.
+    ///
+    /// See `TextServer.font_get_underline_thickness()`.
+    pub fn underline_thickness(&self, size: i64) -> f64 {
+        self.server.font_get_underline_thickness(self.rid, size)
+    }
+}
+
+/// Variation axis of a variable font, see [`OwnedFont::variation_axes()`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FontVariationAxis {
+    /// OpenType tag of the axis as integer, as used by `TextServer`.
+    pub tag: i64,
+
+    /// OpenType tag of the axis as string, e.g. `"wght"`.
+    pub name: GString,
+
+    /// Minimum value of the axis.
+    pub min: i32,
+
+    /// Maximum value of the axis.
+    pub max: i32,
+
+    /// Default value of the axis.
+    pub default: i32,
+}
@@ -5,9 +5,274 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::ops::Range;
+
+use crate::builtin::{Array, Color, Rect2, Rid, Vector2};
+use crate::classes::native::CaretInfo;
+use crate::classes::text_server::{Direction, GraphemeFlag, LineBreakFlag, Orientation};
+use crate::global::InlineAlignment;
+use crate::meta::ToGodot;
+use crate::servers::text::glyph::{ranges_from_pairs, Glyphs};
+
 crate::obj::impl_owned_rid!(
     OwnedShapedText,
     TextServer,
     instance,
     "A RAII wrapper for a text server shaped text RID that is owned by this type.\nThe shaped text is freed when this object is dropped."
 );
+
+impl OwnedShapedText {
+    /// Removes all text and objects, so that the buffer can be reused.
+    ///
+    /// See `TextServer.shaped_text_clear()`.
+    pub fn clear(&mut self) {
+        self.server.shaped_text_clear(self.rid);
+    }
+
+    /// Sets the base text direction. [`Direction::AUTO`] detects it from the content.
+    ///
+    /// See `TextServer.shaped_text_set_direction()`.
+    pub fn set_direction(&mut self, direction: Direction) {
+        self.server
+            .shaped_text_set_direction_ex(self.rid)
+            .direction(direction)
+            .done();
+    }
+
+    /// Returns the direction set through [`set_direction()`][Self::set_direction].
+    pub fn direction(&self) -> Direction {
+        self.server.shaped_text_get_direction(self.rid)
+    }
+
+    /// Returns the actual direction of the text, after detection in case of [`Direction::AUTO`].
+    ///
+    /// See `TextServer.shaped_text_get_inferred_direction()`.
+    pub fn inferred_direction(&self) -> Direction {
+        self.server.shaped_text_get_inferred_direction(self.rid)
+    }
+
+    /// Sets horizontal or vertical layout.
+    ///
+    /// See `TextServer.shaped_text_set_orientation()`.
+    pub fn set_orientation(&mut self, orientation: Orientation) {
+        self.server
+            .shaped_text_set_orientation_ex(self.rid)
+            .orientation(orientation)
+            .done();
+    }
+
+    /// Appends `text` using the first of `fonts` that has the required glyphs, at the given font size.
+    ///
+    /// Returns `false` if the text could not be added. See `TextServer.shaped_text_add_string()`.
+    pub fn add_string(&mut self, text: &str, fonts: &[impl AsRef<Rid>], size: i64) -> bool {
+        let fonts = font_array(fonts);
+        self.server
+            .shaped_text_add_string(self.rid, text, &fonts, size)
+    }
+
+    /// Like [`add_string()`][Self::add_string], but with a language code such as `"en"` or `"ar"` that affects shaping and
+    /// line breaking.
+    pub fn add_string_with_language(
+        &mut self,
+        text: &str,
+        fonts: &[impl AsRef<Rid>],
+        size: i64,
+        language: &str,
+    ) -> bool {
+        let fonts = font_array(fonts);
+        self.server
+            .shaped_text_add_string_ex(self.rid, text, &fonts, size)
+            .language(language)
+            .done()
+    }
+
+    /// Appends an inline object of the given size, e.g. an image or a custom widget, which is laid out like a glyph.
+    ///
+    /// `key` identifies the object in [`object_rect()`][Self::object_rect] and [`resize_object()`][Self::resize_object].
+    /// The object takes up `length` characters of the text.
+    ///
+    /// Returns `false` if the object could not be added. See `TextServer.shaped_text_add_object()`.
+    pub fn add_object(
+        &mut self,
+        key: impl ToGodot,
+        size: Vector2,
+        inline_align: InlineAlignment,
+        length: i64,
+    ) -> bool {
+        self.server
+            .shaped_text_add_object_ex(self.rid, &key.to_variant(), size)
+            .inline_align(inline_align)
+            .length(length)
+            .done()
+    }
+
+    /// Changes size and alignment of an object added with [`add_object()`][Self::add_object].
+    ///
+    /// See `TextServer.shaped_text_resize_object()`.
+    pub fn resize_object(
+        &mut self,
+        key: impl ToGodot,
+        size: Vector2,
+        inline_align: InlineAlignment,
+    ) -> bool {
+        self.server
+            .shaped_text_resize_object_ex(self.rid, &key.to_variant(), size)
+            .inline_align(inline_align)
+            .done()
+    }
+
+    /// Bounding rectangle of an inline object after shaping.
+    ///
+    /// See `TextServer.shaped_text_get_object_rect()`.
+    pub fn object_rect(&self, key: impl ToGodot) -> Rect2 {
+        self.server
+            .shaped_text_get_object_rect(self.rid, &key.to_variant())
+    }
+
+    /// Shapes the text. Most queries shape implicitly, but calling this first makes errors observable.
+    ///
+    /// Returns `false` if shaping failed. See `TextServer.shaped_text_shape()`.
+    pub fn shape(&mut self) -> bool {
+        self.server.shaped_text_shape(self.rid)
+    }
+
+    /// Returns `true` if the text has been shaped and not modified since.
+    pub fn is_ready(&self) -> bool {
+        self.server.shaped_text_is_ready(self.rid)
+    }
+
+    /// Iterates over the glyphs in visual order, i.e. the order in which they are drawn from left to right.
+    ///
+    /// See `TextServer.shaped_text_get_glyphs()`.
+    pub fn glyphs(&self) -> Glyphs {
+        Glyphs::new(self.server.shaped_text_get_glyphs(self.rid))
+    }
+
+    /// Iterates over the glyphs in logical order, i.e. the order of the characters in the source text.
+    ///
+    /// See `TextServer.shaped_text_sort_logical()`.
+    pub fn glyphs_logical(&mut self) -> Glyphs {
+        Glyphs::new(self.server.shaped_text_sort_logical(self.rid))
+    }
+
+    /// Number of glyphs.
+    pub fn glyph_count(&self) -> usize {
+        self.server.shaped_text_get_glyph_count(self.rid) as usize
+    }
+
+    /// Size of the text, width being the advance and height the line height.
+    ///
+    /// See `TextServer.shaped_text_get_size()`.
+    pub fn size(&self) -> Vector2 {
+        self.server.shaped_text_get_size(self.rid)
+    }
+
+    /// Width of the text, without trailing spaces.
+    ///
+    /// See `TextServer.shaped_text_get_width()`.
+    pub fn width(&self) -> f64 {
+        self.server.shaped_text_get_width(self.rid)
+    }
+
+    /// Distance from the baseline to the top of the line.
+    pub fn ascent(&self) -> f64 {
+        self.server.shaped_text_get_ascent(self.rid)
+    }
+
+    /// Distance from the baseline to the bottom of the line.
+    pub fn descent(&self) -> f64 {
+        self.server.shaped_text_get_descent(self.rid)
+    }
+
+    /// Breaks the text into lines that fit into `width`, starting at character `start`.
+    ///
+    /// Returns the character range of each line, to be passed to [`substr()`][Self::substr]. See
+    /// `TextServer.shaped_text_get_line_breaks()`.
+    pub fn line_breaks(
+        &self,
+        width: f64,
+        start: usize,
+        break_flags: LineBreakFlag,
+    ) -> Vec<Range<usize>> {
+        let breaks = self
+            .server
+            .shaped_text_get_line_breaks_ex(self.rid, width)
+            .start(start as i64)
+            .break_flags(break_flags)
+            .done();
+
+        ranges_from_pairs(&breaks)
+    }
+
+    /// Returns the character range of each word, where words are separated by graphemes with one of `separators`.
+    ///
+    /// Use e.g. `GraphemeFlag::SPACE | GraphemeFlag::PUNCTUATION`. See `TextServer.shaped_text_get_word_breaks()`.
+    pub fn word_breaks(&self, separators: GraphemeFlag) -> Vec<Range<usize>> {
+        let breaks = self
+            .server
+            .shaped_text_get_word_breaks_ex(self.rid)
+            .grapheme_flags(separators)
+            .done();
+
+        ranges_from_pairs(&breaks)
+    }
+
+    /// Creates a new shaped text for a part of this one, e.g. a line returned by [`line_breaks()`][Self::line_breaks].
+    ///
+    /// The substring keeps the shaping of the full text, so it is cheaper than shaping the part separately.
+    /// See `TextServer.shaped_text_substr()`.
+    pub fn substr(&self, range: Range<usize>) -> OwnedShapedText {
+        let length = range.end.saturating_sub(range.start);
+        let rid = self
+            .server
+            .shaped_text_substr(self.rid, range.start as i64, length as i64);
+
+        // SAFETY: `shaped_text_substr()` returns a new shaped text owned by the caller, independent of `self`; nothing else frees it.
+        unsafe { OwnedShapedText::from_rid(rid, self.server.clone()) }
+    }
+
+    /// Returns the caret position closest to the horizontal (or vertical, depending on orientation) offset `coords`.
+    ///
+    /// Returns `None` if the text is empty. See `TextServer.shaped_text_hit_test_position()`.
+    pub fn hit_test_position(&self, coords: f64) -> Option<usize> {
+        let position = self.server.shaped_text_hit_test_position(self.rid, coords);
+
+        usize::try_from(position).ok()
+    }
+
+    /// Returns the index of the grapheme at offset `coords`, or `None` if there is no grapheme at that position.
+    ///
+    /// See `TextServer.shaped_text_hit_test_grapheme()`.
+    pub fn hit_test_grapheme(&self, coords: f64) -> Option<usize> {
+        let index = self.server.shaped_text_hit_test_grapheme(self.rid, coords);
+
+        usize::try_from(index).ok()
+    }
+
+    /// Returns the caret rectangles for the caret placed before character `position`.
+    ///
+    /// In bidirectional text, the leading and trailing carets may be at different places.
+    /// See `TextServer.shaped_text_get_carets()`.
+    pub fn carets(&self, position: usize) -> CaretInfo {
+        let carets = self
+            .server
+            .shaped_text_get_carets(self.rid, position as i64);
+
+        CaretInfo::from_dict(&carets)
+    }
+
+    /// Draws the text on a canvas item, with the baseline of the first glyph at `position`.
+    ///
+    /// See `TextServer.shaped_text_draw()`.
+    pub fn draw(&self, canvas_item: Rid, position: Vector2, color: Color) {
+        self.server
+            .clone()
+            .shaped_text_draw_ex(self.rid, canvas_item, position)
+            .color(color)
+            .done();
+    }
+}
+
+fn font_array(fonts: &[impl AsRef<Rid>]) -> Array<Rid> {
+    fonts.iter().map(|font| *font.as_ref()).collect()
+}
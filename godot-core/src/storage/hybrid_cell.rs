@@ -0,0 +1,123 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Cell whose borrow strategy is chosen per class, when `experimental-threads` is disabled.
+//!
+//! Most classes use the panicking cell, which is cheapest. Classes declared with `#[class(thread_safe)]` use the blocking cell instead,
+//! so that [`ThreadSafeGd`][crate::obj::ThreadSafeGd] can bind them from other threads. On a single thread, both cells behave the same:
+//! conflicting borrows panic.
+
+use std::error::Error;
+use std::ops::{Deref, DerefMut};
+
+use godot_cell::{blocking, panicking};
+
+pub enum GdCell<T> {
+    Panicking(panicking::GdCell<T>),
+    Blocking(blocking::GdCell<T>),
+}
+
+impl<T> GdCell<T> {
+    pub fn new(value: T, is_blocking: bool) -> Self {
+        if is_blocking {
+            Self::Blocking(blocking::GdCell::new(value))
+        } else {
+            Self::Panicking(panicking::GdCell::new(value))
+        }
+    }
+
+    pub fn borrow(&self) -> Result<RefGuard<'_, T>, Box<dyn Error>> {
+        match self {
+            Self::Panicking(cell) => cell.borrow().map(RefGuard::Panicking),
+            Self::Blocking(cell) => cell.borrow().map(RefGuard::Blocking),
+        }
+    }
+
+    pub fn borrow_mut(&self) -> Result<MutGuard<'_, T>, Box<dyn Error>> {
+        match self {
+            Self::Panicking(cell) => cell.borrow_mut().map(MutGuard::Panicking),
+            Self::Blocking(cell) => cell.borrow_mut().map(MutGuard::Blocking),
+        }
+    }
+
+    pub fn make_inaccessible<'cell, 'val>(
+        &'cell self,
+        current_ref: &'val mut T,
+    ) -> Result<InaccessibleGuard<'val, T>, Box<dyn Error>>
+    where
+        'cell: 'val,
+    {
+        match self {
+            Self::Panicking(cell) => cell
+                .make_inaccessible(current_ref)
+                .map(InaccessibleGuard::Panicking),
+            Self::Blocking(cell) => cell
+                .make_inaccessible(current_ref)
+                .map(InaccessibleGuard::Blocking),
+        }
+    }
+
+    pub fn is_currently_bound(&self) -> bool {
+        match self {
+            Self::Panicking(cell) => cell.is_currently_bound(),
+            Self::Blocking(cell) => cell.is_currently_bound(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Guards
+
+#[derive(Debug)]
+pub enum RefGuard<'a, T> {
+    Panicking(panicking::RefGuard<'a, T>),
+    Blocking(blocking::RefGuard<'a, T>),
+}
+
+impl<T> Deref for RefGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        match self {
+            Self::Panicking(guard) => guard,
+            Self::Blocking(guard) => guard,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum MutGuard<'a, T> {
+    Panicking(panicking::MutGuard<'a, T>),
+    Blocking(blocking::MutGuard<'a, T>),
+}
+
+impl<T> Deref for MutGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        match self {
+            Self::Panicking(guard) => guard,
+            Self::Blocking(guard) => guard,
+        }
+    }
+}
+
+impl<T> DerefMut for MutGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        match self {
+            Self::Panicking(guard) => guard,
+            Self::Blocking(guard) => guard,
+        }
+    }
+}
+
+/// Only held for its `Drop` impl, which restores the previous borrow.
+#[derive(Debug)]
+pub enum InaccessibleGuard<'a, T> {
+    Panicking(#[allow(dead_code)] panicking::InaccessibleGuard<'a, T>),
+    Blocking(#[allow(dead_code)] blocking::InaccessibleGuard<'a, T>),
+}
@@ -12,15 +12,11 @@ use std::ptr;
 #[cfg(feature = "experimental-threads")]
 use std::sync::atomic::{AtomicPtr, Ordering};
 
-#[cfg(feature = "experimental-threads")]
-use godot_cell::blocking::{InaccessibleGuard, MutGuard, RefGuard};
-#[cfg(not(feature = "experimental-threads"))]
-use godot_cell::panicking::{InaccessibleGuard, MutGuard, RefGuard};
 use godot_ffi as sys;
 
 use crate::godot_error;
 use crate::obj::{Base, Gd, GodotClass, Inherits, Singleton};
-use crate::storage::log_pre_drop;
+use crate::storage::{log_pre_drop, InaccessibleGuard, MutGuard, RefGuard};
 
 #[derive(Copy, Clone, Debug)]
 pub enum Lifecycle {
@@ -29,12 +25,10 @@ pub enum Lifecycle {
     Destroying,
 }
 
-#[cfg_attr(not(feature = "experimental-threads"), allow(dead_code))]
 pub struct AtomicLifecycle {
     atomic: std::sync::atomic::AtomicU32,
 }
 
-#[cfg_attr(not(feature = "experimental-threads"), allow(dead_code))]
 impl AtomicLifecycle {
     pub fn new(value: Lifecycle) -> Self {
         Self {
@@ -5,14 +5,20 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+#[cfg(not(feature = "experimental-threads"))]
+mod hybrid_cell;
 mod instance_storage;
-#[cfg_attr(not(feature = "experimental-threads"), allow(dead_code))]
+#[cfg(feature = "experimental-threads")]
 mod multi_threaded;
-#[cfg_attr(feature = "experimental-threads", allow(dead_code))]
+#[cfg(not(feature = "experimental-threads"))]
 mod single_threaded;
 
 use std::any::type_name;
 
+#[cfg(feature = "experimental-threads")]
+pub use godot_cell::blocking::{InaccessibleGuard, MutGuard, RefGuard};
+#[cfg(not(feature = "experimental-threads"))]
+pub use hybrid_cell::{InaccessibleGuard, MutGuard, RefGuard};
 pub use instance_storage::*;
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -5,22 +5,17 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use std::cell;
-
-#[cfg(feature = "experimental-threads")]
-use godot_cell::blocking::{GdCell, InaccessibleGuard, MutGuard, RefGuard};
-#[cfg(not(feature = "experimental-threads"))]
-use godot_cell::panicking::{GdCell, InaccessibleGuard, MutGuard, RefGuard};
-
 use crate::obj::{Base, GodotClass};
-use crate::storage::{DebugBorrowTracker, Lifecycle, Storage, StorageRefCounted};
+use crate::storage::hybrid_cell::{GdCell, InaccessibleGuard, MutGuard, RefGuard};
+use crate::storage::{AtomicLifecycle, DebugBorrowTracker, Lifecycle, Storage, StorageRefCounted};
 
 pub struct InstanceStorage<T: GodotClass> {
     user_instance: GdCell<T>,
     pub(super) base: Base<T::Base>,
 
     // Declared after `user_instance`, is dropped last.
-    pub(super) lifecycle: cell::Cell<Lifecycle>,
+    // Atomic, since `#[class(thread_safe)]` instances can be bound from other threads while Godot destroys them.
+    pub(super) lifecycle: AtomicLifecycle,
 
     // No-op in Release mode.
     borrow_tracker: DebugBorrowTracker,
@@ -44,9 +39,10 @@ unsafe impl<T: GodotClass> Storage for InstanceStorage<T> {
         super::log_construct::<T>(&base);
 
         Self {
-            user_instance: GdCell::new(user_instance),
+            // Classes accessed through `ThreadSafeGd` need a cell that blocks instead of panicking on concurrent borrows.
+            user_instance: GdCell::new(user_instance, T::__IS_THREAD_SAFE),
             base,
-            lifecycle: cell::Cell::new(Lifecycle::Alive),
+            lifecycle: AtomicLifecycle::new(Lifecycle::Alive),
             borrow_tracker: DebugBorrowTracker::new(),
         }
     }
@@ -0,0 +1,141 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::future::Future;
+use std::hash::{Hash, Hasher};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use crate::builtin::{Callable, RustCallable, Variant};
+
+/// Future that resolves once Godot invokes a callback, for engine APIs that report completion through a `Callable` argument.
+///
+/// Resolves to the value computed from the callback's arguments, or to `None` if Godot dropped the callable without invoking it
+/// (e.g. because the request was discarded).
+pub(crate) struct CallableFuture<R> {
+    shared: Arc<Mutex<CallableShared<R>>>,
+}
+
+impl<R: Send + 'static> CallableFuture<R> {
+    /// Returns the future together with the callable to pass to Godot.
+    ///
+    /// `on_invoke` converts the callback's arguments to the result; it may be called on any thread the engine invokes the callable from.
+    /// `name` is used for the callable's display and in the panic message if the future is polled after completion.
+    pub fn new(
+        name: &'static str,
+        on_invoke: impl FnMut(&[&Variant]) -> R + Send + Sync + 'static,
+    ) -> (Self, Callable) {
+        let shared = Arc::new(Mutex::new(CallableShared {
+            state: CallableState::Pending,
+            waker: None,
+            name,
+        }));
+
+        let resolver = CallableResolver {
+            shared: shared.clone(),
+            on_invoke: Box::new(on_invoke),
+            name,
+        };
+
+        (Self { shared }, Callable::from_custom(resolver))
+    }
+}
+
+impl<R> Future for CallableFuture<R> {
+    type Output = Option<R>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut shared = self.shared.lock().unwrap();
+
+        match std::mem::replace(&mut shared.state, CallableState::Taken) {
+            CallableState::Pending => {
+                shared.state = CallableState::Pending;
+                shared.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+            CallableState::Ready(value) => Poll::Ready(Some(value)),
+            CallableState::Cancelled => Poll::Ready(None),
+            CallableState::Taken => panic!("{} polled after completion", shared.name),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Shared state
+
+struct CallableShared<R> {
+    state: CallableState<R>,
+    waker: Option<Waker>,
+    name: &'static str,
+}
+
+enum CallableState<R> {
+    Pending,
+    Ready(R),
+    Cancelled,
+    Taken,
+}
+
+/// Callable passed to Godot, which fills the shared state once invoked.
+struct CallableResolver<R> {
+    shared: Arc<Mutex<CallableShared<R>>>,
+    on_invoke: Box<dyn FnMut(&[&Variant]) -> R + Send + Sync>,
+    name: &'static str,
+}
+
+impl<R> CallableResolver<R> {
+    fn resolve(&self, state: CallableState<R>) {
+        let waker = {
+            let mut shared = self.shared.lock().unwrap();
+            if !matches!(shared.state, CallableState::Pending) {
+                return;
+            }
+
+            shared.state = state;
+            shared.waker.take()
+        };
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<R: Send + 'static> RustCallable for CallableResolver<R> {
+    fn invoke(&mut self, args: &[&Variant]) -> Variant {
+        let value = (self.on_invoke)(args);
+        self.resolve(CallableState::Ready(value));
+        Variant::nil()
+    }
+}
+
+// Godot drops the callable after invoking it, or without invoking it if the request is discarded.
+impl<R> Drop for CallableResolver<R> {
+    fn drop(&mut self) {
+        self.resolve(CallableState::Cancelled);
+    }
+}
+
+impl<R> PartialEq for CallableResolver<R> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.shared, &other.shared)
+    }
+}
+
+impl<R> Hash for CallableResolver<R> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(Arc::as_ptr(&self.shared) as usize);
+    }
+}
+
+impl<R> fmt::Display for CallableResolver<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
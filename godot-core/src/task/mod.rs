@@ -12,6 +12,8 @@
 //! - A way to [`spawn`] new async tasks by using the engine as the async runtime.
 
 mod async_runtime;
+#[cfg(since_api = "4.4")]
+mod callable_future;
 mod futures;
 
 // Public re-exports
@@ -33,6 +35,8 @@ pub use reexport_test::*;
 // Crate-local re-exports.
 mod reexport_crate {
     pub(crate) use super::async_runtime::cleanup;
+    #[cfg(since_api = "4.4")]
+    pub(crate) use super::callable_future::CallableFuture;
     pub(crate) use super::futures::{impl_dynamic_send, ThreadConfined};
 }
 
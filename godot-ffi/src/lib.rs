@@ -62,6 +62,9 @@ mod plugins;
 mod string_cache;
 mod toolbox;
 
+#[cfg(feature = "stub-interface")]
+pub mod stub;
+
 #[doc(hidden)]
 #[cfg(target_family = "wasm")]
 pub use godot_macros::wasm_declare_init_fn;
@@ -637,6 +640,7 @@ macro_rules! builtin_call {
         };
     }
 
+#[cfg(not(feature = "stub-interface"))]
 #[macro_export]
 #[doc(hidden)]
 macro_rules! interface_fn {
@@ -645,6 +649,19 @@ macro_rules! interface_fn {
     }};
 }
 
+// The stub interface only provides a subset of functions; report the missing ones instead of invoking UB.
+#[cfg(feature = "stub-interface")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! interface_fn {
+    ($name:ident) => {{
+        match unsafe { $crate::get_interface().$name } {
+            Some(fptr) => fptr,
+            None => $crate::stub::unsupported_interface_fn(stringify!($name)),
+        }
+    }};
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Deferred editor message macros
 
@@ -36,3 +36,12 @@ unsafe impl<const N: usize> Send for Opaque<N> {}
 #[cfg(feature = "experimental-threads")]
 // SAFETY: Same as Send, shared access is safe because Godot handles internal synchronization.
 unsafe impl<const N: usize> Sync for Opaque<N> {}
+
+impl<const N: usize> Opaque<N> {
+    /// Compares the raw bytes of both objects.
+    ///
+    /// Only meaningful for types whose identity is fully determined by their bytes, such as `StringName` (a pointer to interned data).
+    pub fn bitwise_eq(&self, other: &Self) -> bool {
+        self.storage == other.storage
+    }
+}
@@ -0,0 +1,1015 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Functions of the GDExtension interface struct: variants, strings and container element access.
+
+use std::ffi::{c_char, CStr};
+use std::ptr;
+
+use super::lifecycle;
+use super::values::{self, ty, Kind, VarType, VariantBox};
+use crate as sys;
+
+fn to_sys(b: bool) -> sys::GDExtensionBool {
+    sys::conv::bool_to_sys(b)
+}
+
+unsafe fn set_flag(flag: *mut sys::GDExtensionBool, value: bool) {
+    if !flag.is_null() {
+        // SAFETY: caller passes a valid or null pointer.
+        unsafe { *flag = to_sys(value) };
+    }
+}
+
+/// Converts a possibly negative index (counting from the end) to a position in `0..len`.
+fn wrap_index(index: sys::GDExtensionInt, len: usize) -> Option<usize> {
+    let index = if index < 0 { index + len as i64 } else { index };
+
+    (0..len as i64).contains(&index).then_some(index as usize)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Variant
+
+pub(super) unsafe extern "C" fn variant_new_copy(
+    dst: sys::GDExtensionUninitializedVariantPtr,
+    src: sys::GDExtensionConstVariantPtr,
+) {
+    // SAFETY: `src` is initialized, `dst` is storage for a variant.
+    unsafe { values::var_init_copy(dst.cast(), src.cast()) };
+}
+
+pub(super) unsafe extern "C" fn variant_new_nil(dst: sys::GDExtensionUninitializedVariantPtr) {
+    // SAFETY: `dst` is storage for a variant.
+    unsafe { values::var_init_nil(dst.cast()) };
+}
+
+pub(super) unsafe extern "C" fn variant_destroy(var: sys::GDExtensionVariantPtr) {
+    // SAFETY: `var` is initialized and no longer used.
+    unsafe { values::var_destroy(var.cast()) };
+}
+
+pub(super) unsafe extern "C" fn variant_evaluate(
+    op: sys::GDExtensionVariantOperator,
+    left: sys::GDExtensionConstVariantPtr,
+    right: sys::GDExtensionConstVariantPtr,
+    ret: sys::GDExtensionUninitializedVariantPtr,
+    valid: *mut sys::GDExtensionBool,
+) {
+    // SAFETY: operands are initialized variants, `ret` is storage for a variant.
+    unsafe {
+        let left = VariantBox::copy_of(left.cast());
+        let right = VariantBox::copy_of(right.cast());
+        let result = evaluate(op, &left, &right);
+
+        set_flag(valid, result.is_some());
+        result.unwrap_or_default().move_into(ret.cast());
+    }
+}
+
+/// Dynamic method calls are only supported for `reserve()`, which godot-rust uses for pre-allocation and which is a no-op here.
+pub(super) unsafe extern "C" fn variant_call(
+    self_: sys::GDExtensionVariantPtr,
+    method: sys::GDExtensionConstStringNamePtr,
+    _args: *const sys::GDExtensionConstVariantPtr,
+    _arg_count: sys::GDExtensionInt,
+    ret: sys::GDExtensionUninitializedVariantPtr,
+    error: *mut sys::GDExtensionCallError,
+) {
+    // SAFETY: `self_` is an initialized variant, `method` a string name, `ret` storage for a variant.
+    unsafe {
+        let is_container = matches!(
+            values::kind(values::var_type(self_.cast())),
+            Kind::Array | Kind::Dictionary | Kind::Packed(_)
+        );
+        let supported =
+            is_container && values::str_chars(method.cast()) == ['r', 'e', 's', 'e', 'r', 'v', 'e'];
+
+        if !supported {
+            (*error).error = sys::GDEXTENSION_CALL_ERROR_INVALID_METHOD;
+        }
+        values::var_init_nil(ret.cast());
+    }
+}
+
+/// Subset of Godot's variant operators: comparisons, arithmetic on numbers, logic, and `+`/`in` on strings and arrays.
+fn evaluate(
+    op: sys::GDExtensionVariantOperator,
+    left: &VariantBox,
+    right: &VariantBox,
+) -> Option<VariantBox> {
+    let boolean =
+        |value: bool| VariantBox::with(ty::BOOL, |data| unsafe { values::write(data, value) });
+    let truthy = |var: &VariantBox| unsafe { values::booleanize(var.ty(), var.data()) };
+
+    let result = match op {
+        sys::GDEXTENSION_VARIANT_OP_EQUAL => boolean(loosely_equal(left, right)),
+        sys::GDEXTENSION_VARIANT_OP_NOT_EQUAL => boolean(!loosely_equal(left, right)),
+        sys::GDEXTENSION_VARIANT_OP_LESS => boolean(values::var_less(left, right)?),
+        sys::GDEXTENSION_VARIANT_OP_GREATER => boolean(values::var_less(right, left)?),
+        sys::GDEXTENSION_VARIANT_OP_LESS_EQUAL => boolean(!values::var_less(right, left)?),
+        sys::GDEXTENSION_VARIANT_OP_GREATER_EQUAL => boolean(!values::var_less(left, right)?),
+        sys::GDEXTENSION_VARIANT_OP_AND => boolean(truthy(left) && truthy(right)),
+        sys::GDEXTENSION_VARIANT_OP_OR => boolean(truthy(left) || truthy(right)),
+        sys::GDEXTENSION_VARIANT_OP_XOR => boolean(truthy(left) != truthy(right)),
+        sys::GDEXTENSION_VARIANT_OP_NOT => boolean(!truthy(left)),
+        sys::GDEXTENSION_VARIANT_OP_IN => boolean(contains(right, left)?),
+        sys::GDEXTENSION_VARIANT_OP_ADD if left.ty() == right.ty() => {
+            concat(left, right).or_else(|| arithmetic(op, left, right))?
+        }
+        _ => arithmetic(op, left, right)?,
+    };
+
+    Some(result)
+}
+
+/// `==` with Godot's numeric promotion; values of different types are otherwise unequal.
+fn loosely_equal(left: &VariantBox, right: &VariantBox) -> bool {
+    match (values::as_number(left), values::as_number(right)) {
+        (Some(l), Some(r)) => l == r,
+        _ => left == right,
+    }
+}
+
+fn arithmetic(
+    op: sys::GDExtensionVariantOperator,
+    left: &VariantBox,
+    right: &VariantBox,
+) -> Option<VariantBox> {
+    // SAFETY: reads are type-checked.
+    let int = |var: &VariantBox| {
+        (var.ty() == ty::INT).then(|| unsafe { values::read::<i64>(var.data()) })
+    };
+    let int_result =
+        |value: i64| VariantBox::with(ty::INT, |data| unsafe { values::write(data, value) });
+    let float_result =
+        |value: f64| VariantBox::with(ty::FLOAT, |data| unsafe { values::write(data, value) });
+
+    // Unary operators ignore the right operand.
+    match op {
+        sys::GDEXTENSION_VARIANT_OP_NEGATE => {
+            return match int(left) {
+                Some(l) => Some(int_result(l.wrapping_neg())),
+                None => values::as_number(left).map(|l| float_result(-l)),
+            };
+        }
+        sys::GDEXTENSION_VARIANT_OP_POSITIVE => {
+            return values::as_number(left).map(|_| left.clone());
+        }
+        _ => {}
+    }
+
+    if let (Some(l), Some(r)) = (int(left), int(right)) {
+        let value = match op {
+            sys::GDEXTENSION_VARIANT_OP_ADD => l.wrapping_add(r),
+            sys::GDEXTENSION_VARIANT_OP_SUBTRACT => l.wrapping_sub(r),
+            sys::GDEXTENSION_VARIANT_OP_MULTIPLY => l.wrapping_mul(r),
+            sys::GDEXTENSION_VARIANT_OP_DIVIDE => l.checked_div(r)?,
+            sys::GDEXTENSION_VARIANT_OP_MODULE => l.checked_rem(r)?,
+            _ => return None,
+        };
+
+        return Some(int_result(value));
+    }
+
+    let (l, r) = (values::as_number(left)?, values::as_number(right)?);
+    let value = match op {
+        sys::GDEXTENSION_VARIANT_OP_ADD => l + r,
+        sys::GDEXTENSION_VARIANT_OP_SUBTRACT => l - r,
+        sys::GDEXTENSION_VARIANT_OP_MULTIPLY => l * r,
+        sys::GDEXTENSION_VARIANT_OP_DIVIDE => l / r,
+        sys::GDEXTENSION_VARIANT_OP_MODULE => l % r,
+        _ => return None,
+    };
+
+    Some(float_result(value))
+}
+
+/// `+` on two strings or two arrays of the same type.
+fn concat(left: &VariantBox, right: &VariantBox) -> Option<VariantBox> {
+    let (var_ty, l, r) = (left.ty(), left.data(), right.data());
+
+    // SAFETY: both operands have type `var_ty`.
+    unsafe {
+        match values::kind(var_ty) {
+            Kind::Str if var_ty == ty::STRING => {
+                let chars = [values::str_chars(l), values::str_chars(r)].concat();
+                Some(VariantBox::with(var_ty, |data| {
+                    values::str_init(data, chars)
+                }))
+            }
+            Kind::Array => {
+                let (l, r) = (
+                    values::array_data(l).borrow(),
+                    values::array_data(r).borrow(),
+                );
+                let array = values::ArrayData {
+                    elems: l.elems.iter().chain(r.elems.iter()).cloned().collect(),
+                    ..l.same_typed()
+                };
+                Some(VariantBox::with(var_ty, |data| {
+                    values::array_init(data, array)
+                }))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// `needle in container`.
+fn contains(container: &VariantBox, needle: &VariantBox) -> Option<bool> {
+    let data = container.data();
+
+    // SAFETY: reads are type-checked.
+    unsafe {
+        match container.ty() {
+            ty::STRING | ty::STRING_NAME if matches!(values::kind(needle.ty()), Kind::Str) => {
+                let haystack = values::str_chars(data);
+                let needle = values::str_chars(needle.data());
+                Some(needle.is_empty() || haystack.windows(needle.len()).any(|w| w == needle))
+            }
+            ty::ARRAY => Some(
+                values::array_data(data)
+                    .borrow()
+                    .find(needle.as_ptr(), 0)
+                    .is_some(),
+            ),
+            ty::DICTIONARY => Some(
+                values::dict_data(data)
+                    .borrow()
+                    .find(needle.as_ptr())
+                    .is_some(),
+            ),
+            _ => None,
+        }
+    }
+}
+
+pub(super) unsafe extern "C" fn variant_get_type(var: sys::GDExtensionConstVariantPtr) -> VarType {
+    // SAFETY: `var` is initialized.
+    unsafe { values::var_type(var.cast()) }
+}
+
+pub(super) unsafe extern "C" fn variant_booleanize(
+    var: sys::GDExtensionConstVariantPtr,
+) -> sys::GDExtensionBool {
+    // SAFETY: `var` is initialized.
+    unsafe {
+        let var = var.cast();
+        to_sys(values::booleanize(
+            values::var_type(var),
+            values::var_data(var),
+        ))
+    }
+}
+
+pub(super) unsafe extern "C" fn variant_hash(
+    var: sys::GDExtensionConstVariantPtr,
+) -> sys::GDExtensionInt {
+    // SAFETY: `var` is initialized.
+    unsafe {
+        let var = var.cast();
+        values::hash_value(values::var_type(var), values::var_data(var)) as sys::GDExtensionInt
+    }
+}
+
+pub(super) unsafe extern "C" fn variant_stringify(
+    var: sys::GDExtensionConstVariantPtr,
+    dst: sys::GDExtensionStringPtr,
+) {
+    // SAFETY: `var` is initialized, `dst` is an initialized string.
+    unsafe {
+        let var = var.cast();
+        let string = values::stringify(values::var_type(var), values::var_data(var), false);
+        values::str_assign(dst.cast(), string.chars().collect());
+    }
+}
+
+pub(super) unsafe extern "C" fn variant_get_type_name(
+    ty: VarType,
+    dst: sys::GDExtensionUninitializedStringPtr,
+) {
+    // SAFETY: `dst` is storage for a string.
+    unsafe { values::str_init(dst.cast(), values::type_name(ty).chars().collect()) };
+}
+
+pub(super) unsafe extern "C" fn variant_can_convert(
+    from: VarType,
+    to: VarType,
+) -> sys::GDExtensionBool {
+    to_sys(lifecycle::can_convert(from, to, false))
+}
+
+pub(super) unsafe extern "C" fn variant_can_convert_strict(
+    from: VarType,
+    to: VarType,
+) -> sys::GDExtensionBool {
+    to_sys(lifecycle::can_convert(from, to, true))
+}
+
+/// Objects are not emulated, so there is never an instance ID.
+#[cfg(since_api = "4.4")]
+pub(super) unsafe extern "C" fn variant_get_object_instance_id(
+    _var: sys::GDExtensionConstVariantPtr,
+) -> sys::GDObjectInstanceID {
+    0
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Variant element access
+
+pub(super) unsafe extern "C" fn variant_get_keyed(
+    var: sys::GDExtensionConstVariantPtr,
+    key: sys::GDExtensionConstVariantPtr,
+    ret: sys::GDExtensionUninitializedVariantPtr,
+    valid: *mut sys::GDExtensionBool,
+) {
+    // SAFETY: `var` and `key` are initialized, `ret` is storage for a variant.
+    unsafe {
+        let var = var.cast::<u8>();
+        let value = match values::var_type(var) {
+            ty::DICTIONARY => {
+                let dict = values::dict_data(values::var_data(var)).borrow();
+                dict.find(key.cast()).map(|i| dict.entries[i].1.clone())
+            }
+            _ => None,
+        };
+
+        set_flag(valid, value.is_some());
+        value.unwrap_or_default().move_into(ret.cast());
+    }
+}
+
+pub(super) unsafe extern "C" fn variant_set_keyed(
+    var: sys::GDExtensionVariantPtr,
+    key: sys::GDExtensionConstVariantPtr,
+    value: sys::GDExtensionConstVariantPtr,
+    valid: *mut sys::GDExtensionBool,
+) {
+    // SAFETY: all variants are initialized.
+    unsafe {
+        let var = var.cast::<u8>();
+        let ok = match values::var_type(var) {
+            ty::DICTIONARY => {
+                let mut dict = values::dict_data(values::var_data(var)).borrow_mut();
+                if dict.read_only {
+                    false
+                } else {
+                    let value = VariantBox::copy_of(value.cast());
+                    dict.entry(key.cast()).1 = value;
+                    true
+                }
+            }
+            _ => false,
+        };
+
+        set_flag(valid, ok);
+    }
+}
+
+pub(super) unsafe extern "C" fn variant_get_indexed(
+    var: sys::GDExtensionConstVariantPtr,
+    index: sys::GDExtensionInt,
+    ret: sys::GDExtensionUninitializedVariantPtr,
+    valid: *mut sys::GDExtensionBool,
+    oob: *mut sys::GDExtensionBool,
+) {
+    // SAFETY: `var` is initialized, `ret` is storage for a variant.
+    unsafe {
+        let var = VariantBox::copy_of(var.cast());
+        let (is_valid, value) = match element_count(&var) {
+            Some(len) => match wrap_index(index, len) {
+                Some(i) => (true, Some(element_at(&var, i))),
+                None => (true, None),
+            },
+            None => (false, None),
+        };
+
+        set_flag(valid, is_valid);
+        set_flag(oob, is_valid && value.is_none());
+        value.unwrap_or_default().move_into(ret.cast());
+    }
+}
+
+pub(super) unsafe extern "C" fn variant_set_indexed(
+    var: sys::GDExtensionVariantPtr,
+    index: sys::GDExtensionInt,
+    value: sys::GDExtensionConstVariantPtr,
+    valid: *mut sys::GDExtensionBool,
+    oob: *mut sys::GDExtensionBool,
+) {
+    // SAFETY: all variants are initialized.
+    unsafe {
+        let (var, value) = (var.cast::<u8>(), VariantBox::copy_of(value.cast()));
+        let (ty, data) = (values::var_type(var), values::var_data(var));
+
+        let (is_valid, is_oob) = match values::kind(ty) {
+            Kind::Array => {
+                let mut array = values::array_data(data).borrow_mut();
+                let len = array.elems.len();
+                let accepts = !array.read_only
+                    && (array.elem_type == ty::NIL || array.elem_type == value.ty());
+                match wrap_index(index, len) {
+                    Some(i) if accepts => {
+                        array.elems[i] = value;
+                        (true, false)
+                    }
+                    Some(_) => (false, false),
+                    None => (accepts, accepts),
+                }
+            }
+            Kind::Packed(elem) => {
+                let elem_ty = values::packed_elem_type(ty);
+                let accepts = value.ty() == elem_ty
+                    || (elem_ty == ty::FLOAT && values::as_number(&value).is_some());
+                let len = values::packed_data(data).map_or(0, |packed| packed.len());
+                match wrap_index(index, len) {
+                    Some(i) if accepts => {
+                        let packed = values::packed_data_mut(data);
+                        let converted = lifecycle::convert(&value, elem_ty);
+                        packed.release_range(elem, i, i + 1);
+                        values::packed_elem_from_arg(
+                            ty,
+                            elem,
+                            packed.elem_ptr(elem, i),
+                            converted.data(),
+                        );
+                        (true, false)
+                    }
+                    Some(_) => (false, false),
+                    None => (accepts, accepts),
+                }
+            }
+            _ => (false, false),
+        };
+
+        set_flag(valid, is_valid);
+        set_flag(oob, is_oob);
+    }
+}
+
+/// Number of elements for indexable types (strings, arrays, packed arrays).
+fn element_count(var: &VariantBox) -> Option<usize> {
+    let data = var.data();
+
+    // SAFETY: reads are type-checked.
+    unsafe {
+        match values::kind(var.ty()) {
+            Kind::Str if var.ty() == ty::STRING => Some(values::str_chars(data).len()),
+            Kind::Array => Some(values::array_data(data).borrow().elems.len()),
+            Kind::Packed(_) => Some(values::packed_data(data).map_or(0, |packed| packed.len())),
+            _ => None,
+        }
+    }
+}
+
+/// Element at an index which is in bounds according to [`element_count()`].
+fn element_at(var: &VariantBox, index: usize) -> VariantBox {
+    let data = var.data();
+
+    // SAFETY: reads are type-checked, index is in bounds.
+    unsafe {
+        match values::kind(var.ty()) {
+            Kind::Str => {
+                let c = values::str_chars(data)[index];
+                VariantBox::with(ty::STRING, |dst| values::str_init(dst, vec![c]))
+            }
+            Kind::Array => values::array_data(data).borrow().elems[index].clone(),
+            Kind::Packed(elem) => {
+                let packed = values::packed_data(data).unwrap();
+                values::packed_elem_to_variant(var.ty(), elem, packed.elem_ptr(elem, index))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Variant iteration
+//
+// Like in Godot, the iterator of a dictionary is the current key. For other types, it's the current index.
+
+pub(super) unsafe extern "C" fn variant_iter_init(
+    var: sys::GDExtensionConstVariantPtr,
+    iter: sys::GDExtensionUninitializedVariantPtr,
+    valid: *mut sys::GDExtensionBool,
+) -> sys::GDExtensionBool {
+    // SAFETY: `var` is initialized, `iter` is storage for a variant.
+    unsafe {
+        let var = VariantBox::copy_of(var.cast());
+        let (is_valid, first) = match var.ty() {
+            ty::DICTIONARY => {
+                let dict = values::dict_data(var.data()).borrow();
+                (true, dict.entries.first().map(|entry| entry.0.clone()))
+            }
+            _ => match element_count(&var) {
+                Some(len) => (true, (len > 0).then(|| int_variant(0))),
+                None => (false, None),
+            },
+        };
+
+        set_flag(valid, is_valid);
+        let has_next = first.is_some();
+        first.unwrap_or_default().move_into(iter.cast());
+        to_sys(has_next)
+    }
+}
+
+pub(super) unsafe extern "C" fn variant_iter_next(
+    var: sys::GDExtensionConstVariantPtr,
+    iter: sys::GDExtensionVariantPtr,
+    valid: *mut sys::GDExtensionBool,
+) -> sys::GDExtensionBool {
+    // SAFETY: `var` and `iter` are initialized.
+    unsafe {
+        let var = VariantBox::copy_of(var.cast());
+        let iter = iter.cast::<u8>();
+
+        let (is_valid, next) = match var.ty() {
+            ty::DICTIONARY => {
+                let dict = values::dict_data(var.data()).borrow();
+                let next = dict
+                    .find(iter)
+                    .and_then(|i| dict.entries.get(i + 1))
+                    .map(|entry| entry.0.clone());
+                (true, next)
+            }
+            _ => match (element_count(&var), values::var_type(iter)) {
+                (Some(len), ty::INT) => {
+                    let next = values::read::<i64>(values::var_data(iter)) + 1;
+                    (true, (next < len as i64).then(|| int_variant(next)))
+                }
+                _ => (false, None),
+            },
+        };
+
+        set_flag(valid, is_valid);
+        let has_next = next.is_some();
+        if let Some(next) = next {
+            values::var_destroy(iter);
+            next.move_into(iter);
+        }
+        to_sys(has_next)
+    }
+}
+
+pub(super) unsafe extern "C" fn variant_iter_get(
+    var: sys::GDExtensionConstVariantPtr,
+    iter: sys::GDExtensionVariantPtr,
+    ret: sys::GDExtensionUninitializedVariantPtr,
+    valid: *mut sys::GDExtensionBool,
+) {
+    // SAFETY: `var` and `iter` are initialized, `ret` is storage for a variant.
+    unsafe {
+        let var = VariantBox::copy_of(var.cast());
+        let iter = VariantBox::copy_of(iter.cast());
+
+        let value = match (var.ty(), iter.ty()) {
+            (ty::DICTIONARY, _) => Some(iter),
+            (_, ty::INT) => {
+                let index = values::read::<i64>(iter.data());
+                element_count(&var)
+                    .and_then(|len| wrap_index(index, len))
+                    .map(|i| element_at(&var, i))
+            }
+            _ => None,
+        };
+
+        set_flag(valid, value.is_some());
+        value.unwrap_or_default().move_into(ret.cast());
+    }
+}
+
+fn int_variant(value: i64) -> VariantBox {
+    // SAFETY: `data` is storage for an int.
+    VariantBox::with(ty::INT, |data| unsafe { values::write(data, value) })
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// String construction and access
+
+unsafe fn c_str_bytes<'a>(chars: *const c_char, len: sys::GDExtensionInt) -> &'a [u8] {
+    // SAFETY: caller passes either a null-terminated string (`len < 0`) or `len` bytes.
+    unsafe {
+        if len < 0 {
+            CStr::from_ptr(chars).to_bytes()
+        } else if len == 0 {
+            &[]
+        } else {
+            std::slice::from_raw_parts(chars.cast(), len as usize)
+        }
+    }
+}
+
+/// Godot stops at the first NUL character, even if a length is given.
+fn until_nul(bytes: &[u8]) -> &[u8] {
+    match bytes.iter().position(|&b| b == 0) {
+        Some(end) => &bytes[..end],
+        None => bytes,
+    }
+}
+
+fn latin1_chars(bytes: &[u8]) -> Vec<char> {
+    until_nul(bytes).iter().map(|&b| char::from(b)).collect()
+}
+
+/// Returns the decoded characters and whether the input was valid UTF-8.
+fn utf8_chars(bytes: &[u8]) -> (Vec<char>, bool) {
+    let bytes = until_nul(bytes);
+    let decoded = String::from_utf8_lossy(bytes);
+    let is_valid = std::str::from_utf8(bytes).is_ok();
+
+    (decoded.chars().collect(), is_valid)
+}
+
+unsafe fn utf32_chars(chars: *const sys::char32_t, len: sys::GDExtensionInt) -> Vec<char> {
+    // SAFETY: caller passes either a null-terminated string (`len < 0`) or `len` code points.
+    let code_points: &[u32] = unsafe {
+        if len < 0 {
+            let mut count = 0;
+            while *chars.add(count) != 0 {
+                count += 1;
+            }
+            std::slice::from_raw_parts(chars, count)
+        } else if len == 0 {
+            &[]
+        } else {
+            std::slice::from_raw_parts(chars, len as usize)
+        }
+    };
+
+    code_points
+        .iter()
+        .take_while(|&&c| c != 0)
+        .map(|&c| char::from_u32(c).unwrap_or(char::REPLACEMENT_CHARACTER))
+        .collect()
+}
+
+pub(super) unsafe extern "C" fn string_new_with_latin1_chars(
+    dst: sys::GDExtensionUninitializedStringPtr,
+    chars: *const c_char,
+) {
+    // SAFETY: `chars` is null-terminated, `dst` is storage for a string.
+    unsafe { values::str_init(dst.cast(), latin1_chars(c_str_bytes(chars, -1))) };
+}
+
+pub(super) unsafe extern "C" fn string_new_with_utf8_chars(
+    dst: sys::GDExtensionUninitializedStringPtr,
+    chars: *const c_char,
+) {
+    // SAFETY: `chars` is null-terminated, `dst` is storage for a string.
+    unsafe { values::str_init(dst.cast(), utf8_chars(c_str_bytes(chars, -1)).0) };
+}
+
+pub(super) unsafe extern "C" fn string_new_with_latin1_chars_and_len(
+    dst: sys::GDExtensionUninitializedStringPtr,
+    chars: *const c_char,
+    len: sys::GDExtensionInt,
+) {
+    // SAFETY: `chars` has `len` bytes, `dst` is storage for a string.
+    unsafe { values::str_init(dst.cast(), latin1_chars(c_str_bytes(chars, len))) };
+}
+
+pub(super) unsafe extern "C" fn string_new_with_utf8_chars_and_len(
+    dst: sys::GDExtensionUninitializedStringPtr,
+    chars: *const c_char,
+    len: sys::GDExtensionInt,
+) {
+    // SAFETY: `chars` has `len` bytes, `dst` is storage for a string.
+    unsafe { values::str_init(dst.cast(), utf8_chars(c_str_bytes(chars, len)).0) };
+}
+
+/// Returns `OK` (0) or `FAILED` (1) for invalid UTF-8, which is still decoded with replacement characters.
+#[cfg(since_api = "4.3")]
+pub(super) unsafe extern "C" fn string_new_with_utf8_chars_and_len2(
+    dst: sys::GDExtensionUninitializedStringPtr,
+    chars: *const c_char,
+    len: sys::GDExtensionInt,
+) -> sys::GDExtensionInt {
+    // SAFETY: `chars` has `len` bytes, `dst` is storage for a string.
+    let (chars, is_valid) = utf8_chars(unsafe { c_str_bytes(chars, len) });
+    unsafe { values::str_init(dst.cast(), chars) };
+
+    if is_valid {
+        0
+    } else {
+        1
+    }
+}
+
+pub(super) unsafe extern "C" fn string_new_with_utf32_chars(
+    dst: sys::GDExtensionUninitializedStringPtr,
+    chars: *const sys::char32_t,
+) {
+    // SAFETY: `chars` is null-terminated, `dst` is storage for a string.
+    unsafe { values::str_init(dst.cast(), utf32_chars(chars, -1)) };
+}
+
+pub(super) unsafe extern "C" fn string_new_with_utf32_chars_and_len(
+    dst: sys::GDExtensionUninitializedStringPtr,
+    chars: *const sys::char32_t,
+    len: sys::GDExtensionInt,
+) {
+    // SAFETY: `chars` has `len` code points, `dst` is storage for a string.
+    unsafe { values::str_init(dst.cast(), utf32_chars(chars, len)) };
+}
+
+/// Copies up to `max_len` units into `out` (if not null) and returns the full length.
+unsafe fn copy_out<T: Copy>(
+    units: &[T],
+    out: *mut T,
+    max_len: sys::GDExtensionInt,
+) -> sys::GDExtensionInt {
+    if !out.is_null() {
+        let count = units.len().min(max_len.max(0) as usize);
+
+        // SAFETY: caller passes a buffer of `max_len` units.
+        unsafe { ptr::copy_nonoverlapping(units.as_ptr(), out, count) };
+    }
+
+    units.len() as sys::GDExtensionInt
+}
+
+pub(super) unsafe extern "C" fn string_to_latin1_chars(
+    s: sys::GDExtensionConstStringPtr,
+    out: *mut c_char,
+    max_len: sys::GDExtensionInt,
+) -> sys::GDExtensionInt {
+    // SAFETY: `s` is an initialized string; `out` is null or has `max_len` bytes.
+    unsafe {
+        let bytes: Vec<c_char> = values::str_chars(s.cast())
+            .iter()
+            .map(|&c| u8::try_from(c).unwrap_or(b'?') as c_char)
+            .collect();
+        copy_out(&bytes, out, max_len)
+    }
+}
+
+pub(super) unsafe extern "C" fn string_to_utf8_chars(
+    s: sys::GDExtensionConstStringPtr,
+    out: *mut c_char,
+    max_len: sys::GDExtensionInt,
+) -> sys::GDExtensionInt {
+    // SAFETY: `s` is an initialized string; `out` is null or has `max_len` bytes.
+    unsafe {
+        let string = values::str_from_chars(values::str_chars(s.cast()));
+        copy_out(string.as_bytes(), out.cast::<u8>(), max_len)
+    }
+}
+
+pub(super) unsafe extern "C" fn string_to_utf32_chars(
+    s: sys::GDExtensionConstStringPtr,
+    out: *mut sys::char32_t,
+    max_len: sys::GDExtensionInt,
+) -> sys::GDExtensionInt {
+    // SAFETY: `s` is an initialized string; `out` is null or has `max_len` code points. `char` has the layout of `u32`.
+    unsafe { copy_out(values::str_chars(s.cast()), out.cast::<char>(), max_len) }
+}
+
+pub(super) unsafe extern "C" fn string_operator_index(
+    s: sys::GDExtensionStringPtr,
+    index: sys::GDExtensionInt,
+) -> *mut sys::char32_t {
+    // SAFETY: `s` is an initialized string.
+    unsafe {
+        let len = values::str_chars(s.cast()).len();
+        if !(0..len as i64).contains(&index) {
+            return ptr::null_mut();
+        }
+
+        let chars = values::str_make_mut(s.cast());
+        chars.as_mut_ptr().add(index as usize).cast()
+    }
+}
+
+pub(super) unsafe extern "C" fn string_operator_index_const(
+    s: sys::GDExtensionConstStringPtr,
+    index: sys::GDExtensionInt,
+) -> *const sys::char32_t {
+    // SAFETY: `s` is an initialized string.
+    unsafe {
+        let chars = values::str_chars(s.cast());
+        if !(0..chars.len() as i64).contains(&index) {
+            return ptr::null();
+        }
+
+        chars.as_ptr().add(index as usize).cast()
+    }
+}
+
+pub(super) unsafe extern "C" fn string_operator_plus_eq_string(
+    s: sys::GDExtensionStringPtr,
+    other: sys::GDExtensionConstStringPtr,
+) {
+    // SAFETY: both strings are initialized. Copy first, in case `other` shares the buffer.
+    unsafe {
+        let other = values::str_chars(other.cast()).to_vec();
+        values::str_make_mut(s.cast()).extend(other);
+    }
+}
+
+pub(super) unsafe extern "C" fn string_operator_plus_eq_char(
+    s: sys::GDExtensionStringPtr,
+    c: sys::char32_t,
+) {
+    let c = char::from_u32(c).unwrap_or(char::REPLACEMENT_CHARACTER);
+
+    // SAFETY: `s` is an initialized string.
+    unsafe { values::str_make_mut(s.cast()).push(c) };
+}
+
+pub(super) unsafe extern "C" fn string_operator_plus_eq_cstr(
+    s: sys::GDExtensionStringPtr,
+    other: *const c_char,
+) {
+    // SAFETY: `s` is an initialized string, `other` is null-terminated Latin-1.
+    unsafe {
+        let other = latin1_chars(c_str_bytes(other, -1));
+        values::str_make_mut(s.cast()).extend(other);
+    }
+}
+
+pub(super) unsafe extern "C" fn string_operator_plus_eq_c32str(
+    s: sys::GDExtensionStringPtr,
+    other: *const sys::char32_t,
+) {
+    // SAFETY: `s` is an initialized string, `other` is null-terminated UTF-32.
+    unsafe {
+        let other = utf32_chars(other, -1);
+        values::str_make_mut(s.cast()).extend(other);
+    }
+}
+
+pub(super) unsafe extern "C" fn string_name_new_with_latin1_chars(
+    dst: sys::GDExtensionUninitializedStringNamePtr,
+    chars: *const c_char,
+    _is_static: sys::GDExtensionBool,
+) {
+    // SAFETY: `chars` is null-terminated, `dst` is storage for a string name.
+    unsafe { values::str_init(dst.cast(), latin1_chars(c_str_bytes(chars, -1))) };
+}
+
+pub(super) unsafe extern "C" fn string_name_new_with_utf8_chars(
+    dst: sys::GDExtensionUninitializedStringNamePtr,
+    chars: *const c_char,
+) {
+    // SAFETY: `chars` is null-terminated, `dst` is storage for a string name.
+    unsafe { values::str_init(dst.cast(), utf8_chars(c_str_bytes(chars, -1)).0) };
+}
+
+pub(super) unsafe extern "C" fn string_name_new_with_utf8_chars_and_len(
+    dst: sys::GDExtensionUninitializedStringNamePtr,
+    chars: *const c_char,
+    len: sys::GDExtensionInt,
+) {
+    // SAFETY: `chars` has `len` bytes, `dst` is storage for a string name.
+    unsafe { values::str_init(dst.cast(), utf8_chars(c_str_bytes(chars, len)).0) };
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Array and dictionary element access
+
+pub(super) unsafe extern "C" fn array_operator_index(
+    array: sys::GDExtensionTypePtr,
+    index: sys::GDExtensionInt,
+) -> sys::GDExtensionVariantPtr {
+    // SAFETY: `array` is initialized. The element pointer stays valid until the array is resized, as in Godot.
+    unsafe {
+        let mut array = values::array_data(array.cast()).borrow_mut();
+        if !(0..array.elems.len() as i64).contains(&index) {
+            return ptr::null_mut();
+        }
+
+        array.elems[index as usize].as_mut_ptr().cast()
+    }
+}
+
+pub(super) unsafe extern "C" fn array_operator_index_const(
+    array: sys::GDExtensionConstTypePtr,
+    index: sys::GDExtensionInt,
+) -> sys::GDExtensionVariantPtr {
+    // SAFETY: see `array_operator_index()`.
+    unsafe { array_operator_index(array.cast_mut(), index) }
+}
+
+pub(super) unsafe extern "C" fn array_ref(
+    array: sys::GDExtensionTypePtr,
+    from: sys::GDExtensionConstTypePtr,
+) {
+    // SAFETY: both arrays are initialized. Reference first, in case both share the same data.
+    unsafe {
+        let mut reference = std::mem::MaybeUninit::<sys::types::OpaqueArray>::uninit();
+        values::construct_copy(ty::ARRAY, reference.as_mut_ptr().cast(), from.cast());
+        values::destroy(ty::ARRAY, array.cast());
+        ptr::copy_nonoverlapping(
+            reference.as_ptr().cast::<u8>(),
+            array.cast::<u8>(),
+            size_of::<sys::types::OpaqueArray>(),
+        );
+    }
+}
+
+pub(super) unsafe extern "C" fn array_set_typed(
+    array: sys::GDExtensionTypePtr,
+    elem_type: VarType,
+    class_name: sys::GDExtensionConstStringNamePtr,
+    script: sys::GDExtensionConstVariantPtr,
+) {
+    // SAFETY: all arguments are initialized.
+    unsafe {
+        let mut array = values::array_data(array.cast()).borrow_mut();
+        array.elem_type = elem_type;
+        array.class_name = values::str_chars(class_name.cast()).to_vec();
+        array.script = VariantBox::copy_of(script.cast());
+    }
+}
+
+pub(super) unsafe extern "C" fn dictionary_operator_index(
+    dict: sys::GDExtensionTypePtr,
+    key: sys::GDExtensionConstVariantPtr,
+) -> sys::GDExtensionVariantPtr {
+    // SAFETY: `dict` and `key` are initialized. Values are boxed, so the pointer stays valid until the key is erased.
+    unsafe {
+        let mut dict = values::dict_data(dict.cast()).borrow_mut();
+        dict.entry(key.cast()).1.as_mut_ptr().cast()
+    }
+}
+
+pub(super) unsafe extern "C" fn dictionary_operator_index_const(
+    dict: sys::GDExtensionConstTypePtr,
+    key: sys::GDExtensionConstVariantPtr,
+) -> sys::GDExtensionVariantPtr {
+    // SAFETY: see `dictionary_operator_index()`.
+    unsafe {
+        let mut dict = values::dict_data(dict.cast()).borrow_mut();
+        match dict.find(key.cast()) {
+            Some(i) => dict.entries[i].1.as_mut_ptr().cast(),
+            None => ptr::null_mut(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Packed array element access
+
+unsafe fn packed_index(packed_ty: VarType, packed: *mut u8, index: sys::GDExtensionInt) -> *mut u8 {
+    let Kind::Packed(elem) = values::kind(packed_ty) else {
+        unreachable!()
+    };
+
+    // SAFETY: `packed` is an initialized packed array of type `packed_ty`.
+    match unsafe { values::packed_data(packed) } {
+        Some(data) if (0..data.len() as i64).contains(&index) => {
+            data.elem_ptr(elem, index as usize)
+        }
+        _ => ptr::null_mut(),
+    }
+}
+
+macro_rules! packed_index_fns {
+    ($( $PACKED:ident: $index:ident -> $Ret:ty, $index_const:ident -> $RetConst:ty; )*) => {
+        $(
+            pub(super) unsafe extern "C" fn $index(
+                packed: sys::GDExtensionTypePtr,
+                index: sys::GDExtensionInt,
+            ) -> $Ret {
+                // SAFETY: `packed` is an initialized packed array of the right type.
+                unsafe { packed_index(ty::$PACKED, packed.cast(), index) as $Ret }
+            }
+
+            pub(super) unsafe extern "C" fn $index_const(
+                packed: sys::GDExtensionConstTypePtr,
+                index: sys::GDExtensionInt,
+            ) -> $RetConst {
+                // SAFETY: `packed` is an initialized packed array of the right type.
+                unsafe { packed_index(ty::$PACKED, packed.cast_mut().cast(), index) as $RetConst }
+            }
+        )*
+    };
+}
+
+packed_index_fns! {
+    PACKED_BYTE_ARRAY: packed_byte_array_operator_index -> *mut u8, packed_byte_array_operator_index_const -> *const u8;
+    PACKED_INT32_ARRAY: packed_int32_array_operator_index -> *mut i32, packed_int32_array_operator_index_const -> *const i32;
+    PACKED_INT64_ARRAY: packed_int64_array_operator_index -> *mut i64, packed_int64_array_operator_index_const -> *const i64;
+    PACKED_FLOAT32_ARRAY: packed_float32_array_operator_index -> *mut f32, packed_float32_array_operator_index_const -> *const f32;
+    PACKED_FLOAT64_ARRAY: packed_float64_array_operator_index -> *mut f64, packed_float64_array_operator_index_const -> *const f64;
+    PACKED_STRING_ARRAY: packed_string_array_operator_index -> sys::GDExtensionStringPtr,
+        packed_string_array_operator_index_const -> sys::GDExtensionStringPtr;
+    PACKED_VECTOR2_ARRAY: packed_vector2_array_operator_index -> sys::GDExtensionTypePtr,
+        packed_vector2_array_operator_index_const -> sys::GDExtensionTypePtr;
+    PACKED_VECTOR3_ARRAY: packed_vector3_array_operator_index -> sys::GDExtensionTypePtr,
+        packed_vector3_array_operator_index_const -> sys::GDExtensionTypePtr;
+    PACKED_COLOR_ARRAY: packed_color_array_operator_index -> sys::GDExtensionTypePtr,
+        packed_color_array_operator_index_const -> sys::GDExtensionTypePtr;
+}
+
+#[cfg(since_api = "4.3")]
+packed_index_fns! {
+    PACKED_VECTOR4_ARRAY: packed_vector4_array_operator_index -> sys::GDExtensionTypePtr,
+        packed_vector4_array_operator_index_const -> sys::GDExtensionTypePtr;
+}
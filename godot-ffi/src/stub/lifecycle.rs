@@ -0,0 +1,428 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Lifecycle table: constructors, destructors, operators, variant conversions, builtin methods and utility functions.
+//!
+//! Every lookup returns a function, even for operations that the stub does not support, because godot-rust eagerly loads and
+//! validates its tables. Unsupported functions abort once they are *called*.
+
+use std::ffi::c_int;
+
+use super::values::{self, per_type, ty, type_name, Kind, VarType, VariantBox};
+use super::{methods, unsupported};
+use crate as sys;
+
+type Ctor =
+    unsafe extern "C" fn(sys::GDExtensionUninitializedTypePtr, *const sys::GDExtensionConstTypePtr);
+type Dtor = unsafe extern "C" fn(sys::GDExtensionTypePtr);
+type OpFn = unsafe extern "C" fn(
+    sys::GDExtensionConstTypePtr,
+    sys::GDExtensionConstTypePtr,
+    sys::GDExtensionTypePtr,
+);
+type ToVariantFn =
+    unsafe extern "C" fn(sys::GDExtensionUninitializedVariantPtr, sys::GDExtensionTypePtr);
+type FromVariantFn =
+    unsafe extern "C" fn(sys::GDExtensionUninitializedTypePtr, sys::GDExtensionVariantPtr);
+type UtilityFn =
+    unsafe extern "C" fn(sys::GDExtensionTypePtr, *const sys::GDExtensionConstTypePtr, c_int);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Table lookups
+
+pub(super) unsafe extern "C" fn variant_get_ptr_constructor(
+    ty: VarType,
+    index: i32,
+) -> sys::GDExtensionPtrConstructor {
+    match (ty, index) {
+        (_, 0) => per_type!(ty, construct_default as Ctor),
+        (_, 1) => per_type!(ty, construct_copy as Ctor),
+
+        // String(StringName), String(NodePath), StringName(String), NodePath(String).
+        (ty::STRING, 2 | 3) | (ty::STRING_NAME | ty::NODE_PATH, 2) => {
+            Some(construct_from_string_like as Ctor)
+        }
+
+        // Array(PackedByteArray) ... Array(PackedVector4Array), in variant type order.
+        (ty::ARRAY, 3..) => {
+            let packed_ty = ty::PACKED_BYTE_ARRAY + (index - 3) as VarType;
+            match values::kind(packed_ty) {
+                Kind::Packed(_) => per_type!(packed_ty, construct_array_from_packed as Ctor),
+                _ => per_type!(ty, construct_unsupported as Ctor),
+            }
+        }
+
+        // PackedXArray(Array).
+        (_, 2) if matches!(values::kind(ty), Kind::Packed(_)) => {
+            per_type!(ty, construct_packed_from_array as Ctor)
+        }
+
+        _ => per_type!(ty, construct_unsupported as Ctor),
+    }
+}
+
+pub(super) unsafe extern "C" fn variant_get_ptr_destructor(
+    ty: VarType,
+) -> sys::GDExtensionPtrDestructor {
+    per_type!(ty, destroy as Dtor)
+}
+
+pub(super) unsafe extern "C" fn variant_get_ptr_operator_evaluator(
+    op: sys::GDExtensionVariantOperator,
+    left_ty: VarType,
+    right_ty: VarType,
+) -> sys::GDExtensionPtrOperatorEvaluator {
+    if left_ty != right_ty {
+        return per_type!(left_ty, operator_unsupported as OpFn);
+    }
+
+    match op {
+        sys::GDEXTENSION_VARIANT_OP_EQUAL => per_type!(left_ty, operator_equal as OpFn),
+        sys::GDEXTENSION_VARIANT_OP_LESS => per_type!(left_ty, operator_less as OpFn),
+        _ => per_type!(left_ty, operator_unsupported as OpFn),
+    }
+}
+
+pub(super) unsafe extern "C" fn get_variant_from_type_constructor(
+    ty: VarType,
+) -> sys::GDExtensionVariantFromTypeConstructorFunc {
+    per_type!(ty, to_variant as ToVariantFn)
+}
+
+pub(super) unsafe extern "C" fn get_variant_to_type_constructor(
+    ty: VarType,
+) -> sys::GDExtensionTypeFromVariantConstructorFunc {
+    per_type!(ty, from_variant as FromVariantFn)
+}
+
+/// Not provided: godot-rust then falls back to [`get_variant_to_type_constructor()`].
+#[cfg(since_api = "4.4")]
+pub(super) unsafe extern "C" fn variant_get_ptr_internal_getter(
+    _ty: VarType,
+) -> sys::GDExtensionVariantGetInternalPtrFunc {
+    None
+}
+
+pub(super) unsafe extern "C" fn variant_get_ptr_builtin_method(
+    ty: VarType,
+    method: sys::GDExtensionConstStringNamePtr,
+    _hash: sys::GDExtensionInt,
+) -> sys::GDExtensionPtrBuiltInMethod {
+    // SAFETY: the name is a StringName created through this stub.
+    let method = values::str_from_chars(unsafe { values::str_chars(method.cast()) });
+
+    methods::lookup(ty, &method)
+}
+
+pub(super) unsafe extern "C" fn variant_get_ptr_utility_function(
+    function: sys::GDExtensionConstStringNamePtr,
+    _hash: sys::GDExtensionInt,
+) -> sys::GDExtensionPtrUtilityFunction {
+    // SAFETY: the name is a StringName created through this stub.
+    let function = values::str_from_chars(unsafe { values::str_chars(function.cast()) });
+
+    let func: UtilityFn = match function.as_str() {
+        "print" | "print_rich" | "print_verbose" => utility_print::<false>,
+        "printerr" => utility_print::<true>,
+        "prints" => utility_prints::<' '>,
+        "printt" => utility_prints::<'\t'>,
+        "push_error" => utility_push_error,
+        "push_warning" => utility_push_warning,
+        "str" => utility_str,
+        _ => utility_unsupported,
+    };
+
+    Some(func)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Constructors and destructors
+
+unsafe extern "C" fn construct_default<const TY: VarType>(
+    dst: sys::GDExtensionUninitializedTypePtr,
+    _args: *const sys::GDExtensionConstTypePtr,
+) {
+    // SAFETY: godot-rust passes storage for a `TY` value.
+    unsafe { values::construct_default(TY, dst.cast()) };
+}
+
+unsafe extern "C" fn construct_copy<const TY: VarType>(
+    dst: sys::GDExtensionUninitializedTypePtr,
+    args: *const sys::GDExtensionConstTypePtr,
+) {
+    // SAFETY: the single argument is a `TY` value.
+    unsafe { values::construct_copy(TY, dst.cast(), (*args).cast()) };
+}
+
+/// All string types share one representation, so conversions are copies.
+unsafe extern "C" fn construct_from_string_like(
+    dst: sys::GDExtensionUninitializedTypePtr,
+    args: *const sys::GDExtensionConstTypePtr,
+) {
+    // SAFETY: the single argument is a `String`, `StringName` or `NodePath`.
+    unsafe { values::construct_copy(ty::STRING, dst.cast(), (*args).cast()) };
+}
+
+unsafe extern "C" fn construct_array_from_packed<const PACKED_TY: VarType>(
+    dst: sys::GDExtensionUninitializedTypePtr,
+    args: *const sys::GDExtensionConstTypePtr,
+) {
+    let Kind::Packed(elem) = values::kind(PACKED_TY) else {
+        unreachable!()
+    };
+
+    // SAFETY: the single argument is a packed array of type `PACKED_TY`.
+    unsafe {
+        let elems = match values::packed_data((*args).cast()) {
+            Some(packed) => (0..packed.len())
+                .map(|i| values::packed_elem_to_variant(PACKED_TY, elem, packed.elem_ptr(elem, i)))
+                .collect(),
+            None => Vec::new(),
+        };
+
+        values::array_init(
+            dst.cast(),
+            values::ArrayData {
+                elems,
+                ..Default::default()
+            },
+        );
+    }
+}
+
+unsafe extern "C" fn construct_packed_from_array<const TY: VarType>(
+    dst: sys::GDExtensionUninitializedTypePtr,
+    args: *const sys::GDExtensionConstTypePtr,
+) {
+    let Kind::Packed(elem) = values::kind(TY) else {
+        unreachable!()
+    };
+
+    // SAFETY: the single argument is an array; `dst` has storage for a packed array.
+    unsafe {
+        let dst = dst.cast::<u8>();
+        values::construct_default(TY, dst);
+
+        let array = values::array_data((*args).cast()).borrow();
+        let packed = values::packed_data_mut(dst);
+        packed.resize(elem, array.elems.len());
+
+        for (i, var) in array.elems.iter().enumerate() {
+            let converted = convert(var, values::packed_elem_type(TY));
+            values::packed_elem_from_arg(TY, elem, packed.elem_ptr(elem, i), converted.data());
+        }
+    }
+}
+
+unsafe extern "C" fn construct_unsupported<const TY: VarType>(
+    _dst: sys::GDExtensionUninitializedTypePtr,
+    _args: *const sys::GDExtensionConstTypePtr,
+) {
+    unsupported(&format!("constructor with arguments of {}", type_name(TY)));
+}
+
+unsafe extern "C" fn destroy<const TY: VarType>(ptr: sys::GDExtensionTypePtr) {
+    // SAFETY: godot-rust passes an initialized `TY` value, which it no longer uses.
+    unsafe { values::destroy(TY, ptr.cast()) };
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Operators
+
+unsafe extern "C" fn operator_equal<const TY: VarType>(
+    left: sys::GDExtensionConstTypePtr,
+    right: sys::GDExtensionConstTypePtr,
+    ret: sys::GDExtensionTypePtr,
+) {
+    // SAFETY: operands are `TY` values, the result is a `bool`.
+    unsafe {
+        let equal = values::values_equal(TY, left.cast(), right.cast());
+        values::write(ret.cast(), equal);
+    }
+}
+
+unsafe extern "C" fn operator_less<const TY: VarType>(
+    left: sys::GDExtensionConstTypePtr,
+    right: sys::GDExtensionConstTypePtr,
+    ret: sys::GDExtensionTypePtr,
+) {
+    // SAFETY: operands are `TY` values, the result is a `bool`.
+    unsafe {
+        let Some(less) = values::values_less(TY, left.cast(), right.cast()) else {
+            unsupported(&format!("operator < on {}", type_name(TY)));
+        };
+        values::write(ret.cast(), less);
+    }
+}
+
+unsafe extern "C" fn operator_unsupported<const TY: VarType>(
+    _left: sys::GDExtensionConstTypePtr,
+    _right: sys::GDExtensionConstTypePtr,
+    _ret: sys::GDExtensionTypePtr,
+) {
+    unsupported(&format!("ptrcall operator on {}", type_name(TY)));
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Variant conversions
+
+unsafe extern "C" fn to_variant<const TY: VarType>(
+    dst: sys::GDExtensionUninitializedVariantPtr,
+    src: sys::GDExtensionTypePtr,
+) {
+    // SAFETY: `src` is a `TY` value, `dst` is storage for a variant.
+    unsafe {
+        values::var_init_with(dst.cast(), TY, |data| {
+            values::construct_copy(TY, data, src.cast())
+        })
+    };
+}
+
+unsafe extern "C" fn from_variant<const TY: VarType>(
+    dst: sys::GDExtensionUninitializedTypePtr,
+    src: sys::GDExtensionVariantPtr,
+) {
+    // SAFETY: `src` is an initialized variant, `dst` is storage for a `TY` value.
+    unsafe {
+        let src = VariantBox::copy_of(src.cast());
+        let converted = convert(&src, TY);
+        values::construct_copy(TY, dst.cast(), converted.data());
+    }
+}
+
+/// Converts a variant to type `to`, like Godot's `Variant` conversion operators. Incompatible values yield the default value.
+pub(super) fn convert(var: &VariantBox, to: VarType) -> VariantBox {
+    let from = var.ty();
+    if from == to {
+        return var.clone();
+    }
+
+    // SAFETY: reads from the source variant are type-checked.
+    VariantBox::with(to, |data| unsafe {
+        let src = var.data();
+        let string_like = |t| matches!(t, ty::STRING | ty::STRING_NAME | ty::NODE_PATH);
+
+        if is_number(to) && (is_number(from) || from == ty::NIL) {
+            let number = match from {
+                ty::BOOL => f64::from(u8::from(values::read::<bool>(src))),
+                ty::INT => values::read::<i64>(src) as f64,
+                ty::FLOAT => values::read::<f64>(src),
+                _ => 0.0,
+            };
+
+            match to {
+                ty::BOOL => values::write(data, number != 0.0),
+                ty::INT => values::write(data, number as i64),
+                _ => values::write(data, number),
+            }
+        } else if string_like(from) && string_like(to) {
+            values::construct_copy(ty::STRING, data, src);
+        } else if to == ty::STRING {
+            let string = values::stringify(from, src, false);
+            values::str_init(data, string.chars().collect());
+        } else {
+            values::construct_default(to, data);
+        }
+    })
+}
+
+fn is_number(ty: VarType) -> bool {
+    matches!(ty, ty::BOOL | ty::INT | ty::FLOAT)
+}
+
+/// Conversions that Godot's `Variant::can_convert_strict()` allows, restricted to the types the stub can convert.
+pub(super) fn can_convert(from: VarType, to: VarType, strict: bool) -> bool {
+    let string_like = |t| matches!(t, ty::STRING | ty::STRING_NAME | ty::NODE_PATH);
+
+    from == to
+        || to == ty::NIL
+        || (from == ty::NIL && to == ty::OBJECT)
+        || (is_number(from) && is_number(to))
+        || (string_like(from) && string_like(to))
+        || (!strict && to == ty::STRING)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Utility functions
+
+/// Variant arguments of a vararg utility function.
+unsafe fn utility_args(args: *const sys::GDExtensionConstTypePtr, arg_count: c_int) -> Vec<String> {
+    (0..arg_count as usize)
+        .map(|i| {
+            // SAFETY: caller passes `arg_count` variant pointers.
+            unsafe {
+                let var = (*args.add(i)).cast::<u8>();
+                values::stringify(values::var_type(var), values::var_data(var), false)
+            }
+        })
+        .collect()
+}
+
+unsafe extern "C" fn utility_print<const TO_STDERR: bool>(
+    _ret: sys::GDExtensionTypePtr,
+    args: *const sys::GDExtensionConstTypePtr,
+    arg_count: c_int,
+) {
+    // SAFETY: forwarded.
+    let message = unsafe { utility_args(args, arg_count) }.concat();
+
+    if TO_STDERR {
+        eprintln!("{message}");
+    } else {
+        println!("{message}");
+    }
+}
+
+unsafe extern "C" fn utility_prints<const SEPARATOR: char>(
+    _ret: sys::GDExtensionTypePtr,
+    args: *const sys::GDExtensionConstTypePtr,
+    arg_count: c_int,
+) {
+    // SAFETY: forwarded.
+    let parts = unsafe { utility_args(args, arg_count) };
+    println!("{}", parts.join(&SEPARATOR.to_string()));
+}
+
+unsafe extern "C" fn utility_push_error(
+    _ret: sys::GDExtensionTypePtr,
+    args: *const sys::GDExtensionConstTypePtr,
+    arg_count: c_int,
+) {
+    // SAFETY: forwarded.
+    let message = unsafe { utility_args(args, arg_count) }.concat();
+    eprintln!("ERROR: {message}");
+}
+
+unsafe extern "C" fn utility_push_warning(
+    _ret: sys::GDExtensionTypePtr,
+    args: *const sys::GDExtensionConstTypePtr,
+    arg_count: c_int,
+) {
+    // SAFETY: forwarded.
+    let message = unsafe { utility_args(args, arg_count) }.concat();
+    eprintln!("WARNING: {message}");
+}
+
+unsafe extern "C" fn utility_str(
+    ret: sys::GDExtensionTypePtr,
+    args: *const sys::GDExtensionConstTypePtr,
+    arg_count: c_int,
+) {
+    // SAFETY: forwarded; the return value is an initialized `String`.
+    unsafe {
+        let message = utility_args(args, arg_count).concat();
+        values::str_assign(ret.cast(), message.chars().collect());
+    }
+}
+
+unsafe extern "C" fn utility_unsupported(
+    _ret: sys::GDExtensionTypePtr,
+    _args: *const sys::GDExtensionConstTypePtr,
+    _arg_count: c_int,
+) {
+    unsupported("utility function (other than print, printerr, push_error, push_warning, str)");
+}
@@ -0,0 +1,1198 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Builtin methods (ptrcall), as used by godot-rust's `Inner*` types.
+//!
+//! Like in the engine, the return value passed to a builtin method is already initialized with the default value of its type.
+
+use std::cmp::Ordering;
+use std::ffi::c_int;
+
+use super::unsupported;
+use super::values::{self, per_type, ty, type_name, Kind, VarType, VariantBox};
+use crate as sys;
+
+type MethodFn = unsafe extern "C" fn(
+    sys::GDExtensionTypePtr,
+    *const sys::GDExtensionConstTypePtr,
+    sys::GDExtensionTypePtr,
+    c_int,
+);
+
+pub(super) fn lookup(ty: VarType, method: &str) -> sys::GDExtensionPtrBuiltInMethod {
+    let func = match values::kind(ty) {
+        Kind::Str => string::lookup(method),
+        Kind::Array => array::lookup(method),
+        Kind::Dictionary => dictionary::lookup(method),
+        Kind::Packed(_) => packed::lookup(ty, method),
+        _ => None,
+    };
+
+    func.or_else(|| match method {
+        "hash" => per_type!(ty, hash as MethodFn),
+        _ => per_type!(ty, method_unsupported as MethodFn),
+    })
+}
+
+/// Defines builtin methods as `extern "C"` functions, plus a `lookup()` function that maps Godot method names to them.
+///
+/// Each body runs in an `unsafe` context, with `$base` and `$ret` as byte pointers and `$args` as [`Args`]. With `<$TY>`, the
+/// methods are generic over the variant type of `$base`.
+macro_rules! methods {
+    (
+        $( fn $name:ident($base:ident, $args:ident, $ret:ident) { $($body:tt)* } )*
+    ) => {
+        $(
+            #[allow(unused_unsafe, unused_variables)]
+            unsafe extern "C" fn $name(
+                base: sys::GDExtensionTypePtr,
+                args: *const sys::GDExtensionConstTypePtr,
+                ret: sys::GDExtensionTypePtr,
+                _arg_count: std::ffi::c_int,
+            ) {
+                let ($base, $args, $ret) = (base.cast::<u8>(), super::Args(args), ret.cast::<u8>());
+
+                // SAFETY: godot-rust passes base, arguments and return value according to the method's signature.
+                unsafe { $($body)* }
+            }
+        )*
+
+        pub(super) fn lookup(method: &str) -> Option<super::MethodFn> {
+            match method {
+                $( stringify!($name) => Some($name as super::MethodFn), )*
+                _ => None,
+            }
+        }
+    };
+
+    (
+        $( fn $name:ident<$TY:ident>($base:ident, $args:ident, $ret:ident) { $($body:tt)* } )*
+    ) => {
+        $(
+            #[allow(unused_unsafe, unused_variables)]
+            unsafe extern "C" fn $name<const $TY: VarType>(
+                base: sys::GDExtensionTypePtr,
+                args: *const sys::GDExtensionConstTypePtr,
+                ret: sys::GDExtensionTypePtr,
+                _arg_count: std::ffi::c_int,
+            ) {
+                let ($base, $args, $ret) = (base.cast::<u8>(), super::Args(args), ret.cast::<u8>());
+
+                // SAFETY: godot-rust passes base, arguments and return value according to the method's signature.
+                unsafe { $($body)* }
+            }
+        )*
+
+        pub(super) fn lookup(ty: VarType, method: &str) -> Option<super::MethodFn> {
+            match method {
+                $( stringify!($name) => per_type!(ty, $name as super::MethodFn), )*
+                _ => None,
+            }
+        }
+    };
+}
+
+unsafe extern "C" fn hash<const TY: VarType>(
+    base: sys::GDExtensionTypePtr,
+    _args: *const sys::GDExtensionConstTypePtr,
+    ret: sys::GDExtensionTypePtr,
+    _arg_count: c_int,
+) {
+    // SAFETY: `base` is a `TY` value, the result is an `int`.
+    unsafe {
+        let hash = values::hash_value(TY, base.cast());
+        values::write(ret.cast(), i64::from(hash));
+    }
+}
+
+unsafe extern "C" fn method_unsupported<const TY: VarType>(
+    _base: sys::GDExtensionTypePtr,
+    _args: *const sys::GDExtensionConstTypePtr,
+    _ret: sys::GDExtensionTypePtr,
+    _arg_count: c_int,
+) {
+    unsupported(&format!("this builtin method of {}", type_name(TY)));
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Argument and return value helpers
+
+/// Typed access to ptrcall arguments.
+struct Args(*const sys::GDExtensionConstTypePtr);
+
+impl Args {
+    unsafe fn ptr(&self, index: usize) -> *const u8 {
+        // SAFETY: caller guarantees that the argument exists.
+        unsafe { (*self.0.add(index)).cast() }
+    }
+
+    unsafe fn int(&self, index: usize) -> i64 {
+        // SAFETY: caller guarantees an `int` argument.
+        unsafe { values::read(self.ptr(index)) }
+    }
+
+    unsafe fn bool(&self, index: usize) -> bool {
+        // SAFETY: caller guarantees a `bool` argument.
+        unsafe { values::read::<u8>(self.ptr(index)) != 0 }
+    }
+
+    unsafe fn chars<'a>(&self, index: usize) -> &'a [char] {
+        // SAFETY: caller guarantees a string argument.
+        unsafe { values::str_chars(self.ptr(index)) }
+    }
+
+    unsafe fn variant(&self, index: usize) -> VariantBox {
+        // SAFETY: caller guarantees a `Variant` argument.
+        unsafe { VariantBox::copy_of(self.ptr(index)) }
+    }
+}
+
+unsafe fn ret_variant(ret: *mut u8, value: VariantBox) {
+    // SAFETY: `ret` is an initialized variant.
+    unsafe {
+        values::var_destroy(ret);
+        value.move_into(ret);
+    }
+}
+
+unsafe fn ret_array(ret: *mut u8, array: values::ArrayData) {
+    // SAFETY: `ret` is an initialized array.
+    unsafe {
+        values::destroy(ty::ARRAY, ret);
+        values::array_init(ret, array);
+    }
+}
+
+/// Replaces the initialized value at `ret` with a copy of `data`, both of type `ty`.
+unsafe fn ret_copy(ty: VarType, ret: *mut u8, data: *const u8) {
+    // SAFETY: `ret` and `data` are initialized values of type `ty`.
+    unsafe {
+        values::destroy(ty, ret);
+        values::construct_copy(ty, ret, data);
+    }
+}
+
+/// Godot's index clamping for `slice()`: negative indices count from the end.
+fn slice_indices(len: usize, begin: i64, end: i64, step: i64) -> Vec<usize> {
+    let len = len as i64;
+    if len == 0 || step == 0 {
+        return Vec::new();
+    }
+
+    let wrap = |i: i64| {
+        let i = i.clamp(-len, len);
+        if i < 0 {
+            i + len
+        } else {
+            i
+        }
+    };
+
+    let (mut index, end) = if step > 0 {
+        (wrap(begin), wrap(end))
+    } else {
+        (wrap(begin).min(len - 1), wrap(end).max(-1))
+    };
+
+    let mut result = Vec::new();
+    while (step > 0 && index < end) || (step < 0 && index > end) {
+        result.push(index as usize);
+        index += step;
+    }
+
+    result
+}
+
+/// Godot's `find(what, from)` start index: negative values count from the end.
+fn find_start(len: usize, from: i64) -> usize {
+    if from < 0 {
+        (from + len as i64).max(0) as usize
+    } else {
+        from as usize
+    }
+}
+
+/// Godot's `rfind(what, from)` start index, where -1 means the last element. `None` if nothing can be found.
+fn rfind_start(len: usize, from: i64) -> Option<usize> {
+    let from = if from < 0 { from + len as i64 } else { from };
+    (from >= 0 && len > 0).then(|| (from as usize).min(len - 1))
+}
+
+fn position_to_int(position: Option<usize>) -> i64 {
+    position.map_or(-1, |i| i as i64)
+}
+
+/// Duplicates nested arrays and dictionaries (for `deep` copies), shares everything else.
+fn duplicate_variant(var: &VariantBox, deep: bool) -> VariantBox {
+    let data = var.data();
+
+    // SAFETY: reads are type-checked.
+    unsafe {
+        match var.ty() {
+            ty::ARRAY if deep => {
+                let array = duplicate_array(&values::array_data(data).borrow(), true);
+                VariantBox::with(ty::ARRAY, |dst| values::array_init(dst, array))
+            }
+            ty::DICTIONARY if deep => {
+                let dict = duplicate_dict(&values::dict_data(data).borrow(), true);
+                VariantBox::with(ty::DICTIONARY, |dst| values::dict_init(dst, dict))
+            }
+            _ => var.clone(),
+        }
+    }
+}
+
+fn duplicate_array(array: &values::ArrayData, deep: bool) -> values::ArrayData {
+    values::ArrayData {
+        elems: array
+            .elems
+            .iter()
+            .map(|e| duplicate_variant(e, deep))
+            .collect(),
+        ..array.same_typed()
+    }
+}
+
+fn duplicate_dict(dict: &values::DictData, deep: bool) -> values::DictData {
+    values::DictData {
+        entries: dict
+            .entries
+            .iter()
+            .map(|entry| {
+                Box::new((
+                    duplicate_variant(&entry.0, deep),
+                    duplicate_variant(&entry.1, deep),
+                ))
+            })
+            .collect(),
+        read_only: false,
+    }
+}
+
+fn report_read_only(what: &str) {
+    eprintln!("ERROR: {what} is in read-only state.");
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// String, StringName, NodePath
+
+mod string {
+    use super::*;
+
+    fn find_chars(haystack: &[char], needle: &[char], from: usize) -> Option<usize> {
+        if needle.is_empty() || from > haystack.len() {
+            return None;
+        }
+
+        haystack[from..]
+            .windows(needle.len())
+            .position(|w| w == needle)
+            .map(|i| i + from)
+    }
+
+    fn rfind_chars(haystack: &[char], needle: &[char], from: i64) -> Option<usize> {
+        if needle.is_empty() || needle.len() > haystack.len() {
+            return None;
+        }
+
+        let last_start = haystack.len() - needle.len();
+        let start = rfind_start(haystack.len(), from)?.min(last_start);
+        (0..=start)
+            .rev()
+            .find(|&i| haystack[i..].starts_with(needle))
+    }
+
+    fn lowercase(chars: &[char]) -> Vec<char> {
+        chars.iter().flat_map(|c| c.to_lowercase()).collect()
+    }
+
+    unsafe fn ret_chars(ret: *mut u8, chars: Vec<char>) {
+        // SAFETY: `ret` is an initialized string.
+        unsafe { values::str_assign(ret, chars) };
+    }
+
+    /// Parses the longest numeric prefix, like Godot's `to_int()` and `to_float()`.
+    fn numeric_prefix(chars: &[char], allow_fraction: bool) -> String {
+        let s: String = chars.iter().collect();
+        let s = s.trim_start();
+
+        let mut end = 0;
+        let mut seen_dot = false;
+        for (i, c) in s.char_indices() {
+            let accept = c.is_ascii_digit()
+                || (i == 0 && (c == '-' || c == '+'))
+                || (allow_fraction && c == '.' && !std::mem::replace(&mut seen_dot, true));
+            if !accept {
+                break;
+            }
+            end = i + c.len_utf8();
+        }
+
+        s[..end].to_string()
+    }
+
+    methods! {
+        fn length(base, args, ret) {
+            values::write(ret, values::str_chars(base).len() as i64);
+        }
+
+        fn is_empty(base, args, ret) {
+            values::write(ret, values::str_chars(base).is_empty());
+        }
+
+        fn begins_with(base, args, ret) {
+            values::write(ret, values::str_chars(base).starts_with(args.chars(0)));
+        }
+
+        fn ends_with(base, args, ret) {
+            values::write(ret, values::str_chars(base).ends_with(args.chars(0)));
+        }
+
+        fn contains(base, args, ret) {
+            let needle = args.chars(0);
+            let found = needle.is_empty() || find_chars(values::str_chars(base), needle, 0).is_some();
+            values::write(ret, found);
+        }
+
+        fn containsn(base, args, ret) {
+            let needle = lowercase(args.chars(0));
+            let found = needle.is_empty() || find_chars(&lowercase(values::str_chars(base)), &needle, 0).is_some();
+            values::write(ret, found);
+        }
+
+        fn find(base, args, ret) {
+            let haystack = values::str_chars(base);
+            let from = find_start(haystack.len(), args.int(1));
+            values::write(ret, position_to_int(find_chars(haystack, args.chars(0), from)));
+        }
+
+        fn findn(base, args, ret) {
+            let haystack = lowercase(values::str_chars(base));
+            let from = find_start(haystack.len(), args.int(1));
+            values::write(ret, position_to_int(find_chars(&haystack, &lowercase(args.chars(0)), from)));
+        }
+
+        fn rfind(base, args, ret) {
+            let found = rfind_chars(values::str_chars(base), args.chars(0), args.int(1));
+            values::write(ret, position_to_int(found));
+        }
+
+        fn substr(base, args, ret) {
+            let chars = values::str_chars(base);
+            let from = (args.int(0).max(0) as usize).min(chars.len());
+            let len = args.int(1);
+            let to = if len < 0 { chars.len() } else { (from + len as usize).min(chars.len()) };
+            ret_chars(ret, chars[from..to].to_vec());
+        }
+
+        fn to_upper(base, args, ret) {
+            ret_chars(ret, values::str_chars(base).iter().flat_map(|c| c.to_uppercase()).collect());
+        }
+
+        fn to_lower(base, args, ret) {
+            ret_chars(ret, lowercase(values::str_chars(base)));
+        }
+
+        fn strip_edges(base, args, ret) {
+            let mut chars = values::str_chars(base);
+            let is_blank = |c: &char| *c <= ' ';
+            if args.bool(0) {
+                let start = chars.iter().position(|c| !is_blank(c)).unwrap_or(chars.len());
+                chars = &chars[start..];
+            }
+            if args.bool(1) {
+                let end = chars.iter().rposition(|c| !is_blank(c)).map_or(0, |i| i + 1);
+                chars = &chars[..end];
+            }
+            ret_chars(ret, chars.to_vec());
+        }
+
+        fn replace(base, args, ret) {
+            let (haystack, what, with) = (values::str_chars(base), args.chars(0), args.chars(1));
+            let mut result = Vec::with_capacity(haystack.len());
+            let mut i = 0;
+            while i < haystack.len() {
+                if !what.is_empty() && haystack[i..].starts_with(what) {
+                    result.extend_from_slice(with);
+                    i += what.len();
+                } else {
+                    result.push(haystack[i]);
+                    i += 1;
+                }
+            }
+            ret_chars(ret, result);
+        }
+
+        fn repeat(base, args, ret) {
+            ret_chars(ret, values::str_chars(base).repeat(args.int(0).max(0) as usize));
+        }
+
+        fn reverse(base, args, ret) {
+            ret_chars(ret, values::str_chars(base).iter().rev().copied().collect());
+        }
+
+        fn unicode_at(base, args, ret) {
+            let chars = values::str_chars(base);
+            let c = usize::try_from(args.int(0)).ok().and_then(|i| chars.get(i));
+            values::write(ret, c.map_or(0, |&c| i64::from(u32::from(c))));
+        }
+
+        fn split(base, args, ret) {
+            let (chars, delimiter) = (values::str_chars(base), args.chars(0));
+            let (allow_empty, max_split) = (args.bool(1), args.int(2));
+
+            let mut parts: Vec<Vec<char>> = Vec::new();
+            if delimiter.is_empty() {
+                parts.extend(chars.iter().map(|&c| vec![c]));
+            } else {
+                let mut start = 0;
+                loop {
+                    let at_limit = max_split > 0 && parts.len() as i64 == max_split;
+                    let next = if at_limit { None } else { find_chars(chars, delimiter, start) };
+                    let end = next.unwrap_or(chars.len());
+                    if allow_empty || end > start {
+                        parts.push(chars[start..end].to_vec());
+                    }
+                    match next {
+                        Some(i) => start = i + delimiter.len(),
+                        None => break,
+                    }
+                }
+            }
+
+            let packed = values::packed_data_mut(ret);
+            let Kind::Packed(elem) = values::kind(ty::PACKED_STRING_ARRAY) else { unreachable!() };
+            packed.resize(elem, parts.len());
+            for (i, part) in parts.into_iter().enumerate() {
+                values::str_init(packed.elem_ptr(elem, i), part);
+            }
+        }
+
+        fn count(base, args, ret) {
+            let (chars, what) = (values::str_chars(base), args.chars(0));
+            let from = (args.int(1).max(0) as usize).min(chars.len());
+            let to = match args.int(2) {
+                0 => chars.len(),
+                to => (to.max(0) as usize).min(chars.len()),
+            };
+
+            let mut count = 0i64;
+            let mut i = from;
+            while let Some(found) = find_chars(&chars[..to.max(from)], what, i) {
+                count += 1;
+                i = found + what.len();
+            }
+            values::write(ret, count);
+        }
+
+        fn insert(base, args, ret) {
+            let chars = values::str_chars(base);
+            let at = (args.int(0).max(0) as usize).min(chars.len());
+            ret_chars(ret, [&chars[..at], args.chars(1), &chars[at..]].concat());
+        }
+
+        fn erase(base, args, ret) {
+            let chars = values::str_chars(base);
+            let from = (args.int(0).max(0) as usize).min(chars.len());
+            let to = from.saturating_add(args.int(1).max(0) as usize).min(chars.len());
+            ret_chars(ret, [&chars[..from], &chars[to..]].concat());
+        }
+
+        fn to_int(base, args, ret) {
+            let digits = numeric_prefix(values::str_chars(base), false);
+            values::write(ret, digits.parse::<i64>().unwrap_or(0));
+        }
+
+        fn to_float(base, args, ret) {
+            let digits = numeric_prefix(values::str_chars(base), true);
+            values::write(ret, digits.parse::<f64>().unwrap_or(0.0));
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Array
+
+mod array {
+    use super::*;
+
+    type ArrayRef = std::cell::RefCell<values::ArrayData>;
+
+    unsafe fn data<'a>(base: *const u8) -> &'a ArrayRef {
+        // SAFETY: caller passes an initialized array.
+        unsafe { values::array_data(base) }
+    }
+
+    /// Mutable access, unless the array is read-only.
+    unsafe fn data_mut<'a>(base: *const u8) -> Option<std::cell::RefMut<'a, values::ArrayData>> {
+        // SAFETY: forwarded.
+        let array = unsafe { data(base) }.borrow_mut();
+        if array.read_only {
+            report_read_only("Array");
+            return None;
+        }
+
+        Some(array)
+    }
+
+    unsafe fn ret_elem(ret: *mut u8, elem: Option<VariantBox>) {
+        // SAFETY: forwarded.
+        unsafe { ret_variant(ret, elem.unwrap_or_default()) };
+    }
+
+    fn var_cmp(a: &VariantBox, b: &VariantBox) -> Ordering {
+        if values::var_less(a, b) == Some(true) {
+            Ordering::Less
+        } else if values::var_less(b, a) == Some(true) {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    methods! {
+        fn size(base, args, ret) {
+            values::write(ret, data(base).borrow().elems.len() as i64);
+        }
+
+        fn is_empty(base, args, ret) {
+            values::write(ret, data(base).borrow().elems.is_empty());
+        }
+
+        fn clear(base, args, ret) {
+            if let Some(mut array) = data_mut(base) {
+                array.elems.clear();
+            }
+        }
+
+        fn resize(base, args, ret) {
+            if let Some(mut array) = data_mut(base) {
+                let elem_type = array.elem_type;
+                let new_len = args.int(0).max(0) as usize;
+                array.elems.resize_with(new_len, || {
+                    VariantBox::with(elem_type, |dst| values::construct_default(elem_type, dst))
+                });
+            }
+            values::write(ret, 0i64);
+        }
+
+        fn push_back(base, args, ret) {
+            if let Some(mut array) = data_mut(base) {
+                array.elems.push(args.variant(0));
+            }
+        }
+
+        fn append(base, args, ret) {
+            if let Some(mut array) = data_mut(base) {
+                array.elems.push(args.variant(0));
+            }
+        }
+
+        fn push_front(base, args, ret) {
+            if let Some(mut array) = data_mut(base) {
+                array.elems.insert(0, args.variant(0));
+            }
+        }
+
+        fn insert(base, args, ret) {
+            let mut error = 1i64;
+            if let Some(mut array) = data_mut(base) {
+                let len = array.elems.len() as i64;
+                let position = args.int(0);
+                let position = if position < 0 { position + len } else { position };
+                if (0..=len).contains(&position) {
+                    array.elems.insert(position as usize, args.variant(1));
+                    error = 0;
+                }
+            }
+            values::write(ret, error);
+        }
+
+        fn pop_back(base, args, ret) {
+            let elem = data_mut(base).and_then(|mut array| array.elems.pop());
+            ret_elem(ret, elem);
+        }
+
+        fn pop_front(base, args, ret) {
+            let elem = data_mut(base).and_then(|mut array| {
+                (!array.elems.is_empty()).then(|| array.elems.remove(0))
+            });
+            ret_elem(ret, elem);
+        }
+
+        fn pop_at(base, args, ret) {
+            let position = args.int(0);
+            let elem = data_mut(base).and_then(|mut array| {
+                let len = array.elems.len() as i64;
+                let position = if position < 0 { position + len } else { position };
+                (0..len).contains(&position).then(|| array.elems.remove(position as usize))
+            });
+            ret_elem(ret, elem);
+        }
+
+        fn remove_at(base, args, ret) {
+            let position = args.int(0);
+            if let Some(mut array) = data_mut(base) {
+                let len = array.elems.len() as i64;
+                let position = if position < 0 { position + len } else { position };
+                if (0..len).contains(&position) {
+                    array.elems.remove(position as usize);
+                }
+            }
+        }
+
+        fn erase(base, args, ret) {
+            let value = args.variant(0);
+            if let Some(mut array) = data_mut(base) {
+                if let Some(i) = array.find(value.as_ptr(), 0) {
+                    array.elems.remove(i);
+                }
+            }
+        }
+
+        fn has(base, args, ret) {
+            values::write(ret, data(base).borrow().find(args.ptr(0), 0).is_some());
+        }
+
+        fn count(base, args, ret) {
+            let value = args.variant(0);
+            let count = data(base).borrow().elems.iter().filter(|e| **e == value).count();
+            values::write(ret, count as i64);
+        }
+
+        fn find(base, args, ret) {
+            let array = data(base).borrow();
+            let from = find_start(array.elems.len(), args.int(1));
+            values::write(ret, position_to_int(array.find(args.ptr(0), from)));
+        }
+
+        fn rfind(base, args, ret) {
+            let array = data(base).borrow();
+            let value = args.variant(0);
+            let found = rfind_start(array.elems.len(), args.int(1))
+                .and_then(|start| (0..=start).rev().find(|&i| array.elems[i] == value));
+            values::write(ret, position_to_int(found));
+        }
+
+        fn front(base, args, ret) {
+            let elem = data(base).borrow().elems.first().cloned();
+            ret_elem(ret, elem);
+        }
+
+        fn back(base, args, ret) {
+            let elem = data(base).borrow().elems.last().cloned();
+            ret_elem(ret, elem);
+        }
+
+        fn min(base, args, ret) {
+            let elem = data(base).borrow().elems.iter().min_by(|a, b| var_cmp(a, b)).cloned();
+            ret_elem(ret, elem);
+        }
+
+        fn max(base, args, ret) {
+            let elem = data(base).borrow().elems.iter().max_by(|a, b| var_cmp(a, b)).cloned();
+            ret_elem(ret, elem);
+        }
+
+        fn fill(base, args, ret) {
+            let value = args.variant(0);
+            if let Some(mut array) = data_mut(base) {
+                array.elems.iter_mut().for_each(|e| *e = value.clone());
+            }
+        }
+
+        fn append_array(base, args, ret) {
+            let other: Vec<VariantBox> = data(args.ptr(0)).borrow().elems.clone();
+            if let Some(mut array) = data_mut(base) {
+                array.elems.extend(other);
+            }
+        }
+
+        fn duplicate(base, args, ret) {
+            let copy = duplicate_array(&data(base).borrow(), args.bool(0));
+            ret_array(ret, copy);
+        }
+
+        fn slice(base, args, ret) {
+            let array = data(base).borrow();
+            let (begin, end, step, deep) = (args.int(0), args.int(1), args.int(2), args.bool(3));
+            let slice = values::ArrayData {
+                elems: slice_indices(array.elems.len(), begin, end, step)
+                    .into_iter()
+                    .map(|i| duplicate_variant(&array.elems[i], deep))
+                    .collect(),
+                ..array.same_typed()
+            };
+            ret_array(ret, slice);
+        }
+
+        fn reverse(base, args, ret) {
+            if let Some(mut array) = data_mut(base) {
+                array.elems.reverse();
+            }
+        }
+
+        fn sort(base, args, ret) {
+            if let Some(mut array) = data_mut(base) {
+                array.elems.sort_by(var_cmp);
+            }
+        }
+
+        fn reserve(base, args, ret) {
+            values::write(ret, 0i64);
+        }
+
+        fn is_read_only(base, args, ret) {
+            values::write(ret, data(base).borrow().read_only);
+        }
+
+        fn make_read_only(base, args, ret) {
+            data(base).borrow_mut().read_only = true;
+        }
+
+        fn is_typed(base, args, ret) {
+            values::write(ret, data(base).borrow().elem_type != ty::NIL);
+        }
+
+        fn is_same_typed(base, args, ret) {
+            let (a, b) = (data(base).borrow(), data(args.ptr(0)).borrow());
+            let same = a.elem_type == b.elem_type && a.class_name == b.class_name && a.script == b.script;
+            values::write(ret, same);
+        }
+
+        fn get_typed_builtin(base, args, ret) {
+            values::write(ret, data(base).borrow().elem_type as i64);
+        }
+
+        fn get_typed_class_name(base, args, ret) {
+            values::str_assign(ret, data(base).borrow().class_name.clone());
+        }
+
+        fn get_typed_script(base, args, ret) {
+            ret_variant(ret, data(base).borrow().script.clone());
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Dictionary
+
+mod dictionary {
+    use super::*;
+
+    type DictRef = std::cell::RefCell<values::DictData>;
+
+    unsafe fn data<'a>(base: *const u8) -> &'a DictRef {
+        // SAFETY: caller passes an initialized dictionary.
+        unsafe { values::dict_data(base) }
+    }
+
+    /// Mutable access, unless the dictionary is read-only.
+    unsafe fn data_mut<'a>(base: *const u8) -> Option<std::cell::RefMut<'a, values::DictData>> {
+        // SAFETY: forwarded.
+        let dict = unsafe { data(base) }.borrow_mut();
+        if dict.read_only {
+            report_read_only("Dictionary");
+            return None;
+        }
+
+        Some(dict)
+    }
+
+    unsafe fn ret_collected(ret: *mut u8, elems: Vec<VariantBox>) {
+        // SAFETY: forwarded.
+        unsafe {
+            ret_array(
+                ret,
+                values::ArrayData {
+                    elems,
+                    ..Default::default()
+                },
+            )
+        };
+    }
+
+    methods! {
+        fn size(base, args, ret) {
+            values::write(ret, data(base).borrow().entries.len() as i64);
+        }
+
+        fn is_empty(base, args, ret) {
+            values::write(ret, data(base).borrow().entries.is_empty());
+        }
+
+        fn clear(base, args, ret) {
+            if let Some(mut dict) = data_mut(base) {
+                dict.entries.clear();
+            }
+        }
+
+        fn has(base, args, ret) {
+            values::write(ret, data(base).borrow().find(args.ptr(0)).is_some());
+        }
+
+        fn has_all(base, args, ret) {
+            let dict = data(base).borrow();
+            let keys = values::array_data(args.ptr(0)).borrow();
+            let all = keys.elems.iter().all(|key| dict.find(key.as_ptr()).is_some());
+            values::write(ret, all);
+        }
+
+        fn erase(base, args, ret) {
+            let mut erased = false;
+            if let Some(mut dict) = data_mut(base) {
+                if let Some(i) = dict.find(args.ptr(0)) {
+                    dict.entries.remove(i);
+                    erased = true;
+                }
+            }
+            values::write(ret, erased);
+        }
+
+        fn get(base, args, ret) {
+            let dict = data(base).borrow();
+            let value = match dict.find(args.ptr(0)) {
+                Some(i) => dict.entries[i].1.clone(),
+                None => args.variant(1),
+            };
+            ret_variant(ret, value);
+        }
+
+        fn get_or_add(base, args, ret) {
+            let value = match data_mut(base) {
+                Some(mut dict) => {
+                    let default = args.variant(1);
+                    let is_new = dict.find(args.ptr(0)).is_none();
+                    let entry = dict.entry(args.ptr(0));
+                    if is_new {
+                        entry.1 = default;
+                    }
+                    entry.1.clone()
+                }
+                None => VariantBox::nil(),
+            };
+            ret_variant(ret, value);
+        }
+
+        fn keys(base, args, ret) {
+            let keys = data(base).borrow().entries.iter().map(|entry| entry.0.clone()).collect();
+            ret_collected(ret, keys);
+        }
+
+        fn values(base, args, ret) {
+            let values = data(base).borrow().entries.iter().map(|entry| entry.1.clone()).collect();
+            ret_collected(ret, values);
+        }
+
+        fn find_key(base, args, ret) {
+            let value = args.variant(0);
+            let key = data(base).borrow().entries.iter().find(|entry| entry.1 == value).map(|entry| entry.0.clone());
+            ret_variant(ret, key.unwrap_or_default());
+        }
+
+        fn duplicate(base, args, ret) {
+            let copy = duplicate_dict(&data(base).borrow(), args.bool(0));
+            values::destroy(ty::DICTIONARY, ret);
+            values::dict_init(ret, copy);
+        }
+
+        fn merge(base, args, ret) {
+            let (other, overwrite) = (duplicate_dict(&data(args.ptr(0)).borrow(), false), args.bool(1));
+            if let Some(mut dict) = data_mut(base) {
+                for entry in other.entries {
+                    let (key, value) = *entry;
+                    let exists = dict.find(key.as_ptr()).is_some();
+                    if overwrite || !exists {
+                        dict.entry(key.as_ptr()).1 = value;
+                    }
+                }
+            }
+        }
+
+        fn is_read_only(base, args, ret) {
+            values::write(ret, data(base).borrow().read_only);
+        }
+
+        fn make_read_only(base, args, ret) {
+            data(base).borrow_mut().read_only = true;
+        }
+
+        // Typed dictionaries are not emulated; all dictionaries are untyped.
+        fn is_typed(base, args, ret) {
+            values::write(ret, false);
+        }
+
+        fn is_typed_key(base, args, ret) {
+            values::write(ret, false);
+        }
+
+        fn is_typed_value(base, args, ret) {
+            values::write(ret, false);
+        }
+
+        fn get_typed_key_builtin(base, args, ret) {
+            values::write(ret, ty::NIL as i64);
+        }
+
+        fn get_typed_value_builtin(base, args, ret) {
+            values::write(ret, ty::NIL as i64);
+        }
+
+        fn get_typed_key_class_name(base, args, ret) {}
+
+        fn get_typed_value_class_name(base, args, ret) {}
+
+        fn get_typed_key_script(base, args, ret) {}
+
+        fn get_typed_value_script(base, args, ret) {}
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Packed arrays
+
+mod packed {
+    use super::*;
+
+    fn elem_of(packed_ty: VarType) -> values::Elem {
+        match values::kind(packed_ty) {
+            Kind::Packed(elem) => elem,
+            _ => unreachable!(),
+        }
+    }
+
+    unsafe fn len(base: *const u8) -> usize {
+        // SAFETY: caller passes an initialized packed array.
+        unsafe { values::packed_data(base) }.map_or(0, |packed| packed.len())
+    }
+
+    /// Element at `index` as variant.
+    unsafe fn get_elem(packed_ty: VarType, base: *const u8, index: usize) -> VariantBox {
+        let elem = elem_of(packed_ty);
+
+        // SAFETY: caller passes an initialized packed array and an in-bounds index.
+        unsafe {
+            let packed = values::packed_data(base).unwrap();
+            values::packed_elem_to_variant(packed_ty, elem, packed.elem_ptr(elem, index))
+        }
+    }
+
+    /// Whether the element at `index` equals the ptrcall argument `value` (of the array's argument type).
+    unsafe fn elem_equals(
+        packed_ty: VarType,
+        base: *const u8,
+        index: usize,
+        value: *const u8,
+    ) -> bool {
+        // SAFETY: forwarded; `value` has the element's ptrcall type.
+        unsafe {
+            let elem = get_elem(packed_ty, base, index);
+            values::values_equal(elem.ty(), elem.data(), value)
+        }
+    }
+
+    /// Writes the ptrcall argument `value` to the (zeroed) element at `index`.
+    unsafe fn set_elem(packed_ty: VarType, base: *mut u8, index: usize, value: *const u8) {
+        let elem = elem_of(packed_ty);
+
+        // SAFETY: caller passes an initialized packed array, an in-bounds index and an argument of the element type.
+        unsafe {
+            let packed = values::packed_data_mut(base);
+            packed.release_range(elem, index, index + 1);
+            values::packed_elem_from_arg(packed_ty, elem, packed.elem_ptr(elem, index), value);
+        }
+    }
+
+    unsafe fn insert_elem(packed_ty: VarType, base: *mut u8, index: usize, value: *const u8) {
+        let elem = elem_of(packed_ty);
+
+        // SAFETY: forwarded; the temporary owns one element, which is moved into the array.
+        unsafe {
+            let mut tmp = [0u64; 16];
+            let tmp = tmp.as_mut_ptr().cast::<u8>();
+            values::packed_elem_from_arg(packed_ty, elem, tmp, value);
+            values::packed_data_mut(base).insert(elem, index, tmp);
+        }
+    }
+
+    /// Element ordering for `sort()`: numbers and strings by value, math types component-wise.
+    fn elem_cmp(a: &VariantBox, b: &VariantBox) -> Ordering {
+        if let Some(less) = values::var_less(a, b) {
+            return if less {
+                Ordering::Less
+            } else if values::var_less(b, a) == Some(true) {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            };
+        }
+
+        // SAFETY: both have the same math type; `Color` uses f32, other types use `real`.
+        let components = |var: &VariantBox| -> Vec<f64> {
+            unsafe {
+                if var.ty() == ty::COLOR {
+                    (0..4)
+                        .map(|i| f64::from(values::read::<f32>(var.data().add(i * 4))))
+                        .collect()
+                } else {
+                    let Kind::Pod(size) = values::kind(var.ty()) else {
+                        return Vec::new();
+                    };
+                    let r = values::real_size();
+                    (0..size / r)
+                        .map(|i| {
+                            if r == 8 {
+                                values::read::<f64>(var.data().add(i * 8))
+                            } else {
+                                f64::from(values::read::<f32>(var.data().add(i * 4)))
+                            }
+                        })
+                        .collect()
+                }
+            }
+        };
+
+        components(a)
+            .partial_cmp(&components(b))
+            .unwrap_or(Ordering::Equal)
+    }
+
+    methods! {
+        fn size<TY>(base, args, ret) {
+            values::write(ret, len(base) as i64);
+        }
+
+        fn is_empty<TY>(base, args, ret) {
+            values::write(ret, len(base) == 0);
+        }
+
+        fn clear<TY>(base, args, ret) {
+            values::destroy(TY, base);
+            values::construct_default(TY, base);
+        }
+
+        fn resize<TY>(base, args, ret) {
+            let new_len = args.int(0).max(0) as usize;
+            values::packed_data_mut(base).resize(elem_of(TY), new_len);
+            values::write(ret, 0i64);
+        }
+
+        fn push_back<TY>(base, args, ret) {
+            insert_elem(TY, base, len(base), args.ptr(0));
+            values::write(ret, true);
+        }
+
+        fn append<TY>(base, args, ret) {
+            insert_elem(TY, base, len(base), args.ptr(0));
+            values::write(ret, true);
+        }
+
+        fn append_array<TY>(base, args, ret) {
+            let other = args.ptr(0);
+            let other_len = len(other);
+            for i in 0..other_len {
+                let value = get_elem(TY, other, i);
+                insert_elem(TY, base, len(base), value.data());
+            }
+        }
+
+        fn insert<TY>(base, args, ret) {
+            let (index, current_len) = (args.int(0), len(base) as i64);
+            let index = if index < 0 { index + current_len } else { index };
+            let error = if (0..=current_len).contains(&index) {
+                insert_elem(TY, base, index as usize, args.ptr(1));
+                0i64
+            } else {
+                1
+            };
+            values::write(ret, error);
+        }
+
+        fn remove_at<TY>(base, args, ret) {
+            let (index, current_len) = (args.int(0), len(base) as i64);
+            let index = if index < 0 { index + current_len } else { index };
+            if (0..current_len).contains(&index) {
+                values::packed_data_mut(base).remove(elem_of(TY), index as usize);
+            }
+        }
+
+        fn set<TY>(base, args, ret) {
+            let index = args.int(0);
+            if (0..len(base) as i64).contains(&index) {
+                set_elem(TY, base, index as usize, args.ptr(1));
+            }
+        }
+
+        fn get<TY>(base, args, ret) {
+            let index = args.int(0);
+            if (0..len(base) as i64).contains(&index) {
+                let value = get_elem(TY, base, index as usize);
+                let value_ty = value.ty();
+                ret_copy(value_ty, ret, value.data());
+            }
+        }
+
+        fn has<TY>(base, args, ret) {
+            let found = (0..len(base)).any(|i| elem_equals(TY, base, i, args.ptr(0)));
+            values::write(ret, found);
+        }
+
+        fn count<TY>(base, args, ret) {
+            let count = (0..len(base)).filter(|&i| elem_equals(TY, base, i, args.ptr(0))).count();
+            values::write(ret, count as i64);
+        }
+
+        fn find<TY>(base, args, ret) {
+            let current_len = len(base);
+            let from = find_start(current_len, args.int(1));
+            let found = (from..current_len).find(|&i| elem_equals(TY, base, i, args.ptr(0)));
+            values::write(ret, position_to_int(found));
+        }
+
+        fn rfind<TY>(base, args, ret) {
+            let found = rfind_start(len(base), args.int(1))
+                .and_then(|start| (0..=start).rev().find(|&i| elem_equals(TY, base, i, args.ptr(0))));
+            values::write(ret, position_to_int(found));
+        }
+
+        fn fill<TY>(base, args, ret) {
+            for i in 0..len(base) {
+                set_elem(TY, base, i, args.ptr(0));
+            }
+        }
+
+        fn reverse<TY>(base, args, ret) {
+            let elems: Vec<VariantBox> = (0..len(base)).map(|i| get_elem(TY, base, i)).collect();
+            for (i, value) in elems.iter().rev().enumerate() {
+                set_elem(TY, base, i, value.data());
+            }
+        }
+
+        fn sort<TY>(base, args, ret) {
+            let mut elems: Vec<VariantBox> = (0..len(base)).map(|i| get_elem(TY, base, i)).collect();
+            elems.sort_by(elem_cmp);
+            for (i, value) in elems.iter().enumerate() {
+                set_elem(TY, base, i, value.data());
+            }
+        }
+
+        fn duplicate<TY>(base, args, ret) {
+            ret_copy(TY, ret, base);
+        }
+
+        fn slice<TY>(base, args, ret) {
+            let indices = slice_indices(len(base), args.int(0), args.int(1), 1);
+            let elems: Vec<VariantBox> = indices.into_iter().map(|i| get_elem(TY, base, i)).collect();
+
+            values::destroy(TY, ret);
+            values::construct_default(TY, ret);
+            for (i, value) in elems.iter().enumerate() {
+                insert_elem(TY, ret, i, value.data());
+            }
+        }
+    }
+}
@@ -0,0 +1,301 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Pure-Rust stand-in for the GDExtension interface, to run builtin types in `cargo test` without Godot.
+//!
+//! Enabled with the `stub-interface` feature. [`run()`] initializes the binding with a `get_proc_address` implemented in Rust,
+//! which provides the builtin lifecycle and method tables. Strings, containers and variants then work as they would in the engine,
+//! within the following scope:
+//!
+//! - `GString`, `StringName` and `NodePath`: construction, conversion, comparison, hashing, and common methods such as `length`,
+//!   `find`, `substr`, `split` or `to_upper`.
+//! - `Array` (typed and untyped), `VarDictionary` and all packed arrays: element access, insertion, removal, iteration, `duplicate`,
+//!   `slice`, `find`, `has` and similar.
+//! - `Variant`: conversion from/to all builtin types, `get_type`, `stringify`, `hash`, `booleanize`, and `evaluate` for comparisons
+//!   and basic arithmetic.
+//! - `print`, `printerr`, `push_error` and `push_warning` utility functions.
+//!
+//! Objects, callables and signals are not emulated; neither are most math-type methods (which godot-rust implements in Rust anyway).
+//! An operation that the stub does not support prints which kind of operation it was and **aborts the test process**, since
+//! panics cannot unwind across the `extern "C"` boundary. Such tests belong into the integration tests (`itest`).
+
+mod interface_fns;
+mod lifecycle;
+mod methods;
+mod values;
+
+use std::ffi::{c_char, CStr, CString};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{mpsc, Mutex, OnceLock};
+
+use crate as sys;
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Runs `f` on the thread that owns the stub binding, initializing the binding on first use.
+///
+/// All calls share one binding and one thread, since the binding is single-threaded (unless `experimental-threads` is enabled).
+/// Panics inside `f` are propagated to the caller, so this can be used directly inside `#[test]` functions:
+///
+/// ```no_run
+/// # use godot_ffi as sys;
+/// #[test]
+/// fn string_length() {
+///     sys::stub::run(|| {
+///         // Use GString, Array, Variant, ...
+///     });
+/// }
+/// ```
+pub fn run<R, F>(f: F) -> R
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    let (result_tx, result_rx) = mpsc::channel();
+    let job: Job = Box::new(move || {
+        let result = panic::catch_unwind(AssertUnwindSafe(f));
+        let _ = result_tx.send(result);
+    });
+
+    job_sender()
+        .send(job)
+        .expect("stub binding thread has terminated");
+
+    match result_rx
+        .recv()
+        .expect("stub binding thread has terminated")
+    {
+        Ok(result) => result,
+        Err(panic) => panic::resume_unwind(panic),
+    }
+}
+
+fn job_sender() -> mpsc::Sender<Job> {
+    static JOBS: OnceLock<Mutex<mpsc::Sender<Job>>> = OnceLock::new();
+
+    let jobs = JOBS.get_or_init(|| {
+        let (job_tx, job_rx) = mpsc::channel::<Job>();
+
+        std::thread::Builder::new()
+            .name("godot-stub-main".to_string())
+            .spawn(move || {
+                // SAFETY: called exactly once, from the thread that executes all subsequent jobs.
+                unsafe {
+                    sys::initialize(
+                        Some(get_proc_address),
+                        std::ptr::null_mut(),
+                        sys::GdextConfig::new(false),
+                    )
+                };
+
+                for job in job_rx {
+                    job();
+                }
+            })
+            .expect("failed to spawn stub binding thread");
+
+        Mutex::new(job_tx)
+    });
+
+    jobs.lock().unwrap().clone()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Function lookup
+
+/// Looks up `$name` among the listed functions, type-checking each against its `GDExtensionInterface*` signature.
+macro_rules! lookup_fn {
+    ($name:expr; $( $(#[$attr:meta])* $module:ident::$func:ident: $FnTy:ident, )*) => {
+        match $name {
+            $(
+                $(#[$attr])*
+                stringify!($func) => {
+                    let fptr: sys::$FnTy = Some($module::$func);
+
+                    // SAFETY: all interface function pointers are type-erased to the same representation.
+                    unsafe {
+                        std::mem::transmute::<sys::$FnTy, sys::GDExtensionInterfaceFunctionPtr>(fptr)
+                    }
+                }
+            )*
+            // Like Godot, report unknown functions as unavailable, so that availability checks see the truth.
+            _ => None,
+        }
+    };
+}
+
+unsafe extern "C" fn get_proc_address(name: *const c_char) -> sys::GDExtensionInterfaceFunctionPtr {
+    // SAFETY: godot-rust passes null-terminated function names.
+    let name = unsafe { CStr::from_ptr(name) }.to_str().unwrap_or("");
+
+    use crate::stub as e;
+    use {interface_fns as i, lifecycle as l};
+    lookup_fn!(name;
+        e::get_godot_version: GDExtensionInterfaceGetGodotVersion,
+        e::print_error: GDExtensionInterfacePrintError,
+        e::print_warning: GDExtensionInterfacePrintWarning,
+
+        l::variant_get_ptr_constructor: GDExtensionInterfaceVariantGetPtrConstructor,
+        l::variant_get_ptr_destructor: GDExtensionInterfaceVariantGetPtrDestructor,
+        l::variant_get_ptr_operator_evaluator: GDExtensionInterfaceVariantGetPtrOperatorEvaluator,
+        l::get_variant_from_type_constructor: GDExtensionInterfaceGetVariantFromTypeConstructor,
+        l::get_variant_to_type_constructor: GDExtensionInterfaceGetVariantToTypeConstructor,
+        #[cfg(since_api = "4.4")]
+        l::variant_get_ptr_internal_getter: GDExtensionInterfaceVariantGetPtrInternalGetter,
+        l::variant_get_ptr_builtin_method: GDExtensionInterfaceVariantGetPtrBuiltinMethod,
+        l::variant_get_ptr_utility_function: GDExtensionInterfaceVariantGetPtrUtilityFunction,
+
+        i::variant_new_copy: GDExtensionInterfaceVariantNewCopy,
+        i::variant_new_nil: GDExtensionInterfaceVariantNewNil,
+        i::variant_destroy: GDExtensionInterfaceVariantDestroy,
+        i::variant_evaluate: GDExtensionInterfaceVariantEvaluate,
+        i::variant_call: GDExtensionInterfaceVariantCall,
+        i::variant_get_type: GDExtensionInterfaceVariantGetType,
+        i::variant_booleanize: GDExtensionInterfaceVariantBooleanize,
+        i::variant_hash: GDExtensionInterfaceVariantHash,
+        i::variant_stringify: GDExtensionInterfaceVariantStringify,
+        i::variant_get_type_name: GDExtensionInterfaceVariantGetTypeName,
+        i::variant_can_convert: GDExtensionInterfaceVariantCanConvert,
+        i::variant_can_convert_strict: GDExtensionInterfaceVariantCanConvertStrict,
+        #[cfg(since_api = "4.4")]
+        i::variant_get_object_instance_id: GDExtensionInterfaceVariantGetObjectInstanceId,
+        i::variant_get_keyed: GDExtensionInterfaceVariantGetKeyed,
+        i::variant_set_keyed: GDExtensionInterfaceVariantSetKeyed,
+        i::variant_get_indexed: GDExtensionInterfaceVariantGetIndexed,
+        i::variant_set_indexed: GDExtensionInterfaceVariantSetIndexed,
+        i::variant_iter_init: GDExtensionInterfaceVariantIterInit,
+        i::variant_iter_next: GDExtensionInterfaceVariantIterNext,
+        i::variant_iter_get: GDExtensionInterfaceVariantIterGet,
+
+        i::string_new_with_latin1_chars: GDExtensionInterfaceStringNewWithLatin1Chars,
+        i::string_new_with_utf8_chars: GDExtensionInterfaceStringNewWithUtf8Chars,
+        i::string_new_with_latin1_chars_and_len: GDExtensionInterfaceStringNewWithLatin1CharsAndLen,
+        i::string_new_with_utf8_chars_and_len: GDExtensionInterfaceStringNewWithUtf8CharsAndLen,
+        #[cfg(since_api = "4.3")]
+        i::string_new_with_utf8_chars_and_len2: GDExtensionInterfaceStringNewWithUtf8CharsAndLen2,
+        i::string_new_with_utf32_chars: GDExtensionInterfaceStringNewWithUtf32Chars,
+        i::string_new_with_utf32_chars_and_len: GDExtensionInterfaceStringNewWithUtf32CharsAndLen,
+        i::string_to_latin1_chars: GDExtensionInterfaceStringToLatin1Chars,
+        i::string_to_utf8_chars: GDExtensionInterfaceStringToUtf8Chars,
+        i::string_to_utf32_chars: GDExtensionInterfaceStringToUtf32Chars,
+        i::string_operator_index: GDExtensionInterfaceStringOperatorIndex,
+        i::string_operator_index_const: GDExtensionInterfaceStringOperatorIndexConst,
+        i::string_operator_plus_eq_string: GDExtensionInterfaceStringOperatorPlusEqString,
+        i::string_operator_plus_eq_char: GDExtensionInterfaceStringOperatorPlusEqChar,
+        i::string_operator_plus_eq_cstr: GDExtensionInterfaceStringOperatorPlusEqCstr,
+        i::string_operator_plus_eq_c32str: GDExtensionInterfaceStringOperatorPlusEqC32str,
+        i::string_name_new_with_latin1_chars: GDExtensionInterfaceStringNameNewWithLatin1Chars,
+        i::string_name_new_with_utf8_chars: GDExtensionInterfaceStringNameNewWithUtf8Chars,
+        i::string_name_new_with_utf8_chars_and_len: GDExtensionInterfaceStringNameNewWithUtf8CharsAndLen,
+
+        i::array_operator_index: GDExtensionInterfaceArrayOperatorIndex,
+        i::array_operator_index_const: GDExtensionInterfaceArrayOperatorIndexConst,
+        i::array_ref: GDExtensionInterfaceArrayRef,
+        i::array_set_typed: GDExtensionInterfaceArraySetTyped,
+        i::dictionary_operator_index: GDExtensionInterfaceDictionaryOperatorIndex,
+        i::dictionary_operator_index_const: GDExtensionInterfaceDictionaryOperatorIndexConst,
+
+        i::packed_byte_array_operator_index: GDExtensionInterfacePackedByteArrayOperatorIndex,
+        i::packed_byte_array_operator_index_const: GDExtensionInterfacePackedByteArrayOperatorIndexConst,
+        i::packed_int32_array_operator_index: GDExtensionInterfacePackedInt32ArrayOperatorIndex,
+        i::packed_int32_array_operator_index_const: GDExtensionInterfacePackedInt32ArrayOperatorIndexConst,
+        i::packed_int64_array_operator_index: GDExtensionInterfacePackedInt64ArrayOperatorIndex,
+        i::packed_int64_array_operator_index_const: GDExtensionInterfacePackedInt64ArrayOperatorIndexConst,
+        i::packed_float32_array_operator_index: GDExtensionInterfacePackedFloat32ArrayOperatorIndex,
+        i::packed_float32_array_operator_index_const: GDExtensionInterfacePackedFloat32ArrayOperatorIndexConst,
+        i::packed_float64_array_operator_index: GDExtensionInterfacePackedFloat64ArrayOperatorIndex,
+        i::packed_float64_array_operator_index_const: GDExtensionInterfacePackedFloat64ArrayOperatorIndexConst,
+        i::packed_string_array_operator_index: GDExtensionInterfacePackedStringArrayOperatorIndex,
+        i::packed_string_array_operator_index_const: GDExtensionInterfacePackedStringArrayOperatorIndexConst,
+        i::packed_vector2_array_operator_index: GDExtensionInterfacePackedVector2ArrayOperatorIndex,
+        i::packed_vector2_array_operator_index_const: GDExtensionInterfacePackedVector2ArrayOperatorIndexConst,
+        i::packed_vector3_array_operator_index: GDExtensionInterfacePackedVector3ArrayOperatorIndex,
+        i::packed_vector3_array_operator_index_const: GDExtensionInterfacePackedVector3ArrayOperatorIndexConst,
+        i::packed_color_array_operator_index: GDExtensionInterfacePackedColorArrayOperatorIndex,
+        i::packed_color_array_operator_index_const: GDExtensionInterfacePackedColorArrayOperatorIndexConst,
+        #[cfg(since_api = "4.3")]
+        i::packed_vector4_array_operator_index: GDExtensionInterfacePackedVector4ArrayOperatorIndex,
+        #[cfg(since_api = "4.3")]
+        i::packed_vector4_array_operator_index_const: GDExtensionInterfacePackedVector4ArrayOperatorIndexConst,
+    )
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Engine-level functions
+
+unsafe extern "C" fn get_godot_version(r_godot_version: *mut sys::GDExtensionGodotVersion) {
+    static VERSION_STRING: OnceLock<CString> = OnceLock::new();
+
+    let (major, minor, patch) = sys::GdextBuild::godot_static_version_triple();
+    let string = VERSION_STRING.get_or_init(|| {
+        let version = sys::GdextBuild::godot_static_version_string();
+        CString::new(format!("Godot Engine {version} (stub)")).unwrap()
+    });
+
+    // SAFETY: caller passes a valid pointer.
+    unsafe {
+        *r_godot_version = sys::GDExtensionGodotVersion {
+            major: major as u32,
+            minor: minor as u32,
+            patch: patch as u32,
+            string: string.as_ptr(),
+        };
+    }
+}
+
+unsafe extern "C" fn print_error(
+    description: *const c_char,
+    function: *const c_char,
+    file: *const c_char,
+    line: i32,
+    _editor_notify: sys::GDExtensionBool,
+) {
+    // SAFETY: caller passes null-terminated strings.
+    unsafe { print_message("ERROR", description, function, file, line) };
+}
+
+unsafe extern "C" fn print_warning(
+    description: *const c_char,
+    function: *const c_char,
+    file: *const c_char,
+    line: i32,
+    _editor_notify: sys::GDExtensionBool,
+) {
+    // SAFETY: caller passes null-terminated strings.
+    unsafe { print_message("WARNING", description, function, file, line) };
+}
+
+unsafe fn print_message(
+    level: &str,
+    description: *const c_char,
+    function: *const c_char,
+    file: *const c_char,
+    line: i32,
+) {
+    // SAFETY: caller passes null-terminated strings.
+    let [description, function, file] =
+        [description, function, file].map(|s| unsafe { CStr::from_ptr(s) }.to_string_lossy());
+
+    eprintln!("{level}: {description}\n   at: {function} ({file}:{line})");
+}
+
+/// Called by [`interface_fn!`][crate::interface_fn] for interface functions that the stub does not provide.
+#[doc(hidden)]
+pub fn unsupported_interface_fn(name: &str) -> ! {
+    unsupported(&format!("GDExtension interface function `{name}`"))
+}
+
+/// Reports an operation that the stub cannot emulate, and aborts.
+///
+/// Cannot panic, as all callers are `extern "C"` functions.
+fn unsupported(what: &str) -> ! {
+    eprintln!(
+        "godot-rust stub interface: {what} is not supported without a running Godot engine.\n\
+        Move this test to the integration tests (itest)."
+    );
+    std::process::abort()
+}
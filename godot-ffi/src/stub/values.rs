@@ -0,0 +1,1152 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Memory representation of builtin values in the stub interface.
+//!
+//! The layout only needs to fit into the opaque types that godot-rust allocates; it is never seen by Godot. Each value occupies the
+//! beginning of its opaque storage:
+//! - Math types, `bool`, `int`, `float`, `RID`: same bytes as in the engine (godot-rust reads those directly).
+//! - `String`, `StringName`, `NodePath`: pointer from `Rc::<Vec<char>>::into_raw()`, null if empty. Copies share the buffer, like
+//!   Godot's copy-on-write strings. This also gives `StringName::chars()` the engine's guarantee of a shared buffer.
+//! - `Array`, `Dictionary`: pointer from `Rc::<RefCell<..>>::into_raw()`. Copies are references to the same container.
+//! - Packed arrays: pointer from `Box::<PackedData>::into_raw()`, null if empty. Copies are deep.
+//! - `Variant`: pointer from `Box::<Value>::into_raw()`, null for nil.
+//! - `Object`, `Callable`, `Signal`: plain bytes without any semantics.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::{fmt, ptr};
+
+use crate as sys;
+
+pub(super) type VarType = sys::GDExtensionVariantType;
+
+/// Shorter names for the variant type constants.
+pub(super) mod ty {
+    use super::VarType;
+    use crate as sys;
+
+    pub const NIL: VarType = sys::GDEXTENSION_VARIANT_TYPE_NIL;
+    pub const BOOL: VarType = sys::GDEXTENSION_VARIANT_TYPE_BOOL;
+    pub const INT: VarType = sys::GDEXTENSION_VARIANT_TYPE_INT;
+    pub const FLOAT: VarType = sys::GDEXTENSION_VARIANT_TYPE_FLOAT;
+    pub const STRING: VarType = sys::GDEXTENSION_VARIANT_TYPE_STRING;
+    pub const VECTOR2: VarType = sys::GDEXTENSION_VARIANT_TYPE_VECTOR2;
+    pub const VECTOR2I: VarType = sys::GDEXTENSION_VARIANT_TYPE_VECTOR2I;
+    pub const RECT2: VarType = sys::GDEXTENSION_VARIANT_TYPE_RECT2;
+    pub const RECT2I: VarType = sys::GDEXTENSION_VARIANT_TYPE_RECT2I;
+    pub const VECTOR3: VarType = sys::GDEXTENSION_VARIANT_TYPE_VECTOR3;
+    pub const VECTOR3I: VarType = sys::GDEXTENSION_VARIANT_TYPE_VECTOR3I;
+    pub const TRANSFORM2D: VarType = sys::GDEXTENSION_VARIANT_TYPE_TRANSFORM2D;
+    pub const VECTOR4: VarType = sys::GDEXTENSION_VARIANT_TYPE_VECTOR4;
+    pub const VECTOR4I: VarType = sys::GDEXTENSION_VARIANT_TYPE_VECTOR4I;
+    pub const PLANE: VarType = sys::GDEXTENSION_VARIANT_TYPE_PLANE;
+    pub const QUATERNION: VarType = sys::GDEXTENSION_VARIANT_TYPE_QUATERNION;
+    pub const AABB: VarType = sys::GDEXTENSION_VARIANT_TYPE_AABB;
+    pub const BASIS: VarType = sys::GDEXTENSION_VARIANT_TYPE_BASIS;
+    pub const TRANSFORM3D: VarType = sys::GDEXTENSION_VARIANT_TYPE_TRANSFORM3D;
+    pub const PROJECTION: VarType = sys::GDEXTENSION_VARIANT_TYPE_PROJECTION;
+    pub const COLOR: VarType = sys::GDEXTENSION_VARIANT_TYPE_COLOR;
+    pub const STRING_NAME: VarType = sys::GDEXTENSION_VARIANT_TYPE_STRING_NAME;
+    pub const NODE_PATH: VarType = sys::GDEXTENSION_VARIANT_TYPE_NODE_PATH;
+    pub const RID: VarType = sys::GDEXTENSION_VARIANT_TYPE_RID;
+    pub const OBJECT: VarType = sys::GDEXTENSION_VARIANT_TYPE_OBJECT;
+    pub const CALLABLE: VarType = sys::GDEXTENSION_VARIANT_TYPE_CALLABLE;
+    pub const SIGNAL: VarType = sys::GDEXTENSION_VARIANT_TYPE_SIGNAL;
+    pub const DICTIONARY: VarType = sys::GDEXTENSION_VARIANT_TYPE_DICTIONARY;
+    pub const ARRAY: VarType = sys::GDEXTENSION_VARIANT_TYPE_ARRAY;
+    pub const PACKED_BYTE_ARRAY: VarType = sys::GDEXTENSION_VARIANT_TYPE_PACKED_BYTE_ARRAY;
+    pub const PACKED_INT32_ARRAY: VarType = sys::GDEXTENSION_VARIANT_TYPE_PACKED_INT32_ARRAY;
+    pub const PACKED_INT64_ARRAY: VarType = sys::GDEXTENSION_VARIANT_TYPE_PACKED_INT64_ARRAY;
+    pub const PACKED_FLOAT32_ARRAY: VarType = sys::GDEXTENSION_VARIANT_TYPE_PACKED_FLOAT32_ARRAY;
+    pub const PACKED_FLOAT64_ARRAY: VarType = sys::GDEXTENSION_VARIANT_TYPE_PACKED_FLOAT64_ARRAY;
+    pub const PACKED_STRING_ARRAY: VarType = sys::GDEXTENSION_VARIANT_TYPE_PACKED_STRING_ARRAY;
+    pub const PACKED_VECTOR2_ARRAY: VarType = sys::GDEXTENSION_VARIANT_TYPE_PACKED_VECTOR2_ARRAY;
+    pub const PACKED_VECTOR3_ARRAY: VarType = sys::GDEXTENSION_VARIANT_TYPE_PACKED_VECTOR3_ARRAY;
+    pub const PACKED_COLOR_ARRAY: VarType = sys::GDEXTENSION_VARIANT_TYPE_PACKED_COLOR_ARRAY;
+    #[cfg(since_api = "4.3")]
+    pub const PACKED_VECTOR4_ARRAY: VarType = sys::GDEXTENSION_VARIANT_TYPE_PACKED_VECTOR4_ARRAY;
+}
+
+/// Instantiates `$func::<TYPE>` for the runtime variant type `$ty`, cast to the function pointer type `$Fn`.
+///
+/// Evaluates to `Option<$Fn>`, which is `None` for unknown types.
+macro_rules! per_type {
+    ($ty:expr, $func:ident as $Fn:ty) => {
+        per_type!(@arms $ty, $func, $Fn; NIL BOOL INT FLOAT STRING VECTOR2 VECTOR2I RECT2 RECT2I VECTOR3 VECTOR3I TRANSFORM2D
+            VECTOR4 VECTOR4I PLANE QUATERNION AABB BASIS TRANSFORM3D PROJECTION COLOR STRING_NAME NODE_PATH RID OBJECT CALLABLE SIGNAL
+            DICTIONARY ARRAY PACKED_BYTE_ARRAY PACKED_INT32_ARRAY PACKED_INT64_ARRAY PACKED_FLOAT32_ARRAY PACKED_FLOAT64_ARRAY
+            PACKED_STRING_ARRAY PACKED_VECTOR2_ARRAY PACKED_VECTOR3_ARRAY PACKED_COLOR_ARRAY)
+    };
+    (@arms $ty:expr, $func:ident, $Fn:ty; $($T:ident)*) => {
+        match $ty {
+            $(
+                $crate::stub::values::ty::$T => Some($func::<{ $crate::stub::values::ty::$T }> as $Fn),
+            )*
+            #[cfg(since_api = "4.3")]
+            $crate::stub::values::ty::PACKED_VECTOR4_ARRAY => {
+                Some($func::<{ $crate::stub::values::ty::PACKED_VECTOR4_ARRAY }> as $Fn)
+            }
+            _ => None,
+        }
+    };
+}
+
+pub(super) use per_type;
+
+pub(super) fn type_name(ty: VarType) -> &'static str {
+    match ty {
+        ty::NIL => "Nil",
+        ty::BOOL => "bool",
+        ty::INT => "int",
+        ty::FLOAT => "float",
+        ty::STRING => "String",
+        ty::VECTOR2 => "Vector2",
+        ty::VECTOR2I => "Vector2i",
+        ty::RECT2 => "Rect2",
+        ty::RECT2I => "Rect2i",
+        ty::VECTOR3 => "Vector3",
+        ty::VECTOR3I => "Vector3i",
+        ty::TRANSFORM2D => "Transform2D",
+        ty::VECTOR4 => "Vector4",
+        ty::VECTOR4I => "Vector4i",
+        ty::PLANE => "Plane",
+        ty::QUATERNION => "Quaternion",
+        ty::AABB => "AABB",
+        ty::BASIS => "Basis",
+        ty::TRANSFORM3D => "Transform3D",
+        ty::PROJECTION => "Projection",
+        ty::COLOR => "Color",
+        ty::STRING_NAME => "StringName",
+        ty::NODE_PATH => "NodePath",
+        ty::RID => "RID",
+        ty::OBJECT => "Object",
+        ty::CALLABLE => "Callable",
+        ty::SIGNAL => "Signal",
+        ty::DICTIONARY => "Dictionary",
+        ty::ARRAY => "Array",
+        ty::PACKED_BYTE_ARRAY => "PackedByteArray",
+        ty::PACKED_INT32_ARRAY => "PackedInt32Array",
+        ty::PACKED_INT64_ARRAY => "PackedInt64Array",
+        ty::PACKED_FLOAT32_ARRAY => "PackedFloat32Array",
+        ty::PACKED_FLOAT64_ARRAY => "PackedFloat64Array",
+        ty::PACKED_STRING_ARRAY => "PackedStringArray",
+        ty::PACKED_VECTOR2_ARRAY => "PackedVector2Array",
+        ty::PACKED_VECTOR3_ARRAY => "PackedVector3Array",
+        ty::PACKED_COLOR_ARRAY => "PackedColorArray",
+        #[cfg(since_api = "4.3")]
+        ty::PACKED_VECTOR4_ARRAY => "PackedVector4Array",
+        _ => "<unknown type>",
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Type classification
+
+/// How values of a variant type are stored.
+#[derive(Copy, Clone)]
+pub(super) enum Kind {
+    Nil,
+    /// Plain bytes of the given size, copied bitwise.
+    Pod(usize),
+    /// `String`, `StringName` or `NodePath`.
+    Str,
+    Array,
+    Dictionary,
+    Packed(Elem),
+}
+
+/// Element of a packed array.
+#[derive(Copy, Clone)]
+pub(super) struct Elem {
+    pub size: usize,
+    /// Elements are strings, which need reference counting. Otherwise, they are plain bytes.
+    pub is_str: bool,
+}
+
+impl Elem {
+    const fn pod(size: usize) -> Self {
+        Self {
+            size,
+            is_str: false,
+        }
+    }
+
+    const STR: Self = Self {
+        size: size_of::<usize>(),
+        is_str: true,
+    };
+}
+
+/// Size of `real`, depending on whether Godot uses single or double precision.
+pub(super) fn real_size() -> usize {
+    size_of::<sys::types::OpaqueVector2>() / 2
+}
+
+pub(super) fn kind(ty: VarType) -> Kind {
+    let r = real_size();
+
+    match ty {
+        ty::BOOL => Kind::Pod(1),
+        ty::INT | ty::FLOAT | ty::RID => Kind::Pod(8),
+        ty::VECTOR2 => Kind::Pod(2 * r),
+        ty::VECTOR2I => Kind::Pod(8),
+        ty::VECTOR3 => Kind::Pod(3 * r),
+        ty::VECTOR3I => Kind::Pod(12),
+        ty::RECT2 | ty::VECTOR4 | ty::PLANE | ty::QUATERNION => Kind::Pod(4 * r),
+        ty::RECT2I | ty::VECTOR4I | ty::COLOR => Kind::Pod(16),
+        ty::TRANSFORM2D | ty::AABB => Kind::Pod(6 * r),
+        ty::BASIS => Kind::Pod(9 * r),
+        ty::TRANSFORM3D => Kind::Pod(12 * r),
+        ty::PROJECTION => Kind::Pod(16 * r),
+        ty::OBJECT => Kind::Pod(size_of::<usize>()),
+        ty::CALLABLE => Kind::Pod(size_of::<sys::types::OpaqueCallable>()),
+        ty::SIGNAL => Kind::Pod(size_of::<sys::types::OpaqueSignal>()),
+        ty::STRING | ty::STRING_NAME | ty::NODE_PATH => Kind::Str,
+        ty::ARRAY => Kind::Array,
+        ty::DICTIONARY => Kind::Dictionary,
+        ty::PACKED_BYTE_ARRAY => Kind::Packed(Elem::pod(1)),
+        ty::PACKED_INT32_ARRAY | ty::PACKED_FLOAT32_ARRAY => Kind::Packed(Elem::pod(4)),
+        ty::PACKED_INT64_ARRAY | ty::PACKED_FLOAT64_ARRAY => Kind::Packed(Elem::pod(8)),
+        ty::PACKED_STRING_ARRAY => Kind::Packed(Elem::STR),
+        ty::PACKED_VECTOR2_ARRAY => Kind::Packed(Elem::pod(2 * r)),
+        ty::PACKED_VECTOR3_ARRAY => Kind::Packed(Elem::pod(3 * r)),
+        ty::PACKED_COLOR_ARRAY => Kind::Packed(Elem::pod(16)),
+        #[cfg(since_api = "4.3")]
+        ty::PACKED_VECTOR4_ARRAY => Kind::Packed(Elem::pod(4 * r)),
+        _ => Kind::Nil,
+    }
+}
+
+/// Variant type of the elements of a packed array, as seen through `Variant` and ptrcall arguments.
+pub(super) fn packed_elem_type(packed_ty: VarType) -> VarType {
+    match packed_ty {
+        ty::PACKED_BYTE_ARRAY | ty::PACKED_INT32_ARRAY | ty::PACKED_INT64_ARRAY => ty::INT,
+        ty::PACKED_FLOAT32_ARRAY | ty::PACKED_FLOAT64_ARRAY => ty::FLOAT,
+        ty::PACKED_STRING_ARRAY => ty::STRING,
+        ty::PACKED_VECTOR2_ARRAY => ty::VECTOR2,
+        ty::PACKED_VECTOR3_ARRAY => ty::VECTOR3,
+        ty::PACKED_COLOR_ARRAY => ty::COLOR,
+        #[cfg(since_api = "4.3")]
+        ty::PACKED_VECTOR4_ARRAY => ty::VECTOR4,
+        _ => ty::NIL,
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Raw slot access
+
+pub(super) unsafe fn read<T: Copy>(src: *const u8) -> T {
+    // SAFETY: caller guarantees `src` points to a `T`; alignment is not required.
+    unsafe { src.cast::<T>().read_unaligned() }
+}
+
+pub(super) unsafe fn write<T>(dst: *mut u8, value: T) {
+    // SAFETY: caller guarantees `dst` has space for a `T`; alignment is not required.
+    unsafe { dst.cast::<T>().write_unaligned(value) }
+}
+
+fn read_real(src: *const u8, index: usize) -> f64 {
+    // SAFETY: callers only pass pointers to math types with at least `index + 1` reals.
+    unsafe {
+        if real_size() == 8 {
+            read::<f64>(src.add(index * 8))
+        } else {
+            read::<f32>(src.add(index * 4)) as f64
+        }
+    }
+}
+
+fn write_real(dst: *mut u8, index: usize, value: f64) {
+    // SAFETY: see `read_real()`.
+    unsafe {
+        if real_size() == 8 {
+            write::<f64>(dst.add(index * 8), value)
+        } else {
+            write::<f32>(dst.add(index * 4), value as f32)
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Strings
+
+type StrBuf = Vec<char>;
+
+/// Characters of a `String`, `StringName` or `NodePath` slot.
+pub(super) unsafe fn str_chars<'a>(slot: *const u8) -> &'a [char] {
+    // SAFETY: caller guarantees a valid string slot; the buffer lives as long as the slot is not modified.
+    unsafe {
+        let buf = read::<*const StrBuf>(slot);
+        if buf.is_null() {
+            &[]
+        } else {
+            &*buf
+        }
+    }
+}
+
+/// Initializes an uninitialized string slot.
+pub(super) unsafe fn str_init(slot: *mut u8, chars: Vec<char>) {
+    let buf = if chars.is_empty() {
+        ptr::null()
+    } else {
+        Rc::into_raw(Rc::new(chars))
+    };
+
+    // SAFETY: caller guarantees a writable slot.
+    unsafe { write(slot, buf) };
+}
+
+/// Replaces the content of an initialized string slot.
+pub(super) unsafe fn str_assign(slot: *mut u8, chars: Vec<char>) {
+    // SAFETY: caller guarantees an initialized slot.
+    unsafe {
+        destroy(ty::STRING, slot);
+        str_init(slot, chars);
+    }
+}
+
+/// Mutable access to the characters of a string slot, un-sharing the buffer if necessary.
+pub(super) unsafe fn str_make_mut<'a>(slot: *mut u8) -> &'a mut StrBuf {
+    // SAFETY: caller guarantees an initialized slot.
+    unsafe {
+        let buf = read::<*const StrBuf>(slot);
+        let mut rc = if buf.is_null() {
+            Rc::new(StrBuf::new())
+        } else {
+            Rc::from_raw(buf)
+        };
+
+        let chars: *mut StrBuf = Rc::make_mut(&mut rc);
+        write(slot, Rc::into_raw(rc));
+        &mut *chars
+    }
+}
+
+pub(super) fn str_from_chars(chars: &[char]) -> String {
+    chars.iter().collect()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Variants
+
+/// Payload of a non-nil variant.
+pub(super) struct Value {
+    pub ty: VarType,
+    /// Large enough for `Projection` in double precision.
+    pub data: [u64; 16],
+}
+
+/// Returns the value of the variant at `var`, or `None` for nil.
+pub(super) unsafe fn var_value<'a>(var: *const u8) -> Option<&'a mut Value> {
+    // SAFETY: caller guarantees an initialized variant.
+    unsafe { read::<*mut Value>(var).as_mut() }
+}
+
+pub(super) unsafe fn var_type(var: *const u8) -> VarType {
+    // SAFETY: forwarded.
+    unsafe { var_value(var) }.map_or(ty::NIL, |value| value.ty)
+}
+
+/// Pointer to the typed data inside a variant, or null for nil.
+pub(super) unsafe fn var_data(var: *const u8) -> *mut u8 {
+    // SAFETY: forwarded.
+    match unsafe { var_value(var) } {
+        Some(value) => value.data.as_mut_ptr().cast(),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Initializes the uninitialized variant at `dst` with type `ty`; `init` receives the uninitialized data pointer.
+pub(super) unsafe fn var_init_with(dst: *mut u8, ty: VarType, init: impl FnOnce(*mut u8)) {
+    let value = if ty == ty::NIL {
+        ptr::null_mut()
+    } else {
+        let mut value = Box::new(Value { ty, data: [0; 16] });
+        init(value.data.as_mut_ptr().cast());
+        Box::into_raw(value)
+    };
+
+    // SAFETY: caller guarantees a writable variant slot.
+    unsafe { write(dst, value) };
+}
+
+pub(super) unsafe fn var_init_nil(dst: *mut u8) {
+    // SAFETY: forwarded.
+    unsafe { write(dst, ptr::null_mut::<Value>()) };
+}
+
+pub(super) unsafe fn var_init_copy(dst: *mut u8, src: *const u8) {
+    // SAFETY: caller guarantees valid pointers.
+    unsafe {
+        let ty = var_type(src);
+        let src_data = var_data(src);
+        var_init_with(dst, ty, |data| construct_copy(ty, data, src_data));
+    }
+}
+
+pub(super) unsafe fn var_destroy(var: *mut u8) {
+    // SAFETY: caller guarantees an initialized variant, which is not used afterward.
+    unsafe {
+        let value = read::<*mut Value>(var);
+        if !value.is_null() {
+            let mut value = Box::from_raw(value);
+            destroy(value.ty, value.data.as_mut_ptr().cast());
+        }
+    }
+}
+
+/// Replaces the initialized variant at `dst` with a copy of `src`.
+pub(super) unsafe fn var_assign(dst: *mut u8, src: *const u8) {
+    // SAFETY: caller guarantees valid pointers. Copy first, in case `src` is owned by `dst`.
+    unsafe {
+        let copy = VariantBox::copy_of(src);
+        var_destroy(dst);
+        copy.move_into(dst);
+    }
+}
+
+pub(super) unsafe fn var_equal(a: *const u8, b: *const u8) -> bool {
+    // SAFETY: caller guarantees valid pointers.
+    unsafe {
+        let ty = var_type(a);
+        ty == var_type(b) && values_equal(ty, var_data(a), var_data(b))
+    }
+}
+
+/// Owned variant in stub representation, with the size of an engine variant. Used as element storage in containers.
+#[repr(transparent)]
+pub(super) struct VariantBox(sys::types::OpaqueVariant);
+
+impl VariantBox {
+    pub fn nil() -> Self {
+        // SAFETY: all-zero is the nil variant, and a valid bit pattern for the opaque type.
+        unsafe { std::mem::zeroed() }
+    }
+
+    pub unsafe fn copy_of(src: *const u8) -> Self {
+        let mut result = Self::nil();
+
+        // SAFETY: `result` is nil, so it can be overwritten without destruction.
+        unsafe { var_init_copy(result.as_mut_ptr(), src) };
+        result
+    }
+
+    pub fn with(ty: VarType, init: impl FnOnce(*mut u8)) -> Self {
+        let mut result = Self::nil();
+
+        // SAFETY: see `copy_of()`.
+        unsafe { var_init_with(result.as_mut_ptr(), ty, init) };
+        result
+    }
+
+    pub fn as_ptr(&self) -> *const u8 {
+        ptr::from_ref(self).cast()
+    }
+
+    pub fn as_mut_ptr(&mut self) -> *mut u8 {
+        ptr::from_mut(self).cast()
+    }
+
+    pub fn ty(&self) -> VarType {
+        // SAFETY: always initialized.
+        unsafe { var_type(self.as_ptr()) }
+    }
+
+    pub fn data(&self) -> *mut u8 {
+        // SAFETY: always initialized.
+        unsafe { var_data(self.as_ptr()) }
+    }
+
+    /// Moves the variant into the uninitialized slot `dst`.
+    pub unsafe fn move_into(self, dst: *mut u8) {
+        let this = std::mem::ManuallyDrop::new(self);
+
+        // SAFETY: the variant representation is position-independent; ownership passes to `dst`.
+        unsafe { ptr::copy_nonoverlapping(this.as_ptr(), dst, size_of::<Self>()) };
+    }
+}
+
+impl Clone for VariantBox {
+    fn clone(&self) -> Self {
+        // SAFETY: always initialized.
+        unsafe { Self::copy_of(self.as_ptr()) }
+    }
+}
+
+impl PartialEq for VariantBox {
+    fn eq(&self, other: &Self) -> bool {
+        // SAFETY: always initialized.
+        unsafe { var_equal(self.as_ptr(), other.as_ptr()) }
+    }
+}
+
+impl Drop for VariantBox {
+    fn drop(&mut self) {
+        // SAFETY: always initialized, not used afterward.
+        unsafe { var_destroy(self.as_mut_ptr()) };
+    }
+}
+
+impl fmt::Display for VariantBox {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&stringify(self.ty(), self.data(), false))
+    }
+}
+
+impl Default for VariantBox {
+    fn default() -> Self {
+        Self::nil()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Containers
+
+#[derive(Default)]
+pub(super) struct ArrayData {
+    pub elems: Vec<VariantBox>,
+    /// Element type for typed arrays, `NIL` otherwise.
+    pub elem_type: VarType,
+    pub class_name: Vec<char>,
+    pub script: VariantBox,
+    pub read_only: bool,
+}
+
+impl ArrayData {
+    /// Empty array with the same element type.
+    pub fn same_typed(&self) -> Self {
+        Self {
+            elem_type: self.elem_type,
+            class_name: self.class_name.clone(),
+            script: self.script.clone(),
+            ..Self::default()
+        }
+    }
+
+    pub fn find(&self, value: *const u8, from: usize) -> Option<usize> {
+        // SAFETY: caller passes an initialized variant.
+        (from..self.elems.len()).find(|&i| unsafe { var_equal(self.elems[i].as_ptr(), value) })
+    }
+}
+
+#[derive(Default)]
+pub(super) struct DictData {
+    /// Boxed, so pointers to values remain valid when inserting other entries.
+    pub entries: Vec<Box<(VariantBox, VariantBox)>>,
+    pub read_only: bool,
+}
+
+impl DictData {
+    pub fn find(&self, key: *const u8) -> Option<usize> {
+        // SAFETY: caller passes an initialized variant.
+        self.entries
+            .iter()
+            .position(|entry| unsafe { var_equal(entry.0.as_ptr(), key) })
+    }
+
+    /// Returns the entry for `key`, inserting a nil value if absent.
+    pub fn entry(&mut self, key: *const u8) -> &mut (VariantBox, VariantBox) {
+        let index = match self.find(key) {
+            Some(index) => index,
+            None => {
+                // SAFETY: caller passes an initialized variant.
+                let key = unsafe { VariantBox::copy_of(key) };
+                self.entries.push(Box::new((key, VariantBox::nil())));
+                self.entries.len() - 1
+            }
+        };
+
+        &mut self.entries[index]
+    }
+}
+
+type ArrayRef = RefCell<ArrayData>;
+type DictRef = RefCell<DictData>;
+
+pub(super) unsafe fn array_data<'a>(slot: *const u8) -> &'a ArrayRef {
+    // SAFETY: caller guarantees an initialized array slot, which keeps the array alive.
+    unsafe { &*read::<*const ArrayRef>(slot) }
+}
+
+pub(super) unsafe fn dict_data<'a>(slot: *const u8) -> &'a DictRef {
+    // SAFETY: caller guarantees an initialized dictionary slot, which keeps the dictionary alive.
+    unsafe { &*read::<*const DictRef>(slot) }
+}
+
+/// Initializes an uninitialized array slot with a new array.
+pub(super) unsafe fn array_init(slot: *mut u8, array: ArrayData) {
+    // SAFETY: caller guarantees a writable slot.
+    unsafe { write(slot, Rc::into_raw(Rc::new(RefCell::new(array)))) };
+}
+
+pub(super) unsafe fn dict_init(slot: *mut u8, dict: DictData) {
+    // SAFETY: caller guarantees a writable slot.
+    unsafe { write(slot, Rc::into_raw(Rc::new(RefCell::new(dict)))) };
+}
+
+/// Elements of a packed array, stored contiguously with the element size as stride.
+#[derive(Default)]
+pub(super) struct PackedData {
+    /// `u64` storage guarantees alignment for all element types.
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl PackedData {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn base_ptr(&self) -> *mut u8 {
+        self.words.as_ptr().cast_mut().cast()
+    }
+
+    pub fn elem_ptr(&self, elem: Elem, index: usize) -> *mut u8 {
+        debug_assert!(index < self.len);
+
+        // SAFETY: index is in bounds.
+        unsafe { self.base_ptr().add(index * elem.size) }
+    }
+
+    /// Resizes the array; new elements are zeroed, which is a valid (empty) value for all element types.
+    pub unsafe fn resize(&mut self, elem: Elem, new_len: usize) {
+        if new_len < self.len {
+            // SAFETY: forwarded.
+            unsafe { self.release_range(elem, new_len, self.len) };
+        }
+
+        let word_count = (new_len * elem.size).div_ceil(8);
+        if self.words.len() < word_count {
+            self.words.resize(word_count, 0);
+        }
+
+        self.len = new_len;
+    }
+
+    /// Inserts an owned element (consumed bytewise) at `index`.
+    pub unsafe fn insert(&mut self, elem: Elem, index: usize, src: *const u8) {
+        let old_len = self.len;
+
+        // SAFETY: the new element at the end is zeroed, so shifting overwrites nothing that needs destruction.
+        unsafe {
+            self.resize(elem, old_len + 1);
+
+            let at = self.base_ptr().add(index * elem.size);
+            ptr::copy(at, at.add(elem.size), (old_len - index) * elem.size);
+            ptr::copy_nonoverlapping(src, at, elem.size);
+        }
+    }
+
+    pub unsafe fn remove(&mut self, elem: Elem, index: usize) {
+        // SAFETY: caller guarantees index < len.
+        unsafe {
+            self.release_range(elem, index, index + 1);
+
+            let at = self.base_ptr().add(index * elem.size);
+            ptr::copy(at.add(elem.size), at, (self.len - index - 1) * elem.size);
+            ptr::write_bytes(
+                self.base_ptr().add((self.len - 1) * elem.size),
+                0,
+                elem.size,
+            );
+        }
+
+        self.len -= 1;
+    }
+
+    pub unsafe fn deep_clone(&self, elem: Elem) -> Self {
+        let copy = Self {
+            words: self.words.clone(),
+            len: self.len,
+        };
+
+        if elem.is_str {
+            for i in 0..copy.len {
+                // SAFETY: every element is an initialized string slot; the copy holds another reference.
+                unsafe {
+                    construct_copy(ty::STRING, copy.elem_ptr(elem, i), self.elem_ptr(elem, i))
+                };
+            }
+        }
+
+        copy
+    }
+
+    /// Destroys the elements in `from..to` and zeroes their storage.
+    pub unsafe fn release_range(&mut self, elem: Elem, from: usize, to: usize) {
+        for i in from..to {
+            let at = self.elem_ptr(elem, i);
+            if elem.is_str {
+                // SAFETY: element is an initialized string slot.
+                unsafe { destroy(ty::STRING, at) };
+            }
+
+            // SAFETY: in bounds.
+            unsafe { ptr::write_bytes(at, 0, elem.size) };
+        }
+    }
+}
+
+/// Packed data of a slot, or `None` if the array is empty and has never been allocated.
+pub(super) unsafe fn packed_data<'a>(slot: *const u8) -> Option<&'a PackedData> {
+    // SAFETY: caller guarantees an initialized packed-array slot.
+    unsafe { read::<*const PackedData>(slot).as_ref() }
+}
+
+/// Mutable packed data of a slot, allocating it if necessary.
+pub(super) unsafe fn packed_data_mut<'a>(slot: *mut u8) -> &'a mut PackedData {
+    // SAFETY: caller guarantees an initialized packed-array slot with exclusive access.
+    unsafe {
+        let mut data = read::<*mut PackedData>(slot);
+        if data.is_null() {
+            data = Box::into_raw(Box::default());
+            write(slot, data);
+        }
+
+        &mut *data
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Generic operations on typed slots
+
+/// Default-constructs a value of type `ty` in the uninitialized slot `dst`.
+pub(super) unsafe fn construct_default(ty: VarType, dst: *mut u8) {
+    // SAFETY: caller guarantees a writable slot of the right size.
+    unsafe {
+        match kind(ty) {
+            Kind::Nil => {}
+            Kind::Pod(size) => {
+                ptr::write_bytes(dst, 0, size);
+                write_identity(ty, dst);
+            }
+            Kind::Str | Kind::Packed(_) => write(dst, ptr::null::<u8>()),
+            Kind::Array => array_init(dst, ArrayData::default()),
+            Kind::Dictionary => dict_init(dst, DictData::default()),
+        }
+    }
+}
+
+/// Sets the components that are 1 in the engine's default value.
+fn write_identity(ty: VarType, dst: *mut u8) {
+    let ones: &[usize] = match ty {
+        ty::QUATERNION => &[3],
+        ty::TRANSFORM2D => &[0, 3],
+        ty::BASIS | ty::TRANSFORM3D => &[0, 4, 8],
+        ty::PROJECTION => &[0, 5, 10, 15],
+        ty::COLOR => {
+            // Color is always single-precision; alpha is 1.
+            // SAFETY: Color has 4 f32 components.
+            unsafe { write::<f32>(dst.add(12), 1.0) };
+            return;
+        }
+        _ => &[],
+    };
+
+    for &index in ones {
+        write_real(dst, index, 1.0);
+    }
+}
+
+/// Copy-constructs a value of type `ty` from `src` into the uninitialized slot `dst`.
+pub(super) unsafe fn construct_copy(ty: VarType, dst: *mut u8, src: *const u8) {
+    // SAFETY: caller guarantees valid slots of the right type.
+    unsafe {
+        match kind(ty) {
+            Kind::Nil => {}
+            Kind::Pod(size) => ptr::copy_nonoverlapping(src, dst, size),
+            Kind::Str => {
+                let buf = read::<*const StrBuf>(src);
+                if !buf.is_null() {
+                    Rc::increment_strong_count(buf);
+                }
+                write(dst, buf);
+            }
+            Kind::Array => {
+                let array = read::<*const ArrayRef>(src);
+                Rc::increment_strong_count(array);
+                write(dst, array);
+            }
+            Kind::Dictionary => {
+                let dict = read::<*const DictRef>(src);
+                Rc::increment_strong_count(dict);
+                write(dst, dict);
+            }
+            Kind::Packed(elem) => {
+                let copy = match packed_data(src) {
+                    Some(data) => Box::into_raw(Box::new(data.deep_clone(elem))),
+                    None => ptr::null_mut(),
+                };
+                write(dst, copy);
+            }
+        }
+    }
+}
+
+/// Destroys the value of type `ty` in the slot `ptr`.
+pub(super) unsafe fn destroy(ty: VarType, ptr: *mut u8) {
+    // SAFETY: caller guarantees an initialized slot, which is not used afterward.
+    unsafe {
+        match kind(ty) {
+            Kind::Nil | Kind::Pod(_) => {}
+            Kind::Str => {
+                let buf = read::<*const StrBuf>(ptr);
+                if !buf.is_null() {
+                    Rc::decrement_strong_count(buf);
+                }
+            }
+            Kind::Array => drop(Rc::from_raw(read::<*const ArrayRef>(ptr))),
+            Kind::Dictionary => drop(Rc::from_raw(read::<*const DictRef>(ptr))),
+            Kind::Packed(elem) => {
+                let data = read::<*mut PackedData>(ptr);
+                if !data.is_null() {
+                    let mut data = Box::from_raw(data);
+                    let len = data.len();
+                    data.release_range(elem, 0, len);
+                }
+            }
+        }
+    }
+}
+
+/// Deep equality, like Godot's `==` for same-typed operands.
+pub(super) unsafe fn values_equal(ty: VarType, a: *const u8, b: *const u8) -> bool {
+    // SAFETY: caller guarantees initialized slots of type `ty`.
+    unsafe {
+        match kind(ty) {
+            Kind::Nil => true,
+            Kind::Pod(_) if ty == ty::FLOAT => read::<f64>(a) == read::<f64>(b),
+            Kind::Pod(size) => {
+                std::slice::from_raw_parts(a, size) == std::slice::from_raw_parts(b, size)
+            }
+            Kind::Str => str_chars(a) == str_chars(b),
+            Kind::Array => {
+                let (a, b) = (array_data(a), array_data(b));
+                ptr::eq(a, b) || a.borrow().elems == b.borrow().elems
+            }
+            Kind::Dictionary => {
+                let (a, b) = (dict_data(a), dict_data(b));
+                if ptr::eq(a, b) {
+                    return true;
+                }
+
+                let (a, b) = (a.borrow(), b.borrow());
+                a.entries.len() == b.entries.len()
+                    && a.entries.iter().all(|entry| {
+                        b.find(entry.0.as_ptr())
+                            .is_some_and(|i| b.entries[i].1 == entry.1)
+                    })
+            }
+            Kind::Packed(elem) => {
+                let len_a = packed_data(a).map_or(0, |d| d.len());
+                let len_b = packed_data(b).map_or(0, |d| d.len());
+                len_a == len_b
+                    && (0..len_a).all(|i| {
+                        let (ea, eb) = (
+                            packed_data(a).unwrap().elem_ptr(elem, i),
+                            packed_data(b).unwrap().elem_ptr(elem, i),
+                        );
+                        if elem.is_str {
+                            str_chars(ea) == str_chars(eb)
+                        } else {
+                            std::slice::from_raw_parts(ea, elem.size)
+                                == std::slice::from_raw_parts(eb, elem.size)
+                        }
+                    })
+            }
+        }
+    }
+}
+
+/// Ordering for types that support Godot's `<` operator; `None` otherwise.
+pub(super) unsafe fn values_less(ty: VarType, a: *const u8, b: *const u8) -> Option<bool> {
+    // SAFETY: caller guarantees initialized slots of type `ty`.
+    unsafe {
+        match ty {
+            ty::BOOL => Some(!read::<bool>(a) & read::<bool>(b)),
+            ty::INT | ty::RID => Some(read::<i64>(a) < read::<i64>(b)),
+            ty::FLOAT => Some(read::<f64>(a) < read::<f64>(b)),
+            ty::STRING | ty::STRING_NAME | ty::NODE_PATH => Some(str_chars(a) < str_chars(b)),
+            ty::ARRAY => {
+                let (a, b) = (array_data(a).borrow(), array_data(b).borrow());
+                for (ea, eb) in a.elems.iter().zip(b.elems.iter()) {
+                    if ea != eb {
+                        return var_less(ea, eb);
+                    }
+                }
+                Some(a.elems.len() < b.elems.len())
+            }
+            _ => None,
+        }
+    }
+}
+
+/// `<` on variants, including mixed `int`/`float` operands.
+pub(super) fn var_less(a: &VariantBox, b: &VariantBox) -> Option<bool> {
+    if let (Some(a), Some(b)) = (as_number(a), as_number(b)) {
+        return Some(a < b);
+    }
+
+    // SAFETY: data pointers belong to the variants.
+    (a.ty() == b.ty())
+        .then(|| unsafe { values_less(a.ty(), a.data(), b.data()) })
+        .flatten()
+}
+
+/// Numeric value of an `int` or `float` variant.
+pub(super) fn as_number(var: &VariantBox) -> Option<f64> {
+    // SAFETY: type is checked.
+    unsafe {
+        match var.ty() {
+            ty::INT => Some(read::<i64>(var.data()) as f64),
+            ty::FLOAT => Some(read::<f64>(var.data())),
+            _ => None,
+        }
+    }
+}
+
+/// Truthiness, like GDScript's `if value:`.
+pub(super) unsafe fn booleanize(ty: VarType, data: *const u8) -> bool {
+    // SAFETY: caller guarantees an initialized slot of type `ty`.
+    unsafe {
+        match kind(ty) {
+            Kind::Nil => false,
+            Kind::Pod(_) if ty == ty::FLOAT => read::<f64>(data) != 0.0,
+            Kind::Pod(size) => std::slice::from_raw_parts(data, size)
+                .iter()
+                .any(|&b| b != 0),
+            Kind::Str => !str_chars(data).is_empty(),
+            Kind::Array => !array_data(data).borrow().elems.is_empty(),
+            Kind::Dictionary => !dict_data(data).borrow().entries.is_empty(),
+            Kind::Packed(_) => packed_data(data).is_some_and(|d| d.len() > 0),
+        }
+    }
+}
+
+/// 32-bit hash, consistent with [`values_equal()`].
+pub(super) unsafe fn hash_value(ty: VarType, data: *const u8) -> u32 {
+    let mut hasher = Fnv::new();
+
+    // SAFETY: forwarded.
+    unsafe { hasher.add_value(ty, data) };
+    hasher.finish()
+}
+
+/// FNV-1a, which gives hashes that are stable across runs.
+struct Fnv(u32);
+
+impl Fnv {
+    fn new() -> Self {
+        Self(0x811c_9dc5)
+    }
+
+    fn add_bytes(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u32::from(byte);
+            self.0 = self.0.wrapping_mul(0x0100_0193);
+        }
+    }
+
+    unsafe fn add_value(&mut self, ty: VarType, data: *const u8) {
+        self.add_bytes(&(ty as u32).to_le_bytes());
+
+        // SAFETY: caller guarantees an initialized slot of type `ty`.
+        unsafe {
+            match kind(ty) {
+                Kind::Nil => {}
+                Kind::Pod(size) => self.add_bytes(std::slice::from_raw_parts(data, size)),
+                Kind::Str => self.add_chars(str_chars(data)),
+                Kind::Array => {
+                    for elem in array_data(data).borrow().elems.iter() {
+                        self.add_value(elem.ty(), elem.data());
+                    }
+                }
+                Kind::Dictionary => {
+                    // Order-independent, like equality.
+                    let mut combined = 0u32;
+                    for entry in dict_data(data).borrow().entries.iter() {
+                        let mut entry_hasher = Fnv::new();
+                        entry_hasher.add_value(entry.0.ty(), entry.0.data());
+                        entry_hasher.add_value(entry.1.ty(), entry.1.data());
+                        combined = combined.wrapping_add(entry_hasher.finish());
+                    }
+                    self.add_bytes(&combined.to_le_bytes());
+                }
+                Kind::Packed(elem) => {
+                    if let Some(packed) = packed_data(data) {
+                        for i in 0..packed.len() {
+                            let at = packed.elem_ptr(elem, i);
+                            if elem.is_str {
+                                self.add_chars(str_chars(at));
+                            } else {
+                                self.add_bytes(std::slice::from_raw_parts(at, elem.size));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn add_chars(&mut self, chars: &[char]) {
+        for &c in chars {
+            self.add_bytes(&u32::from(c).to_le_bytes());
+        }
+    }
+
+    fn finish(&self) -> u32 {
+        self.0
+    }
+}
+
+/// String representation, following Godot's `str()` format. Strings nested in containers are `quoted`.
+pub(super) fn stringify(ty: VarType, data: *const u8, quoted: bool) -> String {
+    let reals = |count: usize| -> String {
+        let parts: Vec<String> = (0..count).map(|i| fmt_float(read_real(data, i))).collect();
+        format!("({})", parts.join(", "))
+    };
+
+    // SAFETY: `data` is an initialized slot of type `ty`.
+    unsafe {
+        let ints = |count: usize| -> String {
+            let parts: Vec<String> = (0..count)
+                .map(|i| read::<i32>(data.add(i * 4)).to_string())
+                .collect();
+            format!("({})", parts.join(", "))
+        };
+
+        match ty {
+            ty::NIL => "<null>".to_string(),
+            ty::BOOL => read::<bool>(data).to_string(),
+            ty::INT => read::<i64>(data).to_string(),
+            ty::FLOAT => fmt_float(read::<f64>(data)),
+            ty::STRING | ty::STRING_NAME | ty::NODE_PATH => {
+                let s = str_from_chars(str_chars(data));
+                match (quoted, ty) {
+                    (false, _) => s,
+                    (true, ty::STRING_NAME) => format!("&{s:?}"),
+                    (true, ty::NODE_PATH) => format!("^{s:?}"),
+                    (true, _) => format!("{s:?}"),
+                }
+            }
+            ty::VECTOR2 => reals(2),
+            ty::VECTOR3 => reals(3),
+            ty::VECTOR4 | ty::QUATERNION | ty::PLANE => reals(4),
+            ty::VECTOR2I => ints(2),
+            ty::VECTOR3I => ints(3),
+            ty::VECTOR4I => ints(4),
+            ty::COLOR => {
+                let parts: Vec<String> = (0..4)
+                    .map(|i| fmt_float(read::<f32>(data.add(i * 4)) as f64))
+                    .collect();
+                format!("({})", parts.join(", "))
+            }
+            ty::RID => format!("RID({})", read::<u64>(data)),
+            ty::ARRAY => {
+                let array = array_data(data).borrow();
+                let parts: Vec<String> = array
+                    .elems
+                    .iter()
+                    .map(|e| stringify(e.ty(), e.data(), true))
+                    .collect();
+                format!("[{}]", parts.join(", "))
+            }
+            ty::DICTIONARY => {
+                let dict = dict_data(data).borrow();
+                if dict.entries.is_empty() {
+                    return "{}".to_string();
+                }
+
+                let parts: Vec<String> = dict
+                    .entries
+                    .iter()
+                    .map(|entry| {
+                        let (k, v) = (&entry.0, &entry.1);
+                        format!(
+                            "{}: {}",
+                            stringify(k.ty(), k.data(), true),
+                            stringify(v.ty(), v.data(), true)
+                        )
+                    })
+                    .collect();
+                format!("{{ {} }}", parts.join(", "))
+            }
+            _ => match kind(ty) {
+                Kind::Packed(elem) => {
+                    let elem_ty = packed_elem_type(ty);
+                    let parts: Vec<String> = packed_data(data).map_or(Vec::new(), |packed| {
+                        (0..packed.len())
+                            .map(|i| {
+                                let var =
+                                    packed_elem_to_variant(ty, elem, packed.elem_ptr(elem, i));
+                                stringify(elem_ty, var.data(), true)
+                            })
+                            .collect()
+                    });
+                    format!("[{}]", parts.join(", "))
+                }
+                _ => format!("<{}>", type_name(ty)),
+            },
+        }
+    }
+}
+
+fn fmt_float(value: f64) -> String {
+    if value.is_finite() && value == value.trunc() && value.abs() < 1e16 {
+        format!("{value:.1}")
+    } else {
+        value.to_string()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Packed-array elements
+
+/// Writes a ptrcall argument of the array's element type into the zeroed element storage `dst`.
+///
+/// Ptrcalls pass integers as `int` (i64) and floats as `float` (f64), independent of the packed element width.
+pub(super) unsafe fn packed_elem_from_arg(
+    packed_ty: VarType,
+    elem: Elem,
+    dst: *mut u8,
+    arg: *const u8,
+) {
+    // SAFETY: caller guarantees valid pointers of the element type.
+    unsafe {
+        match packed_ty {
+            ty::PACKED_BYTE_ARRAY => write(dst, read::<i64>(arg) as u8),
+            ty::PACKED_INT32_ARRAY => write(dst, read::<i64>(arg) as i32),
+            ty::PACKED_FLOAT32_ARRAY => write(dst, read::<f64>(arg) as f32),
+            ty::PACKED_STRING_ARRAY => construct_copy(ty::STRING, dst, arg),
+            _ => ptr::copy_nonoverlapping(arg, dst, elem.size),
+        }
+    }
+}
+
+/// Converts an element to a variant of type [`packed_elem_type()`].
+pub(super) unsafe fn packed_elem_to_variant(
+    packed_ty: VarType,
+    elem: Elem,
+    src: *const u8,
+) -> VariantBox {
+    let elem_ty = packed_elem_type(packed_ty);
+
+    VariantBox::with(elem_ty, |data| {
+        // SAFETY: caller guarantees a valid element; `data` is large enough for any element type.
+        unsafe {
+            match packed_ty {
+                ty::PACKED_BYTE_ARRAY => write(data, read::<u8>(src) as i64),
+                ty::PACKED_INT32_ARRAY => write(data, read::<i32>(src) as i64),
+                ty::PACKED_FLOAT32_ARRAY => write(data, read::<f32>(src) as f64),
+                ty::PACKED_STRING_ARRAY => construct_copy(ty::STRING, data, src),
+                _ => ptr::copy_nonoverlapping(src, data, elem.size),
+            }
+        }
+    })
+}
@@ -337,6 +337,7 @@ impl GetterSetterImpl {
             class_name,
             FuncDefinition {
                 signature_info: into_signature_info(signature, class_name, false),
+                vis_marker: None,
                 // Since we're analyzing a struct's field, we don't have access to the corresponding get/set function's
                 // external (non-#[func]) attributes. We have to assume the function exists and has the name the user
                 // gave us, with the expected signature.
@@ -17,6 +17,9 @@ pub struct FuncDefinition {
     /// Refined signature, with higher level info and renamed parameters.
     pub signature_info: SignatureInfo,
 
+    /// Visibility of the Rust function, e.g. `pub`.
+    pub vis_marker: Option<venial::VisMarker>,
+
     /// The function's non-gdext attributes (all except #[func]).
     pub external_attributes: Vec<venial::Attribute>,
 
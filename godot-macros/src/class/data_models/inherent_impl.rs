@@ -12,12 +12,12 @@ use quote::{format_ident, quote, ToTokens};
 use crate::class::data_models::func;
 use crate::class::{
     into_signature_info, make_constant_registration, make_method_registration,
-    make_signal_registrations, ConstDefinition, FuncDefinition, RpcAttr, RpcMode, SignalDefinition,
-    SignatureInfo, TransferMode,
+    make_signal_registrations, ConstDefinition, FuncDefinition, ReceiverType, RpcAttr, RpcMode,
+    SignalDefinition, SignatureInfo, TransferMode,
 };
 use crate::util::{
-    bail, c_str, format_funcs_collection_struct, ident, make_funcs_collection_constants,
-    replace_class_in_path, require_api_version, KvParser,
+    bail, c_str, format_funcs_collection_struct, format_typed_funcs_collection_struct, ident,
+    make_funcs_collection_constants, replace_class_in_path, require_api_version, KvParser,
 };
 use crate::{handle_mutually_exclusive_keys, util, ParseResult};
 
@@ -98,11 +98,18 @@ pub fn transform_inherent_impl(
     // The struct is declared by #[derive(GodotClass)].
     let funcs_collection = {
         let struct_name = format_funcs_collection_struct(&class_name);
+        replace_class_in_path(self_path.clone(), struct_name)
+    };
+
+    // Container struct with a `TypedFunc` accessor per #[func], also declared by #[derive(GodotClass)].
+    let typed_funcs_collection = {
+        let struct_name = format_typed_funcs_collection_struct(&class_name);
         replace_class_in_path(self_path, struct_name)
     };
 
     // For each #[func] in this impl block, create one constant.
     let func_name_constants = make_funcs_collection_constants(&funcs, &class_name);
+    let typed_func_accessors = make_typed_func_accessors(&funcs, &class_name);
     let (signal_registrations, signal_symbol_types) = make_signal_registrations(
         &signals,
         &class_name,
@@ -193,6 +200,10 @@ pub fn transform_inherent_impl(
             impl #funcs_collection {
                 #( #func_name_constants )*
             }
+            #[allow(dead_code)] // Accessors are only used on demand.
+            impl #typed_funcs_collection {
+                #( #typed_func_accessors )*
+            }
             #signal_symbol_types
             #inherent_impl_docs
         };
@@ -208,6 +219,10 @@ pub fn transform_inherent_impl(
             impl #funcs_collection {
                 #( #func_name_constants )*
             }
+            #[allow(dead_code)] // Accessors are only used on demand.
+            impl #typed_funcs_collection {
+                #( #typed_func_accessors )*
+            }
             #inherent_impl_docs
         };
 
@@ -215,6 +230,59 @@ pub fn transform_inherent_impl(
     }
 }
 
+/// For each #[func] with a receiver, creates a method returning a `TypedFunc`, which calls the Rust method directly.
+///
+/// Static functions are skipped, as `TypedFunc` is invoked on an object.
+fn make_typed_func_accessors(funcs: &[FuncDefinition], class_name: &Ident) -> Vec<TokenStream> {
+    let class_name_str = class_name.to_string();
+
+    funcs
+        .iter()
+        .filter(|func| func.signature_info.receiver_type != ReceiverType::Static)
+        .map(|func| {
+            let signature_info = &func.signature_info;
+            let method_name = &signature_info.method_name;
+            let params = &signature_info.param_idents;
+            let params_tuple = signature_info.params_tuple();
+            let params_type = signature_info.params_type();
+            let return_type = &signature_info.return_type;
+            let godot_name = func.godot_name();
+            let is_script_virtual = func.is_script_virtual;
+            let vis_marker = &func.vis_marker;
+
+            let cfg_attributes = util::extract_cfg_attrs(&func.external_attributes)
+                .into_iter()
+                .collect::<Vec<_>>();
+
+            let method_call = match signature_info.receiver_type {
+                ReceiverType::Ref => quote! {
+                    __this.bind().#method_name(#(#params),*)
+                },
+                ReceiverType::Mut => quote! {
+                    __this.bind_mut().#method_name(#(#params),*)
+                },
+                ReceiverType::GdSelf => quote! {
+                    #class_name::#method_name(__this.clone(), #(#params),*)
+                },
+                ReceiverType::Static => unreachable!("static functions filtered above"),
+            };
+
+            quote! {
+                #(#cfg_attributes)*
+                #vis_marker fn #method_name(&self) -> ::godot::obj::TypedFunc<#class_name, #params_type, #return_type> {
+                    ::godot::obj::TypedFunc::__new(
+                        ::godot::builtin::sname!(#godot_name),
+                        #godot_name,
+                        #class_name_str,
+                        #is_script_virtual,
+                        |__this, #params_tuple| #method_call,
+                    )
+                }
+            }
+        })
+        .collect()
+}
+
 /* Re-enable if we allow controlling declarative macros for signals (base_field_macro, visibility_macros).
 fn extract_hint_attribute(impl_block: &mut venial:: Impl) -> ParseResult<GodotApiHints> {
     // #[hint(has_base_field = BOOL)]
@@ -320,6 +388,7 @@ fn process_godot_fns(
 
                 func_definitions.push(FuncDefinition {
                     signature_info,
+                    vis_marker: function.vis_marker.clone(),
                     external_attributes,
                     registered_name,
                     is_script_virtual: func.is_virtual,
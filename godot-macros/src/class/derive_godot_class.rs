@@ -16,8 +16,8 @@ use crate::class::{
     FieldExport, FieldVar, GetterSetter, SignatureInfo,
 };
 use crate::util::{
-    bail, error, format_funcs_collection_struct, ident, ident_respan, path_ends_with_complex,
-    KvParser,
+    bail, error, format_funcs_collection_struct, format_typed_funcs_collection_struct, ident,
+    ident_respan, path_ends_with_complex, KvParser,
 };
 use crate::{handle_mutually_exclusive_keys, util, ParseResult};
 
@@ -91,6 +91,12 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
         (TokenStream::new(), TokenStream::new())
     };
 
+    let (thread_safe_impl, thread_safe_const) = if struct_cfg.is_thread_safe {
+        make_thread_safe_impl(class_name, &fields.all_fields)
+    } else {
+        (TokenStream::new(), TokenStream::new())
+    };
+
     let is_singleton_bound = if struct_cfg.is_singleton {
         quote! { ::godot::obj::bounds::Yes }
     } else {
@@ -147,6 +153,14 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
         modifiers.push(quote! { with_tool })
     }
 
+    if struct_cfg.has_hot_reload_state {
+        modifiers.push(quote! { with_hot_reload_state::<#class_name> })
+    }
+
+    if struct_cfg.is_shared {
+        modifiers.push(quote! { with_shared })
+    }
+
     // Declares a "funcs collection" struct that, for holds a constant for each #[func].
     // That constant maps the Rust name (constant ident) to the Godot registered name (string value).
     let funcs_collection_struct_name = format_funcs_collection_struct(class_name);
@@ -156,6 +170,24 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
         pub struct #funcs_collection_struct_name {}
     };
 
+    // Declares a collection struct with one `TypedFunc` accessor per #[func], filled by #[godot_api] impl blocks.
+    // Has the class' visibility, as it appears in the `WithFuncs` impl.
+    let typed_funcs_collection_struct_name = format_typed_funcs_collection_struct(class_name);
+    let class_vis = class.vis_marker.as_ref();
+    let typed_funcs_collection_struct = quote! {
+        #[doc(hidden)]
+        #[allow(non_camel_case_types)]
+        #class_vis struct #typed_funcs_collection_struct_name {}
+
+        impl ::godot::obj::WithFuncs for #class_name {
+            type FuncCollection = #typed_funcs_collection_struct_name;
+
+            fn funcs() -> Self::FuncCollection {
+                #typed_funcs_collection_struct_name {}
+            }
+        }
+    };
+
     // Note: one limitation is that macros don't work for `impl nested::MyClass` blocks.
     let visibility_macro = make_visibility_macro(class_name, class.vis_marker.as_ref());
     let base_field_macro = make_base_field_macro(class_name, fields.base_field.is_some());
@@ -166,6 +198,7 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
             type Base = #base_class;
 
             #singleton_init_level_const
+            #thread_safe_const
 
             // Code duplicated in godot-codegen.
             fn class_id() -> ::godot::meta::ClassId {
@@ -188,6 +221,7 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
         }
 
         #funcs_collection_struct
+        #typed_funcs_collection_struct
         #godot_init_impl
         #godot_withbase_impl
         #godot_exports_impl
@@ -199,6 +233,7 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
         #( #deprecations )*
         #( #errors )*
         #user_singleton_impl
+        #thread_safe_impl
 
         #struct_docs_registration
         ::godot::sys::plugin_add!(#prv::__GODOT_PLUGIN_REGISTRY; #prv::ClassPlugin::new::<#class_name>(
@@ -220,6 +255,8 @@ fn make_with_base_impl(base_field: &Option<Field>, class_name: &Ident) -> TokenS
     quote_spanned! { ty.span()=>
         impl ::godot::obj::WithBaseField for #class_name {
             fn to_gd(&self) -> ::godot::obj::Gd<#class_name> {
+                ::godot::private::__check_engine_access::<#class_name>("to_gd");
+
                 // By not referencing the base field directly here we ensure that the user only gets one error when the base
                 // field's type is wrong.
                 let base = <#class_name as ::godot::obj::WithBaseField>::base_field(self);
@@ -248,6 +285,33 @@ fn make_singleton_impl(class_name: &Ident) -> (TokenStream, TokenStream) {
     )
 }
 
+fn make_thread_safe_impl(class_name: &Ident, all_fields: &[Field]) -> (TokenStream, TokenStream) {
+    // Fields are accessed from several threads through ThreadSafeGd. The base field is excluded: engine access stays on the main thread.
+    let field_checks = all_fields.iter().map(|Field { ty, span, .. }| {
+        quote_spanned! { *span=>
+            __assert_send_sync::<#ty>();
+        }
+    });
+
+    (
+        quote! {
+            const _: () = {
+                fn __assert_send_sync<T: Send + Sync>() {}
+
+                #[allow(dead_code)]
+                fn __thread_safe_fields() {
+                    #( #field_checks )*
+                }
+            };
+
+            unsafe impl ::godot::obj::ThreadSafeClass for #class_name {}
+        },
+        quote! {
+            const __IS_THREAD_SAFE: bool = true;
+        },
+    )
+}
+
 /// Generates code for a decl-macro, which takes any item and prepends it with the visibility marker of the class.
 ///
 /// Used to access the visibility of the class in other proc-macros like `#[godot_api]`.
@@ -371,6 +435,9 @@ struct ClassAttributes {
     is_tool: bool,
     is_singleton: bool,
     is_internal: bool,
+    has_hot_reload_state: bool,
+    is_shared: bool,
+    is_thread_safe: bool,
     rename: Option<Ident>,
     deprecations: Vec<TokenStream>,
 }
@@ -579,6 +646,9 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
     let mut is_tool = false;
     let mut is_singleton = false;
     let mut is_internal = false;
+    let mut has_hot_reload_state = false;
+    let mut is_shared = false;
+    let mut is_thread_safe = false;
     let mut rename: Option<Ident> = None;
     #[allow(unused_mut)] // Avoid churn when having 0 deprecations.
     let mut deprecations = vec![];
@@ -634,6 +704,21 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
             }
         }
 
+        // #[class(hot_reload_state)]
+        if parser.handle_alone("hot_reload_state")? {
+            has_hot_reload_state = true;
+        }
+
+        // #[class(shared)]
+        if parser.handle_alone("shared")? {
+            is_shared = true;
+        }
+
+        // #[class(thread_safe)]
+        if parser.handle_alone("thread_safe")? {
+            is_thread_safe = true;
+        }
+
         // Removed #[class(hidden)]
         if let Some(key) = parser.handle_alone_with_span("hidden")? {
             return bail!(
@@ -664,6 +749,14 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
         );
     }
 
+    // Without a default constructor, Godot cannot recreate the Rust part after hot reload, so there is nothing to restore into.
+    if init_strategy == InitStrategy::Absent && has_hot_reload_state {
+        return bail!(
+            class,
+            "#[class(hot_reload_state)] can't be used with #[class(no_init)]",
+        );
+    }
+
     post_validate(&base_ty, is_tool)?;
 
     Ok(ClassAttributes {
@@ -672,6 +765,9 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
         is_tool,
         is_singleton,
         is_internal,
+        has_hot_reload_state,
+        is_shared,
+        is_thread_safe,
         rename,
         deprecations,
     })
@@ -529,6 +529,75 @@ use crate::util::{bail, ident, KvParser};
 /// }
 /// ```
 ///
+/// ## Hot-reload state
+///
+/// On hot reload, Godot restores only properties that are stored in scenes (e.g. `#[export]` fields). All other fields are reset by `init()`.
+/// With `#[class(hot_reload_state)]`, the class implements [`HotReloadState`](../obj/trait.HotReloadState.html) to save additional state
+/// before the library is unloaded, and to restore it into the recreated instance.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::obj::HotReloadState;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node, hot_reload_state)]
+/// struct Level {
+///     visited_rooms: PackedInt32Array,
+/// }
+///
+/// impl HotReloadState for Level {
+///     fn save_hot_reload_state(&self) -> Variant {
+///         self.visited_rooms.to_variant()
+///     }
+///
+///     fn restore_hot_reload_state(&mut self, state: Variant) {
+///         self.visited_rooms = state.try_to().unwrap_or_default();
+///     }
+/// }
+/// ```
+///
+/// State is only saved when running inside the editor. The class needs a constructor, so `no_init` is not supported.
+///
+/// ## Shared classes
+///
+/// Other Rust extensions loaded into the same project cannot use `Gd<T>` for your classes, since instances are only accessible from
+/// the library that registered them. With `#[class(shared)]`, the class' `#[func]` methods are additionally published through a small
+/// C-ABI table, so that other extensions can call them in a typed way, without `Variant` conversions.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, shared)]
+/// struct Inventory {
+///     items: Vec<GString>,
+/// }
+///
+/// #[godot_api]
+/// impl Inventory {
+///     #[func]
+///     fn item_count(&self) -> i64 {
+///         self.items.len() as i64
+///     }
+/// }
+/// ```
+///
+/// The consuming extension declares a proxy with [`shared_proxy!`](../register/shared/macro.shared_proxy.html). See the
+/// [`shared`](../register/shared/index.html) module for details.
+///
+/// ## Thread-safe classes
+///
+/// By default, binding a user instance from two places at once panics. With `#[class(thread_safe)]`, the instance is stored in a blocking
+/// cell instead, and can be accessed from worker threads through [`ThreadSafeGd<T>`](../obj/struct.ThreadSafeGd.html). All fields except
+/// the base must be `Send + Sync`; this is checked at compile time.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=RefCounted, thread_safe)]
+/// struct Simulation {
+///     steps: u64,
+/// }
+/// ```
+///
 ///
 /// # Further field customization
 ///
@@ -608,7 +677,8 @@ use crate::util::{bail, ident, KvParser};
     alias = "export",
     alias = "tool",
     alias = "rename",
-    alias = "internal"
+    alias = "internal",
+    alias = "shared"
 )]
 #[proc_macro_derive(
     GodotClass,
@@ -837,6 +907,36 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 ///
 /// Make sure you understand the limitations in the [tutorial](https://godot-rust.github.io/book/register/virtual-functions.html).
 ///
+/// ## Typed calls from Rust
+///
+/// If you only have a `Gd<Object>` (or another base class) pointing to your class, you can call its `#[func]` methods without going
+/// through `Object::call()` and `Variant` conversions. `MyClass::funcs()` provides a [`TypedFunc`](../obj/struct.TypedFunc.html) for
+/// each `#[func]` with a receiver, which is invoked via [`Gd::call_func()`](../obj/struct.Gd.html#method.call_func):
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Enemy {
+///     health: i64,
+/// }
+///
+/// #[godot_api]
+/// impl Enemy {
+///     #[func]
+///     fn heal(&mut self, amount: i64) -> i64 {
+///         self.health += amount;
+///         self.health
+///     }
+/// }
+///
+/// fn heal_child(mut child: Gd<Node>) -> i64 {
+///     child.call_func(Enemy::funcs().heal(), (20,))
+/// }
+/// ```
+///
+/// Methods overridden by an attached script are still dispatched to the script.
+///
 /// ## RPC attributes
 ///
 /// You can use the `#[rpc]` attribute to let your functions act as remote procedure calls (RPCs) in Godot. This is the Rust equivalent of
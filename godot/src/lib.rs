@@ -117,6 +117,9 @@
 //!   You can use at most one `api-*` feature. If absent, the current Godot minor version is used, with patch level 0.
 //!
 //!   `api-custom` feature requires specifying `GODOT4_BIN` environment variable with a path to your Godot4 binary.
+//!   Files dumped from the binary are cached per binary (in `OUT_DIR`, or in `GODOT4_API_CACHE_DIR` if set), and only dumped again
+//!   once its version changes. Alternatively, `GODOT4_DUMP_DIR` can point to a directory with `extension_api.json` and
+//!   `gdextension_interface.h` dumped in advance, in which case no binary is needed.
 //!
 //!   The `api-custom-json` feature requires specifying `GODOT4_GDEXTENSION_JSON` environment variable with a path
 //!   to your custom-defined `extension_api.json`.<br><br>
@@ -140,11 +143,18 @@
 //!   truly available. Function calls may thus panic only at runtime, possibly in deeply nested code paths.
 //!   This feature is not yet thread-safe and can thus not be combined with `experimental-threads`.<br><br>
 //!
+//! * **`profiling`**
+//!
+//!   Reports calls from Rust into the engine and from Godot into `#[func]` methods to a pluggable profiler, with their durations.
+//!   Helps to find hot FFI calls. See module `godot::meta::profiling` for details.<br><br>
+//!
 //! * **`experimental-threads`**
 //!
 //!   Experimental threading support. This adds synchronization to access the user instance in `Gd<T>` and disables several single-thread checks.
 //!   The safety aspects are not ironed out yet; there is a high risk of unsoundness at the moment.
-//!   As this evolves, it is very likely that the API becomes stricter.<br><br>
+//!   As this evolves, it is very likely that the API becomes stricter.
+//!   To access Rust instances from worker threads without this feature, use `obj::ThreadSafeGd<T>` with `#[class(thread_safe)]`, which
+//!   restricts engine access to the main thread.<br><br>
 //!
 //! * **`experimental-wasm`**
 //!
@@ -170,6 +180,30 @@
 //!   Use rustfmt to format generated binding code. Because rustfmt is so slow, this is detrimental to initial compile time.
 //!   Without it, we use a lightweight and fast custom formatter to enable basic human readability.<br><br>
 //!
+//! * **`codegen-docs`**
+//!
+//!   Includes Godot's class reference in the rustdoc of generated classes, methods, signals, enums and constants. BBCode is converted
+//!   to Markdown, with links to other engine classes. Needs an API JSON with docs: dumped with `--dump-extension-api-with-docs` when
+//!   `GODOT4_BIN` is set, otherwise read from the file at `GODOT4_DOCS_JSON`. Increases build time and size of generated code.<br><br>
+//!
+//! * **`codegen-third-party`**
+//!
+//!   Generates typed bindings for classes registered by other GDExtensions (e.g. C++ plugins), in the module `godot::classes::third_party`.
+//!   The environment variable `GODOT4_THIRD_PARTY_JSON` lists API JSON files (separated like `PATH`), either dumped from an editor with
+//!   the extension loaded, or hand-written with a `classes` array in the `extension_api.json` format. Using such a class panics if its
+//!   GDExtension is not loaded.<br><br>
+//!
+//! * **`codegen-allowlist`**
+//!
+//!   Generates only the engine classes your project uses, which can cut compile times considerably. Requires `default-features = false`
+//!   on the `godot` dependency, since full codegen is a default feature. `GODOT4_CODEGEN_CLASSES` points to either a text file with
+//!   class names (one or more per line, `#` for comments), or a `Cargo.toml` with a `classes` array in `[package.metadata.godot]` or
+//!   `[workspace.metadata.godot]`. Set it in `.cargo/config.toml` under `[env]` with `relative = true`, so it works from any directory.
+//!
+//!   Base classes, types in method and signal signatures of listed classes, and classes needed by godot-rust itself are added
+//!   automatically. Methods of these dependencies referring to other classes are skipped; the build output of `godot-core`
+//!   (`cargo build -vv`) lists them with the classes to add.<br><br>
+//!
 //! * **`register-docs`**
 //!
 //!   Generates documentation for your structs from your Rust documentation.
@@ -196,6 +230,21 @@
 //!   Implement the [serde](https://serde.rs/) traits `Serialize` and `Deserialize` traits for certain built-in types.
 //!   The serialized representation underlies **no stability guarantees** and may change at any time, even without a SemVer-breaking change.
 //!
+//! * **`glam`**
+//!
+//!   Implement `From` conversions between math types such as `Vector3`, `Quaternion`, `Basis`, `Transform3D` or `Projection`
+//!   and their [glam](https://docs.rs/glam) counterparts. Float types map to `f32` or `f64` glam types depending on `double-precision`.
+//!   The matching glam version is re-exported as `godot::builtin::math::glam`.<br><br>
+//!
+//! * **`mint`**
+//!
+//!   Implement conversions to and from [mint](https://docs.rs/mint) types, for interop with other math and geometry crates.<br><br>
+//!
+//! * **`bytemuck`**
+//!
+//!   Implement the [bytemuck](https://docs.rs/bytemuck) traits `Pod` and `Zeroable` for vectors, matrices, `Color` and other
+//!   plain-data builtins, allowing zero-copy casts of slices, e.g. `&[Vector3]` to `&[f32]` or `&[u8]`.
+//!
 
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/godot-rust/assets/master/gdext/ferris.svg"
@@ -236,11 +285,16 @@ pub use godot_core::sys;
 pub use godot_core::{builtin, classes, global, meta, obj, rendering, task, tools};
 
 #[cfg(feature = "__codegen-full")]
-pub use godot_core::{display, physics, text};
+pub use godot_core::{display, physics, rendering_device, text};
 
 #[cfg(all(feature = "__codegen-full", feature = "experimental-godot-api"))]
 pub use godot_core::navigation;
 
+/// Diagnostics for engine servers and their RAII wrappers.
+pub mod servers {
+    pub use godot_core::servers::{debug_live_rids, LiveRidCount};
+}
+
 /// Entry point and global init/shutdown of the library.
 pub mod init {
     pub use godot_core::init::*;
@@ -250,7 +304,9 @@ pub mod init {
 
 /// Register/export Rust symbols to Godot: classes, methods, enums...
 pub mod register {
+    pub use godot_core::registry::api_dump;
     pub use godot_core::registry::property;
+    pub use godot_core::registry::shared;
     pub use godot_core::registry::signal::re_export::*;
     #[cfg(feature = "__codegen-full")]
     pub use godot_core::registry::RpcConfig;
@@ -35,6 +35,7 @@ mod trait_reexports {
     pub use crate::obj::NewGd as _;
     pub use crate::obj::Singleton as _; // singleton()
     pub use crate::obj::WithBaseField as _; // base(), base_mut(), to_gd(), run_deferred(), run_deferred_gd()
+    pub use crate::obj::WithFuncs as _; // MyClass::funcs()
     pub use crate::obj::WithSignals as _; // Gd::signals()
     pub use crate::obj::WithUserSignals as _; // self.signals()
 }
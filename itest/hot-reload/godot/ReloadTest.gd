@@ -27,6 +27,10 @@ func _ready() -> void:
 	retained_obj = Reloadable.from_string("Mars")
 	var planet = retained_obj.favorite_planet
 
+	# Non-property state, preserved through #[class(hot_reload_state)].
+	retained_obj.visit()
+	retained_obj.visit()
+
 	print("[GD Editor] Sanity check: initial number is ", num, "; planet is ", planet)
 	
 	var extensions = GDExtensionManager.get_loaded_extensions()
@@ -76,19 +80,23 @@ func _process(delta: float) -> void:
 	var num = r.get_number()
 	r.free()
 
-	# Check if the property has been restored.
+	# Check if the property and the Rust-side state have been restored.
 	var planet = retained_obj.favorite_planet
+	var visits = retained_obj.visit()
 	retained_obj.free()
 
-	if num == 777 and planet == "Mars":
+	if num == 777 and planet == "Mars" and visits == 3:
 		print("[GD Editor] Successful hot-reload! Exit...")
 		get_tree().quit(0)
 	elif num != 777:
 		fail(str("Number was not updated correctly (is ", num, ")"))
 		return
-	else:
+	elif planet != "Mars":
 		fail(str("Planet was not restored correctly (is ", planet, ")"))
 		return
+	else:
+		fail(str("Hot-reload state was not restored correctly (visits: ", visits, ")"))
+		return
 
 
 func _hot_reload():
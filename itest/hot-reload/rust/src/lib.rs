@@ -5,6 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use godot::obj::HotReloadState;
 use godot::prelude::*;
 
 struct HotReload;
@@ -23,12 +24,15 @@ unsafe impl ExtensionLibrary for HotReload {
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 #[derive(GodotClass)]
-#[class(init, base=Node)]
+#[class(init, base=Node, hot_reload_state)]
 struct Reloadable {
     #[export]
     #[init(val = Planet::Earth)]
     favorite_planet: Planet,
 
+    // Not a property; only survives hot reload through HotReloadState.
+    visits: i64,
+
     #[init(val = NoDefault::obtain())]
     _other_object: Gd<NoDefault>,
 }
@@ -44,9 +48,26 @@ impl Reloadable {
     fn from_string(s: GString) -> Gd<Self> {
         Gd::from_object(Reloadable {
             favorite_planet: Planet::from_godot(s),
+            visits: 0,
             _other_object: NoDefault::obtain(),
         })
     }
+
+    #[func]
+    fn visit(&mut self) -> i64 {
+        self.visits += 1;
+        self.visits
+    }
+}
+
+impl HotReloadState for Reloadable {
+    fn save_hot_reload_state(&self) -> Variant {
+        self.visits.to_variant()
+    }
+
+    fn restore_hot_reload_state(&mut self, state: Variant) {
+        self.visits = state.to();
+    }
 }
 
 // no_init reloadability - https://github.com/godot-rust/gdext/issues/874.
@@ -0,0 +1,52 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Calls a #[func] of a Rust class through a `Gd<Object>`. The `RefCell` is needed since `bench_measure()` takes a `Fn` closure.
+
+use std::cell::RefCell;
+use std::hint::black_box;
+
+use godot::builtin::vslice;
+use godot::prelude::*;
+
+use crate::framework::{bench, bench_measure, BenchResult};
+
+#[bench(manual)]
+fn func_call_varcall() -> BenchResult {
+    let object: Gd<Object> = FuncCallBench::new_gd().upcast();
+    let object = RefCell::new(object);
+
+    bench_measure(100, || {
+        object.borrow_mut().call("add", vslice![black_box(3)])
+    })
+}
+
+#[bench(manual)]
+fn func_call_typed() -> BenchResult {
+    let object: Gd<Object> = FuncCallBench::new_gd().upcast();
+    let object = RefCell::new(object);
+    let add = FuncCallBench::funcs().add();
+
+    bench_measure(100, || object.borrow_mut().call_func(add, (black_box(3),)))
+}
+
+// Helpers for benchmarks above
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct FuncCallBench {
+    total: i64,
+}
+
+#[godot_api]
+impl FuncCallBench {
+    #[func]
+    fn add(&mut self, amount: i64) -> i64 {
+        self.total += amount;
+        self.total
+    }
+}
@@ -19,6 +19,7 @@ use crate::framework::bench;
 
 mod callable;
 mod color;
+mod func_call;
 
 #[bench]
 fn builtin_string_ctor() -> GString {
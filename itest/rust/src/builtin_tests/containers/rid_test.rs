@@ -287,6 +287,80 @@ fn owned_canvas_item_raii() {
     assert_eq!(rid, *item2);
 }
 
+#[itest]
+fn canvas_item_draw_builder() {
+    use godot::classes::rendering_server::NinePatchAxisMode;
+    use godot::rendering::CanvasDrawBuffers;
+
+    let mut server = RenderingServer::singleton();
+    let canvas = server.canvas_create_owned();
+    let mut item = server.canvas_item_create_owned();
+    item.set_parent(*canvas);
+
+    let square = [
+        Vector2::new(0.0, 0.0),
+        Vector2::new(10.0, 0.0),
+        Vector2::new(10.0, 10.0),
+        Vector2::new(0.0, 10.0),
+    ];
+    let rect = Rect2::new(Vector2::ZERO, Vector2::new(16.0, 16.0));
+    let no_texture = Rid::Invalid;
+
+    // Same buffers for several frames; each frame clears and re-records all commands.
+    let mut buffers = CanvasDrawBuffers::new();
+    for frame in 0..3 {
+        let offset = Vector2::new(frame as f32, 0.0);
+
+        item.draw_with(&mut buffers)
+            .clear()
+            .z_index(frame)
+            .clip_ignore(true)
+            .transform(Transform2D::from_angle_origin(0.0, offset))
+            .line(Vector2::ZERO, Vector2::ONE, Color::WHITE, 1.0)
+            .polyline(&square, &[Color::RED], 2.0, true)
+            .multiline(&square, &[Color::GREEN, Color::BLUE], -1.0)
+            .rect(rect, Color::BLACK)
+            .circle(offset, 3.0, Color::WHITE)
+            .polygon(&square, &[Color::WHITE])
+            .triangle_array(
+                &[0, 1, 2, 0, 2, 3],
+                &square,
+                &[Color::WHITE],
+                &[],
+                no_texture,
+            )
+            .primitive(&square[..3], &[], &[], no_texture)
+            .texture_rect(rect, no_texture, Color::WHITE)
+            .nine_patch(
+                rect,
+                rect,
+                no_texture,
+                Vector2::new(4.0, 4.0),
+                Vector2::new(4.0, 4.0),
+                NinePatchAxisMode::TILE,
+                NinePatchAxisMode::STRETCH,
+                true,
+                Color::WHITE,
+            )
+            .clip_ignore(false);
+
+        // Each command overwrites the buffers in place: the last ones to touch them were `triangle_array` and `primitive`.
+        assert_eq!(buffers.points().as_slice(), &square[..3]);
+        assert!(buffers.colors().is_empty());
+        assert!(buffers.uvs().is_empty());
+        assert_eq!(buffers.indices().as_slice(), &[0, 1, 2, 0, 2, 3]);
+    }
+
+    // Buffers are refilled with the new contents, also when growing again.
+    item.draw_with(&mut buffers)
+        .polyline(&square, &[Color::RED, Color::GREEN], 1.0, false);
+    assert_eq!(buffers.points().as_slice(), &square);
+    assert_eq!(buffers.colors().as_slice(), &[Color::RED, Color::GREEN]);
+
+    // Temporary buffers work as well.
+    item.draw().clear().polygon(&square, &[Color::RED]);
+}
+
 #[itest]
 fn owned_canvas_raii() {
     let mut server = RenderingServer::singleton();
@@ -363,6 +437,40 @@ fn owned_environment_raii() {
     assert_eq!(rid, *environment2);
 }
 
+#[itest]
+#[cfg(safeguards_strict)]
+fn owned_rids_tracked_while_alive() {
+    use godot::servers::debug_live_rids;
+
+    fn live_canvas_items() -> usize {
+        debug_live_rids()
+            .into_iter()
+            .find(|entry| entry.kind == "OwnedCanvasItem")
+            .map_or(0, |entry| {
+                assert_eq!(entry.server, "RenderingServer");
+                entry.count
+            })
+    }
+
+    let mut server = RenderingServer::singleton();
+    let before = live_canvas_items();
+
+    let item1 = server.canvas_item_create_owned();
+    let item2 = server.canvas_item_create_owned();
+    assert_eq!(live_canvas_items(), before + 2);
+
+    // Raw RIDs are not tracked.
+    let raw = server.canvas_item_create();
+    assert_eq!(live_canvas_items(), before + 2);
+    server.free_rid(raw);
+
+    drop(item1);
+    assert_eq!(live_canvas_items(), before + 1);
+
+    drop(item2);
+    assert_eq!(live_canvas_items(), before);
+}
+
 #[itest]
 #[cfg(feature = "codegen-full")]
 fn owned_physics_2d_raii() {
@@ -462,6 +570,89 @@ fn owned_physics_3d_raii() {
     assert_eq!(rid_shape, *shape2);
 }
 
+#[itest]
+#[cfg(feature = "codegen-full")]
+fn owned_physics_3d_dependencies() {
+    use std::hash::{BuildHasher, RandomState};
+    use std::sync::Arc;
+
+    use godot::classes::physics_server_3d::{BodyMode, BodyParameter, BodyState, ShapeType};
+    use godot::classes::PhysicsServer3D;
+    use godot::physics::{BodyParam3D, BodyState3D};
+
+    let mut server = PhysicsServer3D::singleton();
+
+    let space = Arc::new(server.space_create_owned());
+    let shape = Arc::new(server.shape_create_owned(ShapeType::BOX));
+    let (space_rid, shape_rid) = (space.rid(), shape.rid());
+
+    let mut body = server.body_create_owned();
+    let hasher = RandomState::new();
+    let hash_before = hasher.hash_one(&body);
+
+    body.add_shape(&shape, Transform3D::IDENTITY);
+    body.set_space(&space);
+    body.set_mode(BodyMode::KINEMATIC);
+    body.set_param(BodyParam3D::Mass(3.0));
+    body.set_state(BodyState3D::LinearVelocity(Vector3::UP));
+
+    // Identity only depends on the RID, not on attached shapes or space.
+    assert_eq!(hasher.hash_one(&body), hash_before);
+
+    assert_eq!(body.shapes().len(), 1);
+    assert_eq!(server.body_get_mode(body.rid()), BodyMode::KINEMATIC);
+    assert_eq!(
+        server.body_get_param(body.rid(), BodyParameter::MASS),
+        3.0.to_variant()
+    );
+    assert_eq!(
+        body.state(BodyState::LINEAR_VELOCITY),
+        Vector3::UP.to_variant()
+    );
+
+    // Dropping our handles must not free the resources still used by the body.
+    let weak_space = Arc::downgrade(&space);
+    let weak_shape = Arc::downgrade(&shape);
+    drop(space);
+    drop(shape);
+
+    assert_eq!(server.body_get_shape(body.rid(), 0), shape_rid);
+    assert_eq!(server.body_get_space(body.rid()), space_rid);
+
+    // The body is freed first, then releases its shape and space.
+    drop(body);
+    assert!(weak_shape.upgrade().is_none());
+    assert!(weak_space.upgrade().is_none());
+}
+
+#[itest]
+#[cfg(feature = "codegen-full")]
+fn owned_physics_2d_remove_shape() {
+    use std::sync::Arc;
+
+    use godot::classes::physics_server_2d::ShapeType;
+    use godot::classes::PhysicsServer2D;
+
+    let mut server = PhysicsServer2D::singleton();
+
+    let circle = Arc::new(server.shape_create_owned(ShapeType::CIRCLE));
+    let rect = Arc::new(server.shape_create_owned(ShapeType::RECTANGLE));
+
+    let mut area = server.area_create_owned();
+    area.add_shape(&circle, Transform2D::IDENTITY);
+    area.add_shape(&rect, Transform2D::IDENTITY);
+    assert_eq!(Arc::strong_count(&circle), 2);
+
+    let removed = area.remove_shape(0);
+    assert!(Arc::ptr_eq(&removed, &circle));
+    assert_eq!(server.area_get_shape_count(area.rid()), 1);
+    assert_eq!(server.area_get_shape(area.rid(), 0), rect.rid());
+
+    area.clear_shapes();
+    assert!(area.shapes().is_empty());
+    assert_eq!(Arc::strong_count(&rect), 1);
+}
+
 #[itest]
 #[cfg(feature = "codegen-full-experimental")]
 fn owned_navigation_2d_raii() {
@@ -514,6 +705,144 @@ fn owned_navigation_3d_raii() {
     assert_eq!(rid_region, *region2);
 }
 
+#[itest]
+#[cfg(feature = "codegen-full-experimental")]
+fn navigation_path_query_clone_is_independent() {
+    use godot::classes::navigation_path_query_parameters_3d::PathfindingAlgorithm;
+    use godot::navigation::NavPathQuery3D;
+
+    let map = Rid::new(1234);
+    let original = NavPathQuery3D::new(map, Vector3::ZERO, Vector3::new(1.0, 0.0, 1.0))
+        .navigation_layers(0b01)
+        .algorithm(PathfindingAlgorithm::ASTAR);
+
+    let clone = original.clone().navigation_layers(0b10);
+
+    let original_params = original.to_parameters();
+    let clone_params = clone.to_parameters();
+
+    assert_eq!(original_params.get_navigation_layers(), 0b01);
+    assert_eq!(clone_params.get_navigation_layers(), 0b10);
+    assert_eq!(
+        clone_params.get_pathfinding_algorithm(),
+        PathfindingAlgorithm::ASTAR
+    );
+
+    // Each run creates its own parameters object.
+    assert_ne!(original_params, original.to_parameters());
+
+    let mut modified = original.to_parameters();
+    modified.set_navigation_layers(0b100);
+    assert_eq!(original.to_parameters().get_navigation_layers(), 0b01);
+    assert_eq!(original_params.get_map(), map);
+}
+
+#[itest(async)]
+#[cfg(all(feature = "codegen-full-experimental", since_api = "4.4"))]
+fn navigation_path_query_3d() -> godot::task::TaskHandle {
+    use godot::classes::{Engine, NavigationMesh, NavigationServer3D, SceneTree};
+    use godot::navigation::NavSegmentType;
+
+    let mut server = NavigationServer3D::singleton();
+    let mut map = server.map_create_owned();
+    map.set_active(true);
+
+    // Single square polygon of 10x10 units.
+    let mut mesh = NavigationMesh::new_gd();
+    mesh.set_vertices(&PackedVector3Array::from(&[
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(10.0, 0.0, 0.0),
+        Vector3::new(10.0, 0.0, 10.0),
+        Vector3::new(0.0, 0.0, 10.0),
+    ]));
+    mesh.add_polygon(&PackedInt32Array::from(&[0, 1, 2, 3]));
+
+    let region = server.region_create_owned();
+    server.region_set_navigation_mesh(*region, &mesh);
+    server.region_set_map(*region, *map);
+
+    let tree = Engine::singleton()
+        .get_main_loop()
+        .unwrap()
+        .cast::<SceneTree>();
+
+    godot::task::spawn(async move {
+        // Maps are synchronized during the frame; wait until the region is part of the map.
+        for _ in 0..3 {
+            let _: () = tree.signals().physics_frame().to_future().await;
+        }
+
+        let start = Vector3::new(1.0, 0.0, 1.0);
+        let target = Vector3::new(9.0, 0.0, 8.0);
+        let query = map.path_query(start, target);
+
+        let path = server.query_path_typed(&query);
+        assert!(!path.is_empty());
+        assert!(path.points[0].is_equal_approx(start));
+        assert!(path.points[path.len() - 1].is_equal_approx(target));
+        assert!(path.length() >= start.distance_to(target) - 0.01);
+
+        assert_eq!(path.types.len(), path.len());
+        assert!(path.types.iter().all(|ty| *ty == NavSegmentType::Region));
+        assert!(path.rids.iter().all(|rid| *rid == *region));
+        assert!(path.owners.iter().all(Option::is_none));
+
+        let async_path = server
+            .query_path_async(&query)
+            .await
+            .expect("query finished");
+        assert_eq!(async_path, path);
+
+        drop(region);
+        drop(map);
+    })
+}
+
+#[itest(async)]
+#[cfg(feature = "codegen-full-experimental")]
+fn navigation_avoidance_callback_3d() -> godot::task::TaskHandle {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use godot::classes::{Engine, NavigationServer3D, SceneTree};
+
+    let mut server = NavigationServer3D::singleton();
+    let mut map = server.map_create_owned();
+    map.set_active(true);
+
+    let safe_velocity = Rc::new(Cell::new(None));
+    let velocity = Vector3::new(1.0, 0.0, 0.0);
+
+    let mut agent = server.agent_create_owned();
+    agent.set_map(*map);
+    agent.set_avoidance_enabled(true);
+    agent.set_max_speed(10.0);
+    agent.set_avoidance_callback({
+        let safe_velocity = safe_velocity.clone();
+        move |v| safe_velocity.set(Some(v))
+    });
+    agent.set_velocity(velocity);
+
+    let tree = Engine::singleton()
+        .get_main_loop()
+        .unwrap()
+        .cast::<SceneTree>();
+
+    godot::task::spawn(async move {
+        for _ in 0..3 {
+            let _: () = tree.signals().physics_frame().to_future().await;
+        }
+
+        // Unobstructed agent keeps its velocity.
+        let received = safe_velocity.get().expect("avoidance callback invoked");
+        assert!(received.is_equal_approx(velocity), "{received}");
+
+        agent.clear_avoidance_callback();
+        drop(agent);
+        drop(map);
+    })
+}
+
 #[itest]
 #[cfg(feature = "codegen-full")]
 fn owned_text_server_raii() {
@@ -541,6 +870,96 @@ fn owned_text_server_raii() {
     assert_eq!(rid_shaped, *shaped2);
 }
 
+#[itest]
+#[cfg(feature = "codegen-full")]
+fn owned_shaped_text_layout() {
+    use godot::classes::text_server::{Direction, GraphemeFlag, LineBreakFlag};
+    use godot::classes::{TextServerManager, ThemeDb};
+
+    let mut ts = TextServerManager::singleton()
+        .get_primary_interface()
+        .expect("primary text server");
+    let fallback = ThemeDb::singleton()
+        .get_fallback_font()
+        .expect("fallback font");
+    let fonts: Vec<Rid> = fallback.get_rids().iter_shared().collect();
+
+    let text = "hello brave new world";
+    let mut shaped = ts.create_shaped_text_owned();
+    shaped.set_direction(Direction::LTR);
+    assert!(shaped.add_string(text, &fonts, 16));
+    assert!(shaped.shape());
+    assert!(shaped.is_ready());
+
+    // Glyphs are decoded from the server's dictionaries; their advances add up to the line width.
+    let glyphs: Vec<_> = shaped.glyphs().collect();
+    assert!(!glyphs.is_empty());
+    assert_eq!(glyphs.len(), shaped.glyph_count());
+    assert!(glyphs.iter().all(|glyph| glyph.font_size == 16));
+    assert_eq!(glyphs[0].char_range(), 0..1);
+
+    let advance: f32 = glyphs
+        .iter()
+        .map(|glyph| glyph.advance * glyph.repeat as f32)
+        .sum();
+    assert!((advance - shaped.size().x).abs() < 0.01);
+
+    let words = shaped.word_breaks(GraphemeFlag::SPACE | GraphemeFlag::PUNCTUATION);
+    assert_eq!(words.len(), 4);
+    assert_eq!(words[0], 0..5);
+
+    let flags = LineBreakFlag::MANDATORY | LineBreakFlag::WORD_BOUND;
+    let half_width = shaped.width() / 2.0;
+    let lines = shaped.line_breaks(half_width, 0, flags);
+    assert!(lines.len() >= 2, "expected several lines: {lines:?}");
+
+    let first_line = shaped.substr(lines[0].clone());
+    assert!(first_line.width() <= half_width);
+
+    assert_eq!(shaped.hit_test_position(-10.0), Some(0));
+    assert_eq!(
+        shaped.hit_test_position(shaped.width() + 10.0),
+        Some(text.len())
+    );
+    assert!(shaped.carets(0).leading_caret.size.y > 0.0);
+
+    // Buffer can be reused after clearing.
+    shaped.clear();
+    assert!(shaped.add_string("x", &fonts, 16));
+    assert_eq!(shaped.glyphs().len(), 1);
+}
+
+#[itest]
+#[cfg(feature = "codegen-full")]
+fn owned_font_configuration() {
+    use godot::classes::{FontFile, TextServerManager, ThemeDb};
+
+    let mut ts = TextServerManager::singleton()
+        .get_primary_interface()
+        .expect("primary text server");
+    let Ok(fallback) = ThemeDb::singleton()
+        .get_fallback_font()
+        .expect("fallback font")
+        .try_cast::<FontFile>()
+    else {
+        return;
+    };
+
+    let mut font = ts.create_font_owned();
+    font.set_data_packed(&fallback.get_data());
+    font.set_embolden(0.5);
+    font.clear_variations();
+
+    assert!(font.has_char('a'));
+    assert!(font.ascent(16) > 0.0);
+    assert!(font.descent(16) > 0.0);
+
+    // Glyphs of a shaped text refer to the font that provided them.
+    let mut shaped = ts.create_shaped_text_owned();
+    assert!(shaped.add_string("abc", &[&font], 20));
+    assert!(shaped.glyphs().all(|glyph| glyph.font_rid == *font));
+}
+
 #[itest]
 #[cfg(feature = "codegen-full")]
 fn owned_rendering_device_raii() {
@@ -566,6 +985,167 @@ fn owned_rendering_device_raii() {
     assert_eq!(rid_buffer, *buffer2);
 }
 
+#[itest]
+#[cfg(feature = "codegen-full")]
+fn typed_rd_buffer_roundtrip() {
+    use godot::classes::rendering_device::UniformType;
+    use godot::classes::RenderingServer;
+    use godot::rendering_device::UniformSetBuilder;
+
+    let Some(mut rd) = RenderingServer::singleton().create_local_rendering_device() else {
+        crate::framework::skip_test("no rendering device");
+        return;
+    };
+
+    let mut buffer = rd.storage_buffer_create_typed(&[Vector4i::new(1, 2, 3, 4); 3]);
+    assert_eq!(buffer.len(), 3);
+    assert_eq!(buffer.size_bytes(), 48);
+
+    buffer.write_at(1, &[Vector4i::new(5, 6, 7, 8)]);
+    assert_eq!(
+        buffer.read(),
+        [
+            Vector4i::new(1, 2, 3, 4),
+            Vector4i::new(5, 6, 7, 8),
+            Vector4i::new(1, 2, 3, 4),
+        ]
+    );
+
+    let params = rd.uniform_buffer_create_typed(&[0.5f32; 4]);
+    let builder = UniformSetBuilder::new()
+        .storage_buffer(0, &buffer)
+        .uniform_buffer(1, &params);
+
+    let uniforms = builder.uniforms();
+    assert_eq!(uniforms.len(), 2);
+    assert_eq!(uniforms.at(0).get_binding(), 0);
+    assert_eq!(
+        uniforms.at(0).get_uniform_type(),
+        UniformType::STORAGE_BUFFER
+    );
+    assert_eq!(uniforms.at(0).get_ids().at(0), buffer.rid());
+    assert_eq!(
+        uniforms.at(1).get_uniform_type(),
+        UniformType::UNIFORM_BUFFER
+    );
+
+    drop(buffer);
+    drop(params);
+    rd.free();
+}
+
+#[itest(async)]
+#[cfg(all(feature = "codegen-full", since_api = "4.4"))]
+fn typed_rd_buffer_read_async() -> godot::task::TaskHandle {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use godot::classes::{Engine, RenderingServer, SceneTree};
+
+    let Some(mut rd) = RenderingServer::singleton().create_local_rendering_device() else {
+        crate::framework::skip_test("no rendering device");
+        return godot::task::spawn(async {});
+    };
+
+    let mut buffer = rd.storage_buffer_create_typed(&[1u32, 2, 3, 4]);
+    buffer.write_at(2, &[30, 40]);
+
+    // Await the future in a separate task, while the test task drives the device.
+    let result = Rc::new(RefCell::new(None));
+    let future = buffer.read_async();
+    godot::task::spawn({
+        let result = result.clone();
+        async move {
+            *result.borrow_mut() = Some(future.await);
+        }
+    });
+
+    let tree = Engine::singleton()
+        .get_main_loop()
+        .unwrap()
+        .cast::<SceneTree>();
+
+    godot::task::spawn(async move {
+        // Local devices process readback requests when synchronizing.
+        for _ in 0..5 {
+            if result.borrow().is_some() {
+                break;
+            }
+
+            rd.submit();
+            rd.sync();
+            let _: () = tree.signals().process_frame().to_future().await;
+        }
+
+        let data = result
+            .take()
+            .expect("read_async() resolved within 5 frames")
+            .expect("read_async() succeeded");
+        assert_eq!(data, [1, 2, 30, 40]);
+        assert_eq!(data, buffer.read());
+
+        drop(buffer);
+        rd.free();
+    })
+}
+
+#[itest]
+#[cfg(feature = "codegen-full")]
+fn compute_pipeline_multi_pass() {
+    use godot::rendering_device::helper::{ComputeList, ComputePipeline, ComputeShaderError};
+    use godot::rendering_device::UniformSetBuilder;
+
+    const SHADER: &str = r#"
+        #version 450
+        layout(local_size_x = 4) in;
+        layout(set = 0, binding = 0, std430) buffer Data { float values[]; };
+        layout(push_constant, std430) uniform Params { float factor; float offset; vec2 _pad; };
+
+        void main() {
+            uint i = gl_GlobalInvocationID.x;
+            values[i] = values[i] * factor + offset;
+        }
+    "#;
+
+    let mut pipeline = match ComputePipeline::new_local_from_glsl(SHADER) {
+        Ok(pipeline) => pipeline,
+        Err(ComputeShaderError::NoRenderingDevice) => {
+            // Headless or Compatibility renderer.
+            crate::framework::skip_test("no rendering device");
+            return;
+        }
+        Err(err) => panic!("valid shader must compile: {err}"),
+    };
+
+    let buffer = pipeline
+        .rd()
+        .storage_buffer_create_typed(&[1.0f32, 2.0, 3.0, 4.0]);
+    pipeline.bind_uniforms(&UniformSetBuilder::new().storage_buffer(0, &buffer));
+
+    // Two passes in one list: (x * 2 + 1) * 2 + 1.
+    pipeline.set_push_constant(&[2.0f32, 1.0, 0.0, 0.0]);
+    let mut list = ComputeList::begin(&pipeline.rd());
+    list.dispatch(&pipeline, 1, 1, 1)
+        .barrier()
+        .dispatch(&pipeline, 1, 1, 1);
+    list.end();
+    pipeline.submit(true);
+
+    assert_eq!(buffer.read(), [7.0, 11.0, 15.0, 19.0]);
+
+    // Compile errors are reported, not just printed.
+    let mut rd = pipeline.rd();
+    let err = godot::rendering_device::helper::compile_compute_shader(
+        &mut rd,
+        "#version 450\nvoid main() { undefined_function(); }",
+    )
+    .expect_err("invalid shader must not compile");
+    assert!(matches!(err, ComputeShaderError::Compile { .. }));
+
+    // Buffer must be freed before the pipeline frees its local device.
+    drop(buffer);
+}
+
 #[itest]
 #[cfg(feature = "codegen-full")]
 fn owned_physics_joints_raii() {
@@ -53,6 +53,7 @@ mod string {
 
 mod script {
     mod node_extension_test;
+    mod script_defaults_test;
     mod script_instance_tests;
 }
 
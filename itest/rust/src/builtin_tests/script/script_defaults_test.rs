@@ -0,0 +1,170 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Minimal script language, relying on ScriptLanguageDefaults/ScriptExtensionDefaults for all but a few virtual methods.
+
+use std::ffi::c_void;
+
+use godot::builtin::{GString, PackedStringArray, StringName, Variant};
+use godot::classes::{
+    Engine, Object, Script, ScriptExtension, ScriptLanguage, ScriptLanguageExtension,
+};
+use godot::global::Error;
+use godot::meta::{PropertyInfo, RawPtr};
+use godot::obj::script::{
+    create_script_instance, impl_script_extension, impl_script_language_extension,
+    PlaceholderScriptInstance, ScriptExtensionDefaults, ScriptLanguageDefaults,
+};
+use godot::obj::{Base, Gd, NewAlloc, WithBaseField};
+use godot::register::GodotClass;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=ScriptLanguageExtension, tool)]
+struct MiniLanguage {
+    #[init(val = GString::from("mini"))]
+    extension: GString,
+    base: Base<ScriptLanguageExtension>,
+}
+
+impl ScriptLanguageDefaults for MiniLanguage {
+    fn get_name(&self) -> GString {
+        GString::from(format!("MiniLanguage.{}", self.extension))
+    }
+
+    fn get_type(&self) -> GString {
+        GString::from(format!("MiniScript.{}", self.extension))
+    }
+
+    fn get_extension(&self) -> GString {
+        self.extension.clone()
+    }
+
+    fn get_reserved_words(&self) -> PackedStringArray {
+        PackedStringArray::from(["let", "print"].map(GString::from))
+    }
+}
+
+impl_script_language_extension!(MiniLanguage);
+
+#[derive(GodotClass)]
+#[class(no_init, base=ScriptExtension, tool)]
+struct MiniScript {
+    language: Gd<MiniLanguage>,
+    base: Base<ScriptExtension>,
+}
+
+impl MiniScript {
+    fn new(language: Gd<MiniLanguage>) -> Gd<Self> {
+        Gd::from_init_fn(|base| Self { language, base })
+    }
+}
+
+impl ScriptExtensionDefaults for MiniScript {
+    fn get_language(&self) -> Option<Gd<ScriptLanguage>> {
+        Some(self.language.clone().upcast())
+    }
+
+    fn can_instantiate(&self) -> bool {
+        true
+    }
+
+    unsafe fn instance_create_rawptr(&self, for_object: Gd<Object>) -> RawPtr<*mut c_void> {
+        let language = self.language.clone().upcast();
+        let instance = PlaceholderScriptInstance::new(self.to_gd().upcast(), language)
+            .with_property(PropertyInfo::new_var::<i64>("mini_value"), Variant::from(3));
+
+        create_script_instance(instance, for_object)
+    }
+}
+
+impl_script_extension!(MiniScript);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Test Cases
+
+#[itest]
+fn script_defaults_language_overrides_and_defaults() {
+    let language = MiniLanguage::new_alloc();
+
+    {
+        let lang = language.bind();
+
+        // Overridden.
+        assert_eq!(lang.get_reserved_words().len(), 2);
+
+        // Defaults.
+        assert_eq!(
+            lang.get_recognized_extensions(),
+            PackedStringArray::from([GString::from("mini")])
+        );
+        assert!(!lang.has_named_classes());
+        assert_eq!(lang.find_function(GString::new(), GString::new()), -1);
+
+        let validation = lang.validate(
+            GString::from("let x"),
+            GString::new(),
+            false,
+            false,
+            false,
+            false,
+        );
+        assert_eq!(validation.get("valid"), Some(Variant::from(true)));
+    }
+
+    language.free();
+}
+
+#[itest]
+fn script_defaults_language_registered_with_engine() {
+    let mut engine = Engine::singleton();
+    let count_before = engine.get_script_language_count();
+
+    let language = MiniLanguage::new_alloc();
+    let result = engine.register_script_language(&language);
+    assert_eq!(result, Error::OK);
+    assert_eq!(engine.get_script_language_count(), count_before + 1);
+
+    // Godot compares extension, name and type of registered languages, which requires the virtual methods to be registered.
+    let duplicate = MiniLanguage::new_alloc();
+    let result = engine.register_script_language(&duplicate);
+    assert_eq!(result, Error::ERR_ALREADY_EXISTS);
+    assert_eq!(engine.get_script_language_count(), count_before + 1);
+
+    let result = engine.unregister_script_language(&language);
+    assert_eq!(result, Error::OK);
+    assert_eq!(engine.get_script_language_count(), count_before);
+
+    duplicate.free();
+    language.free();
+}
+
+#[itest]
+fn script_defaults_script_attached_to_object() {
+    let language = MiniLanguage::new_alloc();
+    let script = MiniScript::new(language.clone());
+    let mut object = Object::new_alloc();
+
+    // Defaults, called through the engine.
+    let engine_script: Gd<Script> = script.clone().upcast();
+    assert_eq!(
+        engine_script.get_instance_base_type(),
+        StringName::from("Object")
+    );
+    assert!(!engine_script.has_source_code());
+    assert!(!engine_script.is_tool());
+    assert!(engine_script.get_script_method_list().is_empty());
+
+    // Overrides: the script can be attached and creates an instance.
+    assert!(engine_script.can_instantiate());
+    object.set_script(&script);
+    assert_eq!(object.get("mini_value"), Variant::from(3));
+
+    object.free();
+    language.free();
+}
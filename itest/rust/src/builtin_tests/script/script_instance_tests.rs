@@ -5,19 +5,32 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::cell::RefCell;
 use std::ffi::c_void;
+use std::io::Write;
 
-use godot::builtin::{Array, GString, StringName, VarArray, VarDictionary, Variant, VariantType};
+use godot::builtin::{
+    varray, Array, GString, PackedStringArray, StringName, VarArray, VarDictionary, Variant,
+    VariantType,
+};
+use godot::classes::file_access::ModeFlags;
+use godot::classes::notify::NodeNotification;
+use godot::classes::resource_loader::CacheMode;
 use godot::classes::{
-    IScriptExtension, IScriptLanguageExtension, Object, Script, ScriptExtension, ScriptLanguage,
+    IResourceFormatLoader, IScriptExtension, IScriptLanguageExtension, Node, Object,
+    ResourceFormatLoader, ResourceLoader, Script, ScriptExtension, ScriptLanguage,
     ScriptLanguageExtension,
 };
 use godot::global::{Error, MethodFlags};
 use godot::meta::error::CallErrorType;
 use godot::meta::{ClassId, FromGodot, MethodInfo, PropertyInfo, RawPtr, ToGodot};
-use godot::obj::script::{create_script_instance, ScriptInstance, SiMut};
-use godot::obj::{Base, Gd, NewAlloc, WithBaseField};
+use godot::obj::script::{
+    create_script_instance, PlaceholderScriptInstance, ScriptFormatLoader, ScriptInstance,
+    ScriptRegistry, SiMut,
+};
+use godot::obj::{Base, Gd, NewAlloc, NewGd, Singleton, WithBaseField};
 use godot::register::{godot_api, GodotClass};
+use godot::tools::GFile;
 
 use crate::framework::itest;
 
@@ -25,12 +38,26 @@ use crate::framework::itest;
 #[class(base = ScriptExtension, no_init, tool)]
 struct TestScript {
     language: Gd<TestScriptLanguage>,
+    /// Whether instances are created as [`PlaceholderScriptInstance`].
+    placeholder: bool,
     base: Base<ScriptExtension>,
 }
 
 impl TestScript {
     fn new(language: Gd<TestScriptLanguage>) -> Gd<Self> {
-        Gd::from_init_fn(|base| Self { language, base })
+        Gd::from_init_fn(|base| Self {
+            language,
+            placeholder: false,
+            base,
+        })
+    }
+
+    fn new_placeholder(language: Gd<TestScriptLanguage>) -> Gd<Self> {
+        Gd::from_init_fn(|base| Self {
+            language,
+            placeholder: true,
+            base,
+        })
     }
 }
 
@@ -42,6 +69,15 @@ impl IScriptExtension for TestScript {
     }
 
     unsafe fn instance_create_rawptr(&self, for_object: Gd<Object>) -> RawPtr<*mut c_void> {
+        if self.placeholder {
+            let language = self.language.clone().upcast();
+            let property = PropertyInfo::new_var::<i64>("placeholder_property");
+            let instance = PlaceholderScriptInstance::new(self.to_gd().upcast(), language)
+                .with_property(property, Variant::from(7));
+
+            return create_script_instance(instance, for_object);
+        }
+
         create_script_instance(TestScriptInstance::new(self.to_gd().upcast()), for_object)
     }
 
@@ -86,6 +122,8 @@ impl IScriptExtension for TestScript {
 struct TestScriptInstance {
     /// A field to store the value of the `script_property_b` during tests.
     script_property_b: bool,
+    /// Notifications received from the engine, with their `reversed` flag.
+    notifications: Vec<(i32, bool)>,
     prop_list: Vec<PropertyInfo>,
     method_list: Vec<MethodInfo>,
     script: Gd<Script>,
@@ -102,6 +140,7 @@ impl TestScriptInstance {
             },
             script: script.upcast(),
             script_property_b: false,
+            notifications: vec![],
             prop_list: vec![PropertyInfo::new_var::<i64>("script_property_a")],
 
             method_list: vec![MethodInfo {
@@ -188,6 +227,16 @@ impl ScriptInstance for TestScriptInstance {
                 Ok(result)
             }
 
+            "script_method_notifications" => {
+                let notifications: VarArray = this
+                    .notifications
+                    .iter()
+                    .map(|&(what, reversed)| varray![what, reversed].to_variant())
+                    .collect();
+
+                Ok(notifications.to_variant())
+            }
+
             other => {
                 println!("CALL: {other} with args: {args:?}");
                 Err(CallErrorType::InvalidMethod)
@@ -240,6 +289,24 @@ impl ScriptInstance for TestScriptInstance {
         false
     }
 
+    fn property_get_revert(&self, name: StringName) -> Option<Variant> {
+        match name.to_string().as_str() {
+            "script_property_b" => Some(Variant::from(false)),
+            _ => None,
+        }
+    }
+
+    fn validate_property(&self, property: &mut PropertyInfo) {
+        // Engine-defined property of `Node`, to check that class properties pass through the script instance as well.
+        if property.property_name == "editor_description" {
+            property.hint_info.hint_string = GString::from("validated by script");
+        }
+    }
+
+    fn on_notification(mut this: SiMut<Self>, what: i32, reversed: bool) {
+        this.notifications.push((what, reversed));
+    }
+
     #[cfg(since_api = "4.3")]
     fn get_method_argument_count(&self, _method: StringName) -> Option<u32> {
         None
@@ -332,6 +399,60 @@ impl IScriptLanguageExtension for TestScriptLanguage {
     fn reload_scripts(&mut self, _scripts: VarArray, _soft: bool) { unreachable!() }
 }
 
+thread_local! {
+    /// Language of scripts created by `TestScriptLoader`. Set for the duration of a test.
+    static LOADER_LANGUAGE: RefCell<Option<Gd<TestScriptLanguage>>> = const { RefCell::new(None) };
+}
+
+/// Source code of scripts created by `TestScriptLoader`.
+static LOADED_SOURCES: ScriptRegistry<String> = ScriptRegistry::new();
+
+#[derive(GodotClass)]
+#[class(base = ResourceFormatLoader, tool, init)]
+struct TestScriptLoader {
+    base: Base<ResourceFormatLoader>,
+}
+
+impl ScriptFormatLoader for TestScriptLoader {
+    type Script = TestScript;
+    const EXTENSIONS: &'static [&'static str] = &["tdsl"];
+
+    fn load_script(_path: &GString, source: GString) -> Result<Gd<TestScript>, Error> {
+        let language = LOADER_LANGUAGE
+            .with_borrow(Clone::clone)
+            .ok_or(Error::ERR_UNCONFIGURED)?;
+
+        let script = TestScript::new(language);
+        LOADED_SOURCES.insert(&script, source.to_string());
+        Ok(script)
+    }
+}
+
+#[godot_api]
+impl IResourceFormatLoader for TestScriptLoader {
+    fn get_recognized_extensions(&self) -> PackedStringArray {
+        Self::recognized_extensions()
+    }
+
+    fn handles_type(&self, type_: StringName) -> bool {
+        Self::handles_script_type(&type_)
+    }
+
+    fn get_resource_type(&self, path: GString) -> GString {
+        Self::script_type_for_path(&path)
+    }
+
+    fn load(
+        &self,
+        path: GString,
+        _original_path: GString,
+        _use_sub_threads: bool,
+        _cache_mode: i32,
+    ) -> Variant {
+        Self::load_from_path(&path)
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Test Cases
 
@@ -355,3 +476,181 @@ fn script_instance_exists() {
     object.free();
     language.free();
 }
+
+#[itest]
+fn script_instance_property_revert() {
+    let language = TestScriptLanguage::new_alloc();
+    let script = TestScript::new(language.clone());
+    let mut object = Object::new_alloc();
+
+    object.set_script(&script);
+
+    assert!(object.property_can_revert("script_property_b"));
+    assert_eq!(
+        object.property_get_revert("script_property_b"),
+        Variant::from(false)
+    );
+
+    assert!(!object.property_can_revert("script_property_a"));
+    assert!(!object.property_can_revert("unknown_property"));
+
+    object.free();
+    language.free();
+}
+
+#[itest]
+fn script_instance_placeholder() {
+    let language = TestScriptLanguage::new_alloc();
+    let script = TestScript::new_placeholder(language.clone());
+    let mut object = Object::new_alloc();
+
+    object.set_script(&script);
+
+    assert_eq!(object.get("placeholder_property"), Variant::from(7));
+
+    object.set("placeholder_property", &Variant::from(12));
+    assert_eq!(object.get("placeholder_property"), Variant::from(12));
+
+    // Placeholders revert to the declared default, and do not run any methods.
+    assert!(object.property_can_revert("placeholder_property"));
+    assert_eq!(
+        object.property_get_revert("placeholder_property"),
+        Variant::from(7)
+    );
+    assert!(!object.has_method("script_method_a"));
+
+    object.free();
+    language.free();
+}
+
+#[itest]
+fn script_registry_maps_scripts_to_data() {
+    static REGISTRY: ScriptRegistry<String> = ScriptRegistry::new();
+
+    let language = TestScriptLanguage::new_alloc();
+    let script_a = TestScript::new(language.clone());
+    let script_b = TestScript::new(language.clone());
+
+    assert!(REGISTRY.insert(&script_a, "source A".to_string()).is_none());
+    assert_eq!(
+        REGISTRY.get(&script_a).as_deref(),
+        Some(&"source A".to_string())
+    );
+    assert!(!REGISTRY.contains(&script_b));
+
+    let upcast: Gd<Script> = script_a.clone().upcast();
+    assert!(REGISTRY.contains(&upcast));
+
+    let removed = REGISTRY.remove(&script_a);
+    assert_eq!(removed.as_deref(), Some(&"source A".to_string()));
+    assert!(REGISTRY.get(&script_a).is_none());
+
+    REGISTRY.clear();
+    language.free();
+}
+
+#[itest]
+fn script_instance_validate_property() {
+    let language = TestScriptLanguage::new_alloc();
+    let script = TestScript::new(language.clone());
+    let mut node = Node::new_alloc();
+
+    let hint_string = |node: &Gd<Node>| {
+        node.get_property_list()
+            .iter_shared()
+            .find(|property| property.get("name") == Some("editor_description".to_variant()))
+            .and_then(|property| property.get("hint_string"))
+            .expect("Node has property `editor_description`")
+    };
+
+    let validated = "validated by script".to_variant();
+    assert_ne!(hint_string(&node), validated);
+
+    node.set_script(&script);
+    assert_eq!(hint_string(&node), validated);
+
+    node.free();
+    language.free();
+}
+
+#[itest]
+fn script_instance_notification() {
+    const NOTIFICATION: i32 = 9001;
+
+    let language = TestScriptLanguage::new_alloc();
+    let script = TestScript::new(language.clone());
+    let mut node = Node::new_alloc();
+
+    node.set_script(&script);
+    node.notify(NodeNotification::Unknown(NOTIFICATION));
+    node.notify_reversed(NodeNotification::Unknown(NOTIFICATION + 1));
+
+    let received = node
+        .call("script_method_notifications", &[])
+        .to::<VarArray>();
+
+    assert!(received.contains(&varray![NOTIFICATION, false].to_variant()));
+    assert!(received.contains(&varray![NOTIFICATION + 1, true].to_variant()));
+    assert!(!received.contains(&varray![NOTIFICATION, true].to_variant()));
+
+    node.free();
+    language.free();
+}
+
+#[itest]
+fn script_format_loader_loads_file() {
+    const PATH: &str = "res://script_format_loader_test.tdsl";
+    const SOURCE: &str = "print hello";
+
+    let language = TestScriptLanguage::new_alloc();
+    LOADER_LANGUAGE.set(Some(language.clone()));
+
+    let mut file = GFile::open(PATH, ModeFlags::WRITE).unwrap();
+    file.write_all(SOURCE.as_bytes())
+        .expect("couldn't write script file");
+    drop(file);
+
+    let format_loader = TestScriptLoader::new_gd();
+    let mut loader = ResourceLoader::singleton();
+    loader.add_resource_format_loader(&format_loader);
+
+    // Queries Godot makes before loading.
+    assert_eq!(
+        TestScriptLoader::script_type_for_path(&PATH.into()),
+        GString::from("TestScript")
+    );
+    assert_eq!(
+        TestScriptLoader::script_type_for_path(&"res://script.gd".into()),
+        GString::new()
+    );
+    assert!(TestScriptLoader::handles_script_type(&"Script".into()));
+    assert!(!TestScriptLoader::handles_script_type(&"Texture2D".into()));
+
+    let extensions = loader.get_recognized_extensions_for_type("Script");
+    assert!(extensions.contains("tdsl"));
+
+    let script = loader
+        .load_ex(PATH)
+        .type_hint("Script")
+        .cache_mode(CacheMode::IGNORE)
+        .done()
+        .expect("script loaded through TestScriptLoader")
+        .cast::<TestScript>();
+
+    assert_eq!(
+        LOADED_SOURCES.get(&script).as_deref(),
+        Some(&SOURCE.to_string())
+    );
+
+    loader.remove_resource_format_loader(&format_loader);
+    LOADED_SOURCES.remove(&script);
+    LOADER_LANGUAGE.set(None);
+    language.free();
+
+    let file_path = std::path::Path::new(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../godot/script_format_loader_test.tdsl"
+    ));
+    std::fs::remove_file(file_path)
+        .unwrap_or_else(|_| panic!("couldn't remove test file: {}", file_path.display()));
+}
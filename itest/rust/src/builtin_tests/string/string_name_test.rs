@@ -7,7 +7,7 @@
 
 use std::collections::HashSet;
 
-use godot::builtin::{static_sname, Encoding, GString, NodePath, StringName};
+use godot::builtin::{match_sname, sname, static_sname, Encoding, GString, NodePath, StringName};
 
 #[cfg(since_api = "4.5")]
 use super::string_test_macros::{APPLE_CHARS, APPLE_STR};
@@ -167,6 +167,59 @@ fn string_name_static_sname() {
     assert_eq!(a, b);
 }
 
+#[itest]
+fn string_name_sname() {
+    let a: &'static StringName = sname!("pure ASCII\t[~]");
+    let b = StringName::from("pure ASCII\t[~]");
+
+    assert_eq!(*a, b);
+    assert!(a.ptr_eq(&b));
+    assert!(a.ptr_eq(static_sname!(c"pure ASCII\t[~]")));
+
+    // Same literal at different call sites yields the same interned name.
+    let a2 = sname!("pure ASCII\t[~]");
+    assert!(a.ptr_eq(a2));
+
+    let utf8 = sname!("UTF-8 ± ¾ ✓");
+    assert_eq!(*utf8, StringName::from("UTF-8 ± ¾ ✓"));
+    assert!(!utf8.ptr_eq(a));
+
+    assert!(sname!("").ptr_eq(&StringName::default()));
+}
+
+#[itest]
+fn string_name_match_sname() {
+    fn dispatch(name: &StringName) -> i32 {
+        match_sname! { name,
+            "first" => 1,
+            "second" | "zweite" => 2,
+            _ => 0,
+        }
+    }
+
+    assert_eq!(dispatch(&StringName::from("first")), 1);
+    assert_eq!(dispatch(&StringName::from("second")), 2);
+    assert_eq!(dispatch(&StringName::from("zweite")), 2);
+    assert_eq!(dispatch(&StringName::from("First")), 0);
+    assert_eq!(dispatch(&StringName::default()), 0);
+
+    // Fallback binds the original (owned) subject.
+    let fallback = match_sname! { StringName::from("unknown"),
+        "first" => StringName::default(),
+        other => other,
+    };
+    assert_eq!(fallback, StringName::from("unknown"));
+
+    // Unit type without fallback.
+    let mut hits = 0;
+    for name in ["a", "b", "a"] {
+        match_sname! { StringName::from(name),
+            "a" => hits += 1,
+        }
+    }
+    assert_eq!(hits, 2);
+}
+
 #[itest]
 fn string_name_with_null() {
     // Godot always ignores bytes after a null byte.
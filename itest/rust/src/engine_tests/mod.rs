@@ -17,7 +17,12 @@ mod native_st_niche_audio_test;
 mod native_st_niche_pointer_test;
 mod native_structures_test;
 mod node_test;
+mod physics_query_test;
+#[cfg(feature = "profiling")]
+mod profiling_test;
 mod save_load_test;
+#[cfg(feature = "codegen-third-party")]
+mod third_party_test;
 mod translate_test;
 mod utilities_test;
 
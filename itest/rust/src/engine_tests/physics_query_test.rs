@@ -0,0 +1,227 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "codegen-full")]
+
+use std::sync::Arc;
+
+use godot::builtin::{vdict, Rid, Transform3D, VarDictionary, Variant, Vector2, Vector3};
+use godot::classes::physics_server_3d::{BodyMode, ShapeType};
+use godot::classes::{Engine, Node, PhysicsServer3D, SceneTree};
+use godot::meta::ToGodot;
+use godot::obj::{NewAlloc, Singleton};
+use godot::physics::{RayHit2D, RayHit3D, RayQuery3D, RestInfo3D, ShapeHit3D, ShapeQuery3D};
+
+use crate::framework::{itest, suppress_godot_print};
+
+#[itest]
+fn ray_query_3d_to_parameters() {
+    let excluded = Rid::new(1234);
+    let query = RayQuery3D::new(Vector3::ZERO, Vector3::new(0.0, -5.0, 0.0))
+        .with_collision_mask(0b110)
+        .with_exclude([excluded])
+        .with_areas(true)
+        .with_bodies(false)
+        .with_hit_back_faces(false);
+
+    let params = query.to_parameters();
+    assert_eq!(params.get_from(), Vector3::ZERO);
+    assert_eq!(params.get_to(), Vector3::new(0.0, -5.0, 0.0));
+    assert_eq!(params.get_collision_mask(), 0b110);
+    assert_eq!(params.get_exclude().len(), 1);
+    assert_eq!(params.get_exclude().at(0), excluded);
+    assert!(params.is_collide_with_areas_enabled());
+    assert!(!params.is_collide_with_bodies_enabled());
+    assert!(!params.is_hit_back_faces_enabled());
+}
+
+#[itest]
+fn shape_query_3d_to_parameters() {
+    let shape = Rid::new(42);
+    let query = ShapeQuery3D::new(shape, Transform3D::IDENTITY)
+        .with_motion(Vector3::RIGHT)
+        .with_margin(0.5);
+
+    let params = query.to_parameters();
+    assert_eq!(params.get_shape_rid(), shape);
+    assert_eq!(params.get_motion(), Vector3::RIGHT);
+    assert_eq!(params.get_margin(), 0.5);
+    assert!(params.is_collide_with_bodies_enabled());
+    assert!(!params.is_collide_with_areas_enabled());
+}
+
+#[itest]
+fn ray_hit_from_dictionary() {
+    assert!(RayHit3D::from_dictionary(&VarDictionary::new()).is_none());
+
+    let node = Node::new_alloc();
+    let rid = Rid::new(7);
+    let dict = vdict! {
+        "position": Vector3::new(1.0, 2.0, 3.0),
+        "normal": Vector3::UP,
+        "collider_id": node.instance_id().to_i64(),
+        "collider": node.clone(),
+        "rid": rid,
+        "shape": 2,
+        "face_index": 5,
+    };
+
+    let hit = RayHit3D::from_dictionary(&dict).expect("dictionary describes a hit");
+    assert_eq!(hit.position, Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(hit.normal, Vector3::UP);
+    assert_eq!(hit.collider_id, Some(node.instance_id()));
+    assert_eq!(
+        hit.collider.map(|c| c.instance_id()),
+        Some(node.instance_id())
+    );
+    assert_eq!(hit.rid, rid);
+    assert_eq!(hit.shape, 2);
+    assert_eq!(hit.face_index, 5);
+
+    // Server-only objects have no collider.
+    let dict = vdict! {
+        "position": Vector2::new(1.0, 2.0),
+        "normal": Vector2::UP,
+        "collider_id": 0,
+        "collider": Variant::nil(),
+        "rid": rid,
+        "shape": 0,
+    };
+
+    let hit = RayHit2D::from_dictionary(&dict).expect("dictionary describes a hit");
+    assert_eq!(hit.collider_id, None);
+    assert!(hit.collider.is_none());
+
+    node.free();
+}
+
+#[itest]
+fn shape_hit_and_rest_info_from_dictionary() {
+    let rid = Rid::new(9);
+    let dict = vdict! {
+        "rid": rid,
+        "collider_id": 0,
+        "shape": 1,
+    };
+
+    let hit = ShapeHit3D::from_dictionary(&dict).expect("dictionary describes a hit");
+    assert_eq!(hit.rid, rid);
+    assert_eq!(hit.shape, 1);
+
+    let dict = vdict! {
+        "point": Vector3::ONE,
+        "normal": Vector3::UP,
+        "rid": rid,
+        "collider_id": 0,
+        "shape": 0,
+        "linear_velocity": Vector3::new(0.0, -1.0, 0.0),
+    };
+
+    let info = RestInfo3D::from_dictionary(&dict).expect("dictionary describes a contact");
+    assert_eq!(info.point, Vector3::ONE);
+    assert_eq!(info.linear_velocity, Vector3::new(0.0, -1.0, 0.0));
+}
+
+#[itest]
+fn owned_space_direct_state() {
+    let mut server = PhysicsServer3D::singleton();
+    let space = server.space_create_owned();
+    server.space_set_active(space.rid(), true);
+
+    assert!(space.direct_state().is_some());
+
+    server.space_set_active(space.rid(), false);
+}
+
+#[itest]
+fn cast_motion_invalid_shape_is_none() {
+    let mut server = PhysicsServer3D::singleton();
+    let space = server.space_create_owned();
+    server.space_set_active(space.rid(), true);
+
+    let mut state = space.direct_state().expect("active space has direct state");
+    let query = ShapeQuery3D::new(Rid::Invalid, Transform3D::IDENTITY).with_motion(Vector3::DOWN);
+
+    // Godot reports the invalid shape and returns an empty array, which must not be mistaken for an unobstructed motion.
+    let mut result = None;
+    suppress_godot_print(|| result = state.cast_motion_result(&query));
+    assert_eq!(result, None);
+
+    server.space_set_active(space.rid(), false);
+}
+
+#[itest(async)]
+fn queries_against_static_body() -> godot::task::TaskHandle {
+    let mut server = PhysicsServer3D::singleton();
+    let space = Arc::new(server.space_create_owned());
+    server.space_set_active(space.rid(), true);
+
+    // Box with half extents 1, centered at the origin.
+    let box_shape = Arc::new(server.shape_create_owned(ShapeType::BOX));
+    server.shape_set_data(box_shape.rid(), &Vector3::ONE.to_variant());
+
+    let mut body = server.body_create_owned();
+    body.set_mode(BodyMode::STATIC);
+    body.add_shape(&box_shape, Transform3D::IDENTITY);
+    body.set_space(&space);
+
+    // Smaller box used as the moving query shape.
+    let probe_shape = server.shape_create_owned(ShapeType::BOX);
+    server.shape_set_data(probe_shape.rid(), &Vector3::splat(0.5).to_variant());
+
+    let tree = Engine::singleton()
+        .get_main_loop()
+        .unwrap()
+        .cast::<SceneTree>();
+
+    godot::task::spawn(async move {
+        // The broadphase is only updated during a physics step.
+        for _ in 0..3 {
+            let _: () = tree.signals().physics_frame().to_future().await;
+        }
+
+        let mut state = space.direct_state().expect("active space has direct state");
+
+        let hit = state
+            .cast_ray(&RayQuery3D::new(
+                Vector3::new(0.0, 5.0, 0.0),
+                Vector3::new(0.0, -5.0, 0.0),
+            ))
+            .expect("ray hits the box");
+        assert!(
+            hit.position.is_equal_approx(Vector3::new(0.0, 1.0, 0.0)),
+            "{}",
+            hit.position
+        );
+        assert!(hit.normal.is_equal_approx(Vector3::UP), "{}", hit.normal);
+        assert_eq!(hit.rid, body.rid());
+        assert_eq!(hit.collider_id, None);
+
+        let miss = state.cast_ray(&RayQuery3D::new(
+            Vector3::new(5.0, 5.0, 0.0),
+            Vector3::new(5.0, -5.0, 0.0),
+        ));
+        assert!(miss.is_none());
+
+        // Probe starts at y=5 and moves 10 units down; it touches the box top (y=1) after 3.5 units.
+        let start = Transform3D::IDENTITY.translated(Vector3::new(0.0, 5.0, 0.0));
+        let query =
+            ShapeQuery3D::new(probe_shape.rid(), start).with_motion(Vector3::new(0.0, -10.0, 0.0));
+        let motion = state.cast_motion_result(&query).expect("valid query");
+        assert!(!motion.is_unobstructed());
+        assert!((motion.safe_fraction - 0.35).abs() < 0.02, "{motion:?}");
+        assert!(motion.unsafe_fraction >= motion.safe_fraction);
+
+        let query =
+            ShapeQuery3D::new(probe_shape.rid(), start).with_motion(Vector3::new(0.0, 2.0, 0.0));
+        let motion = state.cast_motion_result(&query).expect("valid query");
+        assert!(motion.is_unobstructed());
+
+        server.space_set_active(space.rid(), false);
+        drop(body);
+    })
+}
@@ -0,0 +1,120 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::sync::Arc;
+
+use godot::meta::profiling::{set_call_profiler, CallKind, CallStats, CallStatsEntry};
+use godot::prelude::*;
+
+use crate::framework::{itest, suppress_panic_log};
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct ProfiledObj {}
+
+#[godot_api]
+impl ProfiledObj {
+    #[func]
+    fn double(&self, value: i64) -> i64 {
+        value * 2
+    }
+
+    #[func]
+    fn explode(&self) {
+        panic!("profiled panic");
+    }
+}
+
+fn find<'a>(entries: &'a [CallStatsEntry], function_name: &str) -> Option<&'a CallStatsEntry> {
+    entries
+        .iter()
+        .find(|entry| entry.function_name == function_name)
+}
+
+#[itest]
+fn profiling_reports_engine_calls() {
+    let stats = Arc::new(CallStats::new());
+    set_call_profiler(Some(stats.clone()));
+
+    let mut node = Node::new_alloc();
+    node.set_name("profiled");
+    node.set_name("profiled_again");
+    node.free();
+
+    set_call_profiler(None);
+    let entries = stats.snapshot();
+
+    let set_name = find(&entries, "set_name").expect("set_name() is reported");
+    assert_eq!(set_name.class_name, "Node");
+    assert_eq!(set_name.kind, CallKind::ClassPtrcall);
+    assert_eq!(set_name.count, 2);
+    assert!(set_name.max <= set_name.total);
+}
+
+#[itest]
+fn profiling_reports_func_calls() {
+    let mut obj = ProfiledObj::new_gd();
+
+    let stats = Arc::new(CallStats::new());
+    set_call_profiler(Some(stats.clone()));
+
+    let result = obj.call("double", vslice![21]);
+
+    set_call_profiler(None);
+    let entries = stats.snapshot();
+
+    assert_eq!(result, 42.to_variant());
+
+    // Outbound Object::call() contains the inbound #[func] varcall.
+    let call = find(&entries, "call").expect("Object::call() is reported");
+    assert_eq!(call.kind, CallKind::ClassVarcall);
+
+    let double = find(&entries, "double").expect("#[func] is reported");
+    assert_eq!(double.class_name, "ProfiledObj");
+    assert_eq!(double.kind, CallKind::FuncVarcall);
+    assert!(double.kind.is_inbound());
+    assert_eq!(double.count, 1);
+    assert_eq!(double.panicked, 0);
+    assert!(double.total <= call.total);
+}
+
+#[itest]
+fn profiling_reports_panicking_calls() {
+    let mut obj = ProfiledObj::new_gd();
+
+    let stats = Arc::new(CallStats::new());
+    set_call_profiler(Some(stats.clone()));
+
+    let result = suppress_panic_log(|| obj.try_call("explode", &[]));
+
+    set_call_profiler(None);
+    let entries = stats.snapshot();
+
+    assert!(result.is_err(), "panic should cause a call error");
+
+    let explode = find(&entries, "explode").expect("panicking #[func] is reported");
+    assert_eq!(explode.kind, CallKind::FuncVarcall);
+    assert_eq!(explode.count, 1);
+    assert_eq!(explode.panicked, 1);
+    assert!(explode.to_string().ends_with(", 1 panicked"));
+}
+
+#[itest]
+fn profiling_stats_reset() {
+    let stats = Arc::new(CallStats::new());
+    set_call_profiler(Some(stats.clone()));
+
+    let node = Node::new_alloc();
+    let _ = node.get_name();
+    node.free();
+
+    set_call_profiler(None);
+    assert!(!stats.snapshot().is_empty());
+
+    stats.reset();
+    assert!(stats.snapshot().is_empty());
+}
@@ -0,0 +1,58 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Bindings generated from itest/rust/third-party-api.json. `ProvidedCounter` is registered by itest/shared-provider, while
+// `UnloadedExtensionClass` is registered by no loaded GDExtension.
+
+use std::panic;
+
+use godot::classes::third_party::{ProvidedCounter, UnloadedExtensionClass};
+use godot::obj::NewGd;
+
+use crate::framework::{itest, suppress_panic_log};
+
+#[itest]
+fn third_party_class_not_loaded() {
+    expect_panic_message(
+        "class `UnloadedExtensionClass` is not registered in Godot; make sure the GDExtension providing it is loaded",
+        || {
+            let _ = UnloadedExtensionClass::new_gd();
+        },
+    );
+}
+
+#[itest]
+fn third_party_method_not_available() {
+    let counter = ProvidedCounter::new_gd();
+
+    expect_panic_message(
+        "method `ProvidedCounter::removed_method` (hash 3905245786) is not available",
+        || {
+            let _ = counter.removed_method();
+        },
+    );
+
+    // The class itself is usable.
+    assert_eq!(counter.get_class(), "ProvidedCounter".into());
+}
+
+/// Runs `code`, which must panic with a message containing `expected`.
+fn expect_panic_message(expected: &str, code: impl FnOnce()) {
+    let result = suppress_panic_log(|| panic::catch_unwind(panic::AssertUnwindSafe(code)));
+    let payload = result.expect_err("code should have panicked");
+
+    let message = payload
+        .downcast_ref::<String>()
+        .map(String::as_str)
+        .or_else(|| payload.downcast_ref::<&str>().copied())
+        .unwrap_or_default();
+
+    assert!(
+        message.contains(expected),
+        "panic message `{message}` should contain `{expected}`"
+    );
+}
@@ -5,6 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::cell::RefCell;
 use std::collections::HashSet;
 use std::panic;
 
@@ -317,6 +318,23 @@ pub fn suppress_godot_print(mut f: impl FnMut()) {
     Engine::singleton().set_print_error_messages(true);
 }
 
+thread_local! {
+    static SKIP_REASON: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Marks the running test as skipped, for tests that depend on engine capabilities only known at runtime (e.g. a rendering device).
+///
+/// The test should return right after calling this. Instead of passing, it is then reported as skipped, together with `reason`.
+#[allow(dead_code)] // Only used by tests requiring `codegen-full`.
+pub fn skip_test(reason: &str) {
+    SKIP_REASON.set(Some(reason.to_string()));
+}
+
+/// Returns the reason passed to [`skip_test()`] during the last test, and resets it.
+fn take_skip_reason() -> Option<String> {
+    SKIP_REASON.take()
+}
+
 /// Some tests are disabled, as they rely on Godot checks which are only available in Debug builds.
 /// See <https://github.com/godotengine/godot/issues/86264>.
 pub fn runs_release() -> bool {
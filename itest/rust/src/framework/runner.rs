@@ -15,7 +15,7 @@ use godot::register::{godot_api, GodotClass};
 
 use super::AsyncRustTestCase;
 use crate::framework::{
-    bencher, passes_filter, BenchResult, RustBenchmark, RustTestCase, TestContext,
+    bencher, passes_filter, take_skip_reason, BenchResult, RustBenchmark, RustTestCase, TestContext,
 };
 
 #[derive(Debug, Clone, Default)]
@@ -429,7 +429,10 @@ fn run_rust_test(test: &RustTestCase, ctx: &TestContext) -> TestOutcome {
     // Explicit type to prevent tests from returning a value.
     let success: Result<(), _> = godot::private::handle_panic(err_context, || (test.function)(ctx));
 
-    TestOutcome::from_bool(success.is_ok())
+    match take_skip_reason() {
+        Some(reason) if success.is_ok() => TestOutcome::skipped_at_runtime(&reason),
+        _ => TestOutcome::from_bool(success.is_ok()),
+    }
 }
 
 fn run_async_rust_test(
@@ -446,10 +449,16 @@ fn run_async_rust_test(
     let success: Result<godot::task::TaskHandle, _> =
         godot::private::handle_panic(err_context, || (test.function)(ctx));
 
+    let skip_reason = take_skip_reason();
     let Ok(task_handle) = success else {
         return on_test_finished(TestOutcome::Failed);
     };
 
+    // Skipped tests return a placeholder task, which is not awaited.
+    if let Some(reason) = skip_reason {
+        return on_test_finished(TestOutcome::skipped_at_runtime(&reason));
+    }
+
     check_async_test_task(task_handle, on_test_finished, ctx);
 }
 
@@ -625,6 +634,12 @@ impl TestOutcome {
             Self::Failed
         }
     }
+
+    /// Prints the reason on the test's line; the outcome follows.
+    fn skipped_at_runtime(reason: &str) -> Self {
+        print!("{FMT_YELLOW}({reason}){FMT_END} ");
+        Self::Skipped
+    }
 }
 
 impl std::fmt::Display for TestOutcome {
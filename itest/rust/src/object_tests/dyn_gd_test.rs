@@ -4,11 +4,11 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
-use godot::global::godot_str;
+use godot::global::{godot_str, PropertyHint};
 // Test that all important dyn-related symbols are in the prelude.
 use godot::prelude::*;
 
-use crate::framework::{expect_panic, itest};
+use crate::framework::{expect_panic, itest, suppress_panic_log};
 
 #[itest]
 fn dyn_gd_creation_bind() {
@@ -579,6 +579,46 @@ fn dyn_gd_multiple_traits() {
     obj.free();
 }
 
+#[itest]
+fn dyn_gd_export_node_hint_lists_implementors() {
+    let exporter = DynGdNodeExporter::new_alloc();
+
+    let property = exporter
+        .get_property_list()
+        .iter_shared()
+        .find(|p| p.get_or_nil("name") == "health_node".to_variant())
+        .expect("health_node property exists");
+
+    assert_eq!(
+        property.get_or_nil("hint"),
+        PropertyHint::NODE_TYPE.to_variant()
+    );
+    assert_eq!(
+        property.get_or_nil("hint_string"),
+        "NodeHealth".to_variant()
+    );
+
+    exporter.free();
+}
+
+#[itest]
+fn dyn_gd_export_node_rejects_non_implementor() {
+    let mut exporter = DynGdNodeExporter::new_alloc();
+    let implementor = foreign::NodeHealth::new_alloc();
+    let unrelated = Node::new_alloc();
+
+    exporter.set("health_node", &implementor.to_variant());
+    assert_eq!(exporter.get("health_node"), implementor.to_variant());
+
+    // Setter fails with an error; previous value is kept.
+    suppress_panic_log(|| exporter.set("health_node", &unrelated.to_variant()));
+    assert_eq!(exporter.get("health_node"), implementor.to_variant());
+
+    unrelated.free();
+    implementor.free();
+    exporter.free();
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Example symbols
 
@@ -696,3 +736,10 @@ struct DynGdExporter {
     #[export]
     second: OnEditor<DynGd<Resource, dyn Health>>,
 }
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct DynGdNodeExporter {
+    #[export]
+    health_node: Option<DynGd<Node, dyn Health>>,
+}
@@ -0,0 +1,224 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use godot::classes::{Engine, RefCounted, SceneTree};
+use godot::obj::{Base, NewGd, ThreadSafeGd, WithBaseField};
+use godot::register::GodotClass;
+use godot::task::TaskHandle;
+
+use crate::framework::{expect_panic, itest};
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted, thread_safe)]
+struct ThreadCounter {
+    count: u64,
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted, thread_safe)]
+struct ThreadCounterWithBase {
+    count: u64,
+    base: Base<RefCounted>,
+}
+
+#[itest]
+fn thread_safe_gd_concurrent_bind_mut() {
+    const THREADS: u64 = 8;
+    const INCREMENTS: u64 = 1000;
+
+    let handle = ThreadSafeGd::new(ThreadCounter::new_gd());
+
+    let workers = (0..THREADS)
+        .map(|_| {
+            let mut handle = handle.clone();
+            thread::spawn(move || {
+                for _ in 0..INCREMENTS {
+                    handle.bind_mut().count += 1;
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for worker in workers {
+        worker.join().unwrap();
+    }
+
+    assert_eq!(handle.bind().count, THREADS * INCREMENTS);
+}
+
+#[itest]
+fn thread_safe_gd_concurrent_bind() {
+    let mut handle = ThreadSafeGd::new(ThreadCounter::new_gd());
+    handle.bind_mut().count = 42;
+
+    let workers = (0..4)
+        .map(|_| {
+            let handle = handle.clone();
+            thread::spawn(move || (0..100).map(|_| handle.bind().count).sum::<u64>())
+        })
+        .collect::<Vec<_>>();
+
+    for worker in workers {
+        assert_eq!(worker.join().unwrap(), 4200);
+    }
+}
+
+#[itest]
+fn thread_safe_gd_bind_blocks_until_released() {
+    let handle = ThreadSafeGd::new(ThreadCounter::new_gd());
+    let (bound_tx, bound_rx) = mpsc::channel();
+
+    let worker = {
+        let mut handle = handle.clone();
+        thread::spawn(move || {
+            let mut guard = handle.bind_mut();
+            bound_tx.send(()).unwrap();
+
+            // Main thread tries to bind in the meantime, and must wait for this guard to be dropped.
+            thread::sleep(Duration::from_millis(50));
+            guard.count = 7;
+        })
+    };
+
+    bound_rx.recv().unwrap();
+    assert_eq!(handle.bind().count, 7);
+
+    worker.join().unwrap();
+}
+
+#[itest]
+fn thread_safe_gd_engine_access_main_thread_only() {
+    let handle = ThreadSafeGd::new(ThreadCounter::new_gd());
+    let id = handle.instance_id();
+
+    let gd = handle.to_gd();
+    assert_eq!(gd.instance_id(), id);
+
+    let worker = {
+        let handle = handle.clone();
+        thread::spawn(move || (handle.try_to_gd().is_none(), handle.instance_id()))
+    };
+
+    let (denied, worker_id) = worker.join().unwrap();
+    assert!(denied, "engine access must be denied on worker threads");
+    assert_eq!(worker_id, id);
+}
+
+#[itest]
+fn thread_safe_gd_to_gd_panics_on_worker() {
+    let handle = ThreadSafeGd::new(ThreadCounter::new_gd());
+
+    let worker = {
+        let handle = handle.clone();
+        thread::spawn(move || {
+            let result = panic::catch_unwind(AssertUnwindSafe(|| handle.to_gd()));
+            (result.is_err(), handle)
+        })
+    };
+
+    let (panicked, _handle) = worker.join().unwrap();
+    assert!(panicked, "to_gd() must panic on worker threads");
+}
+
+#[itest]
+fn thread_safe_gd_base_panics_on_worker() {
+    let handle = ThreadSafeGd::new(ThreadCounterWithBase::new_gd());
+
+    let worker = {
+        let mut handle = handle.clone();
+        thread::spawn(move || {
+            let mut guard = handle.bind_mut();
+            guard.count += 1;
+
+            let base = panic::catch_unwind(AssertUnwindSafe(|| {
+                guard.base();
+            }));
+            let base_mut = panic::catch_unwind(AssertUnwindSafe(|| {
+                guard.base_mut();
+            }));
+            let to_gd = panic::catch_unwind(AssertUnwindSafe(|| guard.to_gd()));
+
+            drop(guard);
+            (base.is_err(), base_mut.is_err(), to_gd.is_err(), handle)
+        })
+    };
+
+    let (base, base_mut, to_gd, _handle) = worker.join().unwrap();
+    assert!(base, "base() must panic on worker threads");
+    assert!(base_mut, "base_mut() must panic on worker threads");
+    assert!(to_gd, "to_gd() must panic on worker threads");
+
+    // Fine on the main thread.
+    let guard = handle.bind();
+    assert_eq!(guard.count, 1);
+    assert_eq!(guard.base().get_reference_count(), 1);
+}
+
+#[itest]
+fn thread_safe_gd_clone_and_bind_on_worker() {
+    let gd = ThreadCounter::new_gd();
+    let handle = ThreadSafeGd::new(gd.clone());
+
+    // Clones created and dropped on the worker must not touch the engine reference count.
+    let worker = {
+        let handle = handle.clone();
+        thread::spawn(move || {
+            let mut clones = (0..10).map(|_| handle.clone()).collect::<Vec<_>>();
+            for clone in clones.iter_mut() {
+                clone.bind_mut().count += 1;
+            }
+            drop(clones);
+
+            handle.bind().count
+        })
+    };
+
+    assert_eq!(worker.join().unwrap(), 10);
+    assert_eq!(gd.bind().count, 10);
+
+    // Only `gd` and the one strong reference shared by all handles.
+    assert_eq!(gd.get_reference_count(), 2);
+    drop(handle);
+    assert_eq!(gd.get_reference_count(), 1);
+}
+
+#[itest(async)]
+fn thread_safe_gd_drop_last_on_worker() -> TaskHandle {
+    let gd = ThreadCounter::new_gd();
+    let handle = ThreadSafeGd::new(gd.clone());
+
+    thread::spawn(move || drop(handle)).join().unwrap();
+
+    // The reference held by the handle is not released from the worker, but deferred to the main thread.
+    assert_eq!(gd.get_reference_count(), 2);
+
+    let tree = Engine::singleton()
+        .get_main_loop()
+        .unwrap()
+        .cast::<SceneTree>();
+
+    godot::task::spawn(async move {
+        let _: () = tree.signals().process_frame().to_future().await;
+        assert_eq!(gd.get_reference_count(), 1);
+    })
+}
+
+#[itest]
+fn thread_safe_gd_reentrant_bind_panics() {
+    let mut handle = ThreadSafeGd::new(ThreadCounter::new_gd());
+    let other = handle.clone();
+
+    let _guard = other.bind();
+    expect_panic("bind_mut() while bound on same thread", || {
+        handle.bind_mut();
+    });
+}
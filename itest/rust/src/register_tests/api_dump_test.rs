@@ -0,0 +1,157 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::prelude::*;
+use godot::register::api_dump;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct ApiDumpedClass {
+    #[var]
+    level: i32,
+
+    #[var]
+    names: PackedStringArray,
+}
+
+#[godot_api]
+impl ApiDumpedClass {
+    #[constant]
+    const MAX_LEVEL: i64 = 99;
+
+    #[signal]
+    fn level_changed(new_level: i32, node: Gd<Node>);
+
+    #[func]
+    fn greet(&self, name: GString, #[opt(default = 3)] times: i32) -> GString {
+        GString::from(name.to_string().repeat(times as usize).as_str())
+    }
+
+    #[func]
+    fn reset(&mut self) {
+        self.level = 0;
+    }
+
+    #[func]
+    fn create() -> Gd<Self> {
+        Self::new_gd()
+    }
+}
+
+/// Set this environment variable to a file path, to write the API of all itest classes there while running the tests.
+const DUMP_PATH_ENV: &str = "GDRUST_ITEST_API_DUMP";
+
+#[itest]
+fn api_dump_contains_class() {
+    let json = api_dump::extension_api_json();
+
+    if let Ok(path) = std::env::var(DUMP_PATH_ENV) {
+        api_dump::write_extension_api_json(&path).expect("write API dump");
+    }
+
+    assert!(json.starts_with("{\n\t\"header\": {\n\t\t\"version_major\": 4,"));
+
+    let class = class_section(&json, "ApiDumpedClass");
+    assert!(class.contains(r#""is_refcounted": true"#));
+    assert!(class.contains(r#""is_instantiable": true"#));
+    assert!(class.contains(r#""inherits": "RefCounted""#));
+
+    // Other test classes are listed, too.
+    assert!(json.contains(r#""name": "HasConstants""#));
+}
+
+#[itest]
+fn api_dump_members() {
+    let json = api_dump::extension_api_json();
+    let class = class_section(&json, "ApiDumpedClass");
+
+    // Constants.
+    assert!(class.contains(r#""name": "MAX_LEVEL""#));
+    assert!(class.contains(r#""value": 99"#));
+
+    // Methods, including static ones and default arguments.
+    let greet = member_section(class, "greet");
+    assert!(greet.contains(r#""is_static": false"#));
+    assert!(greet.contains(r#""type": "String""#));
+    assert!(greet.contains(r#""name": "times""#));
+    assert!(greet.contains(r#""default_value": "3""#));
+
+    let reset = member_section(class, "reset");
+    assert!(!reset.contains("return_value"), "void method: {reset}");
+
+    let create = member_section(class, "create");
+    assert!(create.contains(r#""is_static": true"#));
+    assert!(create.contains(r#""type": "ApiDumpedClass""#));
+
+    // Signals.
+    let signal = member_section(class, "level_changed");
+    assert!(signal.contains(r#""name": "new_level""#));
+    assert!(signal.contains(r#""type": "int""#));
+    assert!(signal.contains(r#""type": "Node""#));
+
+    // Properties; accessors are generated by #[var].
+    assert!(class.contains(r#""type": "PackedStringArray""#));
+    #[cfg(since_api = "4.4")]
+    {
+        let level = member_section(class, "level");
+        assert!(level.contains(r#""getter": "get_level""#));
+        assert!(level.contains(r#""setter": "set_level""#));
+    }
+
+    // Inherited members are not repeated.
+    assert!(!class.contains(r#""name": "get_reference_count""#));
+}
+
+#[itest]
+fn api_dump_csharp_wrappers() {
+    let csharp = api_dump::csharp_wrappers("Itest");
+
+    assert!(csharp.contains("namespace Itest;"));
+    assert!(csharp.contains("public partial class ApiDumpedClass\n{"));
+    assert!(csharp.contains("public const long MaxLevel = 99;"));
+    assert!(csharp.contains("public string Greet(string name, long times = 3)"));
+    assert!(csharp.contains("public static ApiDumpedClass? Create()"));
+    assert!(csharp.contains("public event Action<long, Node> LevelChanged"));
+    assert!(csharp.contains("public long Level\n"));
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Helpers
+
+/// Returns the JSON text of the class entry named `class_name`.
+fn class_section<'a>(json: &'a str, class_name: &str) -> &'a str {
+    // Class entries are at indentation level 2 (inside root object and "classes" array).
+    section(
+        json,
+        &format!("\n\t\t{{\n\t\t\t\"name\": \"{class_name}\""),
+        "\n\t\t}",
+    )
+}
+
+/// Returns the JSON text of a method, signal or property named `name` within a class section.
+fn member_section<'a>(class: &'a str, name: &str) -> &'a str {
+    let name_pos = class
+        .find(&format!("\t\"name\": \"{name}\""))
+        .unwrap_or_else(|| panic!("member `{name}` not found in:\n{class}"));
+
+    // Members are at indentation level 4; their closing brace is the next one at that level.
+    let start = class[..name_pos]
+        .rfind("\n\t\t\t\t{")
+        .expect("member start");
+    section(&class[start..], "\n\t\t\t\t{", "\n\t\t\t\t}")
+}
+
+fn section<'a>(json: &'a str, start: &str, end: &str) -> &'a str {
+    let start_pos = json
+        .find(start)
+        .unwrap_or_else(|| panic!("`{start}` not found in API dump"));
+    let len = json[start_pos..].find(end).expect("section end");
+
+    &json[start_pos..start_pos + len]
+}
@@ -5,6 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+mod api_dump_test;
 mod constant_test;
 mod conversion_test;
 mod derive_godotconvert_test;
@@ -16,9 +17,13 @@ mod option_ffi_test;
 mod register_docs_test;
 #[cfg(feature = "codegen-full")]
 mod rpc_test;
+mod typed_func_test;
 mod var_test;
 
 #[cfg(since_api = "4.3")]
 mod func_virtual_test;
 
+#[cfg(since_api = "4.4")]
+mod shared_class_test;
+
 pub use gdscript_ffi_test::gen_ffi;
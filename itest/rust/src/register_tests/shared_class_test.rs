@@ -0,0 +1,261 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Most tests use this library as both providing and consuming extension; the proxies only rely on the published tables, not on Rust types.
+// `ProvidedCounter` is registered by a separate library (itest/shared-provider), to cover calls across the extension boundary.
+
+use godot::classes::ClassDb;
+use godot::prelude::*;
+use godot::register::shared::{shared_proxy, SharedClass, SharedClassError};
+
+use crate::framework::{expect_panic, itest};
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted, shared)]
+struct SharedCounter {
+    count: i64,
+}
+
+#[godot_api]
+impl SharedCounter {
+    #[func]
+    fn add(&mut self, amount: i64) -> i64 {
+        self.count += amount;
+        self.count
+    }
+
+    #[func]
+    fn count(&self) -> i64 {
+        self.count
+    }
+
+    #[func]
+    fn reset(&mut self) {
+        self.count = 0;
+    }
+
+    #[func]
+    fn describe(&self, prefix: GString, owner: Option<Gd<Node>>) -> GString {
+        let owner = match owner {
+            Some(node) => node.get_name().to_string(),
+            None => "nobody".to_string(),
+        };
+
+        format!("{prefix} {} ({owner})", self.count).into()
+    }
+
+    #[func]
+    fn make_label(count: i64) -> GString {
+        format!("count={count}").into()
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct NonSharedCounter {}
+
+/// Only used to test unloading; its table is unpublished during the test.
+#[derive(GodotClass)]
+#[class(init, base=RefCounted, shared)]
+struct SharedDisposable {}
+
+#[godot_api]
+impl SharedDisposable {
+    #[func]
+    fn ping(&self) -> i64 {
+        42
+    }
+}
+
+shared_proxy! {
+    struct CounterProxy(class = "SharedCounter") {
+        fn add(&mut self, amount: i64) -> i64;
+        fn count(&self) -> i64;
+        fn reset(&mut self);
+        fn describe(&self, prefix: GString, owner: Option<Gd<Node>>) -> GString;
+    }
+}
+
+shared_proxy! {
+    struct DisposableProxy(class = "SharedDisposable") {
+        fn ping(&self) -> i64;
+    }
+}
+
+shared_proxy! {
+    struct ProvidedCounterProxy(class = "ProvidedCounter") {
+        fn add(&mut self, amount: i64) -> i64;
+        fn count(&self) -> i64;
+        fn greet(&self, name: GString) -> GString;
+    }
+}
+
+shared_proxy! {
+    struct MismatchedCounterProxy(class = "SharedCounter") {
+        fn add(&mut self, amount: GString) -> i64;
+    }
+}
+
+#[itest]
+fn shared_class_proxy_calls() {
+    let counter = SharedCounter::new_gd();
+    let mut proxy = CounterProxy::from_object(counter.clone().upcast());
+
+    assert_eq!(proxy.add(5), 5);
+    assert_eq!(proxy.add(-2), 3);
+    assert_eq!(proxy.count(), 3);
+    assert_eq!(counter.bind().count, 3);
+
+    let mut node = Node::new_alloc();
+    node.set_name("Owner");
+    assert_eq!(
+        proxy.describe("total".into(), Some(node.clone())),
+        "total 3 (Owner)"
+    );
+    assert_eq!(proxy.describe("total".into(), None), "total 3 (nobody)");
+    node.free();
+
+    proxy.reset();
+    assert_eq!(counter.bind().count, 0);
+    assert_eq!(proxy.object().instance_id(), counter.instance_id());
+}
+
+#[itest]
+fn shared_class_static_method() {
+    let class = SharedClass::find("SharedCounter").expect("class is shared");
+    let make_label = class
+        .method::<(i64,), GString>("make_label")
+        .expect("method is shared");
+
+    assert_eq!(make_label.call(None, (7,)), "count=7");
+}
+
+#[itest]
+fn shared_class_errors() {
+    let err = SharedClass::find("NonSharedCounter").unwrap_err();
+    assert_eq!(
+        err,
+        SharedClassError::ClassNotFound {
+            class_name: "NonSharedCounter".to_string()
+        }
+    );
+
+    let err = CounterProxy::try_from_object(NonSharedCounter::new_gd().upcast())
+        .err()
+        .expect("wrong class is rejected");
+    assert_eq!(
+        err,
+        SharedClassError::WrongClass {
+            class_name: "SharedCounter".to_string(),
+            actual_class: "NonSharedCounter".to_string(),
+        }
+    );
+
+    let class = SharedClass::find("SharedCounter").unwrap();
+    let err = class.method::<(), i64>("missing").err().unwrap();
+    assert_eq!(
+        err,
+        SharedClassError::MethodNotFound {
+            class_name: "SharedCounter".to_string(),
+            method_name: "missing".to_string(),
+        }
+    );
+
+    let err = MismatchedCounterProxy::try_from_object(SharedCounter::new_gd().upcast())
+        .err()
+        .expect("signature mismatch is detected");
+    assert!(
+        matches!(&err, SharedClassError::SignatureMismatch { method_name, .. } if method_name == "add"),
+        "unexpected error: {err}"
+    );
+}
+
+#[itest]
+fn shared_class_method_requires_object() {
+    let class = SharedClass::find("SharedCounter").unwrap();
+    let count = class.method::<(), i64>("count").unwrap();
+
+    expect_panic("non-static shared method without object", || {
+        count.call(None, ());
+    });
+}
+
+#[itest]
+fn shared_class_script_meta_is_ignored() {
+    // Any script can write Engine metadata; such entries must not be able to inject a table.
+    let mut engine = godot::classes::Engine::singleton();
+    engine.set_meta("_gdext_shared_class_NonSharedCounter", &0x1234_i64.to_variant());
+
+    let err = SharedClass::find("NonSharedCounter").unwrap_err();
+    assert!(matches!(err, SharedClassError::ClassNotFound { .. }));
+
+    engine.remove_meta("_gdext_shared_class_NonSharedCounter");
+}
+
+#[itest]
+fn shared_class_unloaded() {
+    let proxy = DisposableProxy::from_object(SharedDisposable::new_gd().upcast());
+    let class = SharedClass::find("SharedDisposable").unwrap();
+    let ping = class.method::<(), i64>("ping").unwrap();
+    assert!(proxy.is_alive());
+    assert_eq!(proxy.ping(), 42);
+
+    godot::register::shared::__unpublish_for_test("SharedDisposable");
+
+    assert!(!proxy.is_alive());
+    assert!(!class.is_alive());
+    expect_panic("call after unload", || {
+        proxy.ping();
+    });
+
+    let err = class.method::<(), i64>("ping").err().unwrap();
+    assert_eq!(
+        err,
+        SharedClassError::ClassUnloaded {
+            class_name: "SharedDisposable".to_string()
+        }
+    );
+
+    let object = SharedDisposable::new_gd();
+    expect_panic("method resolved before unload", || {
+        ping.call(Some(&object.upcast()), ());
+    });
+
+    let err = SharedClass::find("SharedDisposable").unwrap_err();
+    assert!(matches!(err, SharedClassError::ClassNotFound { .. }));
+}
+
+#[itest]
+fn shared_class_cross_extension() {
+    // Not a Rust type here: instantiated by name, registered by the shared-provider library.
+    let object = ClassDb::singleton().instantiate("ProvidedCounter");
+    let object = object.to::<Gd<Object>>();
+
+    let mut proxy = ProvidedCounterProxy::from_object(object.clone());
+    assert_eq!(proxy.add(3), 3);
+    assert_eq!(proxy.add(4), 7);
+    assert_eq!(proxy.count(), 7);
+    assert_eq!(proxy.greet("itest".into()), "provider greets itest");
+
+    // Cross-check with the Variant path.
+    assert_eq!(object.clone().call("count", &[]), 7.to_variant());
+
+    let class = SharedClass::find("ProvidedCounter").unwrap();
+    let library_name = class
+        .method::<(), GString>("library_name")
+        .expect("static method is shared");
+    assert_eq!(library_name.call(None, ()), "shared-provider");
+
+    // Own class is not reachable through the provider's proxy, and vice versa.
+    let err = ProvidedCounterProxy::try_from_object(SharedCounter::new_gd().upcast())
+        .err()
+        .unwrap();
+    assert!(matches!(err, SharedClassError::WrongClass { .. }));
+
+    let err = CounterProxy::try_from_object(object).err().unwrap();
+    assert!(matches!(err, SharedClassError::WrongClass { .. }));
+}
@@ -0,0 +1,103 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::prelude::*;
+
+use crate::framework::{expect_panic, itest};
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct TypedFuncCalls {
+    total: i64,
+    base: Base<RefCounted>,
+}
+
+#[godot_api]
+impl TypedFuncCalls {
+    #[func]
+    fn add(&mut self, amount: i64) -> i64 {
+        self.total += amount;
+        self.total
+    }
+
+    #[func(rename = get_total)]
+    fn total(&self) -> i64 {
+        self.total
+    }
+
+    #[func(gd_self)]
+    fn describe(this: Gd<Self>, prefix: GString) -> GString {
+        format!("{prefix} {}", this.bind().total).into()
+    }
+
+    #[func(virtual)]
+    fn label(&self) -> GString {
+        "Rust".into()
+    }
+}
+
+#[godot_api(secondary)]
+impl TypedFuncCalls {
+    #[func]
+    fn reset(&mut self) {
+        self.total = 0;
+    }
+}
+
+#[itest]
+fn typed_func_on_base_pointer() {
+    let object = TypedFuncCalls::new_gd();
+    let mut base: Gd<RefCounted> = object.clone().upcast();
+
+    assert_eq!(base.call_func(TypedFuncCalls::funcs().add(), (5,)), 5);
+    assert_eq!(base.call_func(TypedFuncCalls::funcs().add(), (-2,)), 3);
+    assert_eq!(base.call_func(TypedFuncCalls::funcs().total(), ()), 3);
+    assert_eq!(
+        base.call_func(TypedFuncCalls::funcs().describe(), ("total".into(),)),
+        "total 3"
+    );
+
+    let mut object_ptr: Gd<Object> = object.clone().upcast();
+    object_ptr.call_func(TypedFuncCalls::funcs().reset(), ());
+    assert_eq!(object.bind().total, 0);
+}
+
+#[itest]
+fn typed_func_names() {
+    let funcs = TypedFuncCalls::funcs();
+
+    assert_eq!(funcs.add().godot_name(), &StringName::from("add"));
+    assert_eq!(funcs.total().godot_name(), &StringName::from("get_total"));
+    assert_eq!(funcs.label().godot_name(), &StringName::from("_label"));
+}
+
+#[itest]
+fn typed_func_wrong_class() {
+    let mut other = RefCounted::new_gd();
+
+    expect_panic("call_func() on object of unrelated class", || {
+        other.call_func(TypedFuncCalls::funcs().add(), (1,));
+    });
+}
+
+#[cfg(since_api = "4.3")]
+#[itest]
+fn typed_func_script_override() {
+    let code = r#"
+extends TypedFuncCalls
+
+func _label() -> String:
+    return "GDScript"
+"#;
+
+    let mut object = TypedFuncCalls::new_gd();
+    let label = TypedFuncCalls::funcs().label();
+    assert_eq!(object.call_func(label, ()), "Rust");
+
+    object.set_script(&crate::framework::create_gdscript(code));
+    assert_eq!(object.call_func(label, ()), "GDScript");
+}
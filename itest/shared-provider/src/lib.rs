@@ -0,0 +1,46 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Loaded next to itest via SharedProvider.gdextension. itest has no Rust dependency on this crate; it only knows the class through
+// the shared table, see itest/rust/src/register_tests/shared_class_test.rs.
+
+use godot::prelude::*;
+
+struct SharedProvider;
+
+#[gdextension(entry_symbol = shared_provider_init)]
+unsafe impl ExtensionLibrary for SharedProvider {}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted, shared)]
+struct ProvidedCounter {
+    count: i64,
+}
+
+#[godot_api]
+impl ProvidedCounter {
+    #[func]
+    fn add(&mut self, amount: i64) -> i64 {
+        self.count += amount;
+        self.count
+    }
+
+    #[func]
+    fn count(&self) -> i64 {
+        self.count
+    }
+
+    #[func]
+    fn greet(&self, name: GString) -> GString {
+        format!("provider greets {name}").into()
+    }
+
+    #[func]
+    fn library_name() -> GString {
+        "shared-provider".into()
+    }
+}